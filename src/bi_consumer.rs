@@ -37,6 +37,8 @@ use std::sync::{Arc, Mutex};
 
 use crate::bi_consumer_once::BiConsumerOnce;
 use crate::bi_predicate::{ArcBiPredicate, BiPredicate, BoxBiPredicate, RcBiPredicate};
+use crate::consumer::{ArcConsumer, BoxConsumer, RcConsumer};
+use crate::supplier::Supplier;
 
 /// Type alias for bi-consumer function to simplify complex types.
 ///
@@ -696,6 +698,193 @@ where
         })
     }
 
+    /// Binds the first argument, producing a consumer of the second
+    ///
+    /// `first` is moved into the returned consumer and passed by reference
+    /// on every call, so it is never cloned and any interior state it holds
+    /// is shared across calls. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - The value passed as first argument on every call
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxConsumer<U>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxBiConsumer, Consumer};
+    /// use std::cell::RefCell;
+    ///
+    /// let log = BoxBiConsumer::new(|lines: &RefCell<Vec<String>>, event: &&str| {
+    ///     lines.borrow_mut().push(event.to_string());
+    /// });
+    /// let mut on_event = log.bind_first(RefCell::new(Vec::new()));
+    /// on_event.accept(&"started");
+    /// on_event.accept(&"stopped");
+    /// ```
+    pub fn bind_first(self, first: T) -> BoxConsumer<U> {
+        let mut function = self.function;
+        BoxConsumer::new(move |u| function(&first, u))
+    }
+
+    /// Binds the second argument, producing a consumer of the first
+    ///
+    /// `second` is moved into the returned consumer and passed by reference
+    /// on every call, so it is never cloned. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `second` - The value passed as second argument on every call
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxBiConsumer, Consumer};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let log = Arc::new(Mutex::new(Vec::new()));
+    /// let l = log.clone();
+    /// let tag = BoxBiConsumer::new(move |x: &i32, tag: &String| {
+    ///     l.lock().unwrap().push(format!("{}:{}", tag, x));
+    /// });
+    /// let mut audit = tag.bind_second("audit".to_string());
+    /// audit.accept(&1);
+    /// assert_eq!(*log.lock().unwrap(), vec!["audit:1"]);
+    /// ```
+    pub fn bind_second(self, second: U) -> BoxConsumer<T> {
+        let mut function = self.function;
+        BoxConsumer::new(move |t| function(t, &second))
+    }
+
+    /// Swaps the argument order
+    ///
+    /// Returns a bi-consumer accepting `(&U, &T)` that delegates to this
+    /// bi-consumer with the arguments flipped back. The name is kept.
+    /// Consumes self.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxBiConsumer<U, T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BiConsumer, BoxBiConsumer};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let log = Arc::new(Mutex::new(Vec::new()));
+    /// let l = log.clone();
+    /// let put = BoxBiConsumer::new(move |key: &&str, value: &i32| {
+    ///     l.lock().unwrap().push(format!("{}={}", key, value));
+    /// });
+    /// let mut swapped = put.swap();
+    /// swapped.accept(&1, &"a");
+    /// assert_eq!(*log.lock().unwrap(), vec!["a=1"]);
+    /// ```
+    pub fn swap(self) -> BoxBiConsumer<U, T> {
+        let mut function = self.function;
+        BoxBiConsumer {
+            function: Box::new(move |u: &U, t: &T| function(t, u)),
+            name: self.name,
+        }
+    }
+
+    /// Feeds values drawn pairwise from two suppliers
+    ///
+    /// Calls this bi-consumer `n` times. Before each call one value is taken
+    /// from `ts` and one from `us`, in that order. The bi-consumer stays
+    /// usable afterwards.
+    ///
+    /// # Parameters
+    ///
+    /// * `ts` - The supplier of first arguments
+    /// * `us` - The supplier of second arguments
+    /// * `n` - The number of calls
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxBiConsumer, BoxSupplier};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let total = Arc::new(Mutex::new(0));
+    /// let t = total.clone();
+    /// let mut add = BoxBiConsumer::new(move |x: &i32, y: &i32| {
+    ///     *t.lock().unwrap() += x * y;
+    /// });
+    /// add.reduce_parallel(BoxSupplier::generate(1, |x: &i32| x + 1), || 10, 3);
+    /// assert_eq!(*total.lock().unwrap(), 60); // 1*10 + 2*10 + 3*10
+    /// ```
+    pub fn reduce_parallel<S1, S2>(&mut self, mut ts: S1, mut us: S2, n: usize)
+    where
+        S1: Supplier<T>,
+        S2: Supplier<U>,
+    {
+        for _ in 0..n {
+            let t = ts.get();
+            let u = us.get();
+            (self.function)(&t, &u);
+        }
+    }
+
+    /// Binds both arguments to suppliers, producing a trigger consumer
+    ///
+    /// Every `accept(&())` on the returned consumer takes one value from
+    /// `ts` and one from `us`, in that order, and passes them to this
+    /// bi-consumer. Nothing is pulled before the first call. The name is
+    /// kept. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `ts` - The supplier of first arguments
+    /// * `us` - The supplier of second arguments
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxConsumer<()>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxBiConsumer, BoxSupplier, Consumer};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let log = Arc::new(Mutex::new(Vec::new()));
+    /// let l = log.clone();
+    /// let pair = BoxBiConsumer::new(move |x: &i32, s: &&str| {
+    ///     l.lock().unwrap().push(format!("{}{}", s, x));
+    /// });
+    /// let mut tick = pair.zip_with_suppliers(BoxSupplier::generate(1, |x: &i32| x + 1), || "#");
+    /// tick.accept(&());
+    /// tick.accept(&());
+    /// assert_eq!(*log.lock().unwrap(), vec!["#1", "#2"]);
+    /// ```
+    pub fn zip_with_suppliers<S1, S2>(self, ts: S1, us: S2) -> BoxConsumer<()>
+    where
+        S1: Supplier<T> + 'static,
+        S2: Supplier<U> + 'static,
+    {
+        let mut function = self.function;
+        let mut ts = ts;
+        let mut us = us;
+        let mut consumer = BoxConsumer::new(move |_: &()| {
+            let t = ts.get();
+            let u = us.get();
+            function(&t, &u);
+        });
+        if let Some(name) = self.name {
+            consumer.set_name(name);
+        }
+        consumer
+    }
+
     /// Creates a conditional bi-consumer
     ///
     /// Returns a bi-consumer that only executes when a predicate is satisfied.
@@ -1409,6 +1598,62 @@ where
         }
     }
 
+    /// Binds the first argument, producing a consumer of the second
+    ///
+    /// The returned consumer shares this bi-consumer's function. `first` is
+    /// moved into it and passed by reference on every call, so it is never
+    /// cloned. Borrows &self, does not consume the original bi-consumer.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - The value passed as first argument on every call
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `ArcConsumer<U>`
+    pub fn bind_first(&self, first: T) -> ArcConsumer<U> {
+        let function = Arc::clone(&self.function);
+        ArcConsumer::new(move |u: &U| function.lock().unwrap()(&first, u))
+    }
+
+    /// Binds the second argument, producing a consumer of the first
+    ///
+    /// The returned consumer shares this bi-consumer's function. `second` is
+    /// moved into it and passed by reference on every call, so it is never
+    /// cloned. Borrows &self, does not consume the original bi-consumer.
+    ///
+    /// # Parameters
+    ///
+    /// * `second` - The value passed as second argument on every call
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `ArcConsumer<T>`
+    pub fn bind_second(&self, second: U) -> ArcConsumer<T> {
+        let function = Arc::clone(&self.function);
+        ArcConsumer::new(move |t: &T| function.lock().unwrap()(t, &second))
+    }
+
+    /// Swaps the argument order
+    ///
+    /// Returns a bi-consumer accepting `(&U, &T)` that shares this
+    /// bi-consumer's function and calls it with the arguments flipped
+    /// back. The name is kept. Borrows &self, does not consume the
+    /// original bi-consumer.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `ArcBiConsumer<U, T>`
+    pub fn swap(&self) -> ArcBiConsumer<U, T> {
+        let function = Arc::clone(&self.function);
+        ArcBiConsumer {
+            function: Arc::new(Mutex::new(move |u: &U, t: &T| {
+                function.lock().unwrap()(t, u)
+            })),
+            name: self.name.clone(),
+        }
+    }
+
     /// Creates a conditional bi-consumer (thread-safe version)
     ///
     /// Returns a bi-consumer that only executes when a predicate is satisfied.
@@ -2141,6 +2386,62 @@ where
         }
     }
 
+    /// Binds the first argument, producing a consumer of the second
+    ///
+    /// The returned consumer shares this bi-consumer's function. `first` is
+    /// moved into it and passed by reference on every call, so it is never
+    /// cloned. Borrows &self, does not consume the original bi-consumer.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - The value passed as first argument on every call
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `RcConsumer<U>`
+    pub fn bind_first(&self, first: T) -> RcConsumer<U> {
+        let function = Rc::clone(&self.function);
+        RcConsumer::new(move |u: &U| function.borrow_mut()(&first, u))
+    }
+
+    /// Binds the second argument, producing a consumer of the first
+    ///
+    /// The returned consumer shares this bi-consumer's function. `second` is
+    /// moved into it and passed by reference on every call, so it is never
+    /// cloned. Borrows &self, does not consume the original bi-consumer.
+    ///
+    /// # Parameters
+    ///
+    /// * `second` - The value passed as second argument on every call
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `RcConsumer<T>`
+    pub fn bind_second(&self, second: U) -> RcConsumer<T> {
+        let function = Rc::clone(&self.function);
+        RcConsumer::new(move |t: &T| function.borrow_mut()(t, &second))
+    }
+
+    /// Swaps the argument order
+    ///
+    /// Returns a bi-consumer accepting `(&U, &T)` that shares this
+    /// bi-consumer's function and calls it with the arguments flipped
+    /// back. The name is kept. Borrows &self, does not consume the
+    /// original bi-consumer.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `RcBiConsumer<U, T>`
+    pub fn swap(&self) -> RcBiConsumer<U, T> {
+        let function = Rc::clone(&self.function);
+        RcBiConsumer {
+            function: Rc::new(RefCell::new(move |u: &U, t: &T| {
+                function.borrow_mut()(t, u)
+            })),
+            name: self.name.clone(),
+        }
+    }
+
     /// Creates a conditional bi-consumer (single-threaded shared version)
     ///
     /// Returns a bi-consumer that only executes when a predicate is satisfied.
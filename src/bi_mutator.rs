@@ -0,0 +1,1000 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # BiMutator Types
+//!
+//! Provides bi-mutator interface implementations for operations accepting
+//! two mutable input parameters and returning no result.
+//!
+//! This module provides a unified `BiMutator` trait and three concrete
+//! implementations based on different ownership models:
+//!
+//! - **`BoxBiMutator<T, U>`**: Box-based single ownership for one-time use
+//! - **`ArcBiMutator<T, U>`**: Arc<Mutex<>>-based thread-safe shared
+//!   ownership
+//! - **`RcBiMutator<T, U>`**: Rc<RefCell<>>-based single-threaded shared
+//!   ownership
+//!
+//! # Design Philosophy
+//!
+//! Unlike `BiConsumer` which observes two values without modifying them
+//! (`FnMut(&T, &U)`), `BiMutator` is designed to **modify both input
+//! values** using `FnMut(&mut T, &mut U)`. Typical use cases are swapping,
+//! ordering or transferring state between two values.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Type alias for bi-mutator function signature
+type BiMutatorFn<T, U> = dyn FnMut(&mut T, &mut U);
+
+/// Type alias for thread-safe bi-mutator function signature
+type SendBiMutatorFn<T, U> = dyn FnMut(&mut T, &mut U) + Send;
+
+// =======================================================================
+// 1. BiMutator Trait - Unified BiMutator Interface
+// =======================================================================
+
+/// BiMutator trait - Unified bi-mutator interface
+///
+/// Defines the core behavior of all bi-mutator types. Performs operations
+/// that accept two mutable references and modify the input values.
+///
+/// This trait is automatically implemented by:
+/// - All closures implementing `FnMut(&mut T, &mut U)`
+/// - `BoxBiMutator<T, U>`, `ArcBiMutator<T, U>` and `RcBiMutator<T, U>`
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BiMutator, BoxBiMutator};
+///
+/// fn apply_bi_mutator<M: BiMutator<i32, i32>>(
+///     mutator: &mut M,
+///     a: &mut i32,
+///     b: &mut i32,
+/// ) {
+///     mutator.mutate(a, b);
+/// }
+///
+/// let mut box_mut = BoxBiMutator::new(|x: &mut i32, y: &mut i32| {
+///     *x += *y;
+///     *y = 0;
+/// });
+/// let (mut a, mut b) = (5, 3);
+/// apply_bi_mutator(&mut box_mut, &mut a, &mut b);
+/// assert_eq!((a, b), (8, 0));
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub trait BiMutator<T, U> {
+    /// Performs the mutation operation
+    ///
+    /// Executes an operation on the given two mutable references. The
+    /// operation typically modifies one or both input values.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - Mutable reference to the first value to be mutated
+    /// * `second` - Mutable reference to the second value to be mutated
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BiMutator, BoxBiMutator};
+    ///
+    /// let mut mutator = BoxBiMutator::new(|x: &mut i32, y: &mut String| {
+    ///     *x += 1;
+    ///     y.push('!');
+    /// });
+    /// let mut a = 1;
+    /// let mut b = String::from("hi");
+    /// mutator.mutate(&mut a, &mut b);
+    /// assert_eq!(a, 2);
+    /// assert_eq!(b, "hi!");
+    /// ```
+    fn mutate(&mut self, first: &mut T, second: &mut U);
+
+    /// Converts to BoxBiMutator
+    ///
+    /// **⚠️ Consumes `self`**: Original bi-mutator becomes unavailable
+    /// after calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `BoxBiMutator<T, U>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::BiMutator;
+    ///
+    /// let closure = |x: &mut i32, y: &mut i32| *x += *y;
+    /// let mut boxed = closure.into_box();
+    /// let (mut a, mut b) = (5, 3);
+    /// boxed.mutate(&mut a, &mut b);
+    /// assert_eq!(a, 8);
+    /// ```
+    fn into_box(self) -> BoxBiMutator<T, U>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        U: 'static,
+    {
+        let mut mutator = self;
+        BoxBiMutator::new(move |t, u| mutator.mutate(t, u))
+    }
+
+    /// Converts to RcBiMutator
+    ///
+    /// **⚠️ Consumes `self`**: Original bi-mutator becomes unavailable
+    /// after calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `RcBiMutator<T, U>`
+    fn into_rc(self) -> RcBiMutator<T, U>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        U: 'static,
+    {
+        let mut mutator = self;
+        RcBiMutator::new(move |t, u| mutator.mutate(t, u))
+    }
+
+    /// Converts to ArcBiMutator
+    ///
+    /// **⚠️ Consumes `self`**: Original bi-mutator becomes unavailable
+    /// after calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `ArcBiMutator<T, U>`
+    fn into_arc(self) -> ArcBiMutator<T, U>
+    where
+        Self: Sized + Send + 'static,
+        T: Send + 'static,
+        U: Send + 'static,
+    {
+        let mut mutator = self;
+        ArcBiMutator::new(move |t, u| mutator.mutate(t, u))
+    }
+
+    /// Converts bi-mutator to a closure
+    ///
+    /// **⚠️ Consumes `self`**: Original bi-mutator becomes unavailable
+    /// after calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns a closure implementing `FnMut(&mut T, &mut U)`
+    fn into_fn(self) -> impl FnMut(&mut T, &mut U)
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        U: 'static,
+    {
+        let mut mutator = self;
+        move |t, u| mutator.mutate(t, u)
+    }
+
+    /// Converts to BoxBiMutator (non-consuming)
+    ///
+    /// **⚠️ Requires Clone**: Original bi-mutator must implement Clone.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `BoxBiMutator<T, U>` from the clone
+    fn to_box(&self) -> BoxBiMutator<T, U>
+    where
+        Self: Sized + Clone + 'static,
+        T: 'static,
+        U: 'static,
+    {
+        self.clone().into_box()
+    }
+
+    /// Converts to RcBiMutator (non-consuming)
+    ///
+    /// **⚠️ Requires Clone**: Original bi-mutator must implement Clone.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `RcBiMutator<T, U>` from the clone
+    fn to_rc(&self) -> RcBiMutator<T, U>
+    where
+        Self: Sized + Clone + 'static,
+        T: 'static,
+        U: 'static,
+    {
+        self.clone().into_rc()
+    }
+
+    /// Converts to ArcBiMutator (non-consuming)
+    ///
+    /// **⚠️ Requires Clone + Send**: Original bi-mutator must implement
+    /// Clone + Send.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `ArcBiMutator<T, U>` from the clone
+    fn to_arc(&self) -> ArcBiMutator<T, U>
+    where
+        Self: Sized + Clone + Send + 'static,
+        T: Send + 'static,
+        U: Send + 'static,
+    {
+        self.clone().into_arc()
+    }
+
+    /// Converts to a closure (non-consuming)
+    ///
+    /// **⚠️ Requires Clone**: Original bi-mutator must implement Clone.
+    ///
+    /// # Returns
+    ///
+    /// Returns a closure implementing `FnMut(&mut T, &mut U)` from the clone
+    fn to_fn(&self) -> impl FnMut(&mut T, &mut U)
+    where
+        Self: Sized + Clone + 'static,
+        T: 'static,
+        U: 'static,
+    {
+        self.clone().into_fn()
+    }
+}
+
+// =======================================================================
+// 2. BoxBiMutator - Single Ownership Implementation
+// =======================================================================
+
+/// BoxBiMutator struct
+///
+/// A bi-mutator implementation based on `Box<dyn FnMut(&mut T, &mut U)>`
+/// for single ownership scenarios.
+///
+/// # Features
+///
+/// - **Single Ownership**: Not cloneable, ownership moves on use
+/// - **Zero Overhead**: No reference counting or locking
+/// - **Mutable State**: Can modify captured environment via `FnMut`
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BiMutator, BoxBiMutator};
+///
+/// let mut mutator = BoxBiMutator::new(|x: &mut i32, y: &mut i32| {
+///     std::mem::swap(x, y);
+/// });
+/// let (mut a, mut b) = (1, 2);
+/// mutator.mutate(&mut a, &mut b);
+/// assert_eq!((a, b), (2, 1));
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct BoxBiMutator<T, U> {
+    function: Box<BiMutatorFn<T, U>>,
+}
+
+impl<T, U> BoxBiMutator<T, U>
+where
+    T: 'static,
+    U: 'static,
+{
+    /// Creates a new BoxBiMutator
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxBiMutator<T, U>` instance
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BiMutator, BoxBiMutator};
+    ///
+    /// let mut mutator = BoxBiMutator::new(|x: &mut i32, y: &mut i32| {
+    ///     *x *= 2;
+    ///     *y *= 3;
+    /// });
+    /// let (mut a, mut b) = (1, 1);
+    /// mutator.mutate(&mut a, &mut b);
+    /// assert_eq!((a, b), (2, 3));
+    /// ```
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnMut(&mut T, &mut U) + 'static,
+    {
+        BoxBiMutator {
+            function: Box::new(f),
+        }
+    }
+
+    /// Creates a no-op bi-mutator
+    ///
+    /// # Returns
+    ///
+    /// Returns a bi-mutator that leaves both values unchanged
+    pub fn noop() -> Self {
+        BoxBiMutator::new(|_, _| {})
+    }
+
+    /// Chains another bi-mutator in sequence
+    ///
+    /// Returns a new bi-mutator executing the current operation first, then
+    /// the next operation on the already mutated values. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - The bi-mutator to execute after the current operation.
+    ///   **Note: This parameter is passed by value and will transfer
+    ///   ownership.** Can be:
+    ///   - A closure: `|x: &mut T, y: &mut U|`
+    ///   - A `BoxBiMutator<T, U>`
+    ///   - An `ArcBiMutator<T, U>`
+    ///   - An `RcBiMutator<T, U>`
+    ///   - Any type implementing `BiMutator<T, U>`
+    ///
+    /// # Returns
+    ///
+    /// Returns a new composed `BoxBiMutator<T, U>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BiMutator, BoxBiMutator};
+    ///
+    /// let mut chained = BoxBiMutator::new(|x: &mut i32, y: &mut i32| {
+    ///     *x += *y;
+    /// })
+    /// .and_then(|x: &mut i32, y: &mut i32| *y = *x * 2);
+    /// let (mut a, mut b) = (1, 2);
+    /// chained.mutate(&mut a, &mut b);
+    /// assert_eq!((a, b), (3, 6));
+    /// ```
+    pub fn and_then<C>(self, next: C) -> Self
+    where
+        C: BiMutator<T, U> + 'static,
+    {
+        let mut first = self.function;
+        let mut second = next;
+        BoxBiMutator::new(move |t, u| {
+            first(t, u);
+            second.mutate(t, u);
+        })
+    }
+}
+
+impl<T> BoxBiMutator<T, T>
+where
+    T: 'static,
+{
+    /// Creates a bi-mutator that swaps its two arguments
+    ///
+    /// # Returns
+    ///
+    /// Returns a `BoxBiMutator<T, T>` calling `std::mem::swap` on its
+    /// arguments
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BiMutator, BoxBiMutator};
+    ///
+    /// let mut swap = BoxBiMutator::swap();
+    /// let (mut a, mut b) = ("left".to_string(), "right".to_string());
+    /// swap.mutate(&mut a, &mut b);
+    /// assert_eq!((a.as_str(), b.as_str()), ("right", "left"));
+    /// ```
+    pub fn swap() -> Self {
+        BoxBiMutator::new(|a, b| std::mem::swap(a, b))
+    }
+}
+
+impl<T, U> BiMutator<T, U> for BoxBiMutator<T, U> {
+    fn mutate(&mut self, first: &mut T, second: &mut U) {
+        (self.function)(first, second)
+    }
+
+    fn into_box(self) -> BoxBiMutator<T, U>
+    where
+        T: 'static,
+        U: 'static,
+    {
+        self
+    }
+
+    fn into_rc(self) -> RcBiMutator<T, U>
+    where
+        T: 'static,
+        U: 'static,
+    {
+        let mut func = self.function;
+        RcBiMutator::new(move |t, u| func(t, u))
+    }
+
+    // do NOT override BiMutator::into_arc() because BoxBiMutator is not Send + Sync
+    // and calling BoxBiMutator::into_arc() will cause a compile error
+
+    fn into_fn(self) -> impl FnMut(&mut T, &mut U)
+    where
+        T: 'static,
+        U: 'static,
+    {
+        self.function
+    }
+
+    // do NOT override BiMutator::to_xxx() because BoxBiMutator is not Clone
+    // and calling BoxBiMutator::to_xxx() will cause a compile error
+}
+
+impl<T, U> fmt::Debug for BoxBiMutator<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxBiMutator")
+            .field("function", &"<function>")
+            .finish()
+    }
+}
+
+// =======================================================================
+// 3. ArcBiMutator - Thread-Safe Shared Ownership Implementation
+// =======================================================================
+
+/// ArcBiMutator struct
+///
+/// A bi-mutator implementation based on
+/// `Arc<Mutex<dyn FnMut(&mut T, &mut U) + Send>>` for thread-safe shared
+/// ownership scenarios. Clones share the same underlying function and its
+/// captured state.
+///
+/// # Features
+///
+/// - **Shared Ownership**: Cloneable via `Arc`
+/// - **Thread Safe**: Implements `Send + Sync`
+/// - **Interior Mutability**: Uses `Mutex` for the `FnMut` state, recovering
+///   from poisoning so one panicking call does not break the clones
+/// - **Non-Consuming API**: `and_then` borrows `&self`
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{ArcBiMutator, BiMutator};
+/// use std::thread;
+///
+/// let transfer = ArcBiMutator::new(|from: &mut i32, to: &mut i32| {
+///     *to += *from;
+///     *from = 0;
+/// });
+/// let mut clone = transfer.clone();
+/// let handle = thread::spawn(move || {
+///     let (mut a, mut b) = (5, 1);
+///     clone.mutate(&mut a, &mut b);
+///     (a, b)
+/// });
+/// assert_eq!(handle.join().unwrap(), (0, 6));
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct ArcBiMutator<T, U> {
+    function: Arc<Mutex<SendBiMutatorFn<T, U>>>,
+}
+
+impl<T, U> ArcBiMutator<T, U>
+where
+    T: Send + 'static,
+    U: Send + 'static,
+{
+    /// Creates a new ArcBiMutator
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `ArcBiMutator<T, U>` instance
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnMut(&mut T, &mut U) + Send + 'static,
+    {
+        ArcBiMutator {
+            function: Arc::new(Mutex::new(f)),
+        }
+    }
+
+    /// Creates a no-op bi-mutator
+    ///
+    /// # Returns
+    ///
+    /// Returns a bi-mutator that leaves both values unchanged
+    pub fn noop() -> Self {
+        ArcBiMutator::new(|_, _| {})
+    }
+
+    /// Chains another ArcBiMutator in sequence
+    ///
+    /// Returns a new bi-mutator executing the current operation first, then
+    /// the next operation. Borrows &self, does not consume the original
+    /// bi-mutator.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - The bi-mutator to execute after the current operation.
+    ///   **Note: This parameter is passed by reference, so the original
+    ///   bi-mutator remains usable.**
+    ///
+    /// # Returns
+    ///
+    /// Returns a new composed `ArcBiMutator<T, U>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcBiMutator, BiMutator};
+    ///
+    /// let first = ArcBiMutator::new(|x: &mut i32, y: &mut i32| *x += *y);
+    /// let second = ArcBiMutator::new(|x: &mut i32, y: &mut i32| *y *= *x);
+    /// let mut chained = first.and_then(&second);
+    /// let (mut a, mut b) = (1, 2);
+    /// chained.mutate(&mut a, &mut b);
+    /// assert_eq!((a, b), (3, 6));
+    /// ```
+    pub fn and_then(&self, next: &ArcBiMutator<T, U>) -> ArcBiMutator<T, U> {
+        let first = Arc::clone(&self.function);
+        let second = Arc::clone(&next.function);
+        ArcBiMutator {
+            function: Arc::new(Mutex::new(move |t: &mut T, u: &mut U| {
+                first.lock().unwrap_or_else(PoisonError::into_inner)(t, u);
+                second.lock().unwrap_or_else(PoisonError::into_inner)(t, u);
+            })),
+        }
+    }
+}
+
+impl<T> ArcBiMutator<T, T>
+where
+    T: Send + 'static,
+{
+    /// Creates a bi-mutator that swaps its two arguments
+    ///
+    /// # Returns
+    ///
+    /// Returns an `ArcBiMutator<T, T>` calling `std::mem::swap` on its
+    /// arguments
+    pub fn swap() -> Self {
+        ArcBiMutator::new(|a, b| std::mem::swap(a, b))
+    }
+}
+
+impl<T, U> BiMutator<T, U> for ArcBiMutator<T, U> {
+    fn mutate(&mut self, first: &mut T, second: &mut U) {
+        (self.function.lock().unwrap_or_else(PoisonError::into_inner))(first, second)
+    }
+
+    fn into_box(self) -> BoxBiMutator<T, U>
+    where
+        T: 'static,
+        U: 'static,
+    {
+        let self_fn = self.function;
+        BoxBiMutator::new(move |t, u| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t, u))
+    }
+
+    fn into_rc(self) -> RcBiMutator<T, U>
+    where
+        T: 'static,
+        U: 'static,
+    {
+        let self_fn = self.function;
+        RcBiMutator::new(move |t, u| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t, u))
+    }
+
+    fn into_arc(self) -> ArcBiMutator<T, U>
+    where
+        T: Send + 'static,
+        U: Send + 'static,
+    {
+        self
+    }
+
+    fn into_fn(self) -> impl FnMut(&mut T, &mut U)
+    where
+        T: 'static,
+        U: 'static,
+    {
+        let self_fn = self.function;
+        move |t, u| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t, u)
+    }
+
+    fn to_box(&self) -> BoxBiMutator<T, U>
+    where
+        T: 'static,
+        U: 'static,
+    {
+        let self_fn = self.function.clone();
+        BoxBiMutator::new(move |t, u| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t, u))
+    }
+
+    fn to_rc(&self) -> RcBiMutator<T, U>
+    where
+        T: 'static,
+        U: 'static,
+    {
+        let self_fn = self.function.clone();
+        RcBiMutator::new(move |t, u| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t, u))
+    }
+
+    fn to_arc(&self) -> ArcBiMutator<T, U>
+    where
+        T: Send + 'static,
+        U: Send + 'static,
+    {
+        self.clone()
+    }
+
+    fn to_fn(&self) -> impl FnMut(&mut T, &mut U)
+    where
+        T: 'static,
+        U: 'static,
+    {
+        let self_fn = self.function.clone();
+        move |t, u| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t, u)
+    }
+}
+
+impl<T, U> Clone for ArcBiMutator<T, U> {
+    /// Clones the ArcBiMutator
+    ///
+    /// Creates a new ArcBiMutator sharing the underlying function with the
+    /// original instance.
+    fn clone(&self) -> Self {
+        ArcBiMutator {
+            function: self.function.clone(),
+        }
+    }
+}
+
+impl<T, U> fmt::Debug for ArcBiMutator<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArcBiMutator")
+            .field("function", &"<function>")
+            .finish()
+    }
+}
+
+// =======================================================================
+// 4. RcBiMutator - Single-Threaded Shared Ownership Implementation
+// =======================================================================
+
+/// RcBiMutator struct
+///
+/// A bi-mutator implementation based on
+/// `Rc<RefCell<dyn FnMut(&mut T, &mut U)>>` for single-threaded shared
+/// ownership scenarios. No lock overhead.
+///
+/// # Features
+///
+/// - **Shared Ownership**: Cloneable via `Rc`
+/// - **Single-Threaded**: Not thread-safe
+/// - **Interior Mutability**: Uses `RefCell` for the `FnMut` state
+/// - **Non-Consuming API**: `and_then` borrows `&self`
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BiMutator, RcBiMutator};
+///
+/// let mut swap = RcBiMutator::swap();
+/// let mut clone = swap.clone();
+/// let (mut a, mut b) = (1, 2);
+/// swap.mutate(&mut a, &mut b);
+/// clone.mutate(&mut a, &mut b);
+/// assert_eq!((a, b), (1, 2));
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct RcBiMutator<T, U> {
+    function: Rc<RefCell<BiMutatorFn<T, U>>>,
+}
+
+impl<T, U> RcBiMutator<T, U>
+where
+    T: 'static,
+    U: 'static,
+{
+    /// Creates a new RcBiMutator
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `RcBiMutator<T, U>` instance
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnMut(&mut T, &mut U) + 'static,
+    {
+        RcBiMutator {
+            function: Rc::new(RefCell::new(f)),
+        }
+    }
+
+    /// Creates a no-op bi-mutator
+    ///
+    /// # Returns
+    ///
+    /// Returns a bi-mutator that leaves both values unchanged
+    pub fn noop() -> Self {
+        RcBiMutator::new(|_, _| {})
+    }
+
+    /// Chains another RcBiMutator in sequence
+    ///
+    /// Returns a new bi-mutator executing the current operation first, then
+    /// the next operation. Borrows &self, does not consume the original
+    /// bi-mutator.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - The bi-mutator to execute after the current operation.
+    ///   **Note: This parameter is passed by reference, so the original
+    ///   bi-mutator remains usable.**
+    ///
+    /// # Returns
+    ///
+    /// Returns a new composed `RcBiMutator<T, U>`
+    pub fn and_then(&self, next: &RcBiMutator<T, U>) -> RcBiMutator<T, U> {
+        let first = Rc::clone(&self.function);
+        let second = Rc::clone(&next.function);
+        RcBiMutator {
+            function: Rc::new(RefCell::new(move |t: &mut T, u: &mut U| {
+                first.borrow_mut()(t, u);
+                second.borrow_mut()(t, u);
+            })),
+        }
+    }
+}
+
+impl<T> RcBiMutator<T, T>
+where
+    T: 'static,
+{
+    /// Creates a bi-mutator that swaps its two arguments
+    ///
+    /// # Returns
+    ///
+    /// Returns an `RcBiMutator<T, T>` calling `std::mem::swap` on its
+    /// arguments
+    pub fn swap() -> Self {
+        RcBiMutator::new(|a, b| std::mem::swap(a, b))
+    }
+}
+
+impl<T, U> BiMutator<T, U> for RcBiMutator<T, U> {
+    fn mutate(&mut self, first: &mut T, second: &mut U) {
+        (self.function.borrow_mut())(first, second)
+    }
+
+    fn into_box(self) -> BoxBiMutator<T, U>
+    where
+        T: 'static,
+        U: 'static,
+    {
+        let self_fn = self.function;
+        BoxBiMutator::new(move |t, u| self_fn.borrow_mut()(t, u))
+    }
+
+    fn into_rc(self) -> RcBiMutator<T, U>
+    where
+        T: 'static,
+        U: 'static,
+    {
+        self
+    }
+
+    // do NOT override BiMutator::into_arc() because RcBiMutator is not Send + Sync
+    // and calling RcBiMutator::into_arc() will cause a compile error
+
+    fn into_fn(self) -> impl FnMut(&mut T, &mut U)
+    where
+        T: 'static,
+        U: 'static,
+    {
+        let self_fn = self.function;
+        move |t, u| self_fn.borrow_mut()(t, u)
+    }
+
+    fn to_box(&self) -> BoxBiMutator<T, U>
+    where
+        T: 'static,
+        U: 'static,
+    {
+        let self_fn = self.function.clone();
+        BoxBiMutator::new(move |t, u| self_fn.borrow_mut()(t, u))
+    }
+
+    fn to_rc(&self) -> RcBiMutator<T, U>
+    where
+        T: 'static,
+        U: 'static,
+    {
+        self.clone()
+    }
+
+    // do NOT override BiMutator::to_arc() because RcBiMutator is not Send + Sync
+    // and calling RcBiMutator::to_arc() will cause a compile error
+
+    fn to_fn(&self) -> impl FnMut(&mut T, &mut U)
+    where
+        T: 'static,
+        U: 'static,
+    {
+        let self_fn = self.function.clone();
+        move |t, u| self_fn.borrow_mut()(t, u)
+    }
+}
+
+impl<T, U> Clone for RcBiMutator<T, U> {
+    /// Clones the RcBiMutator
+    ///
+    /// Creates a new RcBiMutator sharing the underlying function with the
+    /// original instance.
+    fn clone(&self) -> Self {
+        RcBiMutator {
+            function: self.function.clone(),
+        }
+    }
+}
+
+impl<T, U> fmt::Debug for RcBiMutator<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RcBiMutator")
+            .field("function", &"<function>")
+            .finish()
+    }
+}
+
+// =======================================================================
+// 5. Implement BiMutator trait for closures
+// =======================================================================
+
+/// Implements BiMutator for all FnMut(&mut T, &mut U)
+impl<T, U, F> BiMutator<T, U> for F
+where
+    F: FnMut(&mut T, &mut U),
+{
+    fn mutate(&mut self, first: &mut T, second: &mut U) {
+        self(first, second)
+    }
+
+    fn into_box(self) -> BoxBiMutator<T, U>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        U: 'static,
+    {
+        BoxBiMutator::new(self)
+    }
+
+    fn into_rc(self) -> RcBiMutator<T, U>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        U: 'static,
+    {
+        RcBiMutator::new(self)
+    }
+
+    fn into_arc(self) -> ArcBiMutator<T, U>
+    where
+        Self: Sized + Send + 'static,
+        T: Send + 'static,
+        U: Send + 'static,
+    {
+        ArcBiMutator::new(self)
+    }
+
+    fn into_fn(self) -> impl FnMut(&mut T, &mut U)
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        U: 'static,
+    {
+        self
+    }
+
+    fn to_fn(&self) -> impl FnMut(&mut T, &mut U)
+    where
+        Self: Sized + Clone + 'static,
+        T: 'static,
+        U: 'static,
+    {
+        self.clone()
+    }
+}
+
+// =======================================================================
+// 6. Provide extension methods for closures
+// =======================================================================
+
+/// Extension trait providing bi-mutator composition methods for closures
+///
+/// Provides `and_then` for all closures implementing
+/// `FnMut(&mut T, &mut U)`, enabling direct method chaining on closures
+/// without explicit wrapper types.
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BiMutator, FnBiMutatorOps};
+///
+/// let mut chained = (|x: &mut i32, y: &mut i32| *x += 1)
+///     .and_then(|x: &mut i32, y: &mut i32| std::mem::swap(x, y));
+/// let (mut a, mut b) = (1, 10);
+/// chained.mutate(&mut a, &mut b);
+/// assert_eq!((a, b), (10, 2));
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub trait FnBiMutatorOps<T, U>: FnMut(&mut T, &mut U) + Sized {
+    /// Chains another bi-mutator in sequence
+    ///
+    /// Returns a new bi-mutator executing the current closure first, then
+    /// the next operation. Consumes the current closure and returns
+    /// `BoxBiMutator<T, U>`.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - The bi-mutator to execute after the current operation.
+    ///   **Note: This parameter is passed by value and will transfer
+    ///   ownership.** Can be a closure or any type implementing
+    ///   `BiMutator<T, U>`
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `BoxBiMutator<T, U>`
+    fn and_then<C>(self, next: C) -> BoxBiMutator<T, U>
+    where
+        Self: 'static,
+        C: BiMutator<T, U> + 'static,
+        T: 'static,
+        U: 'static,
+    {
+        let mut first = self;
+        let mut second = next;
+        BoxBiMutator::new(move |t, u| {
+            first(t, u);
+            second.mutate(t, u);
+        })
+    }
+}
+
+/// Implements FnBiMutatorOps for all closure types
+impl<T, U, F> FnBiMutatorOps<T, U> for F where F: FnMut(&mut T, &mut U) {}
@@ -23,10 +23,13 @@
 //!
 //! Hu Haixing
 
+use std::ops::{Add, Mul};
 use std::rc::Rc;
 use std::sync::Arc;
 
 use crate::bi_predicate::{ArcBiPredicate, BiPredicate, BoxBiPredicate, RcBiPredicate};
+use crate::mapper::BoxMapper;
+use crate::transformer::{BoxTransformer, Transformer};
 
 // ============================================================================
 // Core Trait
@@ -420,6 +423,169 @@ where
     }
 }
 
+impl<T, U, R> BoxBiTransformer<T, U, R>
+where
+    T: Clone + 'static,
+    U: 'static,
+    R: 'static,
+{
+    /// Binds the first argument, returning a single-argument transformer
+    ///
+    /// The returned transformer calls this bi-transformer with a clone of
+    /// `first` and the argument it receives. Consumes self.
+    ///
+    /// # Note
+    ///
+    /// Because a bi-transformer takes its arguments by value, every
+    /// application consumes the bound argument. It is therefore stored
+    /// once and cloned on each call, which is why `T: Clone` is required.
+    /// Borrowing the bound value instead is not possible with by-value
+    /// semantics.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - The value bound to the first argument
+    ///
+    /// # Returns
+    ///
+    /// A `BoxTransformer<U, R>` taking the remaining second argument
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxBiTransformer, Transformer};
+    ///
+    /// let concat = BoxBiTransformer::new(|a: String, b: String| a + &b);
+    /// let greet = concat.partial_apply_left("Hello, ".to_string());
+    ///
+    /// assert_eq!(greet.apply("Alice".to_string()), "Hello, Alice");
+    /// assert_eq!(greet.apply("Bob".to_string()), "Hello, Bob");
+    /// ```
+    pub fn partial_apply_left(self, first: T) -> BoxTransformer<U, R> {
+        let self_fn = self.function;
+        BoxTransformer::new(move |u: U| self_fn(first.clone(), u))
+    }
+}
+
+impl<T, U, R> BoxBiTransformer<T, U, R>
+where
+    T: 'static,
+    U: Clone + 'static,
+    R: 'static,
+{
+    /// Binds the second argument, returning a single-argument transformer
+    ///
+    /// The returned transformer calls this bi-transformer with the
+    /// argument it receives and a clone of `second`. Consumes self.
+    ///
+    /// # Note
+    ///
+    /// As with [`partial_apply_left`](BoxBiTransformer::partial_apply_left),
+    /// the bound argument is cloned on every call because each application
+    /// takes ownership of it, so `U: Clone` is required.
+    ///
+    /// # Parameters
+    ///
+    /// * `second` - The value bound to the second argument
+    ///
+    /// # Returns
+    ///
+    /// A `BoxTransformer<T, R>` taking the remaining first argument
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxBiTransformer, Transformer};
+    ///
+    /// let sub = BoxBiTransformer::new(|x: i32, y: i32| x - y);
+    /// let minus_ten = sub.partial_apply_right(10);
+    ///
+    /// assert_eq!(minus_ten.apply(42), 32);
+    /// ```
+    pub fn partial_apply_right(self, second: U) -> BoxTransformer<T, R> {
+        let self_fn = self.function;
+        BoxTransformer::new(move |t: T| self_fn(t, second.clone()))
+    }
+}
+
+impl<T, U, A, B> BoxBiTransformer<T, U, (A, B)>
+where
+    T: 'static,
+    U: 'static,
+    A: 'static,
+    B: 'static,
+{
+    /// Creates a bi-transformer from a pair of transformers
+    ///
+    /// The resulting bi-transformer applies `first` to its first argument
+    /// and `second` to its second argument, returning both results as a
+    /// tuple. This is the two-argument counterpart of
+    /// `BoxTransformer::product`.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - The transformer applied to the first argument
+    /// * `second` - The transformer applied to the second argument
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxBiTransformer<T, U, (A, B)>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxBiTransformer, BiTransformer};
+    ///
+    /// let pair = BoxBiTransformer::from_pair(|x: i32| x * 2, |s: String| s.len());
+    /// assert_eq!(pair.apply(21, "abc".to_string()), (42, 3));
+    /// ```
+    pub fn from_pair<F, G>(first: F, second: G) -> Self
+    where
+        F: Transformer<T, A> + 'static,
+        G: Transformer<U, B> + 'static,
+    {
+        BoxBiTransformer::new(move |t: T, u: U| (first.apply(t), second.apply(u)))
+    }
+}
+
+impl<T> BoxBiTransformer<T, T, T>
+where
+    T: Add<Output = T> + 'static,
+{
+    /// Creates a binary operator adding its two operands
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BinaryOperator, BoxBinaryOperator};
+    ///
+    /// let sum = BoxBinaryOperator::<i32>::sum();
+    /// assert_eq!(sum.reduce(vec![1, 2, 3, 4]), Some(10));
+    /// ```
+    pub fn sum() -> Self {
+        BoxBiTransformer::new(|x: T, y: T| x + y)
+    }
+}
+
+impl<T> BoxBiTransformer<T, T, T>
+where
+    T: Mul<Output = T> + 'static,
+{
+    /// Creates a binary operator multiplying its two operands
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BinaryOperator, BoxBinaryOperator};
+    ///
+    /// let product = BoxBinaryOperator::<i64>::product();
+    /// assert_eq!(product.fold(vec![2, 3, 7], 1), 42);
+    /// ```
+    pub fn product() -> Self {
+        BoxBiTransformer::new(|x: T, y: T| x * y)
+    }
+}
+
 impl<T, U, R> BiTransformer<T, U, R> for BoxBiTransformer<T, U, R> {
     fn apply(&self, first: T, second: U) -> R {
         (self.function)(first, second)
@@ -1919,7 +2085,254 @@ impl<T, U, R, F> FnBiTransformerOps<T, U, R> for F where F: Fn(T, U) -> R + 'sta
 /// # Author
 ///
 /// Hu Haixing
-pub trait BinaryOperator<T>: BiTransformer<T, T, T> {}
+pub trait BinaryOperator<T>: BiTransformer<T, T, T> {
+    /// Reduces the values of an iterator with this operator
+    ///
+    /// Combines the elements from left to right, using the first element as
+    /// the initial accumulator.
+    ///
+    /// # Parameters
+    ///
+    /// * `iter` - The values to reduce
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if `iter` is empty, otherwise the reduced value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::BinaryOperator;
+    ///
+    /// let max = |a: i32, b: i32| a.max(b);
+    /// assert_eq!(max.reduce(vec![3, 9, 2]), Some(9));
+    /// assert_eq!(max.reduce(Vec::new()), None);
+    /// ```
+    fn reduce<I>(&self, iter: I) -> Option<T>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().reduce(|acc, value| self.apply(acc, value))
+    }
+
+    /// Folds the values of an iterator with this operator
+    ///
+    /// Combines `init` and the elements from left to right.
+    ///
+    /// # Parameters
+    ///
+    /// * `iter` - The values to fold
+    /// * `init` - The initial accumulator, returned as is if `iter` is empty
+    ///
+    /// # Returns
+    ///
+    /// Returns the folded value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BinaryOperator, BoxBinaryOperator};
+    ///
+    /// let concat = BoxBinaryOperator::new(|a: String, b: String| a + &b);
+    /// let words = vec!["a".to_string(), "b".to_string()];
+    /// assert_eq!(concat.fold(words, ">".to_string()), ">ab");
+    /// ```
+    fn fold<I>(&self, iter: I, init: T) -> T
+    where
+        Self: Sized,
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter()
+            .fold(init, |acc, value| self.apply(acc, value))
+    }
+
+    /// Lazily folds the values of an iterator, yielding every intermediate
+    /// accumulation
+    ///
+    /// Like [`fold`](BinaryOperator::fold), but returns an iterator producing
+    /// the accumulator after each element is combined. `init` itself is not
+    /// yielded, so the result has as many items as `iter`, and its last item
+    /// equals the result of `fold`. If the operator panics, the iterator
+    /// yields no further items.
+    ///
+    /// # Parameters
+    ///
+    /// * `iter` - The values to fold
+    /// * `init` - The initial accumulator
+    ///
+    /// # Returns
+    ///
+    /// Returns an iterator over the running accumulations
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BinaryOperator, BoxBinaryOperator};
+    ///
+    /// let sum = BoxBinaryOperator::new(|a: i32, b: i32| a + b);
+    /// let prefix_sums: Vec<i32> = sum.scan(vec![1, 2, 3, 4], 0).collect();
+    /// assert_eq!(prefix_sums, vec![1, 3, 6, 10]);
+    /// ```
+    fn scan<I>(&self, iter: I, init: T) -> impl Iterator<Item = T>
+    where
+        Self: Sized,
+        T: Clone,
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().scan(Some(init), move |acc, value| {
+            // `acc` is only left empty if a previous call panicked
+            let next = self.apply(acc.take()?, value);
+            *acc = Some(next.clone());
+            Some(next)
+        })
+    }
+
+    /// Folds the values of an iterator with this operator, starting from
+    /// `initial`
+    ///
+    /// This is an alias for [`fold`](BinaryOperator::fold) taking the initial
+    /// accumulator first.
+    ///
+    /// # Parameters
+    ///
+    /// * `initial` - The initial accumulator, returned as is if `iter` is
+    ///   empty
+    /// * `iter` - The values to fold
+    ///
+    /// # Returns
+    ///
+    /// Returns the folded value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BinaryOperator, BoxBinaryOperator};
+    ///
+    /// let sum = BoxBinaryOperator::new(|a: i32, b: i32| a + b);
+    /// assert_eq!(sum.fold_iter(100, vec![1, 2, 3]), 106);
+    /// ```
+    fn fold_iter<I>(&self, initial: T, iter: I) -> T
+    where
+        Self: Sized,
+        I: IntoIterator<Item = T>,
+    {
+        self.fold(iter, initial)
+    }
+
+    /// Lazily folds the values of an iterator starting from `initial`,
+    /// yielding every intermediate accumulation
+    ///
+    /// This is an alias for [`scan`](BinaryOperator::scan) taking the initial
+    /// accumulator first.
+    ///
+    /// # Parameters
+    ///
+    /// * `initial` - The initial accumulator
+    /// * `iter` - The values to fold
+    ///
+    /// # Returns
+    ///
+    /// Returns an iterator over the running accumulations
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BinaryOperator, BoxBinaryOperator};
+    ///
+    /// let sum = BoxBinaryOperator::new(|a: i32, b: i32| a + b);
+    /// let running: Vec<i32> = sum.scan_iter(100, vec![1, 2, 3]).collect();
+    /// assert_eq!(running, vec![101, 103, 106]);
+    /// ```
+    fn scan_iter<I>(&self, initial: T, iter: I) -> impl Iterator<Item = T>
+    where
+        Self: Sized,
+        T: Clone,
+        I: IntoIterator<Item = T>,
+    {
+        self.scan(iter, initial)
+    }
+
+    /// Turns this operator into a running accumulator
+    ///
+    /// The returned mapper keeps the accumulation of all values it has seen
+    /// and returns it after each call, like `Iterator::scan` with this
+    /// operator. The accumulator is seeded with the first value, which is
+    /// returned unchanged. Each call clones the accumulation once, for the
+    /// returned value. If the operator panics, the accumulation restarts:
+    /// the next value seeds it anew.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `BoxMapper<T, T>` producing the running accumulation
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BinaryOperator, Mapper};
+    ///
+    /// let mut running_max = (|a: i32, b: i32| a.max(b)).accumulate();
+    /// assert_eq!(running_max.apply(3), 3);
+    /// assert_eq!(running_max.apply(1), 3);
+    /// assert_eq!(running_max.apply(7), 7);
+    /// ```
+    fn accumulate(self) -> BoxMapper<T, T>
+    where
+        Self: Sized + 'static,
+        T: Clone + 'static,
+    {
+        let mut acc: Option<T> = None;
+        BoxMapper::new(move |value: T| {
+            // `acc` is left empty while the operator runs, so a panic resets it
+            let next = match acc.take() {
+                Some(prev) => self.apply(prev, value),
+                None => value,
+            };
+            acc = Some(next.clone());
+            next
+        })
+    }
+
+    /// Turns this operator into a running accumulator with an initial value
+    ///
+    /// Like [`accumulate`](BinaryOperator::accumulate), but the accumulator
+    /// starts at `init`, so the first call already combines `init` with the
+    /// first value. Each call clones the accumulation once, for the returned
+    /// value. If the operator panics, the accumulation restarts from `init`.
+    ///
+    /// # Parameters
+    ///
+    /// * `init` - The initial accumulator
+    ///
+    /// # Returns
+    ///
+    /// Returns a `BoxMapper<T, T>` producing the running accumulation
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BinaryOperator, BoxBinaryOperator, Mapper};
+    ///
+    /// let sum = BoxBinaryOperator::new(|a: i32, b: i32| a + b);
+    /// let mut running_sum = sum.accumulate_from(100);
+    /// assert_eq!(running_sum.apply(1), 101);
+    /// assert_eq!(running_sum.apply(2), 103);
+    /// ```
+    fn accumulate_from(self, init: T) -> BoxMapper<T, T>
+    where
+        Self: Sized + 'static,
+        T: Clone + 'static,
+    {
+        let mut acc = Some(init.clone());
+        BoxMapper::new(move |value: T| {
+            // `acc` is left empty while the operator runs, so a panic resets it
+            let prev = acc.take().unwrap_or_else(|| init.clone());
+            let next = self.apply(prev, value);
+            acc = Some(next.clone());
+            next
+        })
+    }
+}
 
 /// Blanket implementation of BinaryOperator for all BiTransformer<T, T, T>
 ///
@@ -16,11 +16,19 @@
 //! implementations:
 //!
 //! - [`BoxBiTransformerOnce`]: Single ownership, one-time use
+//! - [`RcBiTransformerOnce`]: Single-threaded shared ownership, the wrapped
+//!   function runs at most once across all clones
+//! - [`ArcBiTransformerOnce`]: Thread-safe shared ownership, the wrapped
+//!   function runs at most once across all clones
 //!
 //! # Author
 //!
 //! Hu Haixing
 
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, PoisonError};
+
 use crate::bi_predicate::{BiPredicate, BoxBiPredicate};
 
 // ============================================================================
@@ -470,6 +478,363 @@ where
     }
 }
 
+// ============================================================================
+// RcBiTransformerOnce - Rc<RefCell<Option<Box<dyn FnOnce(T, U) -> R>>>>
+// ============================================================================
+
+/// Type alias for the shared slot of an `RcBiTransformerOnce`
+type RcBiTransformerOnceSlot<T, U, R> = Rc<RefCell<Option<Box<dyn FnOnce(T, U) -> R>>>>;
+
+/// Type alias for the shared slot of an `ArcBiTransformerOnce`
+type ArcBiTransformerOnceSlot<T, U, R> = Arc<Mutex<Option<Box<dyn FnOnce(T, U) -> R + Send>>>>;
+
+/// RcBiTransformerOnce - single-threaded shared one-shot bi-transformer
+///
+/// A one-shot bi-transformer that can be cloned and registered in several
+/// places at once. All clones share the same underlying function stored in
+/// an `Rc<RefCell<Option<..>>>`; whichever clone is invoked first takes the
+/// function out and runs it, every later invocation through any clone
+/// observes that it has already been consumed.
+///
+/// # Features
+///
+/// - **Based on**: `Rc<RefCell<Option<Box<dyn FnOnce(T, U) -> R>>>>`
+/// - **Ownership**: Shared ownership, cloneable
+/// - **Reusability**: The wrapped function runs at most once across all
+///   clones
+/// - **Thread Safety**: Not thread-safe
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::RcBiTransformerOnce;
+///
+/// let add = RcBiTransformerOnce::new(|x: i32, y: i32| x + y);
+/// let on_timeout = add.clone();
+/// let on_success = add.clone();
+///
+/// assert_eq!(on_success.try_transform_once(20, 22), Some(42));
+/// assert_eq!(on_timeout.try_transform_once(1, 2), None);
+/// assert!(add.is_consumed());
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct RcBiTransformerOnce<T, U, R> {
+    function: RcBiTransformerOnceSlot<T, U, R>,
+}
+
+impl<T, U, R> RcBiTransformerOnce<T, U, R>
+where
+    T: 'static,
+    U: 'static,
+    R: 'static,
+{
+    /// Creates a new RcBiTransformerOnce
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure or function to wrap
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BiTransformerOnce, RcBiTransformerOnce};
+    ///
+    /// let add = RcBiTransformerOnce::new(|x: i32, y: i32| x + y);
+    /// assert_eq!(add.apply_once(20, 22), 42);
+    /// ```
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnOnce(T, U) -> R + 'static,
+    {
+        RcBiTransformerOnce {
+            function: Rc::new(RefCell::new(Some(Box::new(f)))),
+        }
+    }
+}
+
+impl<T, U, R> RcBiTransformerOnce<T, U, R> {
+    /// Applies the bi-transformer if it has not been consumed yet
+    ///
+    /// Takes the wrapped function out of the shared slot and invokes it. The
+    /// slot is released before the function runs, so the function itself may
+    /// safely touch other clones of this bi-transformer.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - The first input value (consumed)
+    /// * `second` - The second input value (consumed)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(result)` on the first invocation across all clones, and
+    /// `None` once the function has already been consumed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::RcBiTransformerOnce;
+    ///
+    /// let concat = RcBiTransformerOnce::new(|x: String, y: String| x + &y);
+    /// let other = concat.clone();
+    /// assert_eq!(
+    ///     concat.try_transform_once("a".to_string(), "b".to_string()),
+    ///     Some("ab".to_string())
+    /// );
+    /// assert_eq!(other.try_transform_once("c".to_string(), "d".to_string()), None);
+    /// ```
+    pub fn try_transform_once(&self, first: T, second: U) -> Option<R> {
+        let function = self.function.borrow_mut().take();
+        function.map(|f| f(first, second))
+    }
+
+    /// Returns whether the wrapped function has already been consumed
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if any clone has already invoked the function
+    pub fn is_consumed(&self) -> bool {
+        self.function.borrow().is_none()
+    }
+}
+
+impl<T, U, R> BiTransformerOnce<T, U, R> for RcBiTransformerOnce<T, U, R> {
+    /// Applies the bi-transformer
+    ///
+    /// # Panics
+    ///
+    /// Panics if the wrapped function has already been consumed through
+    /// another clone. Use [`RcBiTransformerOnce::try_transform_once`] to
+    /// handle that case without panicking.
+    fn apply_once(self, first: T, second: U) -> R {
+        self.try_transform_once(first, second)
+            .expect("RcBiTransformerOnce has already been consumed")
+    }
+
+    fn to_box_once(&self) -> BoxBiTransformerOnce<T, U, R>
+    where
+        T: 'static,
+        U: 'static,
+        R: 'static,
+    {
+        self.clone().into_box_once()
+    }
+
+    fn to_fn_once(&self) -> impl FnOnce(T, U) -> R
+    where
+        T: 'static,
+        U: 'static,
+        R: 'static,
+    {
+        self.clone().into_fn_once()
+    }
+}
+
+impl<T, U, R> Clone for RcBiTransformerOnce<T, U, R> {
+    /// Clones the RcBiTransformerOnce
+    ///
+    /// The clone shares the same one-shot slot with the original.
+    fn clone(&self) -> Self {
+        RcBiTransformerOnce {
+            function: Rc::clone(&self.function),
+        }
+    }
+}
+
+impl<T, U, R> From<BoxBiTransformerOnce<T, U, R>> for RcBiTransformerOnce<T, U, R> {
+    /// Converts a BoxBiTransformerOnce into a shareable RcBiTransformerOnce
+    fn from(transformer: BoxBiTransformerOnce<T, U, R>) -> Self {
+        RcBiTransformerOnce {
+            function: Rc::new(RefCell::new(Some(transformer.function))),
+        }
+    }
+}
+
+// ============================================================================
+// ArcBiTransformerOnce - Arc<Mutex<Option<Box<dyn FnOnce(T, U) -> R + Send>>>>
+// ============================================================================
+
+/// ArcBiTransformerOnce - thread-safe shared one-shot bi-transformer
+///
+/// The thread-safe counterpart of [`RcBiTransformerOnce`]. All clones share
+/// the same underlying function stored in an `Arc<Mutex<Option<..>>>`;
+/// whichever clone is invoked first, from whichever thread, runs the
+/// function, and every later invocation observes that it has already been
+/// consumed.
+///
+/// # Features
+///
+/// - **Based on**: `Arc<Mutex<Option<Box<dyn FnOnce(T, U) -> R + Send>>>>`
+/// - **Ownership**: Shared ownership, cloneable
+/// - **Reusability**: The wrapped function runs at most once across all
+///   clones and threads
+/// - **Thread Safety**: Thread-safe (`Send + Sync`)
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::ArcBiTransformerOnce;
+/// use std::thread;
+///
+/// let add = ArcBiTransformerOnce::new(|x: i32, y: i32| x + y);
+/// let handles: Vec<_> = (0..4)
+///     .map(|i| {
+///         let add = add.clone();
+///         thread::spawn(move || add.try_transform_once(i, i))
+///     })
+///     .collect();
+/// let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+/// assert_eq!(results.iter().filter(|r| r.is_some()).count(), 1);
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct ArcBiTransformerOnce<T, U, R> {
+    function: ArcBiTransformerOnceSlot<T, U, R>,
+}
+
+impl<T, U, R> ArcBiTransformerOnce<T, U, R>
+where
+    T: 'static,
+    U: 'static,
+    R: 'static,
+{
+    /// Creates a new ArcBiTransformerOnce
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure or function to wrap; must be `Send`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcBiTransformerOnce, BiTransformerOnce};
+    ///
+    /// let add = ArcBiTransformerOnce::new(|x: i32, y: i32| x + y);
+    /// assert_eq!(add.apply_once(20, 22), 42);
+    /// ```
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnOnce(T, U) -> R + Send + 'static,
+    {
+        ArcBiTransformerOnce {
+            function: Arc::new(Mutex::new(Some(Box::new(f)))),
+        }
+    }
+}
+
+impl<T, U, R> ArcBiTransformerOnce<T, U, R> {
+    /// Applies the bi-transformer if it has not been consumed yet
+    ///
+    /// Takes the wrapped function out of the shared slot and invokes it. The
+    /// lock is released before the function runs.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - The first input value (consumed)
+    /// * `second` - The second input value (consumed)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(result)` on the first invocation across all clones, and
+    /// `None` once the function has already been consumed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::ArcBiTransformerOnce;
+    ///
+    /// let mul = ArcBiTransformerOnce::new(|x: i32, y: i32| x * y);
+    /// assert_eq!(mul.try_transform_once(6, 7), Some(42));
+    /// assert_eq!(mul.try_transform_once(6, 7), None);
+    /// ```
+    pub fn try_transform_once(&self, first: T, second: U) -> Option<R> {
+        let function = self
+            .function
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+        function.map(|f| f(first, second))
+    }
+
+    /// Returns whether the wrapped function has already been consumed
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if any clone has already invoked the function
+    pub fn is_consumed(&self) -> bool {
+        self.function
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_none()
+    }
+}
+
+impl<T, U, R> BiTransformerOnce<T, U, R> for ArcBiTransformerOnce<T, U, R> {
+    /// Applies the bi-transformer
+    ///
+    /// # Panics
+    ///
+    /// Panics if the wrapped function has already been consumed through
+    /// another clone. Use [`ArcBiTransformerOnce::try_transform_once`] to
+    /// handle that case without panicking.
+    fn apply_once(self, first: T, second: U) -> R {
+        self.try_transform_once(first, second)
+            .expect("ArcBiTransformerOnce has already been consumed")
+    }
+
+    fn to_box_once(&self) -> BoxBiTransformerOnce<T, U, R>
+    where
+        T: 'static,
+        U: 'static,
+        R: 'static,
+    {
+        self.clone().into_box_once()
+    }
+
+    fn to_fn_once(&self) -> impl FnOnce(T, U) -> R
+    where
+        T: 'static,
+        U: 'static,
+        R: 'static,
+    {
+        self.clone().into_fn_once()
+    }
+}
+
+impl<T, U, R> Clone for ArcBiTransformerOnce<T, U, R> {
+    /// Clones the ArcBiTransformerOnce
+    ///
+    /// The clone shares the same one-shot slot with the original.
+    fn clone(&self) -> Self {
+        ArcBiTransformerOnce {
+            function: Arc::clone(&self.function),
+        }
+    }
+}
+
+impl<T, U, R> From<ArcBiTransformerOnce<T, U, R>> for RcBiTransformerOnce<T, U, R>
+where
+    T: 'static,
+    U: 'static,
+    R: 'static,
+{
+    /// Wraps an ArcBiTransformerOnce into an RcBiTransformerOnce
+    ///
+    /// The resulting RcBiTransformerOnce delegates to the same shared slot,
+    /// so consuming either one consumes both.
+    fn from(transformer: ArcBiTransformerOnce<T, U, R>) -> Self {
+        RcBiTransformerOnce::new(move |t, u| {
+            transformer
+                .try_transform_once(t, u)
+                .expect("ArcBiTransformerOnce has already been consumed")
+        })
+    }
+}
+
 // ============================================================================
 // Blanket implementation for standard FnOnce trait
 // ============================================================================
@@ -0,0 +1,116 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Broadcast Types
+//!
+//! Provides [`BroadcastConsumer`], a consumer forwarding every value to a
+//! growable list of recipients.
+//!
+//! # Author
+//!
+//! Hu Haixing
+
+use std::fmt;
+
+use crate::consumer::{BoxConsumer, Consumer};
+
+/// BroadcastConsumer struct
+///
+/// A consumer forwarding every accepted value to each of a list of
+/// `BoxConsumer<T>` recipients, in the order they were added. Unlike a chain
+/// of `and_then` calls, the list can grow at runtime.
+///
+/// # Features
+///
+/// - **Fan-Out**: Every recipient receives every value
+/// - **Ordered Delivery**: Recipients are called in insertion order
+/// - **Implements Consumer**: Can be used anywhere a `Consumer` is expected
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BoxConsumer, BroadcastConsumer, Consumer};
+/// use std::sync::{Arc, Mutex};
+///
+/// let log = Arc::new(Mutex::new(Vec::new()));
+/// let (l1, l2) = (log.clone(), log.clone());
+/// let mut broadcast = BroadcastConsumer::new(Vec::new());
+/// broadcast.push(BoxConsumer::new(move |x: &i32| l1.lock().unwrap().push(*x)));
+/// broadcast.push(BoxConsumer::new(move |x: &i32| l2.lock().unwrap().push(-*x)));
+/// broadcast.accept(&3);
+///
+/// assert_eq!(*log.lock().unwrap(), vec![3, -3]);
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct BroadcastConsumer<T> {
+    recipients: Vec<BoxConsumer<T>>,
+}
+
+impl<T> BroadcastConsumer<T> {
+    /// Creates a BroadcastConsumer
+    ///
+    /// # Parameters
+    ///
+    /// * `consumers` - The initial recipients, in delivery order
+    ///
+    /// # Return Value
+    ///
+    /// Returns a new `BroadcastConsumer<T>`
+    pub fn new(consumers: Vec<BoxConsumer<T>>) -> Self {
+        BroadcastConsumer {
+            recipients: consumers,
+        }
+    }
+
+    /// Appends a recipient
+    ///
+    /// The recipient receives every value accepted from now on, after all
+    /// previously added recipients.
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The recipient to add
+    pub fn push(&mut self, consumer: BoxConsumer<T>) {
+        self.recipients.push(consumer);
+    }
+
+    /// Returns the number of recipients
+    pub fn len(&self) -> usize {
+        self.recipients.len()
+    }
+
+    /// Returns whether there are no recipients
+    pub fn is_empty(&self) -> bool {
+        self.recipients.is_empty()
+    }
+}
+
+impl<T> Consumer<T> for BroadcastConsumer<T> {
+    fn accept(&mut self, value: &T) {
+        for recipient in &mut self.recipients {
+            recipient.accept(value);
+        }
+    }
+}
+
+impl<T> Default for BroadcastConsumer<T> {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl<T> fmt::Debug for BroadcastConsumer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BroadcastConsumer")
+            .field("recipients", &self.len())
+            .finish()
+    }
+}
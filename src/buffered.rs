@@ -0,0 +1,282 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Buffered Types
+//!
+//! Provides batching consumers that clone accepted values into an internal
+//! buffer and hand the whole batch to a `Consumer<Vec<T>>` once it reaches
+//! a fixed size, for example to turn single inserts into bulk inserts:
+//!
+//! - [`BufferedConsumer`]: Single-owner buffer feeding a `BoxConsumer<Vec<T>>`
+//! - [`ArcBufferedConsumer`]: Thread-safe, cloneable buffer feeding an
+//!   `ArcConsumer<Vec<T>>`
+//!
+//! Values left in the buffer are not flushed automatically when the
+//! consumer is dropped; call `flush()` or recover them with `into_inner()`.
+//!
+//! # Author
+//!
+//! Hu Haixing
+
+use std::fmt;
+use std::mem;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::consumer::{ArcConsumer, BoxConsumer, Consumer};
+
+// ============================================================================
+// 1. BufferedConsumer - Single Ownership Implementation
+// ============================================================================
+
+/// BufferedConsumer struct
+///
+/// A consumer cloning every accepted value into a buffer and passing the
+/// buffer to a batch consumer each time it holds `batch_size` values.
+///
+/// # Features
+///
+/// - **Fixed Batch Size**: Full batches are flushed as soon as they fill up
+/// - **Manual Flush**: `flush()` hands over a partial batch
+/// - **Leftover Recovery**: `into_inner()` returns the unflushed values
+/// - **Implements Consumer**: Can be used anywhere a `Consumer` is expected
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BoxConsumer, BufferedConsumer, Consumer};
+///
+/// let (sink, batches) = BoxConsumer::<Vec<i32>>::collect();
+/// let mut buffered = BufferedConsumer::new(2, sink);
+/// buffered.accept_all([1, 2, 3]);
+/// buffered.flush();
+/// assert_eq!(*batches.lock().unwrap(), vec![vec![1, 2], vec![3]]);
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct BufferedConsumer<T> {
+    sink: BoxConsumer<Vec<T>>,
+    buffer: Vec<T>,
+    batch_size: usize,
+}
+
+impl<T: 'static> BufferedConsumer<T> {
+    /// Creates a BufferedConsumer
+    ///
+    /// # Parameters
+    ///
+    /// * `batch_size` - The number of values in a full batch
+    /// * `sink` - The consumer receiving each batch. Can be a closure, a
+    ///   `BoxConsumer<Vec<T>>`, `RcConsumer<Vec<T>>`, `ArcConsumer<Vec<T>>`
+    ///   or any type implementing `Consumer<Vec<T>>`
+    ///
+    /// # Return Value
+    ///
+    /// Returns a new `BufferedConsumer<T>` with an empty buffer
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero
+    pub fn new<C>(batch_size: usize, sink: C) -> Self
+    where
+        C: Consumer<Vec<T>> + 'static,
+    {
+        assert!(batch_size > 0, "batch size must be non-zero");
+        BufferedConsumer {
+            sink: sink.into_box(),
+            buffer: Vec::with_capacity(batch_size),
+            batch_size,
+        }
+    }
+}
+
+impl<T> BufferedConsumer<T> {
+    /// Returns the number of values in a full batch
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Returns the values waiting in the buffer
+    pub fn pending(&self) -> &[T] {
+        &self.buffer
+    }
+
+    /// Passes the buffered values to the sink as a partial batch
+    ///
+    /// Does nothing if the buffer is empty.
+    pub fn flush(&mut self) {
+        if !self.buffer.is_empty() {
+            let batch = mem::replace(&mut self.buffer, Vec::with_capacity(self.batch_size));
+            self.sink.accept(&batch);
+        }
+    }
+
+    /// Unwraps the buffered consumer, returning the unflushed values
+    pub fn into_inner(self) -> Vec<T> {
+        self.buffer
+    }
+}
+
+impl<T: Clone> Consumer<T> for BufferedConsumer<T> {
+    fn accept(&mut self, value: &T) {
+        self.buffer.push(value.clone());
+        if self.buffer.len() >= self.batch_size {
+            self.flush();
+        }
+    }
+}
+
+impl<T> fmt::Debug for BufferedConsumer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferedConsumer")
+            .field("batch_size", &self.batch_size)
+            .field("pending", &self.buffer.len())
+            .finish()
+    }
+}
+
+// ============================================================================
+// 2. ArcBufferedConsumer - Thread-Safe Implementation
+// ============================================================================
+
+/// ArcBufferedConsumer struct
+///
+/// A thread-safe buffered consumer passing batches to an
+/// `ArcConsumer<Vec<T>>`. Clones share the buffer and the sink. The buffer
+/// stays locked while a batch is handed to the sink, so batches reach the
+/// sink in the order they were filled and no value is lost or duplicated
+/// under concurrent accepts. A batch is taken out of the buffer before the
+/// sink sees it, so if the sink panics only that batch is lost; the buffer
+/// lock is recovered from poisoning and the consumer keeps working.
+///
+/// # Features
+///
+/// - **Shared Ownership**: Cloneable via `Arc`
+/// - **Ordered Batches**: Flushing is serialized by the buffer lock
+/// - **Implements Consumer**: Can be used anywhere a `Consumer` is expected
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{ArcBufferedConsumer, ArcConsumer, Consumer};
+/// use std::sync::{Arc, Mutex};
+/// use std::thread;
+///
+/// let batches = Arc::new(Mutex::new(Vec::new()));
+/// let b = batches.clone();
+/// let sink = ArcConsumer::new(move |batch: &Vec<i32>| b.lock().unwrap().push(batch.len()));
+/// let buffered = ArcBufferedConsumer::new(2, sink);
+/// let mut clone = buffered.clone();
+/// thread::spawn(move || clone.accept_all([1, 2, 3])).join().unwrap();
+/// buffered.flush();
+/// assert_eq!(*batches.lock().unwrap(), vec![2, 1]);
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct ArcBufferedConsumer<T> {
+    sink: ArcConsumer<Vec<T>>,
+    buffer: Arc<Mutex<Vec<T>>>,
+    batch_size: usize,
+}
+
+impl<T: Send + 'static> ArcBufferedConsumer<T> {
+    /// Creates an ArcBufferedConsumer
+    ///
+    /// # Parameters
+    ///
+    /// * `batch_size` - The number of values in a full batch
+    /// * `sink` - The thread-safe consumer receiving each batch
+    ///
+    /// # Return Value
+    ///
+    /// Returns a new `ArcBufferedConsumer<T>` with an empty buffer
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero
+    pub fn new(batch_size: usize, sink: ArcConsumer<Vec<T>>) -> Self {
+        assert!(batch_size > 0, "batch size must be non-zero");
+        ArcBufferedConsumer {
+            sink,
+            buffer: Arc::new(Mutex::new(Vec::with_capacity(batch_size))),
+            batch_size,
+        }
+    }
+}
+
+impl<T> ArcBufferedConsumer<T> {
+    /// Returns the number of values in a full batch
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Returns the number of values waiting in the shared buffer
+    pub fn pending_len(&self) -> usize {
+        self.buffer
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Passes the buffered values to the sink as a partial batch
+    ///
+    /// Does nothing if the buffer is empty.
+    pub fn flush(&self) {
+        let mut buffer = self.buffer.lock().unwrap_or_else(PoisonError::into_inner);
+        self.flush_locked(&mut buffer);
+    }
+
+    /// Drains the shared buffer, returning the unflushed values
+    ///
+    /// Other clones keep working on the now empty buffer.
+    pub fn into_inner(self) -> Vec<T> {
+        mem::take(&mut *self.buffer.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    fn flush_locked(&self, buffer: &mut Vec<T>) {
+        if !buffer.is_empty() {
+            let batch = mem::replace(buffer, Vec::with_capacity(self.batch_size));
+            self.sink.clone().accept(&batch);
+        }
+    }
+}
+
+impl<T: Clone> Consumer<T> for ArcBufferedConsumer<T> {
+    fn accept(&mut self, value: &T) {
+        let mut buffer = self.buffer.lock().unwrap_or_else(PoisonError::into_inner);
+        buffer.push(value.clone());
+        if buffer.len() >= self.batch_size {
+            self.flush_locked(&mut buffer);
+        }
+    }
+}
+
+impl<T> Clone for ArcBufferedConsumer<T> {
+    /// Clones the ArcBufferedConsumer
+    ///
+    /// The clone shares the buffer and the sink with the original instance.
+    fn clone(&self) -> Self {
+        ArcBufferedConsumer {
+            sink: self.sink.clone(),
+            buffer: Arc::clone(&self.buffer),
+            batch_size: self.batch_size,
+        }
+    }
+}
+
+impl<T> fmt::Debug for ArcBufferedConsumer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArcBufferedConsumer")
+            .field("batch_size", &self.batch_size)
+            .field("pending", &self.pending_len())
+            .finish()
+    }
+}
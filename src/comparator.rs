@@ -171,9 +171,14 @@
 //! Haixing Hu
 
 use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 use std::sync::Arc;
 
+use crate::bi_mutator::BoxBiMutator;
+use crate::bi_predicate::BoxBiPredicate;
+
 // ==========================================================================
 // Type Aliases
 // ==========================================================================
@@ -534,6 +539,225 @@ impl<T: 'static> BoxComparator<T> {
     pub fn into_fn(self) -> impl Fn(&T, &T) -> Ordering {
         move |a: &T, b: &T| (self.function)(a, b)
     }
+
+    /// Puts two values in order according to this comparator.
+    ///
+    /// Swaps `a` and `b` in place if `a` compares greater than `b`, so
+    /// that afterwards `a` is never greater than `b`. Equal values are left
+    /// untouched.
+    ///
+    /// # Parameters
+    ///
+    /// * `a` - Mutable reference to the first value
+    /// * `b` - Mutable reference to the second value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::comparator::BoxComparator;
+    ///
+    /// let cmp = BoxComparator::new(|a: &i32, b: &i32| a.cmp(b));
+    /// let (mut a, mut b) = (5, 3);
+    /// cmp.sort_pair(&mut a, &mut b);
+    /// assert_eq!((a, b), (3, 5));
+    /// ```
+    pub fn sort_pair(&self, a: &mut T, b: &mut T) {
+        if (self.function)(a, b) == Ordering::Greater {
+            std::mem::swap(a, b);
+        }
+    }
+
+    /// Converts this comparator into a bi-mutator that sorts pairs.
+    ///
+    /// The returned bi-mutator behaves like [`BoxComparator::sort_pair`],
+    /// which makes it usable wherever a `BiMutator<T, T>` is expected, e.g.
+    /// as the compare-exchange step of a sorting network.
+    ///
+    /// # Returns
+    ///
+    /// A `BoxBiMutator<T, T>` that swaps its arguments if the first one
+    /// compares greater than the second one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BiMutator, BoxComparator};
+    ///
+    /// let cmp = BoxComparator::new(|a: &i32, b: &i32| b.cmp(a));
+    /// let mut sorter = cmp.as_sort_mutator();
+    /// let (mut a, mut b) = (1, 2);
+    /// sorter.mutate(&mut a, &mut b);
+    /// assert_eq!((a, b), (2, 1));
+    /// ```
+    pub fn as_sort_mutator(self) -> BoxBiMutator<T, T> {
+        BoxBiMutator::new(move |a, b| self.sort_pair(a, b))
+    }
+
+    /// Tests whether two values are equal according to this comparator.
+    ///
+    /// # Parameters
+    ///
+    /// * `a` - The first value
+    /// * `b` - The second value
+    ///
+    /// # Returns
+    ///
+    /// `true` if `compare(a, b)` returns `Ordering::Equal`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::comparator::BoxComparator;
+    ///
+    /// let cmp = BoxComparator::new(|a: &String, b: &String| {
+    ///     a.to_lowercase().cmp(&b.to_lowercase())
+    /// });
+    /// assert!(cmp.equals(&"Rust".to_string(), &"rust".to_string()));
+    /// assert!(!cmp.equals(&"Rust".to_string(), &"Go".to_string()));
+    /// ```
+    pub fn equals(&self, a: &T, b: &T) -> bool {
+        (self.function)(a, b) == Ordering::Equal
+    }
+
+    /// Converts this comparator into an equality bi-predicate.
+    ///
+    /// # Returns
+    ///
+    /// A `BoxBiPredicate<T, T>` that returns `true` when both values compare
+    /// equal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BiPredicate, BoxComparator};
+    ///
+    /// let same_len = BoxComparator::new(|a: &&str, b: &&str| a.len().cmp(&b.len()))
+    ///     .into_eq_predicate();
+    /// assert!(same_len.test(&"abc", &"xyz"));
+    /// assert!(!same_len.test(&"abc", &"xy"));
+    /// ```
+    pub fn into_eq_predicate(self) -> BoxBiPredicate<T, T> {
+        BoxBiPredicate::new(move |a, b| self.equals(a, b))
+    }
+
+    /// Converts this comparator into a factory of comparator-keyed values.
+    ///
+    /// Each value passed to the returned closure is wrapped in a
+    /// [`ComparatorKey`], whose `Eq` and `Ord` implementations delegate to
+    /// this comparator and whose `Hash` implementation hashes the key
+    /// extracted by `hash_key`. This allows values whose type does not
+    /// implement `Hash` or `Ord` to be used as map or set keys.
+    ///
+    /// A comparator alone cannot produce a hash, so the caller supplies a
+    /// hashable projection of the value. It must be consistent with the
+    /// comparator: values comparing `Equal` must yield equal hash keys.
+    ///
+    /// # Parameters
+    ///
+    /// * `hash_key` - Extracts the hashable part of a value
+    ///
+    /// # Returns
+    ///
+    /// A closure wrapping values into `ComparatorKey<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::comparator::BoxComparator;
+    /// use std::collections::HashSet;
+    ///
+    /// let key = BoxComparator::new(|a: &f64, b: &f64| a.total_cmp(b))
+    ///     .into_hash(|x: &f64| x.to_bits());
+    /// let set: HashSet<_> = [1.5, 2.0, 1.5].into_iter().map(key).collect();
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    pub fn into_hash<K, F>(self, hash_key: F) -> impl Fn(T) -> ComparatorKey<T>
+    where
+        K: Hash,
+        F: Fn(&T) -> K + 'static,
+    {
+        let functions = Rc::new(KeyFunctions {
+            comparator: self,
+            hash: Box::new(move |value: &T, mut state: &mut dyn Hasher| {
+                hash_key(value).hash(&mut state)
+            }),
+        });
+        move |value| ComparatorKey {
+            value,
+            functions: Rc::clone(&functions),
+        }
+    }
+}
+
+impl<T: Ord + 'static> BoxComparator<T> {
+    /// Creates a comparator using the natural ordering of `T`.
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxComparator` delegating to `Ord::cmp`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::comparator::{Comparator, BoxComparator};
+    /// use std::cmp::Ordering;
+    ///
+    /// let cmp = BoxComparator::<i32>::natural();
+    /// assert_eq!(cmp.compare(&1, &2), Ordering::Less);
+    /// ```
+    pub fn natural() -> Self {
+        BoxComparator::new(|a: &T, b: &T| a.cmp(b))
+    }
+
+    /// Creates a comparator using the reverse of the natural ordering of
+    /// `T`.
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxComparator` ordering values from largest to smallest.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::comparator::BoxComparator;
+    ///
+    /// let cmp = BoxComparator::<i32>::reverse_natural();
+    /// let mut values = vec![2, 3, 1];
+    /// values.sort_by(cmp.into_fn());
+    /// assert_eq!(values, vec![3, 2, 1]);
+    /// ```
+    pub fn reverse_natural() -> Self {
+        BoxComparator::new(|a: &T, b: &T| b.cmp(a))
+    }
+}
+
+impl<T: PartialOrd + 'static> BoxComparator<T> {
+    /// Creates a comparator from a partial ordering.
+    ///
+    /// Pairs that `PartialOrd` cannot order, such as comparisons involving
+    /// `NaN`, are reported as `on_incomparable`.
+    ///
+    /// # Parameters
+    ///
+    /// * `on_incomparable` - The ordering returned for incomparable pairs
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxComparator` delegating to `PartialOrd::partial_cmp`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::comparator::{Comparator, BoxComparator};
+    /// use std::cmp::Ordering;
+    ///
+    /// let cmp = BoxComparator::<f64>::from_partial_ord(Ordering::Greater);
+    /// assert_eq!(cmp.compare(&1.0, &2.0), Ordering::Less);
+    /// assert_eq!(cmp.compare(&f64::NAN, &2.0), Ordering::Greater);
+    /// ```
+    pub fn from_partial_ord(on_incomparable: Ordering) -> Self {
+        BoxComparator::new(move |a: &T, b: &T| a.partial_cmp(b).unwrap_or(on_incomparable))
+    }
 }
 
 impl<T> Comparator<T> for BoxComparator<T> {
@@ -709,6 +933,32 @@ impl<T: 'static> ArcComparator<T> {
     pub fn into_fn(self) -> impl Fn(&T, &T) -> Ordering {
         move |a: &T, b: &T| (self.function)(a, b)
     }
+
+    /// Puts two values in order according to this comparator.
+    ///
+    /// Swaps `a` and `b` in place if `a` compares greater than `b`. Equal
+    /// values are left untouched.
+    ///
+    /// # Parameters
+    ///
+    /// * `a` - Mutable reference to the first value
+    /// * `b` - Mutable reference to the second value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::comparator::ArcComparator;
+    ///
+    /// let cmp = ArcComparator::new(|a: &i32, b: &i32| a.cmp(b));
+    /// let (mut a, mut b) = (5, 3);
+    /// cmp.sort_pair(&mut a, &mut b);
+    /// assert_eq!((a, b), (3, 5));
+    /// ```
+    pub fn sort_pair(&self, a: &mut T, b: &mut T) {
+        if (self.function)(a, b) == Ordering::Greater {
+            std::mem::swap(a, b);
+        }
+    }
 }
 
 impl<T> Comparator<T> for ArcComparator<T> {
@@ -884,6 +1134,32 @@ impl<T: 'static> RcComparator<T> {
     pub fn into_fn(self) -> impl Fn(&T, &T) -> Ordering {
         move |a: &T, b: &T| (self.function)(a, b)
     }
+
+    /// Puts two values in order according to this comparator.
+    ///
+    /// Swaps `a` and `b` in place if `a` compares greater than `b`. Equal
+    /// values are left untouched.
+    ///
+    /// # Parameters
+    ///
+    /// * `a` - Mutable reference to the first value
+    /// * `b` - Mutable reference to the second value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::comparator::RcComparator;
+    ///
+    /// let cmp = RcComparator::new(|a: &i32, b: &i32| a.cmp(b));
+    /// let (mut a, mut b) = (5, 3);
+    /// cmp.sort_pair(&mut a, &mut b);
+    /// assert_eq!((a, b), (3, 5));
+    /// ```
+    pub fn sort_pair(&self, a: &mut T, b: &mut T) {
+        if (self.function)(a, b) == Ordering::Greater {
+            std::mem::swap(a, b);
+        }
+    }
 }
 
 impl<T> Comparator<T> for RcComparator<T> {
@@ -971,3 +1247,216 @@ pub trait FnComparatorOps<T>: Fn(&T, &T) -> Ordering + Sized {
 }
 
 impl<T, F> FnComparatorOps<T> for F where F: Fn(&T, &T) -> Ordering {}
+
+/// A builder for comparators comparing by several keys in priority order.
+///
+/// Each `then_by*` call appends a key; the built comparator compares by the
+/// first key and only consults the next key when all previous keys are
+/// equal. The keys are kept in a flat list and evaluated in a loop, so
+/// adding keys does not nest closures.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of values being compared
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::comparator::{Comparator, ComparatorBuilder};
+/// use std::cmp::Ordering;
+///
+/// struct Person {
+///     name: String,
+///     age: i32,
+/// }
+///
+/// let cmp = ComparatorBuilder::new()
+///     .then_by(|p: &Person| p.name.len())
+///     .then_by_desc(|p: &Person| p.age)
+///     .build();
+///
+/// let p1 = Person { name: "Alice".to_string(), age: 30 };
+/// let p2 = Person { name: "Carol".to_string(), age: 25 };
+/// let p3 = Person { name: "Bob".to_string(), age: 20 };
+/// assert_eq!(cmp.compare(&p1, &p2), Ordering::Less);
+/// assert_eq!(cmp.compare(&p3, &p1), Ordering::Less);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct ComparatorBuilder<T> {
+    keys: Vec<Box<ComparatorFn<T>>>,
+}
+
+impl<T: 'static> ComparatorBuilder<T> {
+    /// Creates a builder without any key.
+    ///
+    /// A comparator built without keys considers all values equal.
+    pub fn new() -> Self {
+        ComparatorBuilder { keys: Vec::new() }
+    }
+
+    /// Appends a key compared in ascending order.
+    ///
+    /// The key is computed from each value on every comparison, so it may be
+    /// derived, e.g. `|p| p.name.len()`, rather than borrowed from a field.
+    ///
+    /// # Parameters
+    ///
+    /// * `key_fn` - A function that computes a comparable key from values
+    ///
+    /// # Returns
+    ///
+    /// The builder with the key appended.
+    pub fn then_by<K, F>(mut self, key_fn: F) -> Self
+    where
+        K: Ord,
+        F: Fn(&T) -> K + 'static,
+    {
+        self.keys
+            .push(Box::new(move |a: &T, b: &T| key_fn(a).cmp(&key_fn(b))));
+        self
+    }
+
+    /// Appends a key compared in descending order.
+    ///
+    /// The key is computed from each value on every comparison, so it may be
+    /// derived, e.g. `|p| p.name.len()`, rather than borrowed from a field.
+    ///
+    /// # Parameters
+    ///
+    /// * `key_fn` - A function that computes a comparable key from values
+    ///
+    /// # Returns
+    ///
+    /// The builder with the key appended.
+    pub fn then_by_desc<K, F>(mut self, key_fn: F) -> Self
+    where
+        K: Ord,
+        F: Fn(&T) -> K + 'static,
+    {
+        self.keys
+            .push(Box::new(move |a: &T, b: &T| key_fn(b).cmp(&key_fn(a))));
+        self
+    }
+
+    /// Appends an arbitrary comparator as the next key.
+    ///
+    /// # Parameters
+    ///
+    /// * `comparator` - The comparator used for this key. Can be:
+    ///   - A closure: `|a: &T, b: &T| -> Ordering`
+    ///   - A `BoxComparator<T>`, `RcComparator<T>` or `ArcComparator<T>`
+    ///   - Any type implementing `Comparator<T>`
+    ///
+    /// # Returns
+    ///
+    /// The builder with the comparator appended.
+    pub fn then_with<C>(mut self, comparator: C) -> Self
+    where
+        C: Comparator<T> + 'static,
+    {
+        self.keys
+            .push(Box::new(move |a: &T, b: &T| comparator.compare(a, b)));
+        self
+    }
+
+    /// Builds the multi-key comparator.
+    ///
+    /// # Returns
+    ///
+    /// A `BoxComparator<T>` comparing by all keys in the order they were
+    /// added.
+    pub fn build(self) -> BoxComparator<T> {
+        let keys = self.keys;
+        BoxComparator::new(move |a, b| {
+            keys.iter()
+                .map(|key| key(a, b))
+                .find(|ord| *ord != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        })
+    }
+}
+
+impl<T: 'static> Default for ComparatorBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A value keyed by a comparator.
+///
+/// Created by [`BoxComparator::into_hash`]. Equality and ordering delegate
+/// to the comparator that created the key, and hashing uses the hash key
+/// extractor given to `into_hash`. Two keys are always compared with the
+/// comparator of the left-hand key.
+///
+/// Keys created by the same factory share its functions through an `Rc`,
+/// so like the `BoxComparator` they wrap, they are neither `Send` nor
+/// `Sync`.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of the wrapped value
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct ComparatorKey<T> {
+    value: T,
+    functions: Rc<KeyFunctions<T>>,
+}
+
+/// Type alias for the hash function of a ComparatorKey.
+type KeyHashFn<T> = dyn Fn(&T, &mut dyn Hasher);
+
+/// The functions shared by the keys of one `into_hash` factory.
+struct KeyFunctions<T> {
+    comparator: BoxComparator<T>,
+    hash: Box<KeyHashFn<T>>,
+}
+
+impl<T> ComparatorKey<T> {
+    /// Returns a reference to the wrapped value.
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Unwraps the key, returning the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> PartialEq for ComparatorKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.functions.comparator.compare(&self.value, &other.value) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for ComparatorKey<T> {}
+
+impl<T> PartialOrd for ComparatorKey<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for ComparatorKey<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.functions.comparator.compare(&self.value, &other.value)
+    }
+}
+
+impl<T> Hash for ComparatorKey<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.functions.hash)(&self.value, state)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ComparatorKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ComparatorKey").field(&self.value).finish()
+    }
+}
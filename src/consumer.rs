@@ -31,12 +31,26 @@
 //!
 //! Hu Haixing
 
+use std::any::Any;
+use std::borrow::Borrow;
 use std::cell::RefCell;
+use std::collections::{HashSet, VecDeque};
 use std::fmt;
+use std::hash::Hash;
+use std::panic::{self, AssertUnwindSafe};
 use std::rc::Rc;
-use std::sync::{Arc, Mutex};
-
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{Sender, SyncSender};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::bi_consumer::BiConsumer;
+use crate::broadcast::BroadcastConsumer;
+use crate::buffered::{ArcBufferedConsumer, BufferedConsumer};
+use crate::mutator::{ArcMutator, BoxMutator, RcMutator};
 use crate::predicate::{ArcPredicate, BoxPredicate, Predicate, RcPredicate};
+use crate::supplier::BoxSupplier;
+use crate::try_consumer::BoxTryConsumer;
 
 /// Type alias for consumer function to simplify complex types.
 ///
@@ -123,6 +137,139 @@ pub trait Consumer<T> {
     /// ```
     fn accept(&mut self, value: &T);
 
+    /// Execute consumption operation on every value of an iterator
+    ///
+    /// Accepts the values in iteration order. The default implementation
+    /// calls [`accept`](Consumer::accept) once per value; [`ArcConsumer`]
+    /// and [`RcConsumer`] override it to acquire their lock or borrow only
+    /// once for the whole batch.
+    ///
+    /// # Parameters
+    ///
+    /// * `values` - The values to consume. Items can be `T` or `&T`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    ///
+    /// let (mut consumer, values) = BoxConsumer::<i32>::collect();
+    /// consumer.accept_all(&[1, 2, 3]);
+    /// consumer.accept_all(vec![4, 5]);
+    /// assert_eq!(*values.lock().unwrap(), vec![1, 2, 3, 4, 5]);
+    /// ```
+    fn accept_all<I>(&mut self, values: I)
+    where
+        Self: Sized,
+        I: IntoIterator,
+        I::Item: Borrow<T>,
+    {
+        for value in values {
+            self.accept(value.borrow());
+        }
+    }
+
+    /// Isolates panics raised by this consumer
+    ///
+    /// Converts this consumer into a `BoxConsumer<T>` that runs each
+    /// `accept` inside [`std::panic::catch_unwind`]. If the call panics,
+    /// `handler` receives the panic payload and the consumer stays usable
+    /// for later values. The name of a `BoxConsumer` is kept.
+    ///
+    /// # Unwind Safety
+    ///
+    /// The wrapped consumer is asserted to be unwind safe, so state it
+    /// captures may be left partially updated by the interrupted call.
+    /// [`ArcConsumer`] recovers its lock from poisoning, so a caught panic
+    /// does not break later calls.
+    ///
+    /// # Parameters
+    ///
+    /// * `handler` - Called with the payload of every caught panic
+    ///
+    /// # Return Value
+    ///
+    /// Returns the panic-isolating `BoxConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// let panics = Rc::new(RefCell::new(Vec::new()));
+    /// let p = panics.clone();
+    /// let mut consumer = BoxConsumer::new(|x: &i32| {
+    ///     assert!(*x >= 0, "negative value");
+    /// })
+    /// .catch_unwind(move |payload| {
+    ///     let message = payload.downcast_ref::<&str>().copied().unwrap_or("?");
+    ///     p.borrow_mut().push(message.to_string());
+    /// });
+    ///
+    /// consumer.accept(&-1);
+    /// consumer.accept(&1);
+    /// assert_eq!(*panics.borrow(), vec!["negative value"]);
+    /// ```
+    fn catch_unwind<H>(self, handler: H) -> BoxConsumer<T>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        H: FnMut(Box<dyn Any + Send>) + 'static,
+    {
+        let mut handler = handler;
+        self.into_box()
+            .isolate_panics(move |_: &T, payload| handler(payload))
+    }
+
+    /// Measures how long each call takes
+    ///
+    /// Converts this consumer into a `BoxConsumer<T>` that times every
+    /// `accept` with [`Instant`] and passes the value and the elapsed time
+    /// to `report`. Only the inner call is timed, not the report. No
+    /// allocation is made per call. The name of a `BoxConsumer` is kept.
+    ///
+    /// # Parameters
+    ///
+    /// * `report` - The bi-consumer receiving each value and its duration.
+    ///   Use `|_, d| ...` if only the duration matters
+    ///
+    /// # Return Value
+    ///
+    /// Returns the timing `BoxConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    /// use std::time::Duration;
+    ///
+    /// let mut total = Duration::ZERO;
+    /// let mut consumer = BoxConsumer::new(|x: &i32| {
+    ///     let _ = x * 2;
+    /// })
+    /// .timed(move |_: &i32, d: &Duration| total += *d);
+    /// consumer.accept(&21);
+    /// ```
+    fn timed<R>(self, report: R) -> BoxConsumer<T>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        R: BiConsumer<T, Duration> + 'static,
+    {
+        let BoxConsumer { mut function, name } = self.into_box();
+        let mut report = report;
+        BoxConsumer {
+            function: Box::new(move |t| {
+                let start = Instant::now();
+                function(t);
+                report.accept(t, &start.elapsed());
+            }),
+            name,
+        }
+    }
+
     /// Convert to BoxConsumer
     ///
     /// **⚠️ Consumes `self`**: The original consumer will be unavailable after
@@ -568,6 +715,95 @@ where
         BoxConsumer::new(|_| {})
     }
 
+    /// Create a consumer that counts how many values it accepts
+    ///
+    /// # Return Value
+    ///
+    /// Returns the counting consumer together with a shared handle to its
+    /// counter
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let (mut consumer, count) = BoxConsumer::<i32>::count();
+    /// consumer.accept(&1);
+    /// consumer.accept(&2);
+    /// assert_eq!(count.load(Ordering::SeqCst), 2);
+    /// ```
+    pub fn count() -> (Self, Arc<AtomicUsize>) {
+        let count = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&count);
+        let consumer = BoxConsumer::new(move |_: &T| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        (consumer, count)
+    }
+
+    /// Create a consumer broadcasting each value to several consumers
+    ///
+    /// Every accepted value is passed by reference to each of `consumers`,
+    /// in order. More consumers can be added later with
+    /// [`BroadcastConsumer::push`]; an empty list accepts and drops values.
+    ///
+    /// # Parameters
+    ///
+    /// * `consumers` - The initial recipients, in delivery order
+    ///
+    /// # Return Value
+    ///
+    /// Returns a new `BroadcastConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    ///
+    /// let (first, a) = BoxConsumer::collect();
+    /// let (second, b) = BoxConsumer::collect();
+    /// let mut broadcast = BoxConsumer::broadcast(vec![first]);
+    /// broadcast.push(second);
+    /// broadcast.accept(&7);
+    /// assert_eq!(*a.lock().unwrap(), vec![7]);
+    /// assert_eq!(*b.lock().unwrap(), vec![7]);
+    /// ```
+    pub fn broadcast(consumers: Vec<BoxConsumer<T>>) -> BroadcastConsumer<T> {
+        BroadcastConsumer::new(consumers)
+    }
+
+    /// Create a consumer fanning each value out to a fixed set of consumers
+    ///
+    /// Every accepted value is passed by reference to each of `consumers`,
+    /// in order. This is an alias for [`broadcast`](BoxConsumer::broadcast)
+    /// followed by `into_box()`; use `broadcast` directly when consumers
+    /// must be added after construction.
+    ///
+    /// # Parameters
+    ///
+    /// * `consumers` - The recipients, in delivery order
+    ///
+    /// # Return Value
+    ///
+    /// Returns a new `BoxConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    ///
+    /// let (first, a) = BoxConsumer::collect();
+    /// let (second, b) = BoxConsumer::collect();
+    /// let mut fan_out = BoxConsumer::fan_out(vec![first, second]);
+    /// fan_out.accept(&7);
+    /// assert_eq!(*a.lock().unwrap(), vec![7]);
+    /// assert_eq!(*b.lock().unwrap(), vec![7]);
+    /// ```
+    pub fn fan_out(consumers: Vec<BoxConsumer<T>>) -> Self {
+        BroadcastConsumer::new(consumers).into_box()
+    }
+
     /// Get the consumer's name
     ///
     /// # Return Value
@@ -673,110 +909,1293 @@ where
         })
     }
 
-    /// Creates a conditional consumer
+    /// Attaches a hook executed before this consumer
     ///
-    /// Returns a consumer that only executes when a predicate is satisfied.
+    /// Returns a consumer that passes each value to `hook` first, then to
+    /// this consumer. Unlike [`and_then`](BoxConsumer::and_then), this
+    /// decorates the current consumer, so its name is kept. Consumes self.
     ///
     /// # Parameters
     ///
-    /// * `predicate` - The condition to check. **Note: This parameter is passed
-    ///   by value and will transfer ownership.** If you need to preserve the
-    ///   original predicate, clone it first (if it implements `Clone`). Can be:
-    ///   - A closure: `|x: &T| -> bool`
-    ///   - A function pointer: `fn(&T) -> bool`
-    ///   - A `BoxPredicate<T>`
-    ///   - An `RcPredicate<T>`
-    ///   - An `ArcPredicate<T>`
-    ///   - Any type implementing `Predicate<T>`
+    /// * `hook` - Consumer run before the current operation. Can be:
+    ///   - A closure: `|x: &T|`
+    ///   - A `BoxConsumer<T>`, `RcConsumer<T>` or `ArcConsumer<T>`
+    ///   - Any type implementing `Consumer<T>`
     ///
     /// # Return Value
     ///
-    /// Returns `BoxConditionalConsumer<T>`
+    /// Returns the decorated `BoxConsumer<T>`
     ///
     /// # Examples
     ///
-    /// ## Using a closure
-    ///
     /// ```rust
     /// use prism3_function::{Consumer, BoxConsumer};
     /// use std::sync::{Arc, Mutex};
     ///
     /// let log = Arc::new(Mutex::new(Vec::new()));
-    /// let l = log.clone();
-    /// let consumer = BoxConsumer::new(move |x: &i32| {
-    ///     l.lock().unwrap().push(*x);
-    /// });
-    /// let mut conditional = consumer.when(|x: &i32| *x > 0);
+    /// let l1 = log.clone();
+    /// let l2 = log.clone();
+    /// let mut consumer = BoxConsumer::new(move |x: &i32| {
+    ///     l1.lock().unwrap().push(format!("handle {}", x));
+    /// })
+    /// .before(move |x: &i32| l2.lock().unwrap().push(format!("start {}", x)));
+    /// consumer.accept(&1);
+    /// assert_eq!(*log.lock().unwrap(), vec!["start 1", "handle 1"]);
+    /// ```
+    pub fn before<C>(self, hook: C) -> Self
+    where
+        C: Consumer<T> + 'static,
+    {
+        let name = self.name;
+        let mut function = self.function;
+        let mut hook = hook;
+        BoxConsumer {
+            function: Box::new(move |t| {
+                hook.accept(t);
+                function(t);
+            }),
+            name,
+        }
+    }
+
+    /// Attaches a hook executed after this consumer
     ///
-    /// conditional.accept(&5);
-    /// assert_eq!(*log.lock().unwrap(), vec![5]);
+    /// Returns a consumer that passes each value to this consumer first,
+    /// then to `hook`. This decorates the current consumer, so its name is
+    /// kept. Consumes self.
     ///
-    /// conditional.accept(&-5);
-    /// assert_eq!(*log.lock().unwrap(), vec![5]); // Unchanged
-    /// ```
+    /// # Parameters
     ///
-    /// ## Preserving predicate with clone
+    /// * `hook` - Consumer run after the current operation. Can be:
+    ///   - A closure: `|x: &T|`
+    ///   - A `BoxConsumer<T>`, `RcConsumer<T>` or `ArcConsumer<T>`
+    ///   - Any type implementing `Consumer<T>`
+    ///
+    /// # Return Value
+    ///
+    /// Returns the decorated `BoxConsumer<T>`
+    ///
+    /// # Examples
     ///
     /// ```rust
     /// use prism3_function::{Consumer, BoxConsumer};
-    /// use prism3_function::predicate::{Predicate, RcPredicate};
     /// use std::sync::{Arc, Mutex};
     ///
     /// let log = Arc::new(Mutex::new(Vec::new()));
-    /// let l = log.clone();
-    /// let is_positive = RcPredicate::new(|x: &i32| *x > 0);
-    /// let consumer = BoxConsumer::new(move |x: &i32| {
-    ///     l.lock().unwrap().push(*x);
-    /// });
-    ///
-    /// // Clone to preserve original predicate
-    /// let mut conditional = consumer.when(is_positive.clone());
-    ///
-    /// conditional.accept(&5);
-    /// assert_eq!(*log.lock().unwrap(), vec![5]);
-    ///
-    /// // Original predicate still usable
-    /// assert!(is_positive.test(&3));
+    /// let l1 = log.clone();
+    /// let l2 = log.clone();
+    /// let mut consumer = BoxConsumer::new_with_name("handler", move |x: &i32| {
+    ///     l1.lock().unwrap().push(format!("handle {}", x));
+    /// })
+    /// .after(move |x: &i32| l2.lock().unwrap().push(format!("done {}", x)));
+    /// consumer.accept(&1);
+    /// assert_eq!(consumer.name(), Some("handler"));
+    /// assert_eq!(*log.lock().unwrap(), vec!["handle 1", "done 1"]);
     /// ```
-    pub fn when<P>(self, predicate: P) -> BoxConditionalConsumer<T>
+    pub fn after<C>(self, hook: C) -> Self
     where
-        P: Predicate<T> + 'static,
+        C: Consumer<T> + 'static,
     {
-        BoxConditionalConsumer {
-            consumer: self,
-            predicate: predicate.into_box(),
+        let name = self.name;
+        let mut function = self.function;
+        let mut hook = hook;
+        BoxConsumer {
+            function: Box::new(move |t| {
+                function(t);
+                hook.accept(t);
+            }),
+            name,
         }
     }
-}
-
-impl<T> Consumer<T> for BoxConsumer<T> {
-    fn accept(&mut self, value: &T) {
-        (self.function)(value)
-    }
 
-    fn into_box(self) -> BoxConsumer<T>
+    /// Attaches an observer that sees each value before this consumer
+    ///
+    /// Equivalent to [`before`](BoxConsumer::before); the name states the
+    /// intent of a side-effect-only observer, e.g. for logging or tracing
+    /// the values a consumer receives. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `observer` - Consumer observing each value. Can be:
+    ///   - A closure: `|x: &T|`
+    ///   - A `BoxConsumer<T>`, `RcConsumer<T>` or `ArcConsumer<T>`
+    ///   - Any type implementing `Consumer<T>`
+    ///
+    /// # Return Value
+    ///
+    /// Returns the observed `BoxConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let (counter, count) = BoxConsumer::count();
+    /// let mut consumer = BoxConsumer::new(|_: &i32| {}).observe(counter);
+    /// consumer.accept(&1);
+    /// consumer.accept(&2);
+    /// assert_eq!(count.load(Ordering::SeqCst), 2);
+    /// ```
+    pub fn observe<C>(self, observer: C) -> Self
     where
-        T: 'static,
+        C: Consumer<T> + 'static,
     {
-        self
+        self.before(observer)
     }
 
-    fn into_rc(self) -> RcConsumer<T>
+    /// Splits each value to this consumer and another one
+    ///
+    /// Returns a consumer passing each value to this consumer first, then
+    /// to `other`, like the Unix `tee` command. This is an alias for
+    /// [`and_then`](BoxConsumer::and_then). Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The second recipient. Can be:
+    ///   - A closure: `|x: &T|`
+    ///   - A `BoxConsumer<T>`, `RcConsumer<T>` or `ArcConsumer<T>`
+    ///   - Any type implementing `Consumer<T>`
+    ///
+    /// # Return Value
+    ///
+    /// Returns a new `BoxConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    ///
+    /// let (store, stored) = BoxConsumer::collect();
+    /// let (audit, audited) = BoxConsumer::collect();
+    /// let mut consumer = store.tee(audit);
+    /// consumer.accept(&1);
+    /// assert_eq!(*stored.lock().unwrap(), vec![1]);
+    /// assert_eq!(*audited.lock().unwrap(), vec![1]);
+    /// ```
+    pub fn tee<C>(self, other: C) -> BoxConsumer<T>
     where
-        T: 'static,
+        C: Consumer<T> + 'static,
     {
-        let mut self_fn = self.function;
-        RcConsumer::new(move |t| self_fn(t))
+        self.and_then(other)
     }
 
-    // do NOT override Consumer::into_arc() because BoxConsumer is not Send + Sync
-    // and calling BoxConsumer::into_arc() will cause a compile error
-
-    fn into_fn(self) -> impl FnMut(&T)
+    /// Adapts this consumer to accept values of another type
+    ///
+    /// Returns a consumer of `S` that applies `f` to each incoming reference
+    /// and passes a reference to the owned result to this consumer
+    /// (contramap). This is the dual of a transformer's `and_then`. The name
+    /// is kept. Consumes self.
+    ///
+    /// Use [`adapt_ref`](BoxConsumer::adapt_ref) when the value can be
+    /// borrowed from the input, e.g. a field projection, to avoid
+    /// constructing an intermediate value.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `S` - The input type of the adapted consumer
+    /// * `F` - The adapter closure type
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - Function deriving the value to consume from each input
+    ///
+    /// # Return Value
+    ///
+    /// Returns the adapted `BoxConsumer<S>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    ///
+    /// let (lengths, values) = BoxConsumer::collect();
+    /// let mut consumer = lengths.adapt(|s: &&str| s.len());
+    /// consumer.accept(&"abc");
+    /// consumer.accept(&"de");
+    /// assert_eq!(*values.lock().unwrap(), vec![3, 2]);
+    /// ```
+    pub fn adapt<S, F>(self, f: F) -> BoxConsumer<S>
     where
-        T: 'static,
+        S: 'static,
+        F: Fn(&S) -> T + 'static,
     {
-        self.function
+        let name = self.name;
+        let mut function = self.function;
+        BoxConsumer {
+            function: Box::new(move |s| function(&f(s))),
+            name,
+        }
+    }
+
+    /// Adapts this consumer to accept values it can borrow from
+    ///
+    /// Like [`adapt`](BoxConsumer::adapt), but `f` returns a reference into
+    /// the incoming value, so nothing is cloned or allocated per call. The
+    /// name is kept. Consumes self.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `S` - The input type of the adapted consumer
+    /// * `F` - The projection closure type
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - Function borrowing the value to consume from each input
+    ///
+    /// # Return Value
+    ///
+    /// Returns the adapted `BoxConsumer<S>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    ///
+    /// struct Event {
+    ///     user: String,
+    /// }
+    ///
+    /// let (users, values) = BoxConsumer::collect();
+    /// let mut consumer = users.adapt_ref(|e: &Event| &e.user);
+    /// consumer.accept(&Event { user: "alice".to_string() });
+    /// assert_eq!(*values.lock().unwrap(), vec!["alice".to_string()]);
+    /// ```
+    pub fn adapt_ref<S, F>(self, f: F) -> BoxConsumer<S>
+    where
+        S: 'static,
+        F: Fn(&S) -> &T + 'static,
+    {
+        let name = self.name;
+        let mut function = self.function;
+        BoxConsumer {
+            function: Box::new(move |s| function(f(s))),
+            name,
+        }
+    }
+
+    /// Adapts this consumer to accept values owning the consumed field
+    ///
+    /// Projects each incoming value to a borrowed field without cloning it.
+    /// Nested fields are reached by chaining calls, innermost first. This is
+    /// an alias for [`adapt_ref`](BoxConsumer::adapt_ref). Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `accessor` - Function borrowing the field from each input
+    ///
+    /// # Return Value
+    ///
+    /// Returns the adapted `BoxConsumer<S>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    ///
+    /// struct User {
+    ///     name: String,
+    /// }
+    ///
+    /// let (names, values) = BoxConsumer::collect();
+    /// let mut consumer = names.on_field(|u: &User| &u.name);
+    /// consumer.accept(&User { name: "alice".to_string() });
+    /// assert_eq!(*values.lock().unwrap(), vec!["alice".to_string()]);
+    /// ```
+    pub fn on_field<S, F>(self, accessor: F) -> BoxConsumer<S>
+    where
+        S: 'static,
+        F: Fn(&S) -> &T + 'static,
+    {
+        self.adapt_ref(accessor)
+    }
+
+    /// Recovers from panics raised by this consumer
+    ///
+    /// Returns a consumer that runs this consumer inside
+    /// [`std::panic::catch_unwind`]. If it panics, the panic is caught and
+    /// `on_panic` is called with the same value instead. The returned
+    /// consumer stays usable afterwards, and the name is kept. Consumes self.
+    ///
+    /// # Unwind Safety
+    ///
+    /// The wrapped closure is asserted to be unwind safe, so state it
+    /// captures may be left partially updated by the interrupted call. The
+    /// panic hook still runs, so the panic message is printed unless a
+    /// custom hook is installed.
+    ///
+    /// # Parameters
+    ///
+    /// * `on_panic` - The consumer invoked with the value whose consumption
+    ///   panicked. Can be a closure, a `BoxConsumer<T>`, `RcConsumer<T>`,
+    ///   `ArcConsumer<T>` or any type implementing `Consumer<T>`
+    ///
+    /// # Return Value
+    ///
+    /// Returns the recovering `BoxConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    ///
+    /// let (failed, values) = BoxConsumer::collect();
+    /// let mut consumer = BoxConsumer::new(|x: &i32| {
+    ///     assert!(*x >= 0, "negative value");
+    /// })
+    /// .with_panic_recovery(failed);
+    ///
+    /// consumer.accept(&1);
+    /// consumer.accept(&-2);
+    /// consumer.accept(&3);
+    /// assert_eq!(*values.lock().unwrap(), vec![-2]);
+    /// ```
+    pub fn with_panic_recovery<C>(self, on_panic: C) -> BoxConsumer<T>
+    where
+        C: Consumer<T> + 'static,
+    {
+        let mut on_panic = on_panic;
+        self.isolate_panics(move |t: &T, _| on_panic.accept(t))
+    }
+
+    /// Runs this consumer inside `catch_unwind`, passing the value and the
+    /// panic payload to `handler` when it panics
+    ///
+    /// The single panic isolation path behind [`Consumer::catch_unwind`] and
+    /// [`with_panic_recovery`](BoxConsumer::with_panic_recovery). The name is
+    /// kept.
+    fn isolate_panics<H>(self, mut handler: H) -> BoxConsumer<T>
+    where
+        H: FnMut(&T, Box<dyn Any + Send>) + 'static,
+    {
+        let name = self.name;
+        let mut function = self.function;
+        BoxConsumer {
+            function: Box::new(move |t| {
+                if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| function(t))) {
+                    handler(t, payload);
+                }
+            }),
+            name,
+        }
+    }
+
+    /// Restricts this consumer to a single call, checked at runtime
+    ///
+    /// The first `accept` is forwarded to this consumer; every later call
+    /// panics. Use this for callbacks that must fire at most once but have
+    /// to be stored as a reusable [`Consumer`], where
+    /// [`ConsumerOnce`](crate::ConsumerOnce) cannot express the restriction
+    /// at compile time. The name is kept. Consumes self.
+    ///
+    /// # Return Value
+    ///
+    /// Returns the once-only `BoxConsumer<T>`
+    ///
+    /// # Panics
+    ///
+    /// The returned consumer panics when `accept` is called more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    ///
+    /// let (inner, values) = BoxConsumer::collect();
+    /// let mut consumer = inner.once_only();
+    /// consumer.accept(&1);
+    /// assert_eq!(*values.lock().unwrap(), vec![1]);
+    /// // consumer.accept(&2); // would panic
+    /// ```
+    pub fn once_only(self) -> BoxConsumer<T> {
+        let name = self.name;
+        let message = AlreadyConsumedError { name: name.clone() }.to_string();
+        let mut function = self.function;
+        let mut consumed = false;
+        BoxConsumer {
+            function: Box::new(move |t| {
+                if consumed {
+                    panic!("{}", message);
+                }
+                consumed = true;
+                function(t);
+            }),
+            name,
+        }
+    }
+
+    /// Restricts this consumer to a single call, reporting misuse as an error
+    ///
+    /// Non-panicking variant of [`once_only`](BoxConsumer::once_only): the
+    /// first `try_accept` is forwarded to this consumer and returns `Ok(())`;
+    /// every later call returns an [`AlreadyConsumedError`] without calling
+    /// it. Consumes self.
+    ///
+    /// # Return Value
+    ///
+    /// Returns a `BoxTryConsumer<T, AlreadyConsumedError>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxConsumer, TryConsumer};
+    ///
+    /// let mut consumer = BoxConsumer::new_with_name("init", |_: &i32| {}).try_once_only();
+    /// assert!(consumer.try_accept(&1).is_ok());
+    /// let err = consumer.try_accept(&2).unwrap_err();
+    /// assert_eq!(err.name(), Some("init"));
+    /// ```
+    pub fn try_once_only(self) -> BoxTryConsumer<T, AlreadyConsumedError> {
+        let name = self.name;
+        let mut function = self.function;
+        let mut consumed = false;
+        BoxTryConsumer::new(move |t| {
+            if consumed {
+                return Err(AlreadyConsumedError { name: name.clone() });
+            }
+            consumed = true;
+            function(t);
+            Ok(())
+        })
+    }
+
+    /// Forwards only every `n`-th value to this consumer
+    ///
+    /// Returns a consumer forwarding the 1st, `n + 1`-th, `2n + 1`-th, ...
+    /// accepted values and dropping the others. `every(1)` forwards every
+    /// value. The name is kept. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `n` - The sampling interval
+    ///
+    /// # Return Value
+    ///
+    /// Returns the sampling `BoxConsumer<T>`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    ///
+    /// let (inner, values) = BoxConsumer::collect();
+    /// let mut sampled = inner.every(3);
+    /// for x in 1..=7 {
+    ///     sampled.accept(&x);
+    /// }
+    /// assert_eq!(*values.lock().unwrap(), vec![1, 4, 7]);
+    /// ```
+    pub fn every(self, n: usize) -> BoxConsumer<T> {
+        assert!(n > 0, "every requires a non-zero interval");
+        let name = self.name;
+        let mut function = self.function;
+        let mut index = 0;
+        BoxConsumer {
+            function: Box::new(move |t| {
+                if index == 0 {
+                    function(t);
+                }
+                index = (index + 1) % n;
+            }),
+            name,
+        }
+    }
+
+    /// Drops the first `n` values and forwards the rest to this consumer
+    ///
+    /// The name is kept. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `n` - The number of values to drop
+    ///
+    /// # Return Value
+    ///
+    /// Returns the skipping `BoxConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    ///
+    /// let (inner, values) = BoxConsumer::collect();
+    /// let mut consumer = inner.skip(2);
+    /// consumer.accept_all([1, 2, 3, 4]);
+    /// assert_eq!(*values.lock().unwrap(), vec![3, 4]);
+    /// ```
+    pub fn skip(self, n: usize) -> BoxConsumer<T> {
+        let name = self.name;
+        let mut function = self.function;
+        let mut remaining = n;
+        BoxConsumer {
+            function: Box::new(move |t| {
+                if remaining > 0 {
+                    remaining -= 1;
+                } else {
+                    function(t);
+                }
+            }),
+            name,
+        }
+    }
+
+    /// Forwards only the first `n` values to this consumer
+    ///
+    /// Later values are dropped. The name is kept. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `n` - The number of values to forward
+    ///
+    /// # Return Value
+    ///
+    /// Returns the limiting `BoxConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    ///
+    /// let (inner, values) = BoxConsumer::collect();
+    /// let mut consumer = inner.take(2);
+    /// consumer.accept_all([1, 2, 3, 4]);
+    /// assert_eq!(*values.lock().unwrap(), vec![1, 2]);
+    /// ```
+    pub fn take(self, n: usize) -> BoxConsumer<T> {
+        let name = self.name;
+        let mut function = self.function;
+        let mut remaining = n;
+        BoxConsumer {
+            function: Box::new(move |t| {
+                if remaining > 0 {
+                    remaining -= 1;
+                    function(t);
+                }
+            }),
+            name,
+        }
+    }
+
+    /// Drops values arriving too soon after the last forwarded value
+    ///
+    /// A value is forwarded only if at least `min_interval` has elapsed
+    /// since the last value this consumer actually received; otherwise it
+    /// is silently discarded. The first value is always forwarded, so a
+    /// steady stream is thinned to at most one value per `min_interval`.
+    /// The name is kept. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `min_interval` - The minimum time between two forwarded values
+    ///
+    /// # Return Value
+    ///
+    /// Returns the debouncing `BoxConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    /// use std::time::Duration;
+    ///
+    /// let (inner, values) = BoxConsumer::collect();
+    /// let mut consumer = inner.debounce(Duration::from_secs(60));
+    /// consumer.accept_all([1, 2, 3]);
+    /// assert_eq!(*values.lock().unwrap(), vec![1]);
+    /// ```
+    pub fn debounce(self, min_interval: Duration) -> BoxConsumer<T> {
+        self.debounce_with_clock(min_interval, Instant::now)
+    }
+
+    /// Drops values arriving too soon after the last forwarded value,
+    /// reading the current time from `clock`
+    ///
+    /// Same as [`debounce`](BoxConsumer::debounce), which passes
+    /// `Instant::now`. Supplying a manually advanced clock makes the timing
+    /// testable without sleeping. The name is kept. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `min_interval` - The minimum time between two forwarded values
+    /// * `clock` - Returns the current time; must never go backwards
+    ///
+    /// # Return Value
+    ///
+    /// Returns the debouncing `BoxConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let now = Rc::new(Cell::new(Instant::now()));
+    /// let clock = Rc::clone(&now);
+    /// let (inner, values) = BoxConsumer::collect();
+    /// let mut consumer = inner.debounce_with_clock(Duration::from_secs(60), move || clock.get());
+    /// consumer.accept_all([1, 2]);
+    /// now.set(now.get() + Duration::from_secs(60));
+    /// consumer.accept_all([3, 4]);
+    /// assert_eq!(*values.lock().unwrap(), vec![1, 3]);
+    /// ```
+    pub fn debounce_with_clock<C>(self, min_interval: Duration, clock: C) -> BoxConsumer<T>
+    where
+        C: Fn() -> Instant + 'static,
+    {
+        let name = self.name;
+        let mut function = self.function;
+        let mut last_forwarded: Option<Instant> = None;
+        BoxConsumer {
+            function: Box::new(move |t| {
+                let now = clock();
+                let due = match last_forwarded {
+                    Some(last) => now.duration_since(last) >= min_interval,
+                    None => true,
+                };
+                if due {
+                    last_forwarded = Some(now);
+                    function(t);
+                }
+            }),
+            name,
+        }
+    }
+
+    /// Forwards the leading value of each `min_interval` window
+    ///
+    /// The first value is forwarded, then values are suppressed until
+    /// `min_interval` has elapsed since the last forwarded value. Only
+    /// forwarded values move the window, so a steady stream keeps being
+    /// thinned to one value per `min_interval` instead of being silenced.
+    /// This is an alias for [`debounce`](BoxConsumer::debounce). The name is
+    /// kept. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `min_interval` - The minimum time between two forwarded values
+    ///
+    /// # Return Value
+    ///
+    /// Returns the throttling `BoxConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    /// use std::time::Duration;
+    ///
+    /// let (inner, values) = BoxConsumer::collect();
+    /// let mut consumer = inner.throttle_leading(Duration::from_secs(60));
+    /// consumer.accept_all([1, 2, 3]);
+    /// assert_eq!(*values.lock().unwrap(), vec![1]);
+    /// ```
+    pub fn throttle_leading(self, min_interval: Duration) -> BoxConsumer<T> {
+        self.debounce(min_interval)
+    }
+
+    /// Forwards the leading value of each `min_interval` window, reading the
+    /// current time from `clock`
+    ///
+    /// This is an alias for
+    /// [`debounce_with_clock`](BoxConsumer::debounce_with_clock). The name
+    /// is kept. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `min_interval` - The minimum time between two forwarded values
+    /// * `clock` - Returns the current time; must never go backwards
+    ///
+    /// # Return Value
+    ///
+    /// Returns the throttling `BoxConsumer<T>`
+    pub fn throttle_leading_with_clock<C>(self, min_interval: Duration, clock: C) -> BoxConsumer<T>
+    where
+        C: Fn() -> Instant + 'static,
+    {
+        self.debounce_with_clock(min_interval, clock)
+    }
+
+    /// Creates a conditional consumer
+    ///
+    /// Returns a consumer that only executes when a predicate is satisfied.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - The condition to check. **Note: This parameter is passed
+    ///   by value and will transfer ownership.** If you need to preserve the
+    ///   original predicate, clone it first (if it implements `Clone`). Can be:
+    ///   - A closure: `|x: &T| -> bool`
+    ///   - A function pointer: `fn(&T) -> bool`
+    ///   - A `BoxPredicate<T>`
+    ///   - An `RcPredicate<T>`
+    ///   - An `ArcPredicate<T>`
+    ///   - Any type implementing `Predicate<T>`
+    ///
+    /// # Return Value
+    ///
+    /// Returns `BoxConditionalConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ## Using a closure
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let log = Arc::new(Mutex::new(Vec::new()));
+    /// let l = log.clone();
+    /// let consumer = BoxConsumer::new(move |x: &i32| {
+    ///     l.lock().unwrap().push(*x);
+    /// });
+    /// let mut conditional = consumer.when(|x: &i32| *x > 0);
+    ///
+    /// conditional.accept(&5);
+    /// assert_eq!(*log.lock().unwrap(), vec![5]);
+    ///
+    /// conditional.accept(&-5);
+    /// assert_eq!(*log.lock().unwrap(), vec![5]); // Unchanged
+    /// ```
+    ///
+    /// ## Preserving predicate with clone
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    /// use prism3_function::predicate::{Predicate, RcPredicate};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let log = Arc::new(Mutex::new(Vec::new()));
+    /// let l = log.clone();
+    /// let is_positive = RcPredicate::new(|x: &i32| *x > 0);
+    /// let consumer = BoxConsumer::new(move |x: &i32| {
+    ///     l.lock().unwrap().push(*x);
+    /// });
+    ///
+    /// // Clone to preserve original predicate
+    /// let mut conditional = consumer.when(is_positive.clone());
+    ///
+    /// conditional.accept(&5);
+    /// assert_eq!(*log.lock().unwrap(), vec![5]);
+    ///
+    /// // Original predicate still usable
+    /// assert!(is_positive.test(&3));
+    /// ```
+    pub fn when<P>(self, predicate: P) -> BoxConditionalConsumer<T>
+    where
+        P: Predicate<T> + 'static,
+    {
+        BoxConditionalConsumer {
+            consumer: self,
+            predicate: predicate.into_box(),
+        }
+    }
+
+    /// Creates a conditional consumer guarded by several predicates
+    ///
+    /// The consumer executes only when all `predicates` are satisfied. They
+    /// are evaluated in order and evaluation stops at the first one returning
+    /// `false`; an empty list is always satisfied. See
+    /// [`BoxPredicate::all_of`].
+    ///
+    /// # Parameters
+    ///
+    /// * `predicates` - The conditions to check
+    ///
+    /// # Return Value
+    ///
+    /// Returns `BoxConditionalConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxConsumer, BoxPredicate, Consumer};
+    ///
+    /// let (inner, values) = BoxConsumer::collect();
+    /// let mut consumer = inner.when_all(vec![
+    ///     BoxPredicate::new(|x: &i32| *x > 0),
+    ///     BoxPredicate::new(|x: &i32| x % 2 == 0),
+    /// ]);
+    /// for x in [-2, 3, 4] {
+    ///     consumer.accept(&x);
+    /// }
+    /// assert_eq!(*values.lock().unwrap(), vec![4]);
+    /// ```
+    pub fn when_all(self, predicates: Vec<BoxPredicate<T>>) -> BoxConditionalConsumer<T> {
+        self.when(BoxPredicate::all_of(predicates))
+    }
+
+    /// Creates a conditional consumer guarded by alternative predicates
+    ///
+    /// The consumer executes when any of `predicates` is satisfied. They are
+    /// evaluated in order and evaluation stops at the first one returning
+    /// `true`; an empty list is never satisfied. See
+    /// [`BoxPredicate::any_of`].
+    ///
+    /// # Parameters
+    ///
+    /// * `predicates` - The conditions to check
+    ///
+    /// # Return Value
+    ///
+    /// Returns `BoxConditionalConsumer<T>`
+    pub fn when_any(self, predicates: Vec<BoxPredicate<T>>) -> BoxConditionalConsumer<T> {
+        self.when(BoxPredicate::any_of(predicates))
+    }
+
+    /// Converts this consumer into a mutator that never writes
+    ///
+    /// The returned mutator passes a shared reborrow of the mutable
+    /// reference to this consumer, so the value is only read. The name is
+    /// dropped because mutators are unnamed. Consumes self.
+    ///
+    /// # Return Value
+    ///
+    /// Returns a `BoxMutator<T>` that leaves its input unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxConsumer, Mutator};
+    ///
+    /// let (consumer, values) = BoxConsumer::<i32>::collect();
+    /// let mut mutator = consumer.into_mutator();
+    /// let mut value = 5;
+    /// mutator.mutate(&mut value);
+    /// assert_eq!(value, 5);
+    /// assert_eq!(*values.lock().unwrap(), vec![5]);
+    /// ```
+    pub fn into_mutator(self) -> BoxMutator<T> {
+        let mut function = self.function;
+        BoxMutator::new(move |t: &mut T| function(&*t))
+    }
+}
+
+impl<T> BoxConsumer<T>
+where
+    T: Clone + 'static,
+{
+    /// Create a consumer that collects clones of the accepted values
+    ///
+    /// # Return Value
+    ///
+    /// Returns the collecting consumer together with a shared handle to the
+    /// collected values, in acceptance order. The sink recovers from a
+    /// poisoned lock, for example after a panicking `clone`, so readers of
+    /// the handle should do the same.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    /// use std::sync::PoisonError;
+    ///
+    /// let (mut consumer, values) = BoxConsumer::collect();
+    /// consumer.accept(&3);
+    /// consumer.accept(&1);
+    /// consumer.accept(&3);
+    /// let values = values.lock().unwrap_or_else(PoisonError::into_inner);
+    /// assert_eq!(*values, vec![3, 1, 3]);
+    /// ```
+    pub fn collect() -> (Self, Arc<Mutex<Vec<T>>>) {
+        let values = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&values);
+        let consumer = BoxConsumer::new(move |value: &T| {
+            sink.lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(value.clone());
+        });
+        (consumer, values)
+    }
+
+    /// Create a consumer sending a clone of each value into a channel
+    ///
+    /// Values sent after the receiver has been dropped are silently
+    /// discarded. Use [`from_sender_with_dropped`](BoxConsumer::from_sender_with_dropped)
+    /// to keep track of them.
+    ///
+    /// # Parameters
+    ///
+    /// * `sender` - The sending half of an `std::sync::mpsc` channel
+    ///
+    /// # Return Value
+    ///
+    /// Returns the sending consumer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    /// use std::sync::mpsc;
+    ///
+    /// let (tx, rx) = mpsc::channel();
+    /// let mut consumer = BoxConsumer::from_sender(tx);
+    /// consumer.accept(&1);
+    /// consumer.accept(&2);
+    /// assert_eq!(rx.try_iter().collect::<Vec<_>>(), vec![1, 2]);
+    /// ```
+    pub fn from_sender(sender: Sender<T>) -> Self {
+        BoxConsumer::new(move |value: &T| {
+            let _ = sender.send(value.clone());
+        })
+    }
+
+    /// Create a channel-sending consumer counting undelivered values
+    ///
+    /// Like [`from_sender`](BoxConsumer::from_sender), but every value
+    /// that cannot be sent because the receiver has been dropped increments
+    /// the returned counter.
+    ///
+    /// # Parameters
+    ///
+    /// * `sender` - The sending half of an `std::sync::mpsc` channel
+    ///
+    /// # Return Value
+    ///
+    /// Returns the sending consumer together with a shared counter of
+    /// dropped values
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    /// use std::sync::atomic::Ordering;
+    /// use std::sync::mpsc;
+    ///
+    /// let (tx, rx) = mpsc::channel();
+    /// let (mut consumer, dropped) = BoxConsumer::from_sender_with_dropped(tx);
+    /// consumer.accept(&1);
+    /// drop(rx);
+    /// consumer.accept(&2);
+    /// assert_eq!(dropped.load(Ordering::SeqCst), 1);
+    /// ```
+    pub fn from_sender_with_dropped(sender: Sender<T>) -> (Self, Arc<AtomicUsize>) {
+        let dropped = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&dropped);
+        let consumer = BoxConsumer::new(move |value: &T| {
+            if sender.send(value.clone()).is_err() {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+        (consumer, dropped)
+    }
+
+    /// Create a consumer sending a clone of each value into a bounded channel
+    ///
+    /// Each `accept` blocks while the channel is full. Values sent after the
+    /// receiver has been dropped are silently discarded. Use
+    /// [`from_sync_sender_with_dropped`](BoxConsumer::from_sync_sender_with_dropped)
+    /// to keep track of them.
+    ///
+    /// # Parameters
+    ///
+    /// * `sender` - The sending half of an `std::sync::mpsc::sync_channel`
+    ///
+    /// # Return Value
+    ///
+    /// Returns the sending consumer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    /// use std::sync::mpsc;
+    ///
+    /// let (tx, rx) = mpsc::sync_channel(4);
+    /// let mut consumer = BoxConsumer::from_sync_sender(tx);
+    /// consumer.accept(&1);
+    /// assert_eq!(rx.recv(), Ok(1));
+    /// ```
+    pub fn from_sync_sender(sender: SyncSender<T>) -> Self {
+        BoxConsumer::new(move |value: &T| {
+            let _ = sender.send(value.clone());
+        })
+    }
+
+    /// Create a bounded-channel-sending consumer counting undelivered values
+    ///
+    /// Like [`from_sync_sender`](BoxConsumer::from_sync_sender), but every
+    /// value that cannot be sent because the receiver has been dropped
+    /// increments the returned counter.
+    ///
+    /// # Parameters
+    ///
+    /// * `sender` - The sending half of an `std::sync::mpsc::sync_channel`
+    ///
+    /// # Return Value
+    ///
+    /// Returns the sending consumer together with a shared counter of
+    /// dropped values
+    pub fn from_sync_sender_with_dropped(sender: SyncSender<T>) -> (Self, Arc<AtomicUsize>) {
+        let dropped = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&dropped);
+        let consumer = BoxConsumer::new(move |value: &T| {
+            if sender.send(value.clone()).is_err() {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+        (consumer, dropped)
+    }
+}
+
+impl<T> BoxConsumer<T>
+where
+    T: Hash + Eq + Clone + 'static,
+{
+    /// Create a consumer that collects the distinct accepted values
+    ///
+    /// Only the first occurrence of each value is cloned and stored.
+    ///
+    /// # Return Value
+    ///
+    /// Returns the collecting consumer together with a shared handle to the
+    /// set of collected values. As with [`collect`](Self::collect), the sink
+    /// recovers from a poisoned lock.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    /// use std::sync::PoisonError;
+    ///
+    /// let (mut consumer, values) = BoxConsumer::collect_unique();
+    /// consumer.accept(&"a");
+    /// consumer.accept(&"b");
+    /// consumer.accept(&"a");
+    /// let values = values.lock().unwrap_or_else(PoisonError::into_inner);
+    /// assert_eq!(values.len(), 2);
+    /// assert!(values.contains("a") && values.contains("b"));
+    /// ```
+    pub fn collect_unique() -> (Self, Arc<Mutex<HashSet<T>>>) {
+        let values = Arc::new(Mutex::new(HashSet::new()));
+        let sink = Arc::clone(&values);
+        let consumer = BoxConsumer::new(move |value: &T| {
+            let mut set = sink.lock().unwrap_or_else(PoisonError::into_inner);
+            if !set.contains(value) {
+                set.insert(value.clone());
+            }
+        });
+        (consumer, values)
+    }
+}
+
+impl<T> BoxConsumer<Vec<T>>
+where
+    T: Clone + 'static,
+{
+    /// Adapts this consumer to a sliding window over single values
+    ///
+    /// Returns a consumer that keeps the last `window_size` accepted values
+    /// and, once it holds that many, passes them to this consumer oldest
+    /// first on every accept. The first `window_size - 1` values only fill
+    /// the window. The name is kept. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `window_size` - The number of values in each window
+    ///
+    /// # Return Value
+    ///
+    /// Returns the windowing `BoxConsumer<T>`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, BoxConsumer};
+    ///
+    /// let (averages, values) = BoxConsumer::collect();
+    /// let mut moving_average = averages
+    ///     .adapt(|w: &Vec<f64>| w.iter().sum::<f64>() / w.len() as f64)
+    ///     .windowed(3);
+    /// moving_average.accept_all([1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(*values.lock().unwrap(), vec![2.0, 3.0]);
+    /// ```
+    pub fn windowed(self, window_size: usize) -> BoxConsumer<T> {
+        assert!(window_size > 0, "windowed requires a non-zero window size");
+        let name = self.name;
+        let mut function = self.function;
+        let mut buffer = VecDeque::with_capacity(window_size);
+        BoxConsumer {
+            function: Box::new(move |t: &T| {
+                if buffer.len() == window_size {
+                    buffer.pop_front();
+                }
+                buffer.push_back(t.clone());
+                if buffer.len() == window_size {
+                    let window: Vec<T> = buffer.iter().cloned().collect();
+                    function(&window);
+                }
+            }),
+            name,
+        }
+    }
+
+    /// Adapts this batch consumer to single values
+    ///
+    /// Returns a [`BufferedConsumer`] that clones accepted values into a
+    /// buffer and passes it to this consumer every `batch_size` values.
+    /// Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `batch_size` - The number of values in a full batch
+    ///
+    /// # Return Value
+    ///
+    /// Returns the batching `BufferedConsumer<T>`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxConsumer, Consumer};
+    ///
+    /// let (sink, batches) = BoxConsumer::<Vec<i32>>::collect();
+    /// let mut buffered = sink.buffered(3);
+    /// buffered.accept_all([1, 2, 3, 4]);
+    /// assert_eq!(*batches.lock().unwrap(), vec![vec![1, 2, 3]]);
+    /// assert_eq!(buffered.into_inner(), vec![4]);
+    /// ```
+    pub fn buffered(self, batch_size: usize) -> BufferedConsumer<T> {
+        BufferedConsumer::new(batch_size, self)
+    }
+}
+
+impl<T> ArcConsumer<Vec<T>>
+where
+    T: Clone + Send + 'static,
+{
+    /// Adapts this batch consumer to single values (thread-safe version)
+    ///
+    /// Returns an [`ArcBufferedConsumer`] sharing this consumer. Borrows
+    /// &self, does not consume the original consumer.
+    ///
+    /// # Parameters
+    ///
+    /// * `batch_size` - The number of values in a full batch
+    ///
+    /// # Return Value
+    ///
+    /// Returns the batching `ArcBufferedConsumer<T>`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero
+    pub fn buffered(&self, batch_size: usize) -> ArcBufferedConsumer<T> {
+        ArcBufferedConsumer::new(batch_size, self.clone())
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T> BoxConsumer<T>
+where
+    T: 'static,
+{
+    /// Convert into a `futures::Sink` feeding every item to this consumer
+    ///
+    /// The sink is always ready and never fails; each item sent to it is
+    /// accepted immediately. Available with the `futures` feature.
+    ///
+    /// # Return Value
+    ///
+    /// Returns a sink that owns this consumer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use futures::executor::block_on;
+    /// use futures::{stream, SinkExt, StreamExt};
+    /// use prism3_function::BoxConsumer;
+    ///
+    /// let (consumer, values) = BoxConsumer::collect();
+    /// let mut sink = consumer.into_stream_sink();
+    /// block_on(sink.send_all(&mut stream::iter(vec![1, 2, 3]).map(Ok))).unwrap();
+    /// assert_eq!(*values.lock().unwrap(), vec![1, 2, 3]);
+    /// ```
+    pub fn into_stream_sink(
+        self,
+    ) -> impl futures::Sink<T, Error = std::convert::Infallible> + Unpin {
+        ConsumerSink { consumer: self }
+    }
+}
+
+/// Sink adapter returned by `BoxConsumer::into_stream_sink`
+#[cfg(feature = "futures")]
+struct ConsumerSink<T> {
+    consumer: BoxConsumer<T>,
+}
+
+#[cfg(feature = "futures")]
+impl<T> futures::Sink<T> for ConsumerSink<T> {
+    type Error = std::convert::Infallible;
+
+    fn poll_ready(
+        self: std::pin::Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn start_send(mut self: std::pin::Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        self.consumer.accept(&item);
+        Ok(())
+    }
+
+    fn poll_flush(
+        self: std::pin::Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(
+        self: std::pin::Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+}
+
+impl<T> Consumer<T> for BoxConsumer<T> {
+    fn accept(&mut self, value: &T) {
+        (self.function)(value)
+    }
+
+    fn into_box(self) -> BoxConsumer<T>
+    where
+        T: 'static,
+    {
+        self
+    }
+
+    fn into_rc(self) -> RcConsumer<T>
+    where
+        T: 'static,
+    {
+        let mut self_fn = self.function;
+        RcConsumer::new(move |t| self_fn(t))
+    }
+
+    // do NOT override Consumer::into_arc() because BoxConsumer is not Send + Sync
+    // and calling BoxConsumer::into_arc() will cause a compile error
+
+    fn into_fn(self) -> impl FnMut(&T)
+    where
+        T: 'static,
+    {
+        self.function
     }
 
     // do NOT override Consumer::to_xxx() because BoxConsumer is not Clone
@@ -1145,6 +2564,69 @@ where
     }
 }
 
+impl<T> BoxConditionalConsumer<T>
+where
+    T: Clone + 'static,
+{
+    /// Captures the values rejected by the predicate in a dead-letter queue
+    ///
+    /// Returns a consumer behaving like this conditional consumer, except that
+    /// every value failing the predicate is cloned into a bounded queue. The
+    /// paired supplier drains that queue in arrival order and yields `None`
+    /// once it is empty. When the queue already holds `capacity` values, the
+    /// oldest one is discarded to make room for the new one. The queue grows
+    /// on demand, so a generous `capacity` costs nothing up front.
+    ///
+    /// # Parameters
+    ///
+    /// * `capacity` - The maximum number of rejected values kept
+    ///
+    /// # Returns
+    ///
+    /// Returns the consumer together with the supplier draining the
+    /// dead-letter queue
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxConsumer, Consumer, Supplier};
+    ///
+    /// let (valid, values) = BoxConsumer::collect();
+    /// let (mut consumer, mut dead_letters) = valid
+    ///     .when(|x: &i32| *x >= 0)
+    ///     .with_dead_letter_queue(16);
+    ///
+    /// consumer.accept(&1);
+    /// consumer.accept(&-2);
+    /// consumer.accept(&3);
+    /// consumer.accept(&-4);
+    ///
+    /// assert_eq!(*values.lock().unwrap(), vec![1, 3]);
+    /// assert_eq!(dead_letters.get(), Some(-2));
+    /// assert_eq!(dead_letters.get(), Some(-4));
+    /// assert_eq!(dead_letters.get(), None);
+    /// ```
+    pub fn with_dead_letter_queue(
+        self,
+        capacity: usize,
+    ) -> (BoxConsumer<T>, BoxSupplier<Option<T>>) {
+        let queue = Rc::new(RefCell::new(VecDeque::new()));
+        let sink = Rc::clone(&queue);
+        let consumer = self.or_else(move |value: &T| {
+            if capacity == 0 {
+                return;
+            }
+            let mut queue = sink.borrow_mut();
+            if queue.len() == capacity {
+                queue.pop_front();
+            }
+            queue.push_back(value.clone());
+        });
+        let supplier = BoxSupplier::new(move || queue.borrow_mut().pop_front());
+        (consumer, supplier)
+    }
+}
+
 // ============================================================================
 // 4. ArcConsumer - Thread-Safe Shared Ownership Implementation
 // ============================================================================
@@ -1181,7 +2663,10 @@ where
 ///
 /// These overheads are necessary for safe concurrent access. If thread safety
 /// is not needed, consider using `RcConsumer` for less single-threaded sharing
-/// overhead.
+/// overhead. If the closure is `Fn` (for example logging or atomic metrics),
+/// use [`ArcReadonlyConsumer`](crate::ArcReadonlyConsumer) instead: it is
+/// built on `Arc<dyn Fn(&T) + Send + Sync>` without any lock, and its
+/// `and_then` and `when` stay lock-free.
 ///
 /// # Examples
 ///
@@ -1370,64 +2855,400 @@ where
         let second = Arc::clone(&next.function);
         ArcConsumer {
             function: Arc::new(Mutex::new(move |t: &T| {
-                first.lock().unwrap()(t);
-                second.lock().unwrap()(t);
+                first.lock().unwrap_or_else(PoisonError::into_inner)(t);
+                second.lock().unwrap_or_else(PoisonError::into_inner)(t);
             })),
             name: None,
         }
     }
 
-    /// Creates a conditional consumer (thread-safe version)
+    /// Attaches a consumer executed before this consumer
+    ///
+    /// Returns a consumer that passes each value to `prev` first, then to
+    /// this consumer, i.e. `a.before(b)` runs like `b.and_then(a)`. Borrows
+    /// &self; the new consumer shares this consumer's state and keeps its
+    /// name.
+    ///
+    /// # Parameters
+    ///
+    /// * `prev` - Consumer run before the current operation, must be `Send`.
+    ///   Can be:
+    ///   - A closure: `|x: &T|`
+    ///   - An `ArcConsumer<T>`
+    ///   - Any type implementing `Consumer<T> + Send`
+    ///
+    /// # Return Value
+    ///
+    /// Returns the combined `ArcConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcConsumer, Consumer};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let log = Arc::new(Mutex::new(Vec::new()));
+    /// let l1 = log.clone();
+    /// let l2 = log.clone();
+    /// let store = ArcConsumer::new(move |x: &i32| l1.lock().unwrap().push(format!("store {}", x)));
+    /// let mut pipeline = store.before(move |x: &i32| {
+    ///     l2.lock().unwrap().push(format!("log {}", x));
+    /// });
+    /// pipeline.accept(&1);
+    /// assert_eq!(*log.lock().unwrap(), vec!["log 1", "store 1"]);
+    /// ```
+    pub fn before<C>(&self, prev: C) -> ArcConsumer<T>
+    where
+        C: Consumer<T> + Send + 'static,
+    {
+        let current = Arc::clone(&self.function);
+        let mut prev = prev;
+        ArcConsumer {
+            function: Arc::new(Mutex::new(move |t: &T| {
+                prev.accept(t);
+                current.lock().unwrap_or_else(PoisonError::into_inner)(t);
+            })),
+            name: self.name.clone(),
+        }
+    }
+
+    /// Splits each value to this consumer and another ArcConsumer
+    ///
+    /// Returns a thread-safe consumer passing each value to this consumer
+    /// first, then to `other`. This is an alias for
+    /// [`and_then`](ArcConsumer::and_then). Both consumers keep their shared
+    /// state. Borrows &self, does not consume either consumer.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The second recipient
+    ///
+    /// # Return Value
+    ///
+    /// Returns a new `ArcConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, ArcConsumer};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let log = Arc::new(Mutex::new(Vec::new()));
+    /// let (l1, l2) = (log.clone(), log.clone());
+    /// let store = ArcConsumer::new(move |x: &i32| l1.lock().unwrap().push(*x));
+    /// let audit = ArcConsumer::new(move |x: &i32| l2.lock().unwrap().push(-*x));
+    /// let mut both = store.tee_arc(&audit);
+    /// both.accept(&3);
+    /// assert_eq!(*log.lock().unwrap(), vec![3, -3]);
+    /// ```
+    pub fn tee_arc(&self, other: &ArcConsumer<T>) -> ArcConsumer<T> {
+        self.and_then(other)
+    }
+
+    /// Forwards only every `n`-th value to this consumer
+    ///
+    /// Returns a consumer forwarding the 1st, `n + 1`-th, `2n + 1`-th, ...
+    /// accepted values and dropping the others. The counter lives in the
+    /// returned consumer, so all its clones share it. The name is kept.
+    /// Borrows &self.
+    ///
+    /// # Parameters
+    ///
+    /// * `n` - The sampling interval
+    ///
+    /// # Returns
+    ///
+    /// Returns the sampling `ArcConsumer<T>`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn every(&self, n: usize) -> ArcConsumer<T> {
+        assert!(n > 0, "every requires a non-zero interval");
+        let current = Arc::clone(&self.function);
+        let mut index = 0;
+        ArcConsumer {
+            function: Arc::new(Mutex::new(move |t: &T| {
+                if index == 0 {
+                    current.lock().unwrap_or_else(PoisonError::into_inner)(t);
+                }
+                index = (index + 1) % n;
+            })),
+            name: self.name.clone(),
+        }
+    }
+
+    /// Drops the first `n` values and forwards the rest to this consumer
+    ///
+    /// The counter lives in the returned consumer, so all its clones share
+    /// it. The name is kept. Borrows &self.
+    ///
+    /// # Parameters
+    ///
+    /// * `n` - The number of values to drop
+    ///
+    /// # Returns
+    ///
+    /// Returns the skipping `ArcConsumer<T>`
+    pub fn skip(&self, n: usize) -> ArcConsumer<T> {
+        let current = Arc::clone(&self.function);
+        let mut remaining = n;
+        ArcConsumer {
+            function: Arc::new(Mutex::new(move |t: &T| {
+                if remaining > 0 {
+                    remaining -= 1;
+                } else {
+                    current.lock().unwrap_or_else(PoisonError::into_inner)(t);
+                }
+            })),
+            name: self.name.clone(),
+        }
+    }
+
+    /// Forwards only the first `n` values to this consumer
+    ///
+    /// Later values are dropped. The counter lives in the returned consumer,
+    /// so all its clones share it. The name is kept. Borrows &self.
+    ///
+    /// # Parameters
+    ///
+    /// * `n` - The number of values to forward
+    ///
+    /// # Returns
+    ///
+    /// Returns the limiting `ArcConsumer<T>`
+    pub fn take(&self, n: usize) -> ArcConsumer<T> {
+        let current = Arc::clone(&self.function);
+        let mut remaining = n;
+        ArcConsumer {
+            function: Arc::new(Mutex::new(move |t: &T| {
+                if remaining > 0 {
+                    remaining -= 1;
+                    current.lock().unwrap_or_else(PoisonError::into_inner)(t);
+                }
+            })),
+            name: self.name.clone(),
+        }
+    }
+
+    /// Creates a conditional consumer (thread-safe version)
+    ///
+    /// Returns a consumer that only executes when a predicate is satisfied.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - The condition to check, must be `Send + Sync`, can be:
+    ///   - Closure: `|x: &T| -> bool` (requires `Send + Sync`)
+    ///   - Function pointer: `fn(&T) -> bool`
+    ///   - `ArcPredicate<T>`
+    ///   - Any type implementing `Predicate<T> + Send + Sync`
+    ///
+    /// # Returns
+    ///
+    /// Returns `ArcConditionalConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, ArcConsumer};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let log = Arc::new(Mutex::new(Vec::new()));
+    /// let l = log.clone();
+    /// let consumer = ArcConsumer::new(move |x: &i32| {
+    ///     l.lock().unwrap().push(*x);
+    /// });
+    /// let conditional = consumer.when(|x: &i32| *x > 0);
+    ///
+    /// let conditional_clone = conditional.clone();
+    ///
+    /// let mut positive = 5;
+    /// let mut m = conditional;
+    /// m.accept(&positive);
+    /// assert_eq!(*log.lock().unwrap(), vec![5]);
+    /// ```
+    pub fn when<P>(&self, predicate: P) -> ArcConditionalConsumer<T>
+    where
+        P: Predicate<T> + Send + Sync + 'static,
+        T: Send + Sync,
+    {
+        ArcConditionalConsumer {
+            consumer: self.clone(),
+            predicate: predicate.into_arc(),
+        }
+    }
+
+    /// Creates a conditional consumer guarded by several predicates
+    ///
+    /// The consumer executes only when all `predicates` are satisfied. They
+    /// are evaluated in order and evaluation stops at the first one returning
+    /// `false`; an empty list is always satisfied.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicates` - The conditions to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `ArcConditionalConsumer<T>`
+    pub fn when_all(&self, predicates: Vec<ArcPredicate<T>>) -> ArcConditionalConsumer<T>
+    where
+        T: Send + Sync,
+    {
+        self.when(move |value: &T| predicates.iter().all(|p| p.test(value)))
+    }
+
+    /// Creates a conditional consumer guarded by alternative predicates
+    ///
+    /// The consumer executes when any of `predicates` is satisfied. They are
+    /// evaluated in order and evaluation stops at the first one returning
+    /// `true`; an empty list is never satisfied.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicates` - The conditions to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `ArcConditionalConsumer<T>`
+    pub fn when_any(&self, predicates: Vec<ArcPredicate<T>>) -> ArcConditionalConsumer<T>
+    where
+        T: Send + Sync,
+    {
+        self.when(move |value: &T| predicates.iter().any(|p| p.test(value)))
+    }
+
+    /// Creates a conditional consumer from a predicate that is not `Sync`
+    ///
+    /// Like [`when`](ArcConsumer::when), but only requires the predicate to
+    /// be `Send` and does not require `T: Sync`. The predicate is stored
+    /// behind a `Mutex`, so concurrent evaluations of it are serialized; a
+    /// panicking predicate does not poison later evaluations.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - The condition to check, must be `Send`, can be:
+    ///   - Closure: `|x: &T| -> bool` (requires `Send`)
+    ///   - Any type implementing `Predicate<T> + Send`
+    ///
+    /// # Returns
+    ///
+    /// Returns `ArcConditionalConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, ArcConsumer};
+    /// use std::cell::Cell;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let log = Arc::new(Mutex::new(Vec::new()));
+    /// let l = log.clone();
+    /// let consumer = ArcConsumer::new(move |x: &i32| {
+    ///     l.lock().unwrap().push(*x);
+    /// });
+    /// // `Cell` is `Send` but not `Sync`
+    /// let limit = Cell::new(0);
+    /// let mut conditional = consumer.when_unsync(move |x: &i32| *x > limit.get());
+    ///
+    /// conditional.accept(&5);
+    /// conditional.accept(&-5);
+    /// assert_eq!(*log.lock().unwrap(), vec![5]);
+    /// ```
+    pub fn when_unsync<P>(&self, predicate: P) -> ArcConditionalConsumer<T>
+    where
+        P: Predicate<T> + Send + 'static,
+    {
+        let predicate = Mutex::new(predicate);
+        ArcConditionalConsumer {
+            consumer: self.clone(),
+            predicate: ArcPredicate::new(move |t: &T| {
+                predicate
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .test(t)
+            }),
+        }
+    }
+
+    /// Converts this consumer into a mutator that never writes
+    ///
+    /// The returned mutator shares this consumer's function and passes it a
+    /// shared reborrow of the mutable reference, so the value is only read.
+    /// The name is dropped because mutators are unnamed. Consumes self.
+    ///
+    /// # Return Value
+    ///
+    /// Returns an `ArcMutator<T>` that leaves its input unchanged
+    pub fn into_mutator(self) -> ArcMutator<T> {
+        let function = self.function;
+        ArcMutator::new(move |t: &mut T| {
+            function.lock().unwrap_or_else(PoisonError::into_inner)(&*t)
+        })
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T> ArcConsumer<T>
+where
+    T: Send + 'static,
+{
+    /// Drain a `futures::Stream`, passing every item to this consumer
     ///
-    /// Returns a consumer that only executes when a predicate is satisfied.
+    /// The returned future shares this consumer's state with `self` and
+    /// completes once the stream is exhausted. It is `Send` whenever the
+    /// stream is. Available with the `futures` feature.
     ///
     /// # Parameters
     ///
-    /// * `predicate` - The condition to check, must be `Send + Sync`, can be:
-    ///   - Closure: `|x: &T| -> bool` (requires `Send + Sync`)
-    ///   - Function pointer: `fn(&T) -> bool`
-    ///   - `ArcPredicate<T>`
-    ///   - Any type implementing `Predicate<T> + Send + Sync`
+    /// * `stream` - The stream to drain
     ///
-    /// # Returns
+    /// # Return Value
     ///
-    /// Returns `ArcConditionalConsumer<T>`
+    /// Returns a future resolving after the last item has been accepted
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use prism3_function::{Consumer, ArcConsumer};
+    /// use futures::executor::block_on;
+    /// use futures::stream;
+    /// use prism3_function::ArcConsumer;
     /// use std::sync::{Arc, Mutex};
     ///
-    /// let log = Arc::new(Mutex::new(Vec::new()));
-    /// let l = log.clone();
-    /// let consumer = ArcConsumer::new(move |x: &i32| {
-    ///     l.lock().unwrap().push(*x);
-    /// });
-    /// let conditional = consumer.when(|x: &i32| *x > 0);
-    ///
-    /// let conditional_clone = conditional.clone();
-    ///
-    /// let mut positive = 5;
-    /// let mut m = conditional;
-    /// m.accept(&positive);
-    /// assert_eq!(*log.lock().unwrap(), vec![5]);
+    /// let total = Arc::new(Mutex::new(0));
+    /// let t = total.clone();
+    /// let consumer = ArcConsumer::new(move |x: &i32| *t.lock().unwrap() += *x);
+    /// block_on(consumer.as_stream_consumer(stream::iter(1..=4)));
+    /// assert_eq!(*total.lock().unwrap(), 10);
     /// ```
-    pub fn when<P>(&self, predicate: P) -> ArcConditionalConsumer<T>
+    pub fn as_stream_consumer<S>(&self, stream: S) -> impl futures::Future<Output = ()>
     where
-        P: Predicate<T> + Send + Sync + 'static,
-        T: Send + Sync,
+        S: futures::Stream<Item = T>,
     {
-        ArcConditionalConsumer {
-            consumer: self.clone(),
-            predicate: predicate.into_arc(),
-        }
+        use futures::StreamExt;
+
+        let mut consumer = self.clone();
+        stream.for_each(move |item| {
+            consumer.accept(&item);
+            futures::future::ready(())
+        })
     }
 }
 
 impl<T> Consumer<T> for ArcConsumer<T> {
     fn accept(&mut self, value: &T) {
-        (self.function.lock().unwrap())(value)
+        (self.function.lock().unwrap_or_else(PoisonError::into_inner))(value)
+    }
+
+    fn accept_all<I>(&mut self, values: I)
+    where
+        I: IntoIterator,
+        I::Item: Borrow<T>,
+    {
+        let mut function = self.function.lock().unwrap_or_else(PoisonError::into_inner);
+        for value in values {
+            function(value.borrow());
+        }
     }
 
     fn into_box(self) -> BoxConsumer<T>
@@ -1435,7 +3256,7 @@ impl<T> Consumer<T> for ArcConsumer<T> {
         T: 'static,
     {
         let self_fn = self.function;
-        BoxConsumer::new(move |t| self_fn.lock().unwrap()(t))
+        BoxConsumer::new(move |t| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t))
     }
 
     fn into_rc(self) -> RcConsumer<T>
@@ -1443,7 +3264,7 @@ impl<T> Consumer<T> for ArcConsumer<T> {
         T: 'static,
     {
         let self_fn = self.function;
-        RcConsumer::new(move |t| self_fn.lock().unwrap()(t))
+        RcConsumer::new(move |t| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t))
     }
 
     fn into_arc(self) -> ArcConsumer<T>
@@ -1459,7 +3280,7 @@ impl<T> Consumer<T> for ArcConsumer<T> {
     {
         let self_fn = self.function;
         move |t: &T| {
-            self_fn.lock().unwrap()(t);
+            self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t);
         }
     }
 
@@ -1468,7 +3289,7 @@ impl<T> Consumer<T> for ArcConsumer<T> {
         T: 'static,
     {
         let self_fn = self.function.clone();
-        BoxConsumer::new(move |t| self_fn.lock().unwrap()(t))
+        BoxConsumer::new(move |t| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t))
     }
 
     fn to_rc(&self) -> RcConsumer<T>
@@ -1476,7 +3297,7 @@ impl<T> Consumer<T> for ArcConsumer<T> {
         T: 'static,
     {
         let self_fn = self.function.clone();
-        RcConsumer::new(move |t| self_fn.lock().unwrap()(t))
+        RcConsumer::new(move |t| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t))
     }
 
     fn to_arc(&self) -> ArcConsumer<T>
@@ -1488,7 +3309,7 @@ impl<T> Consumer<T> for ArcConsumer<T> {
 
     fn to_fn(&self) -> impl FnMut(&T) {
         let self_fn = self.function.clone();
-        move |t| self_fn.lock().unwrap()(t)
+        move |t| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t)
     }
 }
 
@@ -1662,7 +3483,7 @@ impl<T> crate::consumer_once::ConsumerOnce<T> for ArcConsumer<T> {
     {
         let self_fn = self.function.clone();
         crate::consumer_once::BoxConsumerOnce::new(move |t| {
-            self_fn.lock().unwrap()(t);
+            self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t);
         })
     }
 
@@ -1699,7 +3520,7 @@ impl<T> crate::consumer_once::ConsumerOnce<T> for ArcConsumer<T> {
         T: 'static,
     {
         let self_fn = self.function.clone();
-        move |t| self_fn.lock().unwrap()(t)
+        move |t| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t)
     }
 }
 
@@ -1822,6 +3643,59 @@ impl<T> ArcConditionalConsumer<T>
 where
     T: Send + 'static,
 {
+    /// Chains another consumer in sequence (thread-safe version)
+    ///
+    /// Combines the current conditional consumer with another consumer into a new
+    /// consumer. The current conditional consumer executes first, followed by the
+    /// next consumer. The conditional consumer remains usable after the call.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - The next consumer to execute, can be:
+    ///   - Closure: `|x: &T|` (must be `Send`)
+    ///   - `ArcConsumer<T>`, `BoxConsumer<T>`
+    ///   - Any type implementing `Consumer<T> + Send`
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `ArcConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, ArcConsumer};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let log = Arc::new(Mutex::new(Vec::new()));
+    /// let l1 = log.clone();
+    /// let l2 = log.clone();
+    /// let conditional = ArcConsumer::new(move |x: &i32| {
+    ///     l1.lock().unwrap().push(*x);
+    /// })
+    /// .when(|x: &i32| *x > 0);
+    /// let mut chained = conditional.and_then(move |x: &i32| {
+    ///     l2.lock().unwrap().push(*x * 2);
+    /// });
+    ///
+    /// chained.accept(&5);
+    /// assert_eq!(*log.lock().unwrap(), vec![5, 10]);
+    ///
+    /// chained.accept(&-5);
+    /// assert_eq!(*log.lock().unwrap(), vec![5, 10, -10]);
+    /// ```
+    pub fn and_then<C>(&self, next: C) -> ArcConsumer<T>
+    where
+        C: Consumer<T> + Send + 'static,
+        T: Send + Sync,
+    {
+        let mut first = self.clone();
+        let mut second = next;
+        ArcConsumer::new(move |t: &T| {
+            first.accept(t);
+            second.accept(t);
+        })
+    }
+
     /// Adds an else branch (thread-safe version)
     ///
     /// Executes the original consumer when the condition is satisfied, otherwise
@@ -2140,6 +4014,142 @@ where
         }
     }
 
+    /// Attaches a consumer executed before this consumer
+    ///
+    /// Returns a consumer that passes each value to `prev` first, then to
+    /// this consumer, i.e. `a.before(b)` runs like `b.and_then(a)`. Borrows
+    /// &self; the new consumer shares this consumer's state and keeps its
+    /// name.
+    ///
+    /// # Parameters
+    ///
+    /// * `prev` - Consumer run before the current operation. Can be:
+    ///   - A closure: `|x: &T|`
+    ///   - A `BoxConsumer<T>`, `RcConsumer<T>` or `ArcConsumer<T>`
+    ///   - Any type implementing `Consumer<T>`
+    ///
+    /// # Return Value
+    ///
+    /// Returns the combined `RcConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, RcConsumer};
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// let log = Rc::new(RefCell::new(Vec::new()));
+    /// let l1 = log.clone();
+    /// let l2 = log.clone();
+    /// let store = RcConsumer::new(move |x: &i32| l1.borrow_mut().push(format!("store {}", x)));
+    /// let mut pipeline = store.before(move |x: &i32| {
+    ///     l2.borrow_mut().push(format!("log {}", x));
+    /// });
+    /// pipeline.accept(&1);
+    /// assert_eq!(*log.borrow(), vec!["log 1", "store 1"]);
+    /// ```
+    pub fn before<C>(&self, prev: C) -> RcConsumer<T>
+    where
+        C: Consumer<T> + 'static,
+    {
+        let current = Rc::clone(&self.function);
+        let mut prev = prev;
+        RcConsumer {
+            function: Rc::new(RefCell::new(move |t: &T| {
+                prev.accept(t);
+                current.borrow_mut()(t);
+            })),
+            name: self.name.clone(),
+        }
+    }
+
+    /// Forwards only every `n`-th value to this consumer
+    ///
+    /// Returns a consumer forwarding the 1st, `n + 1`-th, `2n + 1`-th, ...
+    /// accepted values and dropping the others. The counter lives in the
+    /// returned consumer, so all its clones share it. The name is kept.
+    /// Borrows &self.
+    ///
+    /// # Parameters
+    ///
+    /// * `n` - The sampling interval
+    ///
+    /// # Returns
+    ///
+    /// Returns the sampling `RcConsumer<T>`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn every(&self, n: usize) -> RcConsumer<T> {
+        assert!(n > 0, "every requires a non-zero interval");
+        let current = Rc::clone(&self.function);
+        let mut index = 0;
+        RcConsumer {
+            function: Rc::new(RefCell::new(move |t: &T| {
+                if index == 0 {
+                    current.borrow_mut()(t);
+                }
+                index = (index + 1) % n;
+            })),
+            name: self.name.clone(),
+        }
+    }
+
+    /// Drops the first `n` values and forwards the rest to this consumer
+    ///
+    /// The counter lives in the returned consumer, so all its clones share
+    /// it. The name is kept. Borrows &self.
+    ///
+    /// # Parameters
+    ///
+    /// * `n` - The number of values to drop
+    ///
+    /// # Returns
+    ///
+    /// Returns the skipping `RcConsumer<T>`
+    pub fn skip(&self, n: usize) -> RcConsumer<T> {
+        let current = Rc::clone(&self.function);
+        let mut remaining = n;
+        RcConsumer {
+            function: Rc::new(RefCell::new(move |t: &T| {
+                if remaining > 0 {
+                    remaining -= 1;
+                } else {
+                    current.borrow_mut()(t);
+                }
+            })),
+            name: self.name.clone(),
+        }
+    }
+
+    /// Forwards only the first `n` values to this consumer
+    ///
+    /// Later values are dropped. The counter lives in the returned consumer,
+    /// so all its clones share it. The name is kept. Borrows &self.
+    ///
+    /// # Parameters
+    ///
+    /// * `n` - The number of values to forward
+    ///
+    /// # Returns
+    ///
+    /// Returns the limiting `RcConsumer<T>`
+    pub fn take(&self, n: usize) -> RcConsumer<T> {
+        let current = Rc::clone(&self.function);
+        let mut remaining = n;
+        RcConsumer {
+            function: Rc::new(RefCell::new(move |t: &T| {
+                if remaining > 0 {
+                    remaining -= 1;
+                    current.borrow_mut()(t);
+                }
+            })),
+            name: self.name.clone(),
+        }
+    }
+
     /// Creates a conditional consumer (single-threaded shared version)
     ///
     /// Returns a consumer that only executes when a predicate is satisfied.
@@ -2186,6 +4196,54 @@ where
             predicate: predicate.into_rc(),
         }
     }
+
+    /// Creates a conditional consumer guarded by several predicates
+    ///
+    /// The consumer executes only when all `predicates` are satisfied. They
+    /// are evaluated in order and evaluation stops at the first one returning
+    /// `false`; an empty list is always satisfied.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicates` - The conditions to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `RcConditionalConsumer<T>`
+    pub fn when_all(&self, predicates: Vec<RcPredicate<T>>) -> RcConditionalConsumer<T> {
+        self.when(move |value: &T| predicates.iter().all(|p| p.test(value)))
+    }
+
+    /// Creates a conditional consumer guarded by alternative predicates
+    ///
+    /// The consumer executes when any of `predicates` is satisfied. They are
+    /// evaluated in order and evaluation stops at the first one returning
+    /// `true`; an empty list is never satisfied.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicates` - The conditions to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `RcConditionalConsumer<T>`
+    pub fn when_any(&self, predicates: Vec<RcPredicate<T>>) -> RcConditionalConsumer<T> {
+        self.when(move |value: &T| predicates.iter().any(|p| p.test(value)))
+    }
+
+    /// Converts this consumer into a mutator that never writes
+    ///
+    /// The returned mutator shares this consumer's function and passes it a
+    /// shared reborrow of the mutable reference, so the value is only read.
+    /// The name is dropped because mutators are unnamed. Consumes self.
+    ///
+    /// # Return Value
+    ///
+    /// Returns an `RcMutator<T>` that leaves its input unchanged
+    pub fn into_mutator(self) -> RcMutator<T> {
+        let function = self.function;
+        RcMutator::new(move |t: &mut T| function.borrow_mut()(&*t))
+    }
 }
 
 impl<T> Consumer<T> for RcConsumer<T> {
@@ -2193,6 +4251,17 @@ impl<T> Consumer<T> for RcConsumer<T> {
         (self.function.borrow_mut())(value)
     }
 
+    fn accept_all<I>(&mut self, values: I)
+    where
+        I: IntoIterator,
+        I::Item: Borrow<T>,
+    {
+        let mut function = self.function.borrow_mut();
+        for value in values {
+            function(value.borrow());
+        }
+    }
+
     fn into_box(self) -> BoxConsumer<T>
     where
         T: 'static,
@@ -2559,6 +4628,59 @@ impl<T> RcConditionalConsumer<T>
 where
     T: 'static,
 {
+    /// Chains another consumer in sequence (single-threaded shared version)
+    ///
+    /// Combines the current conditional consumer with another consumer into a new
+    /// consumer. The current conditional consumer executes first, followed by the
+    /// next consumer. The conditional consumer remains usable after the call.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - The next consumer to execute, can be:
+    ///   - Closure: `|x: &T|`
+    ///   - `RcConsumer<T>`, `BoxConsumer<T>`
+    ///   - Any type implementing `Consumer<T>`
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `RcConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Consumer, RcConsumer};
+    /// use std::rc::Rc;
+    /// use std::cell::RefCell;
+    ///
+    /// let log = Rc::new(RefCell::new(Vec::new()));
+    /// let l1 = log.clone();
+    /// let l2 = log.clone();
+    /// let conditional = RcConsumer::new(move |x: &i32| {
+    ///     l1.borrow_mut().push(*x);
+    /// })
+    /// .when(|x: &i32| *x > 0);
+    /// let mut chained = conditional.and_then(move |x: &i32| {
+    ///     l2.borrow_mut().push(*x * 2);
+    /// });
+    ///
+    /// chained.accept(&5);
+    /// assert_eq!(*log.borrow(), vec![5, 10]);
+    ///
+    /// chained.accept(&-5);
+    /// assert_eq!(*log.borrow(), vec![5, 10, -10]);
+    /// ```
+    pub fn and_then<C>(&self, next: C) -> RcConsumer<T>
+    where
+        C: Consumer<T> + 'static,
+    {
+        let mut first = self.clone();
+        let mut second = next;
+        RcConsumer::new(move |t: &T| {
+            first.accept(t);
+            second.accept(t);
+        })
+    }
+
     /// Adds an else branch (single-threaded shared version)
     ///
     /// Executes the original consumer when the condition is satisfied, otherwise
@@ -2853,3 +4975,38 @@ pub trait FnConsumerOps<T>: FnMut(&T) + Sized {
 
 /// Implement FnConsumerOps for all closure types
 impl<T, F> FnConsumerOps<T> for F where F: FnMut(&T) {}
+
+// ============================================================================
+// 12. AlreadyConsumedError - Error of Once-Only Consumers
+// ============================================================================
+
+/// Error returned when a once-only consumer is called a second time
+///
+/// Produced by the try-consumer returned from
+/// [`BoxConsumer::try_once_only`].
+///
+/// # Author
+///
+/// Hu Haixing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlreadyConsumedError {
+    name: Option<String>,
+}
+
+impl AlreadyConsumedError {
+    /// Returns the name of the consumer that was already used, if any
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
+impl fmt::Display for AlreadyConsumedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "once-only consumer '{}' was already consumed", name),
+            None => write!(f, "once-only consumer was already consumed"),
+        }
+    }
+}
+
+impl std::error::Error for AlreadyConsumedError {}
@@ -0,0 +1,343 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Consumer Group Types
+//!
+//! Provides consumers forwarding every value to a set of named members that
+//! can be inserted and removed at runtime:
+//!
+//! - [`ConsumerGroup`]: Single-owner group of `BoxConsumer<T>` members
+//! - [`ArcConsumerGroup`]: Thread-safe, cloneable group of `ArcConsumer<T>`
+//!   members behind an `Arc<Mutex<Vec<..>>>`
+//!
+//! # Author
+//!
+//! Hu Haixing
+
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::consumer::{ArcConsumer, BoxConsumer, Consumer};
+
+// ============================================================================
+// 1. ConsumerGroup - Named Fan-Out Dispatcher
+// ============================================================================
+
+/// ConsumerGroup struct
+///
+/// A consumer forwarding every accepted value to a set of named
+/// `BoxConsumer<T>` members, in the order they were inserted. Members can be
+/// inserted, replaced and removed by name at runtime. A group implements
+/// `Consumer<T>` itself, so groups can be nested or used inside any
+/// pipeline.
+///
+/// # Features
+///
+/// - **Named Members**: Each member is identified by a unique name
+/// - **Ordered Delivery**: Members are called in insertion order
+/// - **Implements Consumer**: Can be used anywhere a `Consumer` is expected
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BoxConsumer, Consumer, ConsumerGroup};
+///
+/// let (audit, audited) = BoxConsumer::collect();
+/// let (metrics, measured) = BoxConsumer::collect();
+/// let mut group = ConsumerGroup::new();
+/// group.insert("audit", audit);
+/// group.insert("metrics", metrics);
+/// group.accept(&1);
+///
+/// group.remove("metrics");
+/// group.accept(&2);
+///
+/// assert_eq!(*audited.lock().unwrap(), vec![1, 2]);
+/// assert_eq!(*measured.lock().unwrap(), vec![1]);
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct ConsumerGroup<T> {
+    members: Vec<(String, BoxConsumer<T>)>,
+}
+
+impl<T> ConsumerGroup<T> {
+    /// Creates an empty ConsumerGroup
+    ///
+    /// # Return Value
+    ///
+    /// Returns a new `ConsumerGroup<T>` without any member
+    pub fn new() -> Self {
+        ConsumerGroup {
+            members: Vec::new(),
+        }
+    }
+
+    /// Inserts a named member
+    ///
+    /// If a member with the same name exists, it is replaced and the new
+    /// consumer keeps its position; otherwise the consumer is appended after
+    /// the existing members.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the member
+    /// * `consumer` - The consumer to insert
+    ///
+    /// # Return Value
+    ///
+    /// Returns the replaced consumer, or `None` if the name was not in use
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        consumer: BoxConsumer<T>,
+    ) -> Option<BoxConsumer<T>> {
+        let name = name.into();
+        match self.members.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, existing)) => Some(std::mem::replace(existing, consumer)),
+            None => {
+                self.members.push((name, consumer));
+                None
+            }
+        }
+    }
+
+    /// Removes a named member
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the member to remove
+    ///
+    /// # Return Value
+    ///
+    /// Returns the removed consumer, or `None` if no member has this name
+    pub fn remove(&mut self, name: &str) -> Option<BoxConsumer<T>> {
+        let index = self.members.iter().position(|(n, _)| n == name)?;
+        Some(self.members.remove(index).1)
+    }
+
+    /// Returns whether a member with the given name exists
+    pub fn contains(&self, name: &str) -> bool {
+        self.members.iter().any(|(n, _)| n == name)
+    }
+
+    /// Returns the member names in delivery order
+    pub fn names(&self) -> Vec<&str> {
+        self.members.iter().map(|(n, _)| n.as_str()).collect()
+    }
+
+    /// Returns the number of members
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns whether there are no members
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+}
+
+impl<T> Consumer<T> for ConsumerGroup<T> {
+    fn accept(&mut self, value: &T) {
+        for (_, member) in &mut self.members {
+            member.accept(value);
+        }
+    }
+}
+
+impl<T> Default for ConsumerGroup<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for ConsumerGroup<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConsumerGroup")
+            .field("members", &self.names())
+            .finish()
+    }
+}
+
+// ============================================================================
+// 2. ArcConsumerGroup - Thread-Safe Named Fan-Out Dispatcher
+// ============================================================================
+
+/// ArcConsumerGroup struct
+///
+/// A thread-safe consumer group holding named `ArcConsumer<T>` members in an
+/// `Arc<Mutex<Vec<..>>>`. Clones share the same member list, so members can
+/// be inserted and removed from any thread while others accept values.
+///
+/// The member list lock is released before members are invoked, so a member
+/// may safely insert into or remove from the same group. Such changes take
+/// effect from the next accepted value on. The lock recovers from
+/// poisoning, so a panic on one thread does not break the group for the
+/// others.
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{ArcConsumer, ArcConsumerGroup, Consumer};
+/// use std::sync::{Arc, Mutex};
+/// use std::thread;
+///
+/// let log = Arc::new(Mutex::new(Vec::new()));
+/// let l = log.clone();
+/// let group = ArcConsumerGroup::new();
+/// group.insert("log", ArcConsumer::new(move |x: &i32| l.lock().unwrap().push(*x)));
+///
+/// let mut clone = group.clone();
+/// thread::spawn(move || clone.accept(&1)).join().unwrap();
+/// assert_eq!(*log.lock().unwrap(), vec![1]);
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct ArcConsumerGroup<T> {
+    members: Arc<Mutex<NamedArcConsumers<T>>>,
+}
+
+/// Type alias for the member list of an ArcConsumerGroup
+type NamedArcConsumers<T> = Vec<(String, ArcConsumer<T>)>;
+
+impl<T> ArcConsumerGroup<T> {
+    /// Creates an empty ArcConsumerGroup
+    ///
+    /// # Return Value
+    ///
+    /// Returns a new `ArcConsumerGroup<T>` without any member
+    pub fn new() -> Self {
+        ArcConsumerGroup {
+            members: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Inserts a named member
+    ///
+    /// If a member with the same name exists, it is replaced and the new
+    /// consumer keeps its position; otherwise the consumer is appended after
+    /// the existing members.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the member
+    /// * `consumer` - The consumer to insert
+    ///
+    /// # Return Value
+    ///
+    /// Returns the replaced consumer, or `None` if the name was not in use
+    pub fn insert(
+        &self,
+        name: impl Into<String>,
+        consumer: ArcConsumer<T>,
+    ) -> Option<ArcConsumer<T>> {
+        let name = name.into();
+        let mut members = self.members.lock().unwrap_or_else(PoisonError::into_inner);
+        match members.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, existing)) => Some(std::mem::replace(existing, consumer)),
+            None => {
+                members.push((name, consumer));
+                None
+            }
+        }
+    }
+
+    /// Removes a named member
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the member to remove
+    ///
+    /// # Return Value
+    ///
+    /// Returns the removed consumer, or `None` if no member has this name
+    pub fn remove(&self, name: &str) -> Option<ArcConsumer<T>> {
+        let mut members = self.members.lock().unwrap_or_else(PoisonError::into_inner);
+        let index = members.iter().position(|(n, _)| n == name)?;
+        Some(members.remove(index).1)
+    }
+
+    /// Returns whether a member with the given name exists
+    pub fn contains(&self, name: &str) -> bool {
+        self.members
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .any(|(n, _)| n == name)
+    }
+
+    /// Returns the member names in delivery order
+    pub fn names(&self) -> Vec<String> {
+        self.members
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(n, _)| n.clone())
+            .collect()
+    }
+
+    /// Returns the number of members
+    pub fn len(&self) -> usize {
+        self.members
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Returns whether there are no members
+    pub fn is_empty(&self) -> bool {
+        self.members
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_empty()
+    }
+}
+
+impl<T> Consumer<T> for ArcConsumerGroup<T> {
+    fn accept(&mut self, value: &T) {
+        let members: Vec<ArcConsumer<T>> = self
+            .members
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(_, member)| member.clone())
+            .collect();
+        for mut member in members {
+            member.accept(value);
+        }
+    }
+}
+
+impl<T> Default for ArcConsumerGroup<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for ArcConsumerGroup<T> {
+    /// Clones the ArcConsumerGroup
+    ///
+    /// Creates a new handle sharing the same member list.
+    fn clone(&self) -> Self {
+        ArcConsumerGroup {
+            members: Arc::clone(&self.members),
+        }
+    }
+}
+
+impl<T> fmt::Debug for ArcConsumerGroup<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArcConsumerGroup")
+            .field("members", &self.names())
+            .finish()
+    }
+}
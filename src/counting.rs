@@ -0,0 +1,305 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Counting Types
+//!
+//! Provides instrumented consumers that forward every value to a wrapped
+//! consumer while recording how many values were accepted and, optionally,
+//! the last one:
+//!
+//! - [`CountingConsumer`]: Single-owner wrapper around a `BoxConsumer<T>`
+//! - [`ArcCountingConsumer`]: Thread-safe, cloneable wrapper around an
+//!   `ArcConsumer<T>` whose counter is an `AtomicUsize`, so reading it never
+//!   contends with concurrent accepts
+//!
+//! # Author
+//!
+//! Hu Haixing
+
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::consumer::{ArcConsumer, BoxConsumer, Consumer};
+
+/// Snapshot function deciding whether the last accepted value is recorded
+type SnapshotFn<T> = fn(&T) -> Option<T>;
+
+fn skip_snapshot<T>(_: &T) -> Option<T> {
+    None
+}
+
+fn clone_snapshot<T: Clone>(value: &T) -> Option<T> {
+    Some(value.clone())
+}
+
+// ============================================================================
+// 1. CountingConsumer - Single Ownership Implementation
+// ============================================================================
+
+/// CountingConsumer struct
+///
+/// A consumer forwarding every accepted value to a wrapped `BoxConsumer<T>`
+/// and counting the accepted values. When created with
+/// [`with_last`](CountingConsumer::with_last), it also keeps a clone of the
+/// last accepted value.
+///
+/// # Features
+///
+/// - **Invocation Count**: `count()` returns the number of accepted values
+/// - **Last Value**: Optional, available when `T: Clone`
+/// - **Implements Consumer**: Can be used anywhere a `Consumer` is expected
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{Consumer, CountingConsumer};
+///
+/// let mut consumer = CountingConsumer::with_last(|x: &i32| println!("{}", x));
+/// consumer.accept(&1);
+/// consumer.accept(&2);
+/// assert_eq!(consumer.count(), 2);
+/// assert_eq!(consumer.last(), Some(2));
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct CountingConsumer<T> {
+    consumer: BoxConsumer<T>,
+    count: usize,
+    last: Option<T>,
+    snapshot: SnapshotFn<T>,
+}
+
+impl<T: 'static> CountingConsumer<T> {
+    /// Creates a CountingConsumer recording only the invocation count
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The consumer receiving the values. Can be a closure, a
+    ///   `BoxConsumer<T>`, `RcConsumer<T>`, `ArcConsumer<T>` or any type
+    ///   implementing `Consumer<T>`
+    ///
+    /// # Return Value
+    ///
+    /// Returns a new `CountingConsumer<T>` with a count of zero
+    pub fn new<C>(consumer: C) -> Self
+    where
+        C: Consumer<T> + 'static,
+    {
+        CountingConsumer {
+            consumer: consumer.into_box(),
+            count: 0,
+            last: None,
+            snapshot: skip_snapshot,
+        }
+    }
+
+    /// Creates a CountingConsumer also recording the last accepted value
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The consumer receiving the values
+    ///
+    /// # Return Value
+    ///
+    /// Returns a new `CountingConsumer<T>` with a count of zero and no last
+    /// value
+    pub fn with_last<C>(consumer: C) -> Self
+    where
+        C: Consumer<T> + 'static,
+        T: Clone,
+    {
+        CountingConsumer {
+            snapshot: clone_snapshot,
+            ..Self::new(consumer)
+        }
+    }
+}
+
+impl<T> CountingConsumer<T> {
+    /// Returns the number of values accepted so far
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns a clone of the last accepted value
+    ///
+    /// # Return Value
+    ///
+    /// Returns `None` if no value was accepted yet, or if this consumer was
+    /// not created with [`with_last`](CountingConsumer::with_last)
+    pub fn last(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.last.clone()
+    }
+
+    /// Unwraps the counting consumer, returning the wrapped consumer
+    pub fn into_inner(self) -> BoxConsumer<T> {
+        self.consumer
+    }
+}
+
+impl<T> Consumer<T> for CountingConsumer<T> {
+    fn accept(&mut self, value: &T) {
+        self.consumer.accept(value);
+        self.count += 1;
+        if let Some(last) = (self.snapshot)(value) {
+            self.last = Some(last);
+        }
+    }
+}
+
+impl<T> fmt::Debug for CountingConsumer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CountingConsumer")
+            .field("count", &self.count)
+            .finish()
+    }
+}
+
+// ============================================================================
+// 2. ArcCountingConsumer - Thread-Safe Implementation
+// ============================================================================
+
+/// ArcCountingConsumer struct
+///
+/// A thread-safe counting consumer wrapping an `ArcConsumer<T>`. Clones
+/// share the wrapped consumer, the counter and the last value. The counter
+/// is an `AtomicUsize`, so `count()` never waits for an accept in progress.
+///
+/// Under concurrent use, `last()` returns the value recorded by the most
+/// recent accept to finish, which is not necessarily the one that started
+/// last.
+///
+/// # Features
+///
+/// - **Shared Ownership**: Cloneable via `Arc`
+/// - **Lock-Free Count**: Reading the count does not contend with accepts
+/// - **Implements Consumer**: Can be used anywhere a `Consumer` is expected
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{ArcConsumer, ArcCountingConsumer, Consumer};
+/// use std::thread;
+///
+/// let counter = ArcCountingConsumer::new(ArcConsumer::new(|_: &i32| {}));
+/// let mut clone = counter.clone();
+/// thread::spawn(move || clone.accept(&1)).join().unwrap();
+/// assert_eq!(counter.count(), 1);
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct ArcCountingConsumer<T> {
+    consumer: ArcConsumer<T>,
+    count: Arc<AtomicUsize>,
+    last: Arc<Mutex<Option<T>>>,
+    snapshot: SnapshotFn<T>,
+}
+
+impl<T: Send + 'static> ArcCountingConsumer<T> {
+    /// Creates an ArcCountingConsumer recording only the invocation count
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The thread-safe consumer receiving the values
+    ///
+    /// # Return Value
+    ///
+    /// Returns a new `ArcCountingConsumer<T>` with a count of zero
+    pub fn new(consumer: ArcConsumer<T>) -> Self {
+        ArcCountingConsumer {
+            consumer,
+            count: Arc::new(AtomicUsize::new(0)),
+            last: Arc::new(Mutex::new(None)),
+            snapshot: skip_snapshot,
+        }
+    }
+
+    /// Creates an ArcCountingConsumer also recording the last accepted value
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The thread-safe consumer receiving the values
+    ///
+    /// # Return Value
+    ///
+    /// Returns a new `ArcCountingConsumer<T>` with a count of zero and no
+    /// last value
+    pub fn with_last(consumer: ArcConsumer<T>) -> Self
+    where
+        T: Clone,
+    {
+        ArcCountingConsumer {
+            snapshot: clone_snapshot,
+            ..Self::new(consumer)
+        }
+    }
+}
+
+impl<T> ArcCountingConsumer<T> {
+    /// Returns the number of values accepted so far by all clones
+    pub fn count(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+
+    /// Returns a clone of the last accepted value
+    ///
+    /// # Return Value
+    ///
+    /// Returns `None` if no value was accepted yet, or if this consumer was
+    /// not created with [`with_last`](ArcCountingConsumer::with_last)
+    pub fn last(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.last
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl<T> Consumer<T> for ArcCountingConsumer<T> {
+    fn accept(&mut self, value: &T) {
+        self.consumer.accept(value);
+        self.count.fetch_add(1, Ordering::SeqCst);
+        if let Some(last) = (self.snapshot)(value) {
+            *self.last.lock().unwrap_or_else(PoisonError::into_inner) = Some(last);
+        }
+    }
+}
+
+impl<T> Clone for ArcCountingConsumer<T> {
+    /// Clones the ArcCountingConsumer
+    ///
+    /// The clone shares the wrapped consumer, the counter and the last value
+    /// with the original instance.
+    fn clone(&self) -> Self {
+        ArcCountingConsumer {
+            consumer: self.consumer.clone(),
+            count: Arc::clone(&self.count),
+            last: Arc::clone(&self.last),
+            snapshot: self.snapshot,
+        }
+    }
+}
+
+impl<T> fmt::Debug for ArcCountingConsumer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArcCountingConsumer")
+            .field("count", &self.count())
+            .finish()
+    }
+}
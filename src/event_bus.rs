@@ -0,0 +1,326 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Event Bus Types
+//!
+//! Provides keyed publish/subscribe dispatchers built on top of the consumer
+//! abstractions. Consumers subscribe to a key, and every value published
+//! under that key is delivered to each of them in subscription order.
+//!
+//! This module provides two bus implementations:
+//!
+//! - [`EventBus`]: Single-threaded bus holding `BoxConsumer<T>` subscribers
+//! - [`ArcEventBus`]: Thread-safe, cloneable bus holding `ArcConsumer<T>`
+//!   subscribers behind an `Arc<Mutex<HashMap<..>>>`
+//!
+//! # Author
+//!
+//! Hu Haixing
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::consumer::{ArcConsumer, BoxConsumer, Consumer};
+
+// ============================================================================
+// 1. EventBus - Single-Threaded Implementation
+// ============================================================================
+
+/// EventBus struct
+///
+/// A keyed event dispatcher holding `BoxConsumer<T>` subscribers. Each key
+/// maps to an ordered list of consumers; publishing a value under a key
+/// invokes every consumer registered for that key.
+///
+/// # Features
+///
+/// - **Named Subscription**: Consumers are grouped by key
+/// - **Ordered Delivery**: Consumers receive values in subscription order
+/// - **Single Ownership**: Not cloneable, not thread-safe
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BoxConsumer, EventBus};
+/// use std::sync::{Arc, Mutex};
+///
+/// let log = Arc::new(Mutex::new(Vec::new()));
+/// let l = log.clone();
+/// let mut bus = EventBus::new();
+/// bus.subscribe("created", BoxConsumer::new(move |x: &i32| {
+///     l.lock().unwrap().push(*x);
+/// }));
+///
+/// bus.publish(&"created", &5);
+/// bus.publish(&"deleted", &7); // No subscriber, ignored
+/// assert_eq!(*log.lock().unwrap(), vec![5]);
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct EventBus<K, T> {
+    subscribers: HashMap<K, Vec<BoxConsumer<T>>>,
+}
+
+impl<K, T> EventBus<K, T>
+where
+    K: Eq + Hash,
+{
+    /// Creates an empty EventBus
+    ///
+    /// # Return Value
+    ///
+    /// Returns a new `EventBus<K, T>` without any subscriber
+    pub fn new() -> Self {
+        EventBus {
+            subscribers: HashMap::new(),
+        }
+    }
+
+    /// Subscribes a consumer to a key
+    ///
+    /// The consumer is appended after any consumer already registered for the
+    /// same key.
+    ///
+    /// # Parameters
+    ///
+    /// * `key` - The key to subscribe to
+    /// * `consumer` - The consumer invoked for every value published under
+    ///   `key`
+    pub fn subscribe(&mut self, key: K, consumer: BoxConsumer<T>) {
+        self.subscribers.entry(key).or_default().push(consumer);
+    }
+
+    /// Publishes a value under a key
+    ///
+    /// Invokes every consumer subscribed to `key` in subscription order. Does
+    /// nothing if no consumer is subscribed to `key`.
+    ///
+    /// # Parameters
+    ///
+    /// * `key` - The key to publish under
+    /// * `value` - Reference to the value delivered to the subscribers
+    pub fn publish(&mut self, key: &K, value: &T) {
+        if let Some(consumers) = self.subscribers.get_mut(key) {
+            for consumer in consumers.iter_mut() {
+                consumer.accept(value);
+            }
+        }
+    }
+
+    /// Removes all consumers subscribed to a key
+    ///
+    /// # Parameters
+    ///
+    /// * `key` - The key whose subscribers are removed
+    ///
+    /// # Return Value
+    ///
+    /// Returns the removed consumers in subscription order, or an empty
+    /// vector if no consumer was subscribed to `key`
+    pub fn unsubscribe_all(&mut self, key: &K) -> Vec<BoxConsumer<T>> {
+        self.subscribers.remove(key).unwrap_or_default()
+    }
+
+    /// Returns the number of consumers subscribed to a key
+    ///
+    /// # Parameters
+    ///
+    /// * `key` - The key to look up
+    pub fn subscriber_count(&self, key: &K) -> usize {
+        self.subscribers.get(key).map_or(0, Vec::len)
+    }
+}
+
+impl<K, T> Default for EventBus<K, T>
+where
+    K: Eq + Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, T> fmt::Debug for EventBus<K, T>
+where
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventBus")
+            .field("keys", &self.subscribers.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+// ============================================================================
+// 2. ArcEventBus - Thread-Safe Implementation
+// ============================================================================
+
+/// ArcEventBus struct
+///
+/// A thread-safe keyed event dispatcher holding `ArcConsumer<T>` subscribers
+/// in an `Arc<Mutex<HashMap<K, Vec<ArcConsumer<T>>>>>`. Clones share the same
+/// subscription table.
+///
+/// The subscription table lock is released before subscribers are invoked,
+/// so a subscriber may safely subscribe to or publish on the same bus. The
+/// lock recovers from poisoning, so a panic on one thread does not break
+/// the bus for the others.
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{ArcConsumer, ArcEventBus};
+/// use std::sync::{Arc, Mutex};
+/// use std::thread;
+///
+/// let log = Arc::new(Mutex::new(Vec::new()));
+/// let l = log.clone();
+/// let bus = ArcEventBus::new();
+/// bus.subscribe("tick", ArcConsumer::new(move |x: &i32| {
+///     l.lock().unwrap().push(*x);
+/// }));
+///
+/// let bus_clone = bus.clone();
+/// thread::spawn(move || bus_clone.publish(&"tick", &1))
+///     .join()
+///     .unwrap();
+/// assert_eq!(*log.lock().unwrap(), vec![1]);
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct ArcEventBus<K, T> {
+    subscribers: Arc<Mutex<HashMap<K, Vec<ArcConsumer<T>>>>>,
+}
+
+impl<K, T> ArcEventBus<K, T>
+where
+    K: Eq + Hash,
+{
+    /// Creates an empty ArcEventBus
+    ///
+    /// # Return Value
+    ///
+    /// Returns a new `ArcEventBus<K, T>` without any subscriber
+    pub fn new() -> Self {
+        ArcEventBus {
+            subscribers: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Subscribes a consumer to a key
+    ///
+    /// # Parameters
+    ///
+    /// * `key` - The key to subscribe to
+    /// * `consumer` - The consumer invoked for every value published under
+    ///   `key`
+    pub fn subscribe(&self, key: K, consumer: ArcConsumer<T>) {
+        self.subscribers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(key)
+            .or_default()
+            .push(consumer);
+    }
+
+    /// Publishes a value under a key
+    ///
+    /// Takes a snapshot of the consumers subscribed to `key`, releases the
+    /// subscription table lock, then invokes them in subscription order.
+    ///
+    /// # Parameters
+    ///
+    /// * `key` - The key to publish under
+    /// * `value` - Reference to the value delivered to the subscribers
+    pub fn publish(&self, key: &K, value: &T) {
+        let consumers = match self
+            .subscribers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+        {
+            Some(consumers) => consumers.clone(),
+            None => return,
+        };
+        for mut consumer in consumers {
+            consumer.accept(value);
+        }
+    }
+
+    /// Removes all consumers subscribed to a key
+    ///
+    /// # Parameters
+    ///
+    /// * `key` - The key whose subscribers are removed
+    ///
+    /// # Return Value
+    ///
+    /// Returns the removed consumers in subscription order, or an empty
+    /// vector if no consumer was subscribed to `key`
+    pub fn unsubscribe_all(&self, key: &K) -> Vec<ArcConsumer<T>> {
+        self.subscribers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(key)
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of consumers subscribed to a key
+    ///
+    /// # Parameters
+    ///
+    /// * `key` - The key to look up
+    pub fn subscriber_count(&self, key: &K) -> usize {
+        self.subscribers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+            .map_or(0, Vec::len)
+    }
+}
+
+impl<K, T> Default for ArcEventBus<K, T>
+where
+    K: Eq + Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, T> Clone for ArcEventBus<K, T> {
+    /// Clones the ArcEventBus
+    ///
+    /// Creates a new handle sharing the same subscription table.
+    fn clone(&self) -> Self {
+        ArcEventBus {
+            subscribers: Arc::clone(&self.subscribers),
+        }
+    }
+}
+
+impl<K, T> fmt::Debug for ArcEventBus<K, T>
+where
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let subscribers = self
+            .subscribers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        f.debug_struct("ArcEventBus")
+            .field("keys", &subscribers.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
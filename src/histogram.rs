@@ -0,0 +1,178 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Histogram Types
+//!
+//! Provides [`PredicateHistogram`], a consumer that sorts accepted values
+//! into named buckets defined by predicates and counts the values in each
+//! bucket, bridging predicates with metrics collection.
+//!
+//! # Author
+//!
+//! Hu Haixing
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::consumer::Consumer;
+use crate::predicate::{BoxPredicate, Predicate};
+
+/// PredicateHistogram struct
+///
+/// A consumer counting accepted values per bucket. Each bucket has a unique
+/// name and a predicate. A value is counted in the first bucket, in
+/// insertion order, whose predicate it satisfies; values matching no
+/// bucket are counted as unmatched. The counters are `AtomicUsize`, so
+/// they can be read and reset through a shared reference.
+///
+/// # Features
+///
+/// - **First Match Wins**: Buckets may overlap; order decides precedence
+/// - **Snapshot Report**: `report()` returns the counts keyed by bucket name
+/// - **Implements Consumer**: Can be used anywhere a `Consumer` is expected
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{Consumer, PredicateHistogram};
+///
+/// let mut latency = PredicateHistogram::new()
+///     .bucket("fast", |ms: &u32| *ms < 100)
+///     .bucket("ok", |ms: &u32| *ms < 500)
+///     .bucket("slow", |_: &u32| true);
+/// latency.accept_all([20, 80, 300, 900]);
+///
+/// let report = latency.report();
+/// assert_eq!(report["fast"], 2);
+/// assert_eq!(report["ok"], 1);
+/// assert_eq!(report["slow"], 1);
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct PredicateHistogram<T> {
+    buckets: Vec<(String, BoxPredicate<T>)>,
+    counts: Vec<AtomicUsize>,
+    unmatched: AtomicUsize,
+}
+
+impl<T> PredicateHistogram<T> {
+    /// Creates a PredicateHistogram without buckets
+    pub fn new() -> Self {
+        PredicateHistogram {
+            buckets: Vec::new(),
+            counts: Vec::new(),
+            unmatched: AtomicUsize::new(0),
+        }
+    }
+
+    /// Appends a bucket
+    ///
+    /// The new bucket has the lowest precedence so far. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The unique name of the bucket
+    /// * `predicate` - The condition a value must satisfy to be counted in
+    ///   this bucket. Can be a closure, a `BoxPredicate<T>`,
+    ///   `RcPredicate<T>`, `ArcPredicate<T>` or any type implementing
+    ///   `Predicate<T>`
+    ///
+    /// # Return Value
+    ///
+    /// Returns the histogram with the bucket appended
+    ///
+    /// # Panics
+    ///
+    /// Panics if a bucket with the same name already exists
+    pub fn bucket<P>(mut self, name: impl Into<String>, predicate: P) -> Self
+    where
+        T: 'static,
+        P: Predicate<T> + 'static,
+    {
+        let name = name.into();
+        assert!(
+            self.buckets.iter().all(|(existing, _)| *existing != name),
+            "duplicate histogram bucket name: {}",
+            name
+        );
+        self.buckets.push((name, predicate.into_box()));
+        self.counts.push(AtomicUsize::new(0));
+        self
+    }
+
+    /// Returns the count of the named bucket
+    ///
+    /// # Return Value
+    ///
+    /// Returns `None` if there is no bucket with this name
+    pub fn count(&self, name: &str) -> Option<usize> {
+        self.buckets
+            .iter()
+            .position(|(existing, _)| existing == name)
+            .map(|index| self.counts[index].load(Ordering::Relaxed))
+    }
+
+    /// Returns the number of values that matched no bucket
+    pub fn unmatched(&self) -> usize {
+        self.unmatched.load(Ordering::Relaxed)
+    }
+
+    /// Returns the current count of every bucket, keyed by bucket name
+    pub fn report(&self) -> HashMap<&str, usize> {
+        self.buckets
+            .iter()
+            .zip(&self.counts)
+            .map(|((name, _), count)| (name.as_str(), count.load(Ordering::Relaxed)))
+            .collect()
+    }
+
+    /// Sets every count, including the unmatched count, back to zero
+    pub fn reset(&self) {
+        for count in &self.counts {
+            count.store(0, Ordering::Relaxed);
+        }
+        self.unmatched.store(0, Ordering::Relaxed);
+    }
+}
+
+impl<T> Default for PredicateHistogram<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: 'static> Consumer<T> for PredicateHistogram<T> {
+    fn accept(&mut self, value: &T) {
+        match self
+            .buckets
+            .iter()
+            .position(|(_, predicate)| predicate.test(value))
+        {
+            Some(index) => self.counts[index].fetch_add(1, Ordering::Relaxed),
+            None => self.unmatched.fetch_add(1, Ordering::Relaxed),
+        };
+    }
+}
+
+impl<T> fmt::Debug for PredicateHistogram<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let buckets: Vec<(&str, usize)> = self
+            .buckets
+            .iter()
+            .zip(&self.counts)
+            .map(|((name, _), count)| (name.as_str(), count.load(Ordering::Relaxed)))
+            .collect();
+        f.debug_struct("PredicateHistogram")
+            .field("buckets", &buckets)
+            .field("unmatched", &self.unmatched())
+            .finish()
+    }
+}
@@ -15,13 +15,27 @@
 //! - **BiTransformer types**: Transform two values to produce a result
 //! - **BinaryOperator types**: Transform two values of type T to produce a T
 //! - **Consumer types**: Functions that consume values without returning
+//! - **TryConsumer types**: Functions that consume values and may fail
+//! - **OwnedConsumer types**: Functions that take ownership of the values they
+//!   consume
+//! - **Counting types**: Consumers that record invocation count and last value
+//! - **Buffered types**: Consumers that hand accepted values over in batches
+//! - **Histogram types**: Consumers that count values in predicate buckets
+//! - **Dispatching types**: Consumers that forward values to one, several or
+//!   named recipients, or release them in priority order
 //! - **BiConsumer types**: Functions that consume two values without returning
+//! - **BiMutator types**: Functions that modify two values in place
+//! - **TriConsumer types**: Functions that consume three values without
+//!   returning
+//! - **TriTransformer types**: Transform three values to produce a result
 //! - **Predicate types**: Functions that test values and return boolean
 //! - **BiPredicate types**: Functions that test two values and return boolean
 //! - **Supplier types**: Functions that produce values without input
+//! - **Pipeline types**: Flat chains of transformation stages
 //! - **Mapper types**: Stateful transformations from type T to type R
 //! - **Tester types**: Functions that test conditions without input
 //! - **Comparator types**: Functions that compare values and return ordering
+//! - **EventBus types**: Keyed publish/subscribe dispatchers over consumers
 //!
 //! # Author
 //!
@@ -29,17 +43,28 @@
 
 pub mod bi_consumer;
 pub mod bi_consumer_once;
+pub mod bi_mutator;
 pub mod bi_predicate;
 pub mod bi_transformer;
 pub mod bi_transformer_once;
+pub mod broadcast;
+pub mod buffered;
 pub mod comparator;
 pub mod consumer;
+pub mod consumer_group;
 pub mod consumer_once;
+pub mod counting;
+pub mod event_bus;
+pub mod histogram;
 pub mod mapper;
 pub mod mapper_once;
+pub mod memoize;
 pub mod mutator;
 pub mod mutator_once;
+pub mod owned_consumer;
+pub mod pipeline;
 pub mod predicate;
+pub mod priority;
 pub mod readonly_bi_consumer;
 pub mod readonly_consumer;
 pub mod readonly_supplier;
@@ -48,9 +73,14 @@ pub mod supplier_once;
 pub mod tester;
 pub mod transformer;
 pub mod transformer_once;
+pub mod tri_consumer;
+pub mod tri_transformer;
+pub mod try_consumer;
+pub mod unicast;
 
 pub use bi_consumer::{ArcBiConsumer, BiConsumer, BoxBiConsumer, FnBiConsumerOps, RcBiConsumer};
 pub use bi_consumer_once::{BiConsumerOnce, BoxBiConsumerOnce, FnBiConsumerOnceOps};
+pub use bi_mutator::{ArcBiMutator, BiMutator, BoxBiMutator, FnBiMutatorOps, RcBiMutator};
 pub use bi_predicate::{
     ArcBiPredicate, BiPredicate, BoxBiPredicate, FnBiPredicateOps, RcBiPredicate,
 };
@@ -59,23 +89,41 @@ pub use bi_transformer::{
     BoxBinaryOperator, FnBiTransformerOps, RcBiTransformer, RcBinaryOperator,
 };
 pub use bi_transformer_once::{
-    BiTransformerOnce, BinaryOperatorOnce, BoxBiTransformerOnce, BoxBinaryOperatorOnce,
-    FnBiTransformerOnceOps,
+    ArcBiTransformerOnce, BiTransformerOnce, BinaryOperatorOnce, BoxBiTransformerOnce,
+    BoxBinaryOperatorOnce, FnBiTransformerOnceOps, RcBiTransformerOnce,
 };
-pub use comparator::{ArcComparator, BoxComparator, Comparator, FnComparatorOps, RcComparator};
-pub use consumer::{ArcConsumer, BoxConsumer, Consumer, FnConsumerOps, RcConsumer};
+pub use broadcast::BroadcastConsumer;
+pub use buffered::{ArcBufferedConsumer, BufferedConsumer};
+pub use comparator::{
+    ArcComparator, BoxComparator, Comparator, ComparatorBuilder, ComparatorKey, FnComparatorOps,
+    RcComparator,
+};
+pub use consumer::{
+    AlreadyConsumedError, ArcConsumer, BoxConsumer, Consumer, FnConsumerOps, RcConsumer,
+};
+pub use consumer_group::{ArcConsumerGroup, ConsumerGroup};
 pub use consumer_once::{BoxConsumerOnce, ConsumerOnce, FnConsumerOnceOps};
+pub use counting::{ArcCountingConsumer, CountingConsumer};
+pub use event_bus::{ArcEventBus, EventBus};
+pub use histogram::PredicateHistogram;
 pub use mapper::{
-    ArcConditionalMapper, ArcMapper, BoxConditionalMapper, BoxMapper, FnMapperOps, Mapper,
-    RcConditionalMapper, RcMapper,
+    ArcConditionalMapper, ArcMapper, BoxConditionalMapper, BoxMapper, ConditionalStatefulBiMapper,
+    FnMapperOps, Mapper, RcConditionalMapper, RcMapper, StatefulBiMapper, StatefulMapper,
 };
 pub use mapper_once::{BoxConditionalMapperOnce, BoxMapperOnce, FnMapperOnceOps, MapperOnce};
+pub use memoize::LruMemoizedMapper;
 pub use mutator::{
     ArcConditionalMutator, ArcMutator, BoxConditionalMutator, BoxMutator, FnMutatorOps, Mutator,
     RcConditionalMutator, RcMutator,
 };
 pub use mutator_once::{BoxConditionalMutatorOnce, BoxMutatorOnce, FnMutatorOnceOps, MutatorOnce};
+pub use owned_consumer::{
+    ArcOwnedConsumer, BoxOwnedConsumer, BoxOwnedConsumerOnce, OwnedConsumer, OwnedConsumerOnce,
+    RcOwnedConsumer,
+};
+pub use pipeline::{NamedPipeline, Pipeline};
 pub use predicate::{ArcPredicate, BoxPredicate, FnPredicateOps, Predicate, RcPredicate};
+pub use priority::PriorityConsumer;
 pub use readonly_bi_consumer::{
     ArcReadonlyBiConsumer, BoxReadonlyBiConsumer, FnReadonlyBiConsumerOps, RcReadonlyBiConsumer,
     ReadonlyBiConsumer,
@@ -87,7 +135,9 @@ pub use readonly_consumer::{
 pub use readonly_supplier::{
     ArcReadonlySupplier, BoxReadonlySupplier, RcReadonlySupplier, ReadonlySupplier,
 };
-pub use supplier::{ArcSupplier, BoxSupplier, FnSupplierOps, RcSupplier, Supplier};
+pub use supplier::{
+    ArcSupplier, BoxSupplier, BoxSupplierIter, FnSupplierOps, RcSupplier, Supplier,
+};
 pub use supplier_once::{BoxSupplierOnce, SupplierOnce};
 pub use tester::{ArcTester, BoxTester, FnTesterOps, RcTester, Tester};
 pub use transformer::{
@@ -96,6 +146,15 @@ pub use transformer::{
     RcUnaryOperator, Transformer, UnaryOperator,
 };
 pub use transformer_once::{
-    BoxConditionalTransformerOnce, BoxTransformerOnce, BoxUnaryOperatorOnce, FnTransformerOnceOps,
-    TransformerOnce, UnaryOperatorOnce,
+    ArcTransformerOnce, BoxConditionalTransformerOnce, BoxTransformerOnce, BoxUnaryOperatorOnce,
+    FnTransformerOnceOps, FnUnaryOperatorOnceOps, RcTransformerOnce, TransformerOnce,
+    UnaryOperatorOnce,
+};
+pub use tri_consumer::{
+    ArcTriConsumer, BoxTriConsumer, FnTriConsumerOps, RcTriConsumer, TriConsumer,
+};
+pub use tri_transformer::{
+    ArcTriTransformer, BoxTriTransformer, FnTriTransformerOps, RcTriTransformer, TriTransformer,
 };
+pub use try_consumer::{ArcTryConsumer, BoxTryConsumer, RcTryConsumer, TryConsumer};
+pub use unicast::UnicastConsumer;
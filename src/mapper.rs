@@ -18,16 +18,23 @@
 //! - [`ArcMapper`]: Thread-safe shared ownership, cloneable
 //! - [`RcMapper`]: Single-threaded shared ownership, cloneable
 //!
+//! [`StatefulMapper`] additionally keeps an explicit state next to its
+//! function, see [`BoxMapper::with_state`]. [`StatefulBiMapper`] does the
+//! same for functions of two arguments.
+//!
 //! # Author
 //!
 //! Haixing Hu
 
 use std::cell::RefCell;
 use std::rc::Rc;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, PoisonError};
 
+use crate::bi_predicate::{BiPredicate, BoxBiPredicate};
+use crate::consumer::Consumer;
 use crate::mapper_once::{BoxMapperOnce, MapperOnce};
 use crate::predicate::{ArcPredicate, BoxPredicate, Predicate, RcPredicate};
+use crate::supplier::Supplier;
 
 // ============================================================================
 // Core Trait
@@ -285,6 +292,79 @@ pub trait Mapper<T, R> {
     {
         self.clone().into_fn()
     }
+
+    /// Applies the mapper to every element of a sequence, collecting the
+    /// results
+    ///
+    /// Elements are processed strictly in iteration order, so the state of
+    /// the mapper evolves exactly as if `apply` were called in a loop. This
+    /// is the eager counterpart of [`Mapper::iter_scan`].
+    ///
+    /// # Parameters
+    ///
+    /// * `iter` - The input sequence
+    ///
+    /// # Returns
+    ///
+    /// Returns the mapped values in input order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Mapper, BoxMapper};
+    ///
+    /// let mut sum = 0;
+    /// let mut running_total = BoxMapper::new(move |x: i32| {
+    ///     sum += x;
+    ///     sum
+    /// });
+    /// assert_eq!(running_total.scan(vec![1, 2, 3]), vec![1, 3, 6]);
+    /// // State carries over to the next call
+    /// assert_eq!(running_total.apply(4), 10);
+    /// ```
+    fn scan<I>(&mut self, iter: I) -> Vec<R>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = T>,
+    {
+        self.iter_scan(iter).collect()
+    }
+
+    /// Lazily applies the mapper to every element of a sequence
+    ///
+    /// Returns an iterator that borrows the mapper mutably and calls `apply`
+    /// on each element only when the element is pulled, like the body of
+    /// `Iterator::scan`.
+    ///
+    /// # Parameters
+    ///
+    /// * `iter` - The input sequence
+    ///
+    /// # Returns
+    ///
+    /// Returns an iterator over the mapped values
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Mapper, BoxMapper};
+    ///
+    /// let mut last = None;
+    /// let mut changed = BoxMapper::new(move |x: i32| {
+    ///     let is_new = last != Some(x);
+    ///     last = Some(x);
+    ///     is_new
+    /// });
+    /// let flags: Vec<bool> = changed.iter_scan(vec![1, 1, 2]).collect();
+    /// assert_eq!(flags, vec![true, false, true]);
+    /// ```
+    fn iter_scan<I>(&mut self, iter: I) -> impl Iterator<Item = R>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().map(move |input| self.apply(input))
+    }
 }
 
 // ============================================================================
@@ -367,6 +447,45 @@ where
         BoxMapper::new(|x| x)
     }
 
+    /// Creates a mapper owning an explicit state
+    ///
+    /// Instead of capturing mutable state in the closure environment, the
+    /// state is stored in the returned [`StatefulMapper`] and passed to `f`
+    /// on every call, so it can be inspected or reset through
+    /// [`StatefulMapper::state`] and [`StatefulMapper::state_mut`].
+    ///
+    /// # Parameters
+    ///
+    /// * `initial` - The initial state
+    /// * `f` - The function computing the output from the state and input
+    ///
+    /// # Returns
+    ///
+    /// A new `StatefulMapper<S, T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxMapper, Mapper};
+    ///
+    /// let mut running_total = BoxMapper::with_state(0, |total: &mut i32, x: i32| {
+    ///     *total += x;
+    ///     *total
+    /// });
+    /// assert_eq!(running_total.apply(3), 3);
+    /// assert_eq!(running_total.apply(4), 7);
+    /// assert_eq!(*running_total.state(), 7);
+    /// ```
+    pub fn with_state<S, F>(initial: S, f: F) -> StatefulMapper<S, T, R>
+    where
+        F: FnMut(&mut S, T) -> R + 'static,
+    {
+        StatefulMapper {
+            state: initial,
+            function: Box::new(f),
+        }
+    }
+
     /// Chain composition - applies self first, then after
     ///
     /// Creates a new mapper that applies this mapper first, then applies
@@ -481,6 +600,132 @@ where
         })
     }
 
+    /// Fan-out composition - applies self and other to the same input
+    ///
+    /// Creates a new mapper that feeds the input to both this mapper and
+    /// `other`, returning both results as a tuple. The input is cloned once
+    /// so that each mapper receives its own copy. Each mapper keeps its own
+    /// state, which evolves independently. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The mapper applied alongside self. Can be a closure, a
+    ///   `BoxMapper<T, S>`, `RcMapper<T, S>`, `ArcMapper<T, S>` or any type
+    ///   implementing `Mapper<T, S>`
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxMapper<T, (R, S)>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxMapper, Mapper};
+    ///
+    /// let mut count = 0;
+    /// let counter = BoxMapper::new(move |_: i32| {
+    ///     count += 1;
+    ///     count
+    /// });
+    /// let mut sum = 0;
+    /// let mut both = counter.fanout(move |x: i32| {
+    ///     sum += x;
+    ///     sum
+    /// });
+    /// assert_eq!(both.apply(10), (1, 10));
+    /// assert_eq!(both.apply(5), (2, 15));
+    /// ```
+    pub fn fanout<S, F>(self, other: F) -> BoxMapper<T, (R, S)>
+    where
+        T: Clone,
+        S: 'static,
+        F: Mapper<T, S> + 'static,
+    {
+        let mut self_mapper = self;
+        let mut other_mapper = other;
+        BoxMapper::new(move |x: T| (self_mapper.apply(x.clone()), other_mapper.apply(x)))
+    }
+
+    /// Inspection - observes the output without changing it
+    ///
+    /// Creates a new mapper that applies this mapper, passes a reference to
+    /// the result to `consumer`, then returns the result unchanged. Useful
+    /// for logging or collecting intermediate values of a chain. Consumes
+    /// self.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `C` - The type of the consumer (must implement `Consumer<R>`)
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The consumer observing each output. Can be:
+    ///   - A closure: `|x: &R|`
+    ///   - A `BoxConsumer<R>`, `RcConsumer<R>` or `ArcConsumer<R>`
+    ///   - Any type implementing `Consumer<R>`
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxMapper<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxMapper, Mapper};
+    ///
+    /// let mut total = 0;
+    /// let mut seen = Vec::new();
+    /// let mut mapper = BoxMapper::new(move |x: i32| {
+    ///     total += x;
+    ///     total
+    /// })
+    /// .inspect(move |x: &i32| seen.push(*x));
+    /// assert_eq!(mapper.apply(1), 1);
+    /// assert_eq!(mapper.apply(2), 3);
+    /// ```
+    pub fn inspect<C>(self, consumer: C) -> BoxMapper<T, R>
+    where
+        C: Consumer<R> + 'static,
+    {
+        let mut self_mapper = self;
+        let mut consumer = consumer;
+        BoxMapper::new(move |x: T| {
+            let result = self_mapper.apply(x);
+            consumer.accept(&result);
+            result
+        })
+    }
+
+    /// Applies the mapper to every element of a vector
+    ///
+    /// Elements are processed strictly in input order, so state carried
+    /// between calls sees the values in the same order they appear in
+    /// `values`.
+    ///
+    /// # Parameters
+    ///
+    /// * `values` - The input values
+    ///
+    /// # Returns
+    ///
+    /// A vector with the result for each input, in input order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::BoxMapper;
+    ///
+    /// let mut total = 0;
+    /// let mut running_sum = BoxMapper::new(move |x: i32| {
+    ///     total += x;
+    ///     total
+    /// });
+    /// assert_eq!(running_sum.map_vec(vec![1, 2, 3]), vec![1, 3, 6]);
+    /// ```
+    pub fn map_vec(&mut self, values: Vec<T>) -> Vec<R> {
+        values.into_iter().map(&mut self.function).collect()
+    }
+
     /// Creates a conditional mapper
     ///
     /// Returns a mapper that only executes when a predicate is satisfied.
@@ -529,6 +774,68 @@ where
             predicate: predicate.into_box(),
         }
     }
+
+    /// Lifts this mapper over `Option`
+    ///
+    /// Returns a mapper mapping `Some(x)` to `Some(f(x))` and passing `None`
+    /// through without calling this mapper, so its state only changes for
+    /// `Some` inputs. Consumes self.
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxMapper<Option<T>, Option<R>>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxMapper, Mapper};
+    ///
+    /// let mut count = 0;
+    /// let mut numbered = BoxMapper::new(move |s: &str| {
+    ///     count += 1;
+    ///     format!("{}{}", s, count)
+    /// })
+    /// .lift_option();
+    /// assert_eq!(numbered.apply(Some("a")), Some("a1".to_string()));
+    /// assert_eq!(numbered.apply(None), None);
+    /// assert_eq!(numbered.apply(Some("b")), Some("b2".to_string()));
+    /// ```
+    pub fn lift_option(self) -> BoxMapper<Option<T>, Option<R>> {
+        let mut function = self.function;
+        BoxMapper::new(move |x: Option<T>| x.map(&mut function))
+    }
+
+    /// Lifts this mapper over the `Ok` side of `Result`
+    ///
+    /// Returns a mapper mapping `Ok(x)` to `Ok(f(x))` and passing `Err(e)`
+    /// through without calling this mapper. Consumes self.
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxMapper<Result<T, E>, Result<R, E>>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxMapper, Mapper};
+    ///
+    /// let mut total = 0;
+    /// let mut running = BoxMapper::new(move |x: i32| {
+    ///     total += x;
+    ///     total
+    /// })
+    /// .lift_ok::<&str>();
+    /// assert_eq!(running.apply(Ok(2)), Ok(2));
+    /// assert_eq!(running.apply(Err("skip")), Err("skip"));
+    /// assert_eq!(running.apply(Ok(3)), Ok(5));
+    /// ```
+    pub fn lift_ok<E>(self) -> BoxMapper<Result<T, E>, Result<R, E>>
+    where
+        E: 'static,
+    {
+        let mut function = self.function;
+        BoxMapper::new(move |x: Result<T, E>| x.map(&mut function))
+    }
 }
 
 impl<T, R> BoxMapper<T, R>
@@ -551,6 +858,72 @@ where
     }
 }
 
+impl<T, R> BoxMapper<T, Vec<R>>
+where
+    T: 'static,
+    R: 'static,
+{
+    /// Applies the mapper to every element and flattens the results
+    ///
+    /// Elements are processed strictly in input order and the produced
+    /// vectors are concatenated in that same order.
+    ///
+    /// # Parameters
+    ///
+    /// * `values` - The input values
+    ///
+    /// # Returns
+    ///
+    /// The concatenation of the vectors produced for each input
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::BoxMapper;
+    ///
+    /// let mut repeat = BoxMapper::new(|x: u32| vec![x; x as usize]);
+    /// assert_eq!(repeat.flat_map_vec(vec![1, 0, 2]), vec![1, 2, 2]);
+    /// ```
+    pub fn flat_map_vec(&mut self, values: Vec<T>) -> Vec<R> {
+        values.into_iter().flat_map(&mut self.function).collect()
+    }
+}
+
+impl<T> BoxMapper<T, T>
+where
+    T: Clone + 'static,
+{
+    /// Replaces every element of a slice with its mapped value
+    ///
+    /// Elements are processed strictly in slice order. Each element is
+    /// cloned to be passed to the mapper by value.
+    ///
+    /// # Parameters
+    ///
+    /// * `values` - The slice to update in place
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::BoxMapper;
+    ///
+    /// let mut previous = 0;
+    /// let mut delta = BoxMapper::new(move |x: i32| {
+    ///     let d = x - previous;
+    ///     previous = x;
+    ///     d
+    /// });
+    /// let mut values = [3, 5, 9];
+    /// delta.process_slice_in_place(&mut values);
+    /// assert_eq!(values, [3, 2, 4]);
+    /// ```
+    pub fn process_slice_in_place(&mut self, values: &mut [T]) {
+        for value in values.iter_mut() {
+            *value = (self.function)(value.clone());
+        }
+    }
+}
+
 impl<T, R> Mapper<T, R> for BoxMapper<T, R> {
     fn apply(&mut self, input: T) -> R {
         (self.function)(input)
@@ -718,6 +1091,366 @@ where
             }
         })
     }
+
+    /// Adds an else branch returning `R::default()`
+    ///
+    /// Shortcut for `or_else(|_| R::default())`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `BoxMapper<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxMapper, Mapper};
+    ///
+    /// let mut positive_only = BoxMapper::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_default();
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), 0);
+    /// ```
+    pub fn or_else_default(self) -> BoxMapper<T, R>
+    where
+        R: Default,
+    {
+        self.or_else(|_: T| R::default())
+    }
+
+    /// Adds an else branch returning a clone of `value`
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The fallback value, cloned on every else-branch call
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `BoxMapper<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxMapper, Mapper};
+    ///
+    /// let mut positive_only = BoxMapper::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_value(-1);
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), -1);
+    /// ```
+    pub fn or_else_value(self, value: R) -> BoxMapper<T, R>
+    where
+        R: Clone,
+    {
+        self.or_else(move |_: T| value.clone())
+    }
+
+    /// Adds an else branch producing its result from a supplier
+    ///
+    /// The supplier is only called when the predicate rejects the input,
+    /// so it can lazily build expensive fallbacks.
+    ///
+    /// # Parameters
+    ///
+    /// * `supplier` - The supplier of fallback values, can be:
+    ///   - Closure: `|| -> R`
+    ///   - `BoxSupplier<R>`, `RcSupplier<R>`, `ArcSupplier<R>`
+    ///   - Any type implementing `Supplier<R>`
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `BoxMapper<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxMapper, Mapper};
+    ///
+    /// let mut next_id = 100;
+    /// let mut positive_only = BoxMapper::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_supply(move || {
+    ///         next_id += 1;
+    ///         next_id
+    ///     });
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), 101);
+    /// assert_eq!(positive_only.apply(-5), 102);
+    /// ```
+    pub fn or_else_supply<S>(self, supplier: S) -> BoxMapper<T, R>
+    where
+        S: Supplier<R> + 'static,
+    {
+        let mut supplier = supplier;
+        self.or_else(move |_: T| supplier.get())
+    }
+}
+
+// ============================================================================
+// StatefulMapper - Mapper with an explicit, accessible state
+// ============================================================================
+
+/// Type alias for the function of a `StatefulMapper`
+type StatefulMapperFn<S, T, R> = dyn FnMut(&mut S, T) -> R;
+
+/// StatefulMapper - mapper owning an explicit state
+///
+/// Holds a state value of type `S` next to a function of
+/// `(&mut S, T) -> R`. Each call passes the state to the function, and the
+/// state can be read or modified between calls.
+///
+/// Created by [`BoxMapper::with_state`].
+///
+/// # Features
+///
+/// - **Accessible State**: `state`, `state_mut` and `into_state`
+/// - **Ownership**: Single ownership, cannot be cloned
+/// - **Thread Safety**: Not thread-safe
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BoxMapper, Mapper};
+///
+/// let mut numbering = BoxMapper::with_state(1, |next: &mut usize, s: &str| {
+///     let line = format!("{}. {}", next, s);
+///     *next += 1;
+///     line
+/// });
+/// assert_eq!(numbering.apply("a"), "1. a");
+/// *numbering.state_mut() = 10;
+/// assert_eq!(numbering.apply("b"), "10. b");
+/// assert_eq!(numbering.into_state(), 11);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct StatefulMapper<S, T, R> {
+    state: S,
+    function: Box<StatefulMapperFn<S, T, R>>,
+}
+
+impl<S, T, R> StatefulMapper<S, T, R> {
+    /// Returns a reference to the current state
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Returns a mutable reference to the current state
+    pub fn state_mut(&mut self) -> &mut S {
+        &mut self.state
+    }
+
+    /// Consumes the mapper and returns its state
+    pub fn into_state(self) -> S {
+        self.state
+    }
+}
+
+impl<S, T, R> Mapper<T, R> for StatefulMapper<S, T, R> {
+    fn apply(&mut self, input: T) -> R {
+        (self.function)(&mut self.state, input)
+    }
+}
+
+// ============================================================================
+// StatefulBiMapper - Binary mapper with an explicit, accessible state
+// ============================================================================
+
+/// Type alias for the function of a `StatefulBiMapper`
+type StatefulBiMapperFn<S, T, U, R> = dyn FnMut(&mut S, T, U) -> R;
+
+/// StatefulBiMapper - binary mapper owning an explicit state
+///
+/// The two-argument counterpart of [`StatefulMapper`]. Holds a state value
+/// of type `S` next to a function of `(&mut S, T, U) -> R`. Each call passes
+/// the state to the function, and the state can be read or modified between
+/// calls. Bi-transformers are `Fn` based and have no mutable counterpart
+/// trait, so the mapper is called through its inherent
+/// [`apply`](StatefulBiMapper::apply) method.
+///
+/// # Features
+///
+/// - **Accessible State**: `state`, `state_mut` and `into_state`
+/// - **Ownership**: Single ownership, cannot be cloned
+/// - **Thread Safety**: Not thread-safe
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::StatefulBiMapper;
+///
+/// let mut weighted = StatefulBiMapper::with_state(0, |total: &mut i32, x: i32, w: i32| {
+///     *total += x * w;
+///     *total
+/// });
+/// assert_eq!(weighted.apply(3, 2), 6);
+/// assert_eq!(weighted.apply(1, 4), 10);
+/// assert_eq!(weighted.into_state(), 10);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct StatefulBiMapper<S, T, U, R> {
+    state: S,
+    function: Box<StatefulBiMapperFn<S, T, U, R>>,
+}
+
+impl<S, T, U, R> StatefulBiMapper<S, T, U, R> {
+    /// Creates a binary mapper owning an explicit state
+    ///
+    /// # Parameters
+    ///
+    /// * `initial` - The initial state
+    /// * `f` - The function computing the output from the state and both
+    ///   inputs
+    ///
+    /// # Returns
+    ///
+    /// A new `StatefulBiMapper<S, T, U, R>`
+    pub fn with_state<F>(initial: S, f: F) -> Self
+    where
+        F: FnMut(&mut S, T, U) -> R + 'static,
+    {
+        StatefulBiMapper {
+            state: initial,
+            function: Box::new(f),
+        }
+    }
+
+    /// Applies the function to the state and both inputs
+    pub fn apply(&mut self, first: T, second: U) -> R {
+        (self.function)(&mut self.state, first, second)
+    }
+
+    /// Returns a reference to the current state
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Returns a mutable reference to the current state
+    pub fn state_mut(&mut self) -> &mut S {
+        &mut self.state
+    }
+
+    /// Consumes the mapper and returns its state
+    pub fn into_state(self) -> S {
+        self.state
+    }
+}
+
+impl<S, T, U, R> StatefulBiMapper<S, T, U, R>
+where
+    S: 'static,
+    T: 'static,
+    U: 'static,
+    R: 'static,
+{
+    /// Creates a conditional binary mapper
+    ///
+    /// Returns a mapper that only runs this mapper's function when the
+    /// predicate is satisfied. Complete it with
+    /// [`or_else`](ConditionalStatefulBiMapper::or_else).
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - The condition to check, tested on references to both
+    ///   inputs. Can be a closure `|x: &T, y: &U| -> bool`, a
+    ///   `BoxBiPredicate<T, U>`, `RcBiPredicate<T, U>`,
+    ///   `ArcBiPredicate<T, U>` or any type implementing `BiPredicate<T, U>`
+    ///
+    /// # Returns
+    ///
+    /// Returns `ConditionalStatefulBiMapper<S, T, U, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::StatefulBiMapper;
+    ///
+    /// let mut diff = StatefulBiMapper::with_state(0, |_: &mut i32, a: i32, b: i32| a - b)
+    ///     .when(|a: &i32, b: &i32| a >= b)
+    ///     .or_else(|swaps: &mut i32, a: i32, b: i32| {
+    ///         *swaps += 1;
+    ///         b - a
+    ///     });
+    /// assert_eq!(diff.apply(5, 3), 2);
+    /// assert_eq!(diff.apply(3, 5), 2);
+    /// assert_eq!(*diff.state(), 1);
+    /// ```
+    pub fn when<P>(self, predicate: P) -> ConditionalStatefulBiMapper<S, T, U, R>
+    where
+        P: BiPredicate<T, U> + 'static,
+    {
+        ConditionalStatefulBiMapper {
+            mapper: self,
+            predicate: predicate.into_box(),
+        }
+    }
+}
+
+// ============================================================================
+// ConditionalStatefulBiMapper - StatefulBiMapper guarded by a BiPredicate
+// ============================================================================
+
+/// ConditionalStatefulBiMapper - conditional binary mapper with a state
+///
+/// Created by [`StatefulBiMapper::when`] and completed with
+/// [`or_else`](ConditionalStatefulBiMapper::or_else), which returns a
+/// `StatefulBiMapper` running one of two functions on the same state.
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct ConditionalStatefulBiMapper<S, T, U, R> {
+    mapper: StatefulBiMapper<S, T, U, R>,
+    predicate: BoxBiPredicate<T, U>,
+}
+
+impl<S, T, U, R> ConditionalStatefulBiMapper<S, T, U, R>
+where
+    S: 'static,
+    T: 'static,
+    U: 'static,
+    R: 'static,
+{
+    /// Adds an else branch
+    ///
+    /// Runs the original function when the condition is satisfied,
+    /// otherwise runs `else_fn`. Both functions receive the same state,
+    /// which stays accessible on the returned mapper.
+    ///
+    /// # Parameters
+    ///
+    /// * `else_fn` - The function for the else branch, of
+    ///   `(&mut S, T, U) -> R`
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `StatefulBiMapper<S, T, U, R>`
+    pub fn or_else<F>(self, mut else_fn: F) -> StatefulBiMapper<S, T, U, R>
+    where
+        F: FnMut(&mut S, T, U) -> R + 'static,
+    {
+        let predicate = self.predicate;
+        let StatefulBiMapper {
+            state,
+            function: mut then_fn,
+        } = self.mapper;
+        StatefulBiMapper::with_state(state, move |state: &mut S, first: T, second: U| {
+            if predicate.test(&first, &second) {
+                then_fn(state, first, second)
+            } else {
+                else_fn(state, first, second)
+            }
+        })
+    }
 }
 
 // ============================================================================
@@ -747,37 +1480,108 @@ pub struct ArcMapper<T, R> {
     function: Arc<Mutex<dyn FnMut(T) -> R + Send>>,
 }
 
-impl<T, R> ArcMapper<T, R>
-where
-    T: Send + Sync + 'static,
-    R: Send + 'static,
-{
-    /// Creates a new ArcMapper
+impl<T, R> ArcMapper<T, R>
+where
+    T: Send + Sync + 'static,
+    R: Send + 'static,
+{
+    /// Creates a new ArcMapper
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure or function to wrap (must be Send)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcMapper, Mapper};
+    ///
+    /// let mut counter = 0;
+    /// let mut mapper = ArcMapper::new(move |x: i32| {
+    ///     counter += 1;
+    ///     x + counter
+    /// });
+    /// assert_eq!(mapper.apply(10), 11);
+    /// assert_eq!(mapper.apply(10), 12);
+    /// ```
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnMut(T) -> R + Send + 'static,
+    {
+        ArcMapper {
+            function: Arc::new(Mutex::new(f)),
+        }
+    }
+
+    /// Creates an ArcMapper from a stateless function
+    ///
+    /// The function is still stored behind the `Mutex` of `ArcMapper`, so
+    /// concurrent calls are serialized. This is only useful where an
+    /// `ArcMapper` is required; otherwise prefer
+    /// [`ArcTransformer::new`](crate::ArcTransformer::new), which wraps the
+    /// function without any lock.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The stateless function to wrap
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcMapper, Mapper};
+    ///
+    /// let mut mapper = ArcMapper::from_fn(|x: i32| x * 2);
+    /// assert_eq!(mapper.apply(21), 42);
+    /// ```
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(T) -> R + Send + 'static,
+    {
+        ArcMapper::new(f)
+    }
+
+    /// Creates a mapper over an explicit, shared state
+    ///
+    /// The state is kept in an `Arc<Mutex<S>>` that is returned together
+    /// with the mapper, so it can be read or modified from outside. Each call
+    /// locks the state for the duration of `f`.
     ///
     /// # Parameters
     ///
-    /// * `f` - The closure or function to wrap (must be Send)
+    /// * `initial` - The initial state
+    /// * `f` - The function computing the output from the state and input
+    ///
+    /// # Returns
+    ///
+    /// The mapper together with a handle to its state
     ///
     /// # Examples
     ///
     /// ```rust
     /// use prism3_function::{ArcMapper, Mapper};
     ///
-    /// let mut counter = 0;
-    /// let mut mapper = ArcMapper::new(move |x: i32| {
-    ///     counter += 1;
-    ///     x + counter
+    /// let (mut running_total, total) = ArcMapper::with_state_arc(0, |total: &mut i32, x: i32| {
+    ///     *total += x;
+    ///     *total
     /// });
-    /// assert_eq!(mapper.apply(10), 11);
-    /// assert_eq!(mapper.apply(10), 12);
+    /// assert_eq!(running_total.apply(3), 3);
+    /// assert_eq!(running_total.apply(4), 7);
+    /// assert_eq!(*total.lock().unwrap(), 7);
     /// ```
-    pub fn new<F>(f: F) -> Self
+    pub fn with_state_arc<S, F>(initial: S, mut f: F) -> (Self, Arc<Mutex<S>>)
     where
-        F: FnMut(T) -> R + Send + 'static,
+        S: Send + 'static,
+        F: FnMut(&mut S, T) -> R + Send + 'static,
     {
-        ArcMapper {
-            function: Arc::new(Mutex::new(f)),
-        }
+        let state = Arc::new(Mutex::new(initial));
+        let shared = Arc::clone(&state);
+        let mapper = ArcMapper::new(move |x: T| {
+            f(
+                &mut shared.lock().unwrap_or_else(PoisonError::into_inner),
+                x,
+            )
+        });
+        (mapper, state)
     }
 
     /// Creates an identity mapper
@@ -909,6 +1713,34 @@ where
         }
     }
 
+    /// Inspection - observes the output without changing it
+    ///
+    /// Creates a new thread-safe mapper that applies this mapper, passes a
+    /// reference to the result to `consumer`, then returns the result
+    /// unchanged. Uses &self, so original mapper remains usable.
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The consumer observing each output (must be `Send`)
+    ///
+    /// # Returns
+    ///
+    /// A new `ArcMapper<T, R>`
+    pub fn inspect<C>(&self, consumer: C) -> ArcMapper<T, R>
+    where
+        C: Consumer<R> + Send + 'static,
+    {
+        let self_fn = Arc::clone(&self.function);
+        let mut consumer = consumer;
+        ArcMapper {
+            function: Arc::new(Mutex::new(move |x: T| {
+                let result = self_fn.lock().unwrap_or_else(PoisonError::into_inner)(x);
+                consumer.accept(&result);
+                result
+            })),
+        }
+    }
+
     /// Creates a conditional mapper (thread-safe version)
     ///
     /// Returns a mapper that only executes when a predicate is satisfied.
@@ -951,6 +1783,66 @@ where
             predicate: predicate.into_arc(),
         }
     }
+
+    /// Lifts this mapper over `Option`
+    ///
+    /// Returns a mapper mapping `Some(x)` to `Some(f(x))` and passing `None`
+    /// through without locking or calling this mapper. Borrows &self; the
+    /// function and its state are shared with the original.
+    ///
+    /// # Returns
+    ///
+    /// A new `ArcMapper<Option<T>, Option<R>>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcMapper, Mapper};
+    ///
+    /// let mut count = 0;
+    /// let numbered = ArcMapper::new(move |x: i32| {
+    ///     count += 1;
+    ///     x * 10 + count
+    /// });
+    /// let mut lifted = numbered.lift_option();
+    /// assert_eq!(lifted.apply(Some(1)), Some(11));
+    /// assert_eq!(lifted.apply(None), None);
+    /// ```
+    pub fn lift_option(&self) -> ArcMapper<Option<T>, Option<R>> {
+        let function = Arc::clone(&self.function);
+        ArcMapper::new(move |x: Option<T>| {
+            x.map(|v| function.lock().unwrap_or_else(PoisonError::into_inner)(v))
+        })
+    }
+
+    /// Lifts this mapper over the `Ok` side of `Result`
+    ///
+    /// Returns a mapper mapping `Ok(x)` to `Ok(f(x))` and passing `Err(e)`
+    /// through without locking or calling this mapper. Borrows &self; the
+    /// function and its state are shared with the original.
+    ///
+    /// # Returns
+    ///
+    /// A new `ArcMapper<Result<T, E>, Result<R, E>>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcMapper, Mapper};
+    ///
+    /// let mut lifted = ArcMapper::new(|x: i32| x + 1).lift_ok::<String>();
+    /// assert_eq!(lifted.apply(Ok(1)), Ok(2));
+    /// assert_eq!(lifted.apply(Err("e".to_string())), Err("e".to_string()));
+    /// ```
+    pub fn lift_ok<E>(&self) -> ArcMapper<Result<T, E>, Result<R, E>>
+    where
+        E: Send + Sync + 'static,
+    {
+        let function = Arc::clone(&self.function);
+        ArcMapper::new(move |x: Result<T, E>| {
+            x.map(|v| function.lock().unwrap_or_else(PoisonError::into_inner)(v))
+        })
+    }
 }
 
 impl<T, R> ArcMapper<T, R>
@@ -1199,6 +2091,103 @@ where
             })),
         }
     }
+
+    /// Adds an else branch returning `R::default()`
+    ///
+    /// Shortcut for `or_else(|_| R::default())`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `ArcMapper<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcMapper, Mapper};
+    ///
+    /// let mut positive_only = ArcMapper::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_default();
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), 0);
+    /// ```
+    pub fn or_else_default(self) -> ArcMapper<T, R>
+    where
+        R: Default,
+    {
+        self.or_else(|_: T| R::default())
+    }
+
+    /// Adds an else branch returning a clone of `value`
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The fallback value, cloned on every else-branch call
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `ArcMapper<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcMapper, Mapper};
+    ///
+    /// let mut positive_only = ArcMapper::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_value(-1);
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), -1);
+    /// ```
+    pub fn or_else_value(self, value: R) -> ArcMapper<T, R>
+    where
+        R: Clone,
+    {
+        self.or_else(move |_: T| value.clone())
+    }
+
+    /// Adds an else branch producing its result from a supplier
+    ///
+    /// The supplier is only called when the predicate rejects the input,
+    /// so it can lazily build expensive fallbacks.
+    ///
+    /// # Parameters
+    ///
+    /// * `supplier` - The supplier of fallback values, can be:
+    ///   - Closure: `|| -> R`
+    ///   - `BoxSupplier<R>`, `RcSupplier<R>`, `ArcSupplier<R>`
+    ///   - Any type implementing `Supplier<R>`
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `ArcMapper<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcMapper, Mapper};
+    ///
+    /// let mut next_id = 100;
+    /// let mut positive_only = ArcMapper::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_supply(move || {
+    ///         next_id += 1;
+    ///         next_id
+    ///     });
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), 101);
+    /// assert_eq!(positive_only.apply(-5), 102);
+    /// ```
+    pub fn or_else_supply<S>(self, supplier: S) -> ArcMapper<T, R>
+    where
+        S: Supplier<R> + Send + 'static,
+    {
+        let mut supplier = supplier;
+        self.or_else(move |_: T| supplier.get())
+    }
 }
 
 impl<T, R> Clone for ArcConditionalMapper<T, R> {
@@ -1273,6 +2262,45 @@ where
         }
     }
 
+    /// Creates a mapper over an explicit, shared state
+    ///
+    /// The state is kept in an `Rc<RefCell<S>>` that is returned together
+    /// with the mapper, so it can be read or modified from outside between
+    /// calls.
+    ///
+    /// # Parameters
+    ///
+    /// * `initial` - The initial state
+    /// * `f` - The function computing the output from the state and input
+    ///
+    /// # Returns
+    ///
+    /// The mapper together with a handle to its state
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Mapper, RcMapper};
+    ///
+    /// let (mut running_total, total) = RcMapper::with_state_rc(0, |total: &mut i32, x: i32| {
+    ///     *total += x;
+    ///     *total
+    /// });
+    /// assert_eq!(running_total.apply(3), 3);
+    /// assert_eq!(running_total.apply(4), 7);
+    /// assert_eq!(*total.borrow(), 7);
+    /// ```
+    pub fn with_state_rc<S, F>(initial: S, mut f: F) -> (Self, Rc<RefCell<S>>)
+    where
+        S: 'static,
+        F: FnMut(&mut S, T) -> R + 'static,
+    {
+        let state = Rc::new(RefCell::new(initial));
+        let shared = Rc::clone(&state);
+        let mapper = RcMapper::new(move |x: T| f(&mut shared.borrow_mut(), x));
+        (mapper, state)
+    }
+
     /// Creates an identity mapper
     ///
     /// # Examples
@@ -1402,6 +2430,34 @@ where
         }
     }
 
+    /// Inspection - observes the output without changing it
+    ///
+    /// Creates a new mapper that applies this mapper, passes a reference to
+    /// the result to `consumer`, then returns the result unchanged. Uses
+    /// &self, so original mapper remains usable.
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The consumer observing each output
+    ///
+    /// # Returns
+    ///
+    /// A new `RcMapper<T, R>`
+    pub fn inspect<C>(&self, consumer: C) -> RcMapper<T, R>
+    where
+        C: Consumer<R> + 'static,
+    {
+        let self_fn = Rc::clone(&self.function);
+        let mut consumer = consumer;
+        RcMapper {
+            function: Rc::new(RefCell::new(move |x: T| {
+                let result = self_fn.borrow_mut()(x);
+                consumer.accept(&result);
+                result
+            })),
+        }
+    }
+
     /// Creates a conditional mapper (single-threaded shared version)
     ///
     /// Returns a mapper that only executes when a predicate is satisfied.
@@ -1446,6 +2502,57 @@ where
             predicate: predicate.into_rc(),
         }
     }
+
+    /// Lifts this mapper over `Option`
+    ///
+    /// Returns a mapper mapping `Some(x)` to `Some(f(x))` and passing `None`
+    /// through without calling this mapper. Borrows &self; the function and
+    /// its state are shared with the original.
+    ///
+    /// # Returns
+    ///
+    /// A new `RcMapper<Option<T>, Option<R>>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{RcMapper, Mapper};
+    ///
+    /// let mut lifted = RcMapper::new(|x: i32| x + 1).lift_option();
+    /// assert_eq!(lifted.apply(Some(1)), Some(2));
+    /// assert_eq!(lifted.apply(None), None);
+    /// ```
+    pub fn lift_option(&self) -> RcMapper<Option<T>, Option<R>> {
+        let function = Rc::clone(&self.function);
+        RcMapper::new(move |x: Option<T>| x.map(|v| function.borrow_mut()(v)))
+    }
+
+    /// Lifts this mapper over the `Ok` side of `Result`
+    ///
+    /// Returns a mapper mapping `Ok(x)` to `Ok(f(x))` and passing `Err(e)`
+    /// through without calling this mapper. Borrows &self; the function and
+    /// its state are shared with the original.
+    ///
+    /// # Returns
+    ///
+    /// A new `RcMapper<Result<T, E>, Result<R, E>>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{RcMapper, Mapper};
+    ///
+    /// let mut lifted = RcMapper::new(|x: i32| x + 1).lift_ok::<String>();
+    /// assert_eq!(lifted.apply(Ok(1)), Ok(2));
+    /// assert_eq!(lifted.apply(Err("e".to_string())), Err("e".to_string()));
+    /// ```
+    pub fn lift_ok<E>(&self) -> RcMapper<Result<T, E>, Result<R, E>>
+    where
+        E: 'static,
+    {
+        let function = Rc::clone(&self.function);
+        RcMapper::new(move |x: Result<T, E>| x.map(|v| function.borrow_mut()(v)))
+    }
 }
 
 impl<T, R> RcMapper<T, R>
@@ -1647,6 +2754,103 @@ where
             })),
         }
     }
+
+    /// Adds an else branch returning `R::default()`
+    ///
+    /// Shortcut for `or_else(|_| R::default())`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `RcMapper<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{RcMapper, Mapper};
+    ///
+    /// let mut positive_only = RcMapper::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_default();
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), 0);
+    /// ```
+    pub fn or_else_default(self) -> RcMapper<T, R>
+    where
+        R: Default,
+    {
+        self.or_else(|_: T| R::default())
+    }
+
+    /// Adds an else branch returning a clone of `value`
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The fallback value, cloned on every else-branch call
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `RcMapper<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{RcMapper, Mapper};
+    ///
+    /// let mut positive_only = RcMapper::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_value(-1);
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), -1);
+    /// ```
+    pub fn or_else_value(self, value: R) -> RcMapper<T, R>
+    where
+        R: Clone,
+    {
+        self.or_else(move |_: T| value.clone())
+    }
+
+    /// Adds an else branch producing its result from a supplier
+    ///
+    /// The supplier is only called when the predicate rejects the input,
+    /// so it can lazily build expensive fallbacks.
+    ///
+    /// # Parameters
+    ///
+    /// * `supplier` - The supplier of fallback values, can be:
+    ///   - Closure: `|| -> R`
+    ///   - `BoxSupplier<R>`, `RcSupplier<R>`, `ArcSupplier<R>`
+    ///   - Any type implementing `Supplier<R>`
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `RcMapper<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{RcMapper, Mapper};
+    ///
+    /// let mut next_id = 100;
+    /// let mut positive_only = RcMapper::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_supply(move || {
+    ///         next_id += 1;
+    ///         next_id
+    ///     });
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), 101);
+    /// assert_eq!(positive_only.apply(-5), 102);
+    /// ```
+    pub fn or_else_supply<S>(self, supplier: S) -> RcMapper<T, R>
+    where
+        S: Supplier<R> + 'static,
+    {
+        let mut supplier = supplier;
+        self.or_else(move |_: T| supplier.get())
+    }
 }
 
 impl<T, R> Clone for RcConditionalMapper<T, R> {
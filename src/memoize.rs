@@ -0,0 +1,246 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Memoization Types
+//!
+//! Provides [`LruMemoizedMapper`], a mapper caching the results of a
+//! transformer in a bounded least-recently-used cache, together with the
+//! small LRU cache backing it.
+//!
+//! # Author
+//!
+//! Hu Haixing
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::num::NonZeroUsize;
+
+use crate::mapper::Mapper;
+use crate::transformer::{BoxTransformer, Transformer};
+
+// ============================================================================
+// LruCache - bounded least-recently-used cache
+// ============================================================================
+
+/// Cache entry stored in the slab of an `LruCache`
+struct LruEntry<K, V> {
+    key: K,
+    value: V,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+/// Bounded least-recently-used cache
+///
+/// Entries live in a slab (`Vec`) and are linked into a doubly linked
+/// recency list by index, most recently used first. A `HashMap` maps keys to
+/// slab indices, so lookups, insertions and evictions are all `O(1)`.
+struct LruCache<K, V> {
+    capacity: NonZeroUsize,
+    index: HashMap<K, usize>,
+    entries: Vec<LruEntry<K, V>>,
+    head: Option<usize>,
+    tail: Option<usize>,
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    fn new(capacity: NonZeroUsize) -> Self {
+        LruCache {
+            capacity,
+            index: HashMap::new(),
+            entries: Vec::new(),
+            head: None,
+            tail: None,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Looks up a key and marks it as most recently used
+    fn get(&mut self, key: &K) -> Option<&V> {
+        let slot = *self.index.get(key)?;
+        self.move_to_front(slot);
+        Some(&self.entries[slot].value)
+    }
+
+    /// Inserts a new key, evicting the least recently used entry if full
+    ///
+    /// The key must not already be present.
+    fn insert(&mut self, key: K, value: V) {
+        debug_assert!(!self.index.contains_key(&key));
+        let slot = if self.entries.len() < self.capacity.get() {
+            self.entries.push(LruEntry {
+                key: key.clone(),
+                value,
+                prev: None,
+                next: None,
+            });
+            self.entries.len() - 1
+        } else {
+            let slot = self.tail.expect("a full cache has a tail");
+            self.unlink(slot);
+            let entry = &mut self.entries[slot];
+            self.index.remove(&entry.key);
+            entry.key = key.clone();
+            entry.value = value;
+            slot
+        };
+        self.index.insert(key, slot);
+        self.push_front(slot);
+    }
+
+    fn move_to_front(&mut self, slot: usize) {
+        if self.head != Some(slot) {
+            self.unlink(slot);
+            self.push_front(slot);
+        }
+    }
+
+    fn unlink(&mut self, slot: usize) {
+        let (prev, next) = (self.entries[slot].prev, self.entries[slot].next);
+        match prev {
+            Some(p) => self.entries[p].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(n) => self.entries[n].prev = prev,
+            None => self.tail = prev,
+        }
+        self.entries[slot].prev = None;
+        self.entries[slot].next = None;
+    }
+
+    fn push_front(&mut self, slot: usize) {
+        self.entries[slot].prev = None;
+        self.entries[slot].next = self.head;
+        if let Some(h) = self.head {
+            self.entries[h].prev = Some(slot);
+        }
+        self.head = Some(slot);
+        if self.tail.is_none() {
+            self.tail = Some(slot);
+        }
+    }
+}
+
+// ============================================================================
+// LruMemoizedMapper - transformer with a bounded LRU result cache
+// ============================================================================
+
+/// LruMemoizedMapper - memoizing wrapper with a bounded LRU cache
+///
+/// Wraps a transformer and caches its results keyed by input. At most
+/// `capacity` results are kept; when a new result does not fit, the least
+/// recently used one is evicted. Because the cache is updated on every call
+/// this is a [`Mapper`] (`&mut self`), not a `Transformer`.
+///
+/// Created by [`BoxTransformer::memoize_lru`].
+///
+/// # Features
+///
+/// - **Bounded Memory**: Never holds more than `capacity` results
+/// - **Metrics**: Counts cache hits and misses
+/// - **Ownership**: Single ownership, cannot be cloned
+/// - **Thread Safety**: Not thread-safe
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BoxTransformer, Mapper};
+/// use std::num::NonZeroUsize;
+///
+/// let square = BoxTransformer::new(|x: u64| x * x);
+/// let mut cached = square.memoize_lru(NonZeroUsize::new(2).unwrap());
+///
+/// assert_eq!(cached.apply(3), 9); // miss
+/// assert_eq!(cached.apply(3), 9); // hit
+/// assert_eq!(cached.apply(4), 16); // miss
+/// assert_eq!(cached.apply(5), 25); // miss, evicts 3
+/// assert_eq!(cached.apply(3), 9); // miss again
+/// assert_eq!(cached.cache_hits(), 1);
+/// assert_eq!(cached.cache_misses(), 4);
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct LruMemoizedMapper<T, R> {
+    transformer: BoxTransformer<T, R>,
+    cache: LruCache<T, R>,
+    hits: u64,
+    misses: u64,
+}
+
+impl<T, R> LruMemoizedMapper<T, R>
+where
+    T: Hash + Eq + Clone + 'static,
+    R: Clone + 'static,
+{
+    pub(crate) fn new(transformer: BoxTransformer<T, R>, capacity: NonZeroUsize) -> Self {
+        LruMemoizedMapper {
+            transformer,
+            cache: LruCache::new(capacity),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Returns the number of calls answered from the cache
+    pub fn cache_hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Returns the number of calls that invoked the wrapped transformer
+    pub fn cache_misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Returns the number of results currently cached
+    pub fn cache_len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Returns the maximum number of results kept in the cache
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.cache.capacity
+    }
+}
+
+impl<T, R> Mapper<T, R> for LruMemoizedMapper<T, R>
+where
+    T: Hash + Eq + Clone + 'static,
+    R: Clone + 'static,
+{
+    fn apply(&mut self, input: T) -> R {
+        if let Some(value) = self.cache.get(&input) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+        let value = self.transformer.apply(input.clone());
+        self.cache.insert(input, value.clone());
+        value
+    }
+}
+
+impl<T, R> fmt::Debug for LruMemoizedMapper<T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LruMemoizedMapper")
+            .field("capacity", &self.cache.capacity)
+            .field("len", &self.cache.entries.len())
+            .field("hits", &self.hits)
+            .field("misses", &self.misses)
+            .finish()
+    }
+}
@@ -196,8 +196,10 @@ use std::cell::RefCell;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 
+use crate::consumer::{ArcConsumer, BoxConsumer, RcConsumer};
 use crate::mutator_once::{BoxMutatorOnce, MutatorOnce};
 use crate::predicate::{ArcPredicate, BoxPredicate, Predicate, RcPredicate};
+use crate::transformer::{BoxTransformer, BoxUnaryOperator};
 
 // ============================================================================
 // 1. Mutator Trait - Unified Mutator Interface
@@ -294,6 +296,63 @@ pub trait Mutator<T> {
     /// ```
     fn mutate(&mut self, value: &mut T);
 
+    /// Mutates every element of a slice
+    ///
+    /// Mutates the elements in order. The default implementation calls
+    /// [`mutate`](Mutator::mutate) once per element; [`ArcMutator`] and
+    /// [`RcMutator`] override it to acquire their lock or borrow only once
+    /// for the whole slice.
+    ///
+    /// # Parameters
+    ///
+    /// * `values` - The elements to mutate
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Mutator, BoxMutator};
+    ///
+    /// let mut mutator = BoxMutator::new(|x: &mut i32| *x *= 2);
+    /// let mut values = vec![1, 2, 3];
+    /// mutator.apply_to_all(&mut values);
+    /// assert_eq!(values, vec![2, 4, 6]);
+    /// ```
+    fn apply_to_all(&mut self, values: &mut [T]) {
+        for value in values {
+            self.mutate(value);
+        }
+    }
+
+    /// Mutates every element yielded by an iterator of mutable references
+    ///
+    /// Like [`apply_to_all`](Mutator::apply_to_all), but accepts any source
+    /// of `&mut T`, e.g. `HashMap::values_mut()` or a filtered iterator.
+    ///
+    /// # Parameters
+    ///
+    /// * `values` - The mutable references to the elements to mutate
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{Mutator, BoxMutator};
+    ///
+    /// let mut mutator = BoxMutator::new(|x: &mut i32| *x = -*x);
+    /// let mut values = vec![1, 2, 3, 4];
+    /// mutator.apply_to_iter(values.iter_mut().filter(|x| **x % 2 == 0));
+    /// assert_eq!(values, vec![1, -2, 3, -4]);
+    /// ```
+    fn apply_to_iter<'a, I>(&mut self, values: I)
+    where
+        Self: Sized,
+        T: 'a,
+        I: IntoIterator<Item = &'a mut T>,
+    {
+        for value in values {
+            self.mutate(value);
+        }
+    }
+
     /// Convert this mutator into a `BoxMutator<T>`.
     ///
     /// This consuming conversion takes ownership of `self` and returns a
@@ -770,6 +829,164 @@ where
             predicate: predicate.into_box(),
         }
     }
+
+    /// Repeats the mutation until a predicate is satisfied
+    ///
+    /// Returns a mutator that, on each call, tests the value and applies this
+    /// mutation while the predicate returns `false`, at most `max_iters`
+    /// times. A value that already satisfies the predicate is left unchanged.
+    /// If the limit is reached, the value is left as the last mutation
+    /// produced; use [`try_repeat_until`](BoxMutator::try_repeat_until) to
+    /// detect that case.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - The condition that stops the repetition
+    /// * `max_iters` - The maximum number of mutations per call
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxMutator<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxMutator, Mutator};
+    ///
+    /// let mut grow = BoxMutator::new(|x: &mut i32| *x *= 2)
+    ///     .repeat_until(|x: &i32| *x >= 100, 10);
+    ///
+    /// let mut value = 3;
+    /// grow.mutate(&mut value);
+    /// assert_eq!(value, 192);
+    /// ```
+    pub fn repeat_until<P>(mut self, predicate: P, max_iters: usize) -> BoxMutator<T>
+    where
+        P: Predicate<T> + 'static,
+    {
+        BoxMutator::new(move |value: &mut T| {
+            let _ = self.try_repeat_until(value, &predicate, max_iters);
+        })
+    }
+
+    /// Applies the mutation until a predicate is satisfied, reporting
+    /// whether it was reached
+    ///
+    /// Tests `value` and applies this mutation while the predicate returns
+    /// `false`, at most `max_iters` times.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value to mutate
+    /// * `predicate` - The condition that stops the repetition
+    /// * `max_iters` - The maximum number of mutations
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(n)` with the number of mutations applied once the
+    /// predicate is satisfied, or `Err(max_iters)` if the predicate still
+    /// fails after `max_iters` mutations
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::BoxMutator;
+    ///
+    /// let mut increment = BoxMutator::new(|x: &mut i32| *x += 1);
+    ///
+    /// let mut value = 0;
+    /// assert_eq!(increment.try_repeat_until(&mut value, &|x: &i32| *x == 3, 5), Ok(3));
+    ///
+    /// let mut value = 0;
+    /// assert_eq!(increment.try_repeat_until(&mut value, &|x: &i32| *x == 9, 5), Err(5));
+    /// assert_eq!(value, 5);
+    /// ```
+    pub fn try_repeat_until<P>(
+        &mut self,
+        value: &mut T,
+        predicate: &P,
+        max_iters: usize,
+    ) -> Result<usize, usize>
+    where
+        P: Predicate<T>,
+    {
+        for applied in 0..max_iters {
+            if predicate.test(value) {
+                return Ok(applied);
+            }
+            (self.function)(value);
+        }
+        if predicate.test(value) {
+            Ok(max_iters)
+        } else {
+            Err(max_iters)
+        }
+    }
+
+    /// Converts this mutator into a unary operator
+    ///
+    /// The operator takes ownership of its input, mutates it in place and
+    /// returns it, so no clone is needed. The mutator is kept in a
+    /// `RefCell` since transformers are called through `&self`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `BoxUnaryOperator<T>` running this mutator
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxMutator, Transformer};
+    ///
+    /// let shout = BoxMutator::new(|s: &mut String| s.make_ascii_uppercase());
+    /// let op = shout.into_unary_operator();
+    /// assert_eq!(op.apply("hi".to_string()), "HI");
+    /// ```
+    pub fn into_unary_operator(self) -> BoxUnaryOperator<T> {
+        let function = RefCell::new(self.function);
+        BoxTransformer::new(move |mut value: T| {
+            (function.borrow_mut())(&mut value);
+            value
+        })
+    }
+}
+
+impl<T> BoxMutator<T>
+where
+    T: Clone + 'static,
+{
+    /// Converts this mutator into a consumer working on clones
+    ///
+    /// Every accepted value is cloned, the clone is mutated and then
+    /// dropped, so the original value is never changed. This is useful for
+    /// dry runs that check a mutation would succeed without applying it.
+    ///
+    /// # Cost
+    ///
+    /// Each `accept` performs one full `clone` of the value, which may be
+    /// expensive for large values.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `BoxConsumer<T>` running this mutator on a clone
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxMutator, Consumer};
+    ///
+    /// let mut dry_run = BoxMutator::new(|v: &mut Vec<i32>| v.push(4)).into_consumer();
+    /// let original = vec![1, 2, 3];
+    /// dry_run.accept(&original);
+    /// assert_eq!(original, vec![1, 2, 3]);
+    /// ```
+    pub fn into_consumer(self) -> BoxConsumer<T> {
+        let mut function = self.function;
+        BoxConsumer::new(move |t: &T| {
+            let mut copy = t.clone();
+            function(&mut copy);
+        })
+    }
 }
 
 impl<T> Mutator<T> for BoxMutator<T> {
@@ -1304,11 +1521,48 @@ where
     }
 }
 
+impl<T> RcMutator<T>
+where
+    T: Clone + 'static,
+{
+    /// Converts this mutator into a consumer working on clones
+    ///
+    /// The returned consumer shares this mutator's function. Every accepted
+    /// value is cloned, the clone is mutated and then dropped, so each call
+    /// costs one `clone` of the value.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `RcConsumer<T>` running this mutator on a clone
+    pub fn into_consumer(self) -> RcConsumer<T> {
+        let function = self.function;
+        RcConsumer::new(move |t: &T| {
+            let mut copy = t.clone();
+            function.borrow_mut()(&mut copy);
+        })
+    }
+}
+
 impl<T> Mutator<T> for RcMutator<T> {
     fn mutate(&mut self, value: &mut T) {
         (self.function.borrow_mut())(value)
     }
 
+    fn apply_to_all(&mut self, values: &mut [T]) {
+        self.apply_to_iter(values);
+    }
+
+    fn apply_to_iter<'a, I>(&mut self, values: I)
+    where
+        T: 'a,
+        I: IntoIterator<Item = &'a mut T>,
+    {
+        let mut function = self.function.borrow_mut();
+        for value in values {
+            function(value);
+        }
+    }
+
     fn into_box(self) -> BoxMutator<T>
     where
         T: 'static,
@@ -1849,11 +2103,48 @@ where
     }
 }
 
+impl<T> ArcMutator<T>
+where
+    T: Clone + Send + 'static,
+{
+    /// Converts this mutator into a consumer working on clones
+    ///
+    /// The returned consumer shares this mutator's function. Every accepted
+    /// value is cloned, the clone is mutated and then dropped, so each call
+    /// costs one `clone` of the value.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `ArcConsumer<T>` running this mutator on a clone
+    pub fn into_consumer(self) -> ArcConsumer<T> {
+        let function = self.function;
+        ArcConsumer::new(move |t: &T| {
+            let mut copy = t.clone();
+            function.lock().unwrap()(&mut copy);
+        })
+    }
+}
+
 impl<T> Mutator<T> for ArcMutator<T> {
     fn mutate(&mut self, value: &mut T) {
         (self.function.lock().unwrap())(value)
     }
 
+    fn apply_to_all(&mut self, values: &mut [T]) {
+        self.apply_to_iter(values);
+    }
+
+    fn apply_to_iter<'a, I>(&mut self, values: I)
+    where
+        T: 'a,
+        I: IntoIterator<Item = &'a mut T>,
+    {
+        let mut function = self.function.lock().unwrap();
+        for value in values {
+            function(value);
+        }
+    }
+
     fn into_box(self) -> BoxMutator<T>
     where
         T: 'static,
@@ -0,0 +1,1029 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # OwnedConsumer Types
+//!
+//! Provides consumer interface implementations for operations that take
+//! ownership of their input, e.g. pushing into a `Vec` or sending a
+//! non-`Clone` value to another component.
+//!
+//! This module provides a unified `OwnedConsumer` trait, a one-time
+//! `OwnedConsumerOnce` trait and four concrete implementations based on
+//! different ownership models:
+//!
+//! - **`BoxOwnedConsumer<T>`**: Box-based single ownership for one-time use
+//! - **`ArcOwnedConsumer<T>`**: Arc<Mutex<>>-based thread-safe shared
+//!   ownership
+//! - **`RcOwnedConsumer<T>`**: Rc<RefCell<>>-based single-threaded shared
+//!   ownership
+//! - **`BoxOwnedConsumerOnce<T>`**: Box-based consumer callable only once
+//!
+//! An owned consumer is the by-value counterpart of
+//! [`Consumer`](crate::Consumer): it receives `T` through `FnMut(T)` instead
+//! of `&T`, so no `Clone` bound is needed to keep the value. `Clone` is only
+//! required where a value is actually handed to more than one consumer, as in
+//! `and_then`.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::consumer::{ArcConsumer, BoxConsumer, Consumer, RcConsumer};
+use crate::consumer_once::ConsumerOnce;
+
+/// Type alias for owned consumer function signature
+type OwnedConsumerFn<T> = dyn FnMut(T);
+
+/// Type alias for thread-safe owned consumer function signature
+type SendOwnedConsumerFn<T> = dyn FnMut(T) + Send;
+
+// =======================================================================
+// 1. OwnedConsumer Trait - Unified OwnedConsumer Interface
+// =======================================================================
+
+/// OwnedConsumer trait - Unified by-value consumer interface
+///
+/// Defines the core behavior of all owned consumer types. Performs an
+/// operation that takes ownership of its input and returns nothing.
+///
+/// This trait is automatically implemented by:
+/// - All closures implementing `FnMut(T)`
+/// - `BoxOwnedConsumer<T>`, `ArcOwnedConsumer<T>` and `RcOwnedConsumer<T>`
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BoxOwnedConsumer, OwnedConsumer};
+///
+/// struct Ticket(u32);
+///
+/// fn issue<C: OwnedConsumer<Ticket>>(consumer: &mut C) {
+///     consumer.accept_owned(Ticket(1));
+///     consumer.accept_owned(Ticket(2));
+/// }
+///
+/// let (mut sink, tickets) = BoxOwnedConsumer::collect();
+/// issue(&mut sink);
+/// assert_eq!(tickets.lock().unwrap().len(), 2);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub trait OwnedConsumer<T> {
+    /// Performs the consumption operation, taking ownership of the value
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value to consume
+    fn accept_owned(&mut self, value: T);
+
+    /// Converts to BoxOwnedConsumer
+    ///
+    /// **⚠️ Consumes `self`**: Original consumer becomes unavailable after
+    /// calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `BoxOwnedConsumer<T>`
+    fn into_box(self) -> BoxOwnedConsumer<T>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+    {
+        let mut consumer = self;
+        BoxOwnedConsumer::new(move |t| consumer.accept_owned(t))
+    }
+
+    /// Converts to RcOwnedConsumer
+    ///
+    /// **⚠️ Consumes `self`**: Original consumer becomes unavailable after
+    /// calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `RcOwnedConsumer<T>`
+    fn into_rc(self) -> RcOwnedConsumer<T>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+    {
+        let mut consumer = self;
+        RcOwnedConsumer::new(move |t| consumer.accept_owned(t))
+    }
+
+    /// Converts to ArcOwnedConsumer
+    ///
+    /// **⚠️ Consumes `self`**: Original consumer becomes unavailable after
+    /// calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `ArcOwnedConsumer<T>`
+    fn into_arc(self) -> ArcOwnedConsumer<T>
+    where
+        Self: Sized + Send + 'static,
+        T: Send + 'static,
+    {
+        let mut consumer = self;
+        ArcOwnedConsumer::new(move |t| consumer.accept_owned(t))
+    }
+
+    /// Converts owned consumer to a closure
+    ///
+    /// **⚠️ Consumes `self`**: Original consumer becomes unavailable after
+    /// calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns a closure implementing `FnMut(T)`
+    fn into_fn(self) -> impl FnMut(T)
+    where
+        Self: Sized + 'static,
+        T: 'static,
+    {
+        let mut consumer = self;
+        move |t| consumer.accept_owned(t)
+    }
+}
+
+// =======================================================================
+// 2. BoxOwnedConsumer - Single Ownership Implementation
+// =======================================================================
+
+/// BoxOwnedConsumer struct
+///
+/// An owned consumer implementation based on `Box<dyn FnMut(T)>` for single
+/// ownership scenarios.
+///
+/// # Features
+///
+/// - **Single Ownership**: Not cloneable, ownership moves on use
+/// - **Zero Overhead**: No reference counting or locking
+/// - **No Clone Bound**: Values are moved, never cloned, unless fanned out
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BoxOwnedConsumer, OwnedConsumer};
+///
+/// let mut lines = Vec::new();
+/// let mut sink = BoxOwnedConsumer::new(move |line: String| {
+///     lines.push(line);
+///     println!("{} lines", lines.len());
+/// });
+/// sink.accept_owned("hello".to_string());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct BoxOwnedConsumer<T> {
+    function: Box<OwnedConsumerFn<T>>,
+}
+
+impl<T> BoxOwnedConsumer<T>
+where
+    T: 'static,
+{
+    /// Creates a new BoxOwnedConsumer
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxOwnedConsumer<T>` instance
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnMut(T) + 'static,
+    {
+        BoxOwnedConsumer {
+            function: Box::new(f),
+        }
+    }
+
+    /// Creates an owned consumer that moves every value into a vector
+    ///
+    /// # Returns
+    ///
+    /// Returns the collecting consumer together with a shared handle to the
+    /// collected values
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxOwnedConsumer, OwnedConsumer};
+    ///
+    /// let (mut consumer, values) = BoxOwnedConsumer::collect();
+    /// consumer.accept_owned(vec![1, 2]);
+    /// consumer.accept_owned(vec![3]);
+    /// assert_eq!(*values.lock().unwrap(), vec![vec![1, 2], vec![3]]);
+    /// ```
+    pub fn collect() -> (Self, Arc<Mutex<Vec<T>>>) {
+        let values = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&values);
+        let consumer = BoxOwnedConsumer::new(move |value: T| {
+            sink.lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(value);
+        });
+        (consumer, values)
+    }
+
+    /// Creates an owned consumer from a reference-based consumer
+    ///
+    /// The returned consumer passes a reference to every value to
+    /// `consumer` and then drops the value.
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The consumer to wrap. Can be a closure, a
+    ///   `BoxConsumer<T>`, `RcConsumer<T>`, `ArcConsumer<T>` or any type
+    ///   implementing `Consumer<T>`
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxOwnedConsumer<T>`
+    pub fn from_consumer<C>(consumer: C) -> Self
+    where
+        C: Consumer<T> + 'static,
+    {
+        let mut consumer = consumer;
+        BoxOwnedConsumer::new(move |t: T| consumer.accept(&t))
+    }
+
+    /// Chains another owned consumer in sequence
+    ///
+    /// Returns a new consumer passing a clone of each value to the current
+    /// operation first, then moving the value into `next`. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - The owned consumer to execute after the current operation.
+    ///   **Note: This parameter is passed by value and will transfer
+    ///   ownership.** Can be:
+    ///   - A closure: `|x: T|`
+    ///   - A `BoxOwnedConsumer<T>`
+    ///   - An `ArcOwnedConsumer<T>`
+    ///   - An `RcOwnedConsumer<T>`
+    ///   - Any type implementing `OwnedConsumer<T>`
+    ///
+    /// # Returns
+    ///
+    /// Returns a new composed `BoxOwnedConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxOwnedConsumer, OwnedConsumer};
+    ///
+    /// let (first, a) = BoxOwnedConsumer::collect();
+    /// let (second, b) = BoxOwnedConsumer::collect();
+    /// let mut both = first.and_then(second);
+    /// both.accept_owned("x".to_string());
+    /// assert_eq!(*a.lock().unwrap(), vec!["x"]);
+    /// assert_eq!(*b.lock().unwrap(), vec!["x"]);
+    /// ```
+    pub fn and_then<C>(self, next: C) -> Self
+    where
+        C: OwnedConsumer<T> + 'static,
+        T: Clone,
+    {
+        let mut first = self.function;
+        let mut second = next;
+        BoxOwnedConsumer::new(move |t: T| {
+            first(t.clone());
+            second.accept_owned(t);
+        })
+    }
+
+    /// Converts into a reference-based consumer
+    ///
+    /// The returned consumer passes a clone of every value it sees to this
+    /// owned consumer. Consumes self.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxOwnedConsumer, Consumer};
+    ///
+    /// let (owned, values) = BoxOwnedConsumer::collect();
+    /// let mut consumer = owned.into_consumer();
+    /// consumer.accept(&5);
+    /// assert_eq!(*values.lock().unwrap(), vec![5]);
+    /// ```
+    pub fn into_consumer(self) -> BoxConsumer<T>
+    where
+        T: Clone,
+    {
+        let mut function = self.function;
+        BoxConsumer::new(move |t: &T| function(t.clone()))
+    }
+}
+
+impl<T> OwnedConsumer<T> for BoxOwnedConsumer<T> {
+    fn accept_owned(&mut self, value: T) {
+        (self.function)(value)
+    }
+
+    fn into_box(self) -> BoxOwnedConsumer<T>
+    where
+        T: 'static,
+    {
+        self
+    }
+
+    fn into_rc(self) -> RcOwnedConsumer<T>
+    where
+        T: 'static,
+    {
+        RcOwnedConsumer::new(self.function)
+    }
+
+    // do NOT override OwnedConsumer::into_arc() because BoxOwnedConsumer is
+    // not Send + Sync and calling BoxOwnedConsumer::into_arc() will cause a
+    // compile error
+
+    fn into_fn(self) -> impl FnMut(T)
+    where
+        T: 'static,
+    {
+        self.function
+    }
+}
+
+impl<T> fmt::Debug for BoxOwnedConsumer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxOwnedConsumer")
+            .field("function", &"<function>")
+            .finish()
+    }
+}
+
+// =======================================================================
+// 3. ArcOwnedConsumer - Thread-Safe Shared Ownership Implementation
+// =======================================================================
+
+/// ArcOwnedConsumer struct
+///
+/// An owned consumer implementation based on `Arc<Mutex<dyn FnMut(T) +
+/// Send>>` for thread-safe shared ownership scenarios. Clones share the same
+/// underlying function and its captured state.
+///
+/// # Features
+///
+/// - **Shared Ownership**: Cloneable via `Arc`
+/// - **Thread Safe**: Implements `Send + Sync`
+/// - **Interior Mutability**: Uses `Mutex` for the `FnMut` state, recovering
+///   from poisoning so one panicking call does not break the clones
+/// - **Non-Consuming API**: `and_then` and `to_consumer` borrow `&self`
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{ArcOwnedConsumer, OwnedConsumer};
+/// use std::sync::{Arc, Mutex};
+/// use std::thread;
+///
+/// let jobs = Arc::new(Mutex::new(Vec::new()));
+/// let j = jobs.clone();
+/// let consumer = ArcOwnedConsumer::new(move |job: String| j.lock().unwrap().push(job));
+/// let mut clone = consumer.clone();
+/// thread::spawn(move || clone.accept_owned("build".to_string()))
+///     .join()
+///     .unwrap();
+/// assert_eq!(*jobs.lock().unwrap(), vec!["build"]);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct ArcOwnedConsumer<T> {
+    function: Arc<Mutex<SendOwnedConsumerFn<T>>>,
+}
+
+impl<T> ArcOwnedConsumer<T>
+where
+    T: Send + 'static,
+{
+    /// Creates a new ArcOwnedConsumer
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `ArcOwnedConsumer<T>` instance
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnMut(T) + Send + 'static,
+    {
+        ArcOwnedConsumer {
+            function: Arc::new(Mutex::new(f)),
+        }
+    }
+
+    /// Creates an owned consumer from a reference-based consumer
+    ///
+    /// The returned consumer passes a reference to every value to
+    /// `consumer` and then drops the value.
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The thread-safe consumer to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `ArcOwnedConsumer<T>`
+    pub fn from_consumer<C>(consumer: C) -> Self
+    where
+        C: Consumer<T> + Send + 'static,
+    {
+        let mut consumer = consumer;
+        ArcOwnedConsumer::new(move |t: T| consumer.accept(&t))
+    }
+
+    /// Chains another ArcOwnedConsumer in sequence
+    ///
+    /// Returns a new consumer passing a clone of each value to the current
+    /// operation first, then moving the value into `next`. Borrows &self,
+    /// does not consume the original consumer.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - The consumer to execute after the current operation.
+    ///   **Note: This parameter is passed by reference, so the original
+    ///   consumer remains usable.**
+    ///
+    /// # Returns
+    ///
+    /// Returns a new composed `ArcOwnedConsumer<T>`
+    pub fn and_then(&self, next: &ArcOwnedConsumer<T>) -> ArcOwnedConsumer<T>
+    where
+        T: Clone,
+    {
+        let first = Arc::clone(&self.function);
+        let second = Arc::clone(&next.function);
+        ArcOwnedConsumer {
+            function: Arc::new(Mutex::new(move |t: T| {
+                first.lock().unwrap_or_else(PoisonError::into_inner)(t.clone());
+                second.lock().unwrap_or_else(PoisonError::into_inner)(t);
+            })),
+        }
+    }
+
+    /// Converts to a reference-based consumer
+    ///
+    /// The returned consumer shares this consumer's function and passes it a
+    /// clone of every value it sees. Borrows &self.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `ArcConsumer<T>`
+    pub fn to_consumer(&self) -> ArcConsumer<T>
+    where
+        T: Clone,
+    {
+        let function = Arc::clone(&self.function);
+        ArcConsumer::new(move |t: &T| {
+            function.lock().unwrap_or_else(PoisonError::into_inner)(t.clone())
+        })
+    }
+}
+
+impl<T> OwnedConsumer<T> for ArcOwnedConsumer<T> {
+    fn accept_owned(&mut self, value: T) {
+        (self.function.lock().unwrap_or_else(PoisonError::into_inner))(value)
+    }
+
+    fn into_box(self) -> BoxOwnedConsumer<T>
+    where
+        T: 'static,
+    {
+        let self_fn = self.function;
+        BoxOwnedConsumer::new(move |t| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t))
+    }
+
+    fn into_rc(self) -> RcOwnedConsumer<T>
+    where
+        T: 'static,
+    {
+        let self_fn = self.function;
+        RcOwnedConsumer::new(move |t| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t))
+    }
+
+    fn into_arc(self) -> ArcOwnedConsumer<T>
+    where
+        T: Send + 'static,
+    {
+        self
+    }
+
+    fn into_fn(self) -> impl FnMut(T)
+    where
+        T: 'static,
+    {
+        let self_fn = self.function;
+        move |t| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t)
+    }
+}
+
+impl<T> Clone for ArcOwnedConsumer<T> {
+    /// Clones the ArcOwnedConsumer
+    ///
+    /// Creates a new ArcOwnedConsumer sharing the underlying function with
+    /// the original instance.
+    fn clone(&self) -> Self {
+        ArcOwnedConsumer {
+            function: self.function.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for ArcOwnedConsumer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArcOwnedConsumer")
+            .field("function", &"<function>")
+            .finish()
+    }
+}
+
+// =======================================================================
+// 4. RcOwnedConsumer - Single-Threaded Shared Ownership Implementation
+// =======================================================================
+
+/// RcOwnedConsumer struct
+///
+/// An owned consumer implementation based on `Rc<RefCell<dyn FnMut(T)>>`
+/// for single-threaded shared ownership scenarios. No lock overhead.
+///
+/// # Features
+///
+/// - **Shared Ownership**: Cloneable via `Rc`
+/// - **Single-Threaded**: Not thread-safe
+/// - **Interior Mutability**: Uses `RefCell` for the `FnMut` state
+/// - **Non-Consuming API**: `and_then` and `to_consumer` borrow `&self`
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{OwnedConsumer, RcOwnedConsumer};
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// let queue = Rc::new(RefCell::new(Vec::new()));
+/// let q = queue.clone();
+/// let mut consumer = RcOwnedConsumer::new(move |item: Box<str>| q.borrow_mut().push(item));
+/// let mut clone = consumer.clone();
+/// consumer.accept_owned("a".into());
+/// clone.accept_owned("b".into());
+/// assert_eq!(queue.borrow().len(), 2);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct RcOwnedConsumer<T> {
+    function: Rc<RefCell<OwnedConsumerFn<T>>>,
+}
+
+impl<T> RcOwnedConsumer<T>
+where
+    T: 'static,
+{
+    /// Creates a new RcOwnedConsumer
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `RcOwnedConsumer<T>` instance
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnMut(T) + 'static,
+    {
+        RcOwnedConsumer {
+            function: Rc::new(RefCell::new(f)),
+        }
+    }
+
+    /// Creates an owned consumer from a reference-based consumer
+    ///
+    /// The returned consumer passes a reference to every value to
+    /// `consumer` and then drops the value.
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The consumer to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `RcOwnedConsumer<T>`
+    pub fn from_consumer<C>(consumer: C) -> Self
+    where
+        C: Consumer<T> + 'static,
+    {
+        let mut consumer = consumer;
+        RcOwnedConsumer::new(move |t: T| consumer.accept(&t))
+    }
+
+    /// Chains another RcOwnedConsumer in sequence
+    ///
+    /// Returns a new consumer passing a clone of each value to the current
+    /// operation first, then moving the value into `next`. Borrows &self,
+    /// does not consume the original consumer.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - The consumer to execute after the current operation.
+    ///   **Note: This parameter is passed by reference, so the original
+    ///   consumer remains usable.**
+    ///
+    /// # Returns
+    ///
+    /// Returns a new composed `RcOwnedConsumer<T>`
+    pub fn and_then(&self, next: &RcOwnedConsumer<T>) -> RcOwnedConsumer<T>
+    where
+        T: Clone,
+    {
+        let first = Rc::clone(&self.function);
+        let second = Rc::clone(&next.function);
+        RcOwnedConsumer {
+            function: Rc::new(RefCell::new(move |t: T| {
+                first.borrow_mut()(t.clone());
+                second.borrow_mut()(t);
+            })),
+        }
+    }
+
+    /// Converts to a reference-based consumer
+    ///
+    /// The returned consumer shares this consumer's function and passes it a
+    /// clone of every value it sees. Borrows &self.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `RcConsumer<T>`
+    pub fn to_consumer(&self) -> RcConsumer<T>
+    where
+        T: Clone,
+    {
+        let function = Rc::clone(&self.function);
+        RcConsumer::new(move |t: &T| function.borrow_mut()(t.clone()))
+    }
+}
+
+impl<T> OwnedConsumer<T> for RcOwnedConsumer<T> {
+    fn accept_owned(&mut self, value: T) {
+        (self.function.borrow_mut())(value)
+    }
+
+    fn into_box(self) -> BoxOwnedConsumer<T>
+    where
+        T: 'static,
+    {
+        let self_fn = self.function;
+        BoxOwnedConsumer::new(move |t| self_fn.borrow_mut()(t))
+    }
+
+    fn into_rc(self) -> RcOwnedConsumer<T>
+    where
+        T: 'static,
+    {
+        self
+    }
+
+    // do NOT override OwnedConsumer::into_arc() because RcOwnedConsumer is
+    // not Send + Sync and calling RcOwnedConsumer::into_arc() will cause a
+    // compile error
+
+    fn into_fn(self) -> impl FnMut(T)
+    where
+        T: 'static,
+    {
+        let self_fn = self.function;
+        move |t| self_fn.borrow_mut()(t)
+    }
+}
+
+impl<T> Clone for RcOwnedConsumer<T> {
+    /// Clones the RcOwnedConsumer
+    ///
+    /// Creates a new RcOwnedConsumer sharing the underlying function with the
+    /// original instance.
+    fn clone(&self) -> Self {
+        RcOwnedConsumer {
+            function: self.function.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for RcOwnedConsumer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RcOwnedConsumer")
+            .field("function", &"<function>")
+            .finish()
+    }
+}
+
+// =======================================================================
+// 5. Implement OwnedConsumer trait for closures
+// =======================================================================
+
+/// Implements OwnedConsumer for all FnMut(T)
+impl<T, F> OwnedConsumer<T> for F
+where
+    F: FnMut(T),
+{
+    fn accept_owned(&mut self, value: T) {
+        self(value)
+    }
+
+    fn into_box(self) -> BoxOwnedConsumer<T>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+    {
+        BoxOwnedConsumer::new(self)
+    }
+
+    fn into_rc(self) -> RcOwnedConsumer<T>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+    {
+        RcOwnedConsumer::new(self)
+    }
+
+    fn into_arc(self) -> ArcOwnedConsumer<T>
+    where
+        Self: Sized + Send + 'static,
+        T: Send + 'static,
+    {
+        ArcOwnedConsumer::new(self)
+    }
+
+    fn into_fn(self) -> impl FnMut(T)
+    where
+        Self: Sized + 'static,
+        T: 'static,
+    {
+        self
+    }
+}
+
+// =======================================================================
+// 6. OwnedConsumerOnce Trait - One-Time By-Value Consumer Interface
+// =======================================================================
+
+/// OwnedConsumerOnce trait - One-time by-value consumer interface
+///
+/// Performs an operation that takes ownership of both itself and its input.
+///
+/// This trait is automatically implemented by:
+/// - All closures implementing `FnOnce(T)`
+/// - `BoxOwnedConsumerOnce<T>`
+/// - `BoxOwnedConsumer<T>`, `ArcOwnedConsumer<T>` and `RcOwnedConsumer<T>`
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BoxOwnedConsumerOnce, OwnedConsumerOnce};
+/// use std::sync::mpsc;
+///
+/// let (tx, rx) = mpsc::channel();
+/// let reply = BoxOwnedConsumerOnce::new(move |answer: String| {
+///     tx.send(answer).unwrap();
+/// });
+/// reply.accept_owned_once("done".to_string());
+/// assert_eq!(rx.recv().unwrap(), "done");
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub trait OwnedConsumerOnce<T> {
+    /// Performs the one-time consumption operation
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value to consume
+    fn accept_owned_once(self, value: T);
+
+    /// Converts to BoxOwnedConsumerOnce
+    ///
+    /// **⚠️ Consumes `self`**: Original consumer becomes unavailable after
+    /// calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `BoxOwnedConsumerOnce<T>`
+    fn into_box_once(self) -> BoxOwnedConsumerOnce<T>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+    {
+        BoxOwnedConsumerOnce::new(move |t| self.accept_owned_once(t))
+    }
+
+    /// Converts to a closure
+    ///
+    /// **⚠️ Consumes `self`**: Original consumer becomes unavailable after
+    /// calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns a closure implementing `FnOnce(T)`
+    fn into_fn_once(self) -> impl FnOnce(T)
+    where
+        Self: Sized + 'static,
+        T: 'static,
+    {
+        move |t| self.accept_owned_once(t)
+    }
+}
+
+// =======================================================================
+// 7. BoxOwnedConsumerOnce - One-Time Implementation
+// =======================================================================
+
+/// BoxOwnedConsumerOnce struct
+///
+/// A one-time owned consumer implementation based on `Box<dyn FnOnce(T)>`.
+///
+/// # Features
+///
+/// - **One-Time Use**: Consumed on first call
+/// - **Moves Captures**: The closure may move its captured state out
+/// - **No Clone Bound**: The value is moved, never cloned, unless fanned out
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct BoxOwnedConsumerOnce<T> {
+    function: Box<dyn FnOnce(T)>,
+}
+
+impl<T> BoxOwnedConsumerOnce<T>
+where
+    T: 'static,
+{
+    /// Creates a new BoxOwnedConsumerOnce
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxOwnedConsumerOnce<T>` instance
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnOnce(T) + 'static,
+    {
+        BoxOwnedConsumerOnce {
+            function: Box::new(f),
+        }
+    }
+
+    /// Creates a one-time owned consumer from a reference-based one
+    ///
+    /// The returned consumer passes a reference to the value to `consumer`
+    /// and then drops the value.
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The one-time consumer to wrap. Can be a closure, a
+    ///   `BoxConsumerOnce<T>` or any type implementing `ConsumerOnce<T>`
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxOwnedConsumerOnce<T>`
+    pub fn from_consumer_once<C>(consumer: C) -> Self
+    where
+        C: ConsumerOnce<T> + 'static,
+    {
+        BoxOwnedConsumerOnce::new(move |t: T| consumer.accept_once(&t))
+    }
+
+    /// Chains another one-time owned consumer in sequence
+    ///
+    /// Returns a new consumer passing a clone of the value to the current
+    /// operation first, then moving the value into `next`. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - The one-time consumer to execute after the current
+    ///   operation. Can be a closure `|x: T|` or any type implementing
+    ///   `OwnedConsumerOnce<T>`
+    ///
+    /// # Returns
+    ///
+    /// Returns a new composed `BoxOwnedConsumerOnce<T>`
+    pub fn and_then<C>(self, next: C) -> Self
+    where
+        C: OwnedConsumerOnce<T> + 'static,
+        T: Clone,
+    {
+        let first = self.function;
+        BoxOwnedConsumerOnce::new(move |t: T| {
+            first(t.clone());
+            next.accept_owned_once(t);
+        })
+    }
+}
+
+impl<T> OwnedConsumerOnce<T> for BoxOwnedConsumerOnce<T> {
+    fn accept_owned_once(self, value: T) {
+        (self.function)(value)
+    }
+
+    fn into_box_once(self) -> BoxOwnedConsumerOnce<T>
+    where
+        T: 'static,
+    {
+        self
+    }
+
+    fn into_fn_once(self) -> impl FnOnce(T)
+    where
+        T: 'static,
+    {
+        self.function
+    }
+}
+
+impl<T> fmt::Debug for BoxOwnedConsumerOnce<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxOwnedConsumerOnce")
+            .field("function", &"<function>")
+            .finish()
+    }
+}
+
+// =======================================================================
+// 8. Implement OwnedConsumerOnce trait for reusable consumers and closures
+// =======================================================================
+
+impl<T> OwnedConsumerOnce<T> for BoxOwnedConsumer<T> {
+    fn accept_owned_once(mut self, value: T) {
+        self.accept_owned(value)
+    }
+}
+
+impl<T> OwnedConsumerOnce<T> for ArcOwnedConsumer<T> {
+    fn accept_owned_once(mut self, value: T) {
+        self.accept_owned(value)
+    }
+}
+
+impl<T> OwnedConsumerOnce<T> for RcOwnedConsumer<T> {
+    fn accept_owned_once(mut self, value: T) {
+        self.accept_owned(value)
+    }
+}
+
+/// Implements OwnedConsumerOnce for all FnOnce(T)
+impl<T, F> OwnedConsumerOnce<T> for F
+where
+    F: FnOnce(T),
+{
+    fn accept_owned_once(self, value: T) {
+        self(value)
+    }
+
+    fn into_box_once(self) -> BoxOwnedConsumerOnce<T>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+    {
+        BoxOwnedConsumerOnce::new(self)
+    }
+
+    fn into_fn_once(self) -> impl FnOnce(T)
+    where
+        Self: Sized + 'static,
+        T: 'static,
+    {
+        self
+    }
+}
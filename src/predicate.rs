@@ -171,10 +171,14 @@
 //!
 //! Haixing Hu
 
+use std::cell::OnceCell;
+use std::cmp::Ordering;
 use std::fmt::{Debug, Display};
 use std::rc::Rc;
 use std::sync::Arc;
 
+use crate::comparator::{BoxComparator, Comparator};
+
 /// Predicate name constant for always-true predicates
 const ALWAYS_TRUE_NAME: &str = "always_true";
 
@@ -565,6 +569,97 @@ impl<T: 'static> BoxPredicate<T> {
         }
     }
 
+    /// Creates a predicate satisfied when all given predicates are.
+    ///
+    /// The predicates are evaluated in order and evaluation stops at the
+    /// first one returning `false`. An empty list is always satisfied.
+    /// Use this instead of a chain of `and` calls when the number of
+    /// predicates is only known at runtime.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicates` - The predicates to combine
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxPredicate` representing the logical AND of `predicates`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::predicate::{Predicate, BoxPredicate};
+    ///
+    /// let pred = BoxPredicate::all_of(vec![
+    ///     BoxPredicate::new(|x: &i32| *x > 0),
+    ///     BoxPredicate::new(|x: &i32| x % 2 == 0),
+    /// ]);
+    /// assert!(pred.test(&4));
+    /// assert!(!pred.test(&3));
+    /// assert!(!pred.test(&-2));
+    /// ```
+    pub fn all_of(predicates: Vec<BoxPredicate<T>>) -> Self {
+        BoxPredicate::new(move |value: &T| predicates.iter().all(|p| p.test(value)))
+    }
+
+    /// Creates a predicate satisfied when any of the given predicates is.
+    ///
+    /// The predicates are evaluated in order and evaluation stops at the
+    /// first one returning `true`. An empty list is never satisfied.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicates` - The predicates to combine
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxPredicate` representing the logical OR of `predicates`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::predicate::{Predicate, BoxPredicate};
+    ///
+    /// let pred = BoxPredicate::any_of(vec![
+    ///     BoxPredicate::new(|x: &i32| *x < 0),
+    ///     BoxPredicate::new(|x: &i32| *x > 100),
+    /// ]);
+    /// assert!(pred.test(&-1));
+    /// assert!(pred.test(&101));
+    /// assert!(!pred.test(&50));
+    /// ```
+    pub fn any_of(predicates: Vec<BoxPredicate<T>>) -> Self {
+        BoxPredicate::new(move |value: &T| predicates.iter().any(|p| p.test(value)))
+    }
+
+    /// Creates a predicate satisfied when none of the given predicates is.
+    ///
+    /// The predicates are evaluated in order and evaluation stops at the
+    /// first one returning `true`. An empty list is always satisfied.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicates` - The predicates to combine
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxPredicate` representing the logical NOR of `predicates`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::predicate::{Predicate, BoxPredicate};
+    ///
+    /// let pred = BoxPredicate::none_of(vec![
+    ///     BoxPredicate::new(|s: &&str| s.is_empty()),
+    ///     BoxPredicate::new(|s: &&str| s.contains(' ')),
+    /// ]);
+    /// assert!(pred.test(&"word"));
+    /// assert!(!pred.test(&"two words"));
+    /// ```
+    pub fn none_of(predicates: Vec<BoxPredicate<T>>) -> Self {
+        BoxPredicate::new(move |value: &T| !predicates.iter().any(|p| p.test(value)))
+    }
+
     /// Returns the name of this predicate, if set.
     ///
     /// # Returns
@@ -588,6 +683,10 @@ impl<T: 'static> BoxPredicate<T> {
     ///
     /// This method consumes `self` due to single-ownership semantics.
     ///
+    /// Evaluation short-circuits: `other` is not tested when this predicate
+    /// returns `false`. Use [`and_lazy`](BoxPredicate::and_lazy) to also defer
+    /// constructing `other` until it is first needed.
+    ///
     /// # Parameters
     ///
     /// * `other` - The other predicate to combine with. **Note: This parameter
@@ -694,6 +793,10 @@ impl<T: 'static> BoxPredicate<T> {
     ///
     /// This method consumes `self` due to single-ownership semantics.
     ///
+    /// Evaluation short-circuits: `other` is not tested when this predicate
+    /// returns `true`. Use [`or_lazy`](BoxPredicate::or_lazy) to also defer
+    /// constructing `other` until it is first needed.
+    ///
     /// # Parameters
     ///
     /// * `other` - The other predicate to combine with. **Note: This parameter
@@ -783,6 +886,104 @@ impl<T: 'static> BoxPredicate<T> {
         }
     }
 
+    /// Returns a predicate that represents the logical AND of this predicate
+    /// and a lazily constructed one.
+    ///
+    /// Unlike [`and`](BoxPredicate::and), which requires the second predicate
+    /// to exist up front, `factory` is only called the first time this
+    /// predicate returns `true`. The predicate it builds is cached and reused
+    /// for later tests, so `factory` runs at most once. Use this when the
+    /// second predicate is expensive to build (e.g. loads a lookup table).
+    ///
+    /// This method consumes `self` due to single-ownership semantics.
+    ///
+    /// # Parameters
+    ///
+    /// * `factory` - Builds the second predicate on first use
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxPredicate` representing the logical AND.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::predicate::{Predicate, BoxPredicate};
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    ///
+    /// let built = Rc::new(Cell::new(0));
+    /// let b = built.clone();
+    /// let pred = BoxPredicate::new(|x: &i32| *x > 0).and_lazy(move || {
+    ///     b.set(b.get() + 1);
+    ///     BoxPredicate::new(|x: &i32| x % 2 == 0)
+    /// });
+    ///
+    /// assert!(!pred.test(&-4));
+    /// assert_eq!(built.get(), 0); // never needed the second predicate
+    /// assert!(pred.test(&4));
+    /// assert!(!pred.test(&3));
+    /// assert_eq!(built.get(), 1); // built once, then reused
+    /// ```
+    pub fn and_lazy<F>(self, factory: F) -> BoxPredicate<T>
+    where
+        F: Fn() -> BoxPredicate<T> + 'static,
+    {
+        let other: OnceCell<BoxPredicate<T>> = OnceCell::new();
+        BoxPredicate {
+            function: Box::new(move |value: &T| {
+                (self.function)(value) && other.get_or_init(&factory).test(value)
+            }),
+            name: None,
+        }
+    }
+
+    /// Returns a predicate that represents the logical OR of this predicate
+    /// and a lazily constructed one.
+    ///
+    /// `factory` is only called the first time this predicate returns
+    /// `false`; the predicate it builds is cached and reused, so `factory`
+    /// runs at most once. See [`and_lazy`](BoxPredicate::and_lazy).
+    ///
+    /// This method consumes `self` due to single-ownership semantics.
+    ///
+    /// # Parameters
+    ///
+    /// * `factory` - Builds the second predicate on first use
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxPredicate` representing the logical OR.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::predicate::{Predicate, BoxPredicate};
+    /// use std::collections::HashSet;
+    ///
+    /// let allowed = BoxPredicate::new(|name: &String| name == "admin").or_lazy(|| {
+    ///     let whitelist: HashSet<String> =
+    ///         ["alice", "bob"].iter().map(|s| s.to_string()).collect();
+    ///     BoxPredicate::new(move |name: &String| whitelist.contains(name))
+    /// });
+    ///
+    /// assert!(allowed.test(&"admin".to_string()));
+    /// assert!(allowed.test(&"alice".to_string()));
+    /// assert!(!allowed.test(&"mallory".to_string()));
+    /// ```
+    pub fn or_lazy<F>(self, factory: F) -> BoxPredicate<T>
+    where
+        F: Fn() -> BoxPredicate<T> + 'static,
+    {
+        let other: OnceCell<BoxPredicate<T>> = OnceCell::new();
+        BoxPredicate {
+            function: Box::new(move |value: &T| {
+                (self.function)(value) || other.get_or_init(&factory).test(value)
+            }),
+            name: None,
+        }
+    }
+
     /// Returns a predicate that represents the logical negation of this
     /// predicate.
     ///
@@ -900,6 +1101,8 @@ impl<T: 'static> BoxPredicate<T> {
     /// this predicate and another.
     ///
     /// XOR returns `true` if exactly one of the predicates is `true`.
+    /// Unlike `and` and `or`, XOR cannot short-circuit, so both predicates
+    /// are always evaluated.
     ///
     /// This method consumes `self` due to single-ownership semantics.
     ///
@@ -1096,6 +1299,69 @@ impl<T: 'static> BoxPredicate<T> {
             name: None,
         }
     }
+
+    /// Converts this predicate into a sort comparator.
+    ///
+    /// Values satisfying the predicate are ordered before values that do
+    /// not satisfy it. Two values in the same group compare equal, so a
+    /// stable sort keeps their relative order.
+    ///
+    /// # Returns
+    ///
+    /// A `BoxComparator<T>` ordering matching values first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxPredicate, Comparator};
+    ///
+    /// let is_even = BoxPredicate::new(|x: &i32| x % 2 == 0);
+    /// let cmp = is_even.to_comparator();
+    ///
+    /// let mut values = vec![1, 2, 3, 4, 5, 6];
+    /// values.sort_by(|a, b| cmp.compare(a, b));
+    /// assert_eq!(values, vec![2, 4, 6, 1, 3, 5]);
+    /// ```
+    pub fn to_comparator(self) -> BoxComparator<T> {
+        BoxComparator::new(move |a: &T, b: &T| (self.function)(b).cmp(&(self.function)(a)))
+    }
+
+    /// Creates a predicate from a comparator and a threshold value.
+    ///
+    /// The returned predicate is satisfied by values that the comparator
+    /// orders strictly before `threshold`. Combined with
+    /// [`BoxPredicate::to_comparator`] this partitions values at the
+    /// threshold.
+    ///
+    /// # Parameters
+    ///
+    /// * `threshold` - The value splitting the two partitions.
+    /// * `comparator` - The comparator defining the order. Can be a closure
+    ///   `|a: &T, b: &T| -> Ordering` or any type implementing
+    ///   `Comparator<T>`.
+    ///
+    /// # Returns
+    ///
+    /// A `BoxPredicate<T>` testing whether a value sorts before `threshold`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxPredicate, Predicate};
+    ///
+    /// let short = BoxPredicate::partition_by_comparator(
+    ///     "abcd".to_string(),
+    ///     |a: &String, b: &String| a.len().cmp(&b.len()),
+    /// );
+    /// assert!(short.test(&"abc".to_string()));
+    /// assert!(!short.test(&"wxyz".to_string()));
+    /// ```
+    pub fn partition_by_comparator<C>(threshold: T, comparator: C) -> BoxPredicate<T>
+    where
+        C: Comparator<T> + 'static,
+    {
+        BoxPredicate::new(move |value: &T| comparator.compare(value, &threshold) == Ordering::Less)
+    }
 }
 
 impl<T: 'static> Predicate<T> for BoxPredicate<T> {
@@ -1479,6 +1745,8 @@ impl<T: 'static> RcPredicate<T> {
     /// this predicate and another.
     ///
     /// XOR returns `true` if exactly one of the predicates is `true`.
+    /// Unlike `and` and `or`, XOR cannot short-circuit, so both predicates
+    /// are always evaluated.
     ///
     /// # Parameters
     ///
@@ -1955,6 +2223,8 @@ impl<T: 'static> ArcPredicate<T> {
     /// this predicate and another.
     ///
     /// XOR returns `true` if exactly one of the predicates is `true`.
+    /// Unlike `and` and `or`, XOR cannot short-circuit, so both predicates
+    /// are always evaluated.
     ///
     /// # Parameters
     ///
@@ -2331,6 +2601,8 @@ pub trait FnPredicateOps<T>: Fn(&T) -> bool + Sized + 'static {
     /// this predicate and another.
     ///
     /// XOR returns `true` if exactly one of the predicates is `true`.
+    /// Unlike `and` and `or`, XOR cannot short-circuit, so both predicates
+    /// are always evaluated.
     ///
     /// # Parameters
     ///
@@ -0,0 +1,193 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Priority Types
+//!
+//! Provides [`PriorityConsumer`], a consumer buffering values and handing
+//! them on in comparator order rather than arrival order.
+//!
+//! # Author
+//!
+//! Hu Haixing
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::comparator::{BoxComparator, Comparator};
+use crate::consumer::Consumer;
+
+/// PriorityConsumer struct
+///
+/// A consumer buffering every accepted value in a binary heap ordered by a
+/// comparator, a `BoxComparator<T>` by default. Buffered values are later
+/// handed to another consumer in priority order, the greatest value
+/// according to the comparator first, regardless of the order in which they
+/// were accepted.
+///
+/// Values that compare equal are released in an unspecified order. The
+/// consumer is `Send` when `T` is `Send` and the comparator is
+/// `Send + Sync`, so buffering across threads needs an `ArcComparator<T>`.
+///
+/// # Features
+///
+/// - **Priority Ordering**: Values are released greatest-first
+/// - **Partial Flushing**: Only the top `n` values can be released
+/// - **Implements Consumer**: Can be used anywhere a `Consumer` is expected
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BoxComparator, Consumer, PriorityConsumer};
+///
+/// let mut queue = PriorityConsumer::new(BoxComparator::new(|a: &i32, b: &i32| a.cmp(b)));
+/// queue.accept(&2);
+/// queue.accept(&5);
+/// queue.accept(&1);
+///
+/// let mut out = Vec::new();
+/// queue.drain_all(|x: &i32| out.push(*x));
+/// assert_eq!(out, vec![5, 2, 1]);
+/// assert!(queue.is_empty());
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct PriorityConsumer<T, P = BoxComparator<T>> {
+    heap: BinaryHeap<PriorityEntry<T, P>>,
+    comparator: Arc<P>,
+}
+
+/// A buffered value ordered by the comparator of its PriorityConsumer
+///
+/// Every entry shares the consumer's comparator through an `Arc`, so the
+/// entries are `Send` whenever `T` and the comparator are `Send + Sync`.
+struct PriorityEntry<T, P> {
+    value: T,
+    comparator: Arc<P>,
+}
+
+impl<T, P: Comparator<T>> PartialEq for PriorityEntry<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T, P: Comparator<T>> Eq for PriorityEntry<T, P> {}
+
+impl<T, P: Comparator<T>> PartialOrd for PriorityEntry<T, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, P: Comparator<T>> Ord for PriorityEntry<T, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.comparator.compare(&self.value, &other.value)
+    }
+}
+
+impl<T, P> PriorityConsumer<T, P>
+where
+    P: Comparator<T>,
+{
+    /// Creates an empty PriorityConsumer
+    ///
+    /// # Parameters
+    ///
+    /// * `comparator` - The comparator defining the priority; greater values
+    ///   are released first. Usually a `BoxComparator<T>`, or an
+    ///   `ArcComparator<T>` when the consumer must be `Send`
+    ///
+    /// # Return Value
+    ///
+    /// Returns a new `PriorityConsumer<T, P>` with an empty buffer
+    pub fn new(comparator: P) -> Self {
+        PriorityConsumer {
+            heap: BinaryHeap::new(),
+            comparator: Arc::new(comparator),
+        }
+    }
+
+    /// Releases every buffered value in priority order
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The consumer receiving the buffered values
+    ///
+    /// # Return Value
+    ///
+    /// Returns the number of values released
+    pub fn drain_all<C>(&mut self, consumer: C) -> usize
+    where
+        C: Consumer<T>,
+    {
+        self.flush_top_n(self.heap.len(), consumer)
+    }
+
+    /// Releases at most `n` of the highest-priority buffered values
+    ///
+    /// The remaining values stay buffered.
+    ///
+    /// # Parameters
+    ///
+    /// * `n` - The maximum number of values to release
+    /// * `consumer` - The consumer receiving the released values
+    ///
+    /// # Return Value
+    ///
+    /// Returns the number of values released, which is less than `n` when
+    /// fewer values were buffered
+    pub fn flush_top_n<C>(&mut self, n: usize, mut consumer: C) -> usize
+    where
+        C: Consumer<T>,
+    {
+        let mut released = 0;
+        while released < n {
+            match self.heap.pop() {
+                Some(entry) => consumer.accept(&entry.value),
+                None => break,
+            }
+            released += 1;
+        }
+        released
+    }
+
+    /// Returns the number of buffered values
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns whether no value is buffered
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+}
+
+impl<T, P> Consumer<T> for PriorityConsumer<T, P>
+where
+    T: Clone,
+    P: Comparator<T>,
+{
+    fn accept(&mut self, value: &T) {
+        self.heap.push(PriorityEntry {
+            value: value.clone(),
+            comparator: Arc::clone(&self.comparator),
+        });
+    }
+}
+
+impl<T, P> fmt::Debug for PriorityConsumer<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PriorityConsumer")
+            .field("len", &self.heap.len())
+            .finish()
+    }
+}
@@ -21,6 +21,10 @@
 //! - **`RcReadonlyBiConsumer<T, U>`**: Rc-based single-threaded shared
 //!   ownership
 //!
+//! Each implementation provides `when` to build a conditional readonly
+//! bi-consumer, which can be completed into if-then-else logic with
+//! `or_else`.
+//!
 //! # Design Philosophy
 //!
 //! ReadonlyBiConsumer uses `Fn(&T, &U)` semantics: neither modifies its
@@ -37,6 +41,9 @@ use std::fmt;
 use std::rc::Rc;
 use std::sync::Arc;
 
+use crate::bi_predicate::{ArcBiPredicate, BiPredicate, BoxBiPredicate, RcBiPredicate};
+use crate::readonly_consumer::{ArcReadonlyConsumer, BoxReadonlyConsumer, RcReadonlyConsumer};
+
 // ==========================================================================
 // Type Aliases
 // ==========================================================================
@@ -504,6 +511,126 @@ where
             second.accept(t, u);
         })
     }
+
+    /// Binds the first argument, producing a consumer of the second
+    ///
+    /// `first` is moved into the returned consumer and passed by reference
+    /// on every call, so it is never cloned. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - The value passed as first argument on every call
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxReadonlyConsumer<U>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxReadonlyBiConsumer, ReadonlyConsumer};
+    ///
+    /// let print = BoxReadonlyBiConsumer::new(|prefix: &String, msg: &&str| {
+    ///     println!("{}{}", prefix, msg);
+    /// });
+    /// let info = print.bind_first("[INFO] ".to_string());
+    /// info.accept(&"ready");
+    /// ```
+    pub fn bind_first(self, first: T) -> BoxReadonlyConsumer<U> {
+        let function = self.function;
+        BoxReadonlyConsumer::new(move |u| function(&first, u))
+    }
+
+    /// Binds the second argument, producing a consumer of the first
+    ///
+    /// `second` is moved into the returned consumer and passed by reference
+    /// on every call, so it is never cloned. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `second` - The value passed as second argument on every call
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxReadonlyConsumer<T>`
+    pub fn bind_second(self, second: U) -> BoxReadonlyConsumer<T> {
+        let function = self.function;
+        BoxReadonlyConsumer::new(move |t| function(t, &second))
+    }
+
+    /// Swaps the argument order
+    ///
+    /// Returns a readonly bi-consumer accepting `(&U, &T)` that delegates to this
+    /// readonly bi-consumer with the arguments flipped back. The name is kept.
+    /// Consumes self.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxReadonlyBiConsumer<U, T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ReadonlyBiConsumer, BoxReadonlyBiConsumer};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let log = Arc::new(Mutex::new(Vec::new()));
+    /// let l = log.clone();
+    /// let put = BoxReadonlyBiConsumer::new(move |key: &&str, value: &i32| {
+    ///     l.lock().unwrap().push(format!("{}={}", key, value));
+    /// });
+    /// let swapped = put.swap();
+    /// swapped.accept(&1, &"a");
+    /// assert_eq!(*log.lock().unwrap(), vec!["a=1"]);
+    /// ```
+    pub fn swap(self) -> BoxReadonlyBiConsumer<U, T> {
+        let function = self.function;
+        BoxReadonlyBiConsumer {
+            function: Box::new(move |u: &U, t: &T| function(t, u)),
+            name: self.name,
+        }
+    }
+
+    /// Creates a conditional readonly bi-consumer
+    ///
+    /// Returns a readonly bi-consumer that only executes when a predicate is
+    /// satisfied. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - The condition to check. Can be:
+    ///   - A closure: `|x: &T, y: &U| -> bool`
+    ///   - A function pointer: `fn(&T, &U) -> bool`
+    ///   - A `BoxBiPredicate<T, U>`, `RcBiPredicate<T, U>` or
+    ///     `ArcBiPredicate<T, U>`
+    ///   - Any type implementing `BiPredicate<T, U>`
+    ///
+    /// # Returns
+    ///
+    /// Returns `BoxConditionalReadonlyBiConsumer<T, U>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxReadonlyBiConsumer, ReadonlyBiConsumer};
+    ///
+    /// let consumer = BoxReadonlyBiConsumer::new(|x: &i32, y: &i32| {
+    ///     println!("sum: {}", x + y);
+    /// })
+    /// .when(|x: &i32, y: &i32| *x > 0 && *y > 0)
+    /// .or_else(|x: &i32, y: &i32| println!("skipped: {} {}", x, y));
+    /// consumer.accept(&1, &2);
+    /// consumer.accept(&-1, &2);
+    /// ```
+    pub fn when<P>(self, predicate: P) -> BoxConditionalReadonlyBiConsumer<T, U>
+    where
+        P: BiPredicate<T, U> + 'static,
+    {
+        BoxConditionalReadonlyBiConsumer {
+            consumer: self,
+            predicate: predicate.into_box(),
+        }
+    }
 }
 
 impl<T, U> ReadonlyBiConsumer<T, U> for BoxReadonlyBiConsumer<T, U> {
@@ -717,6 +844,87 @@ where
             name: None,
         }
     }
+
+    /// Binds the first argument, producing a consumer of the second
+    ///
+    /// The returned consumer shares this bi-consumer's function. `first` is
+    /// moved into it and passed by reference on every call, so it is never
+    /// cloned. Borrows &self, does not consume the original bi-consumer.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - The value passed as first argument on every call
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `ArcReadonlyConsumer<U>`
+    pub fn bind_first(&self, first: T) -> ArcReadonlyConsumer<U> {
+        let function = Arc::clone(&self.function);
+        ArcReadonlyConsumer::new(move |u: &U| function(&first, u))
+    }
+
+    /// Binds the second argument, producing a consumer of the first
+    ///
+    /// The returned consumer shares this bi-consumer's function. `second` is
+    /// moved into it and passed by reference on every call, so it is never
+    /// cloned. Borrows &self, does not consume the original bi-consumer.
+    ///
+    /// # Parameters
+    ///
+    /// * `second` - The value passed as second argument on every call
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `ArcReadonlyConsumer<T>`
+    pub fn bind_second(&self, second: U) -> ArcReadonlyConsumer<T> {
+        let function = Arc::clone(&self.function);
+        ArcReadonlyConsumer::new(move |t: &T| function(t, &second))
+    }
+
+    /// Swaps the argument order
+    ///
+    /// Returns a readonly bi-consumer accepting `(&U, &T)` that shares this
+    /// readonly bi-consumer's function and calls it with the arguments flipped
+    /// back. The name is kept. Borrows &self, does not consume the
+    /// original readonly bi-consumer.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `ArcReadonlyBiConsumer<U, T>`
+    pub fn swap(&self) -> ArcReadonlyBiConsumer<U, T> {
+        let function = Arc::clone(&self.function);
+        ArcReadonlyBiConsumer {
+            function: Arc::new(move |u: &U, t: &T| function(t, u)),
+            name: self.name.clone(),
+        }
+    }
+
+    /// Creates a conditional readonly bi-consumer
+    ///
+    /// Returns a readonly bi-consumer that only executes when a predicate is
+    /// satisfied. Borrows &self.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - The condition to check. Can be:
+    ///   - A closure: `|x: &T, y: &U| -> bool`
+    ///   - A function pointer: `fn(&T, &U) -> bool`
+    ///   - A `BoxBiPredicate<T, U>`, `RcBiPredicate<T, U>` or
+    ///     `ArcBiPredicate<T, U>`
+    ///   - Any type implementing `BiPredicate<T, U>`
+    ///
+    /// # Returns
+    ///
+    /// Returns `ArcConditionalReadonlyBiConsumer<T, U>`
+    pub fn when<P>(&self, predicate: P) -> ArcConditionalReadonlyBiConsumer<T, U>
+    where
+        P: BiPredicate<T, U> + Send + Sync + 'static,
+    {
+        ArcConditionalReadonlyBiConsumer {
+            consumer: self.clone(),
+            predicate: predicate.into_arc(),
+        }
+    }
 }
 
 impl<T, U> ReadonlyBiConsumer<T, U> for ArcReadonlyBiConsumer<T, U> {
@@ -984,6 +1192,87 @@ where
             name: None,
         }
     }
+
+    /// Binds the first argument, producing a consumer of the second
+    ///
+    /// The returned consumer shares this bi-consumer's function. `first` is
+    /// moved into it and passed by reference on every call, so it is never
+    /// cloned. Borrows &self, does not consume the original bi-consumer.
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - The value passed as first argument on every call
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `RcReadonlyConsumer<U>`
+    pub fn bind_first(&self, first: T) -> RcReadonlyConsumer<U> {
+        let function = Rc::clone(&self.function);
+        RcReadonlyConsumer::new(move |u: &U| function(&first, u))
+    }
+
+    /// Binds the second argument, producing a consumer of the first
+    ///
+    /// The returned consumer shares this bi-consumer's function. `second` is
+    /// moved into it and passed by reference on every call, so it is never
+    /// cloned. Borrows &self, does not consume the original bi-consumer.
+    ///
+    /// # Parameters
+    ///
+    /// * `second` - The value passed as second argument on every call
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `RcReadonlyConsumer<T>`
+    pub fn bind_second(&self, second: U) -> RcReadonlyConsumer<T> {
+        let function = Rc::clone(&self.function);
+        RcReadonlyConsumer::new(move |t: &T| function(t, &second))
+    }
+
+    /// Swaps the argument order
+    ///
+    /// Returns a readonly bi-consumer accepting `(&U, &T)` that shares this
+    /// readonly bi-consumer's function and calls it with the arguments flipped
+    /// back. The name is kept. Borrows &self, does not consume the
+    /// original readonly bi-consumer.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `RcReadonlyBiConsumer<U, T>`
+    pub fn swap(&self) -> RcReadonlyBiConsumer<U, T> {
+        let function = Rc::clone(&self.function);
+        RcReadonlyBiConsumer {
+            function: Rc::new(move |u: &U, t: &T| function(t, u)),
+            name: self.name.clone(),
+        }
+    }
+
+    /// Creates a conditional readonly bi-consumer
+    ///
+    /// Returns a readonly bi-consumer that only executes when a predicate is
+    /// satisfied. Borrows &self.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - The condition to check. Can be:
+    ///   - A closure: `|x: &T, y: &U| -> bool`
+    ///   - A function pointer: `fn(&T, &U) -> bool`
+    ///   - A `BoxBiPredicate<T, U>`, `RcBiPredicate<T, U>` or
+    ///     `ArcBiPredicate<T, U>`
+    ///   - Any type implementing `BiPredicate<T, U>`
+    ///
+    /// # Returns
+    ///
+    /// Returns `RcConditionalReadonlyBiConsumer<T, U>`
+    pub fn when<P>(&self, predicate: P) -> RcConditionalReadonlyBiConsumer<T, U>
+    where
+        P: BiPredicate<T, U> + 'static,
+    {
+        RcConditionalReadonlyBiConsumer {
+            consumer: self.clone(),
+            predicate: predicate.into_rc(),
+        }
+    }
 }
 
 impl<T, U> ReadonlyBiConsumer<T, U> for RcReadonlyBiConsumer<T, U> {
@@ -1256,3 +1545,457 @@ pub trait FnReadonlyBiConsumerOps<T, U>: Fn(&T, &U) + Sized {
 
 /// Implements FnReadonlyBiConsumerOps for all closure types
 impl<T, U, F> FnReadonlyBiConsumerOps<T, U> for F where F: Fn(&T, &U) {}
+
+// =======================================================================
+// 7. BoxConditionalReadonlyBiConsumer - Box-based Conditional Consumer
+// =======================================================================
+
+/// BoxConditionalReadonlyBiConsumer struct
+///
+/// A conditional readonly bi-consumer that only executes when a predicate is
+/// satisfied. Uses `BoxReadonlyBiConsumer` and `BoxBiPredicate` for single
+/// ownership semantics.
+///
+/// This type is typically created by calling `BoxReadonlyBiConsumer::when()`
+/// and is designed to work with the `or_else()` method to create
+/// if-then-else logic.
+///
+/// # Features
+///
+/// - **Single Ownership**: Not cloneable, consumes `self` on use
+/// - **Conditional Execution**: Only consumes when predicate returns `true`
+/// - **Chainable**: Can add `or_else` branch to create if-then-else logic
+/// - **Implements ReadonlyBiConsumer**: Can be used anywhere a
+///   `ReadonlyBiConsumer` is expected
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BoxReadonlyBiConsumer, ReadonlyBiConsumer};
+///
+/// let conditional = BoxReadonlyBiConsumer::new(|x: &i32, y: &i32| {
+///     println!("{} + {} = {}", x, y, x + y);
+/// })
+/// .when(|x: &i32, y: &i32| *x > 0 && *y > 0);
+///
+/// conditional.accept(&5, &3); // Executed
+/// conditional.accept(&-5, &3); // Not executed
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct BoxConditionalReadonlyBiConsumer<T, U> {
+    consumer: BoxReadonlyBiConsumer<T, U>,
+    predicate: BoxBiPredicate<T, U>,
+}
+
+impl<T, U> ReadonlyBiConsumer<T, U> for BoxConditionalReadonlyBiConsumer<T, U>
+where
+    T: 'static,
+    U: 'static,
+{
+    fn accept(&self, first: &T, second: &U) {
+        if self.predicate.test(first, second) {
+            self.consumer.accept(first, second);
+        }
+    }
+
+    fn into_box(self) -> BoxReadonlyBiConsumer<T, U> {
+        let pred = self.predicate;
+        let consumer = self.consumer;
+        BoxReadonlyBiConsumer::new(move |t, u| {
+            if pred.test(t, u) {
+                consumer.accept(t, u);
+            }
+        })
+    }
+
+    fn into_rc(self) -> RcReadonlyBiConsumer<T, U> {
+        let pred = self.predicate.into_rc();
+        let consumer = self.consumer.into_rc();
+        RcReadonlyBiConsumer::new(move |t, u| {
+            if pred.test(t, u) {
+                consumer.accept(t, u);
+            }
+        })
+    }
+
+    // do NOT override ReadonlyBiConsumer::into_arc() because
+    // BoxConditionalReadonlyBiConsumer is not Send + Sync and calling
+    // BoxConditionalReadonlyBiConsumer::into_arc() will cause a compile error
+
+    fn into_fn(self) -> impl Fn(&T, &U) {
+        let pred = self.predicate;
+        let consumer = self.consumer;
+        move |t: &T, u: &U| {
+            if pred.test(t, u) {
+                consumer.accept(t, u);
+            }
+        }
+    }
+
+    // do NOT override ReadonlyBiConsumer::to_xxx() because
+    // BoxConditionalReadonlyBiConsumer is not Clone and calling
+    // BoxConditionalReadonlyBiConsumer::to_xxx() will cause a compile error
+}
+
+impl<T, U> BoxConditionalReadonlyBiConsumer<T, U>
+where
+    T: 'static,
+    U: 'static,
+{
+    /// Adds an else branch
+    ///
+    /// Executes the original consumer when the condition is satisfied,
+    /// otherwise executes `else_consumer`. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `else_consumer` - The consumer for the else branch. Can be:
+    ///   - A closure: `|x: &T, y: &U|`
+    ///   - A `BoxReadonlyBiConsumer<T, U>`, `RcReadonlyBiConsumer<T, U>` or
+    ///     `ArcReadonlyBiConsumer<T, U>`
+    ///   - Any type implementing `ReadonlyBiConsumer<T, U>`
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `BoxReadonlyBiConsumer<T, U>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxReadonlyBiConsumer, ReadonlyBiConsumer};
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// let log = Rc::new(RefCell::new(Vec::new()));
+    /// let (l1, l2) = (log.clone(), log.clone());
+    /// let consumer = BoxReadonlyBiConsumer::new(move |x: &i32, y: &i32| {
+    ///     l1.borrow_mut().push(x + y);
+    /// })
+    /// .when(|x: &i32, y: &i32| *x > 0 && *y > 0)
+    /// .or_else(move |x: &i32, y: &i32| l2.borrow_mut().push(x * y));
+    ///
+    /// consumer.accept(&5, &3);
+    /// consumer.accept(&-5, &3);
+    /// assert_eq!(*log.borrow(), vec![8, -15]);
+    /// ```
+    pub fn or_else<C>(self, else_consumer: C) -> BoxReadonlyBiConsumer<T, U>
+    where
+        C: ReadonlyBiConsumer<T, U> + 'static,
+    {
+        let pred = self.predicate;
+        let then_cons = self.consumer;
+        BoxReadonlyBiConsumer::new(move |t, u| {
+            if pred.test(t, u) {
+                then_cons.accept(t, u);
+            } else {
+                else_consumer.accept(t, u);
+            }
+        })
+    }
+}
+
+// =======================================================================
+// 8. ArcConditionalReadonlyBiConsumer - Arc-based Conditional Consumer
+// =======================================================================
+
+/// ArcConditionalReadonlyBiConsumer struct
+///
+/// A thread-safe conditional readonly bi-consumer that only executes when a
+/// predicate is satisfied. Uses `ArcReadonlyBiConsumer` and `ArcBiPredicate`
+/// for shared ownership across threads.
+///
+/// This type is typically created by calling `ArcReadonlyBiConsumer::when()`
+/// and is designed to work with the `or_else()` method to create
+/// if-then-else logic.
+///
+/// # Features
+///
+/// - **Shared Ownership**: Cloneable via `Arc`, multiple owners allowed
+/// - **Thread-Safe**: Implements `Send + Sync`, safe for concurrent use
+/// - **Conditional Execution**: Only consumes when predicate returns `true`
+/// - **Chainable**: Can add `or_else` branch to create if-then-else logic
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{ArcReadonlyBiConsumer, ReadonlyBiConsumer};
+/// use std::thread;
+///
+/// let conditional = ArcReadonlyBiConsumer::new(|x: &i32, y: &i32| {
+///     println!("{} + {} = {}", x, y, x + y);
+/// })
+/// .when(|x: &i32, y: &i32| *x > 0 && *y > 0);
+///
+/// let clone = conditional.clone();
+/// thread::spawn(move || clone.accept(&5, &3)).join().unwrap();
+/// conditional.accept(&-5, &3);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct ArcConditionalReadonlyBiConsumer<T, U> {
+    consumer: ArcReadonlyBiConsumer<T, U>,
+    predicate: ArcBiPredicate<T, U>,
+}
+
+impl<T, U> ReadonlyBiConsumer<T, U> for ArcConditionalReadonlyBiConsumer<T, U>
+where
+    T: Send + Sync + 'static,
+    U: Send + Sync + 'static,
+{
+    fn accept(&self, first: &T, second: &U) {
+        if self.predicate.test(first, second) {
+            self.consumer.accept(first, second);
+        }
+    }
+
+    fn into_box(self) -> BoxReadonlyBiConsumer<T, U>
+    where
+        T: 'static,
+        U: 'static,
+    {
+        let pred = self.predicate;
+        let consumer = self.consumer;
+        BoxReadonlyBiConsumer::new(move |t, u| {
+            if pred.test(t, u) {
+                consumer.accept(t, u);
+            }
+        })
+    }
+
+    fn into_rc(self) -> RcReadonlyBiConsumer<T, U>
+    where
+        T: 'static,
+        U: 'static,
+    {
+        let pred = self.predicate;
+        let consumer = self.consumer;
+        RcReadonlyBiConsumer::new(move |t, u| {
+            if pred.test(t, u) {
+                consumer.accept(t, u);
+            }
+        })
+    }
+
+    fn into_arc(self) -> ArcReadonlyBiConsumer<T, U>
+    where
+        T: Send + Sync + 'static,
+        U: Send + Sync + 'static,
+    {
+        let pred = self.predicate;
+        let consumer = self.consumer;
+        ArcReadonlyBiConsumer::new(move |t, u| {
+            if pred.test(t, u) {
+                consumer.accept(t, u);
+            }
+        })
+    }
+
+    fn into_fn(self) -> impl Fn(&T, &U)
+    where
+        T: 'static,
+        U: 'static,
+    {
+        let pred = self.predicate;
+        let consumer = self.consumer;
+        move |t: &T, u: &U| {
+            if pred.test(t, u) {
+                consumer.accept(t, u);
+            }
+        }
+    }
+
+    // Use the default implementation of to_xxx() from ReadonlyBiConsumer
+}
+
+impl<T, U> ArcConditionalReadonlyBiConsumer<T, U>
+where
+    T: Send + Sync + 'static,
+    U: Send + Sync + 'static,
+{
+    /// Adds an else branch (thread-safe version)
+    ///
+    /// Executes the original consumer when the condition is satisfied,
+    /// otherwise executes `else_consumer`. Borrows &self.
+    ///
+    /// # Parameters
+    ///
+    /// * `else_consumer` - The consumer for the else branch. Must be
+    ///   `Send + Sync`, can be:
+    ///   - A closure: `|x: &T, y: &U|` (requires `Send + Sync`)
+    ///   - An `ArcReadonlyBiConsumer<T, U>`
+    ///   - Any type implementing `ReadonlyBiConsumer<T, U> + Send + Sync`
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `ArcReadonlyBiConsumer<T, U>`
+    pub fn or_else<C>(&self, else_consumer: C) -> ArcReadonlyBiConsumer<T, U>
+    where
+        C: ReadonlyBiConsumer<T, U> + Send + Sync + 'static,
+    {
+        let pred = self.predicate.clone();
+        let then_cons = self.consumer.clone();
+        ArcReadonlyBiConsumer::new(move |t: &T, u: &U| {
+            if pred.test(t, u) {
+                then_cons.accept(t, u);
+            } else {
+                else_consumer.accept(t, u);
+            }
+        })
+    }
+}
+
+impl<T, U> Clone for ArcConditionalReadonlyBiConsumer<T, U> {
+    /// Clones the conditional consumer
+    ///
+    /// Creates a new instance that shares the underlying consumer and
+    /// predicate with the original instance.
+    fn clone(&self) -> Self {
+        ArcConditionalReadonlyBiConsumer {
+            consumer: self.consumer.clone(),
+            predicate: self.predicate.clone(),
+        }
+    }
+}
+
+// =======================================================================
+// 9. RcConditionalReadonlyBiConsumer - Rc-based Conditional Consumer
+// =======================================================================
+
+/// RcConditionalReadonlyBiConsumer struct
+///
+/// A single-threaded conditional readonly bi-consumer that only executes
+/// when a predicate is satisfied. Uses `RcReadonlyBiConsumer` and
+/// `RcBiPredicate` for shared ownership within a single thread.
+///
+/// This type is typically created by calling `RcReadonlyBiConsumer::when()`
+/// and is designed to work with the `or_else()` method to create
+/// if-then-else logic.
+///
+/// # Features
+///
+/// - **Shared Ownership**: Cloneable via `Rc`, multiple owners allowed
+/// - **Single-Threaded**: Not thread-safe, cannot be sent across threads
+/// - **Conditional Execution**: Only consumes when predicate returns `true`
+/// - **Chainable**: Can add `or_else` branch to create if-then-else logic
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct RcConditionalReadonlyBiConsumer<T, U> {
+    consumer: RcReadonlyBiConsumer<T, U>,
+    predicate: RcBiPredicate<T, U>,
+}
+
+impl<T, U> ReadonlyBiConsumer<T, U> for RcConditionalReadonlyBiConsumer<T, U>
+where
+    T: 'static,
+    U: 'static,
+{
+    fn accept(&self, first: &T, second: &U) {
+        if self.predicate.test(first, second) {
+            self.consumer.accept(first, second);
+        }
+    }
+
+    fn into_box(self) -> BoxReadonlyBiConsumer<T, U>
+    where
+        T: 'static,
+        U: 'static,
+    {
+        let pred = self.predicate;
+        let consumer = self.consumer;
+        BoxReadonlyBiConsumer::new(move |t, u| {
+            if pred.test(t, u) {
+                consumer.accept(t, u);
+            }
+        })
+    }
+
+    fn into_rc(self) -> RcReadonlyBiConsumer<T, U>
+    where
+        T: 'static,
+        U: 'static,
+    {
+        let pred = self.predicate;
+        let consumer = self.consumer;
+        RcReadonlyBiConsumer::new(move |t, u| {
+            if pred.test(t, u) {
+                consumer.accept(t, u);
+            }
+        })
+    }
+
+    // do NOT override ReadonlyBiConsumer::into_arc() because
+    // RcConditionalReadonlyBiConsumer is not Send + Sync and calling
+    // RcConditionalReadonlyBiConsumer::into_arc() will cause a compile error
+
+    fn into_fn(self) -> impl Fn(&T, &U)
+    where
+        T: 'static,
+        U: 'static,
+    {
+        let pred = self.predicate;
+        let consumer = self.consumer;
+        move |t: &T, u: &U| {
+            if pred.test(t, u) {
+                consumer.accept(t, u);
+            }
+        }
+    }
+
+    // Use the default implementation of to_xxx() from ReadonlyBiConsumer
+}
+
+impl<T, U> RcConditionalReadonlyBiConsumer<T, U>
+where
+    T: 'static,
+    U: 'static,
+{
+    /// Adds an else branch (single-threaded shared version)
+    ///
+    /// Executes the original consumer when the condition is satisfied,
+    /// otherwise executes `else_consumer`. Borrows &self.
+    ///
+    /// # Parameters
+    ///
+    /// * `else_consumer` - The consumer for the else branch. Can be:
+    ///   - A closure: `|x: &T, y: &U|`
+    ///   - An `RcReadonlyBiConsumer<T, U>` or `BoxReadonlyBiConsumer<T, U>`
+    ///   - Any type implementing `ReadonlyBiConsumer<T, U>`
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `RcReadonlyBiConsumer<T, U>`
+    pub fn or_else<C>(&self, else_consumer: C) -> RcReadonlyBiConsumer<T, U>
+    where
+        C: ReadonlyBiConsumer<T, U> + 'static,
+    {
+        let pred = self.predicate.clone();
+        let then_cons = self.consumer.clone();
+        RcReadonlyBiConsumer::new(move |t: &T, u: &U| {
+            if pred.test(t, u) {
+                then_cons.accept(t, u);
+            } else {
+                else_consumer.accept(t, u);
+            }
+        })
+    }
+}
+
+impl<T, U> Clone for RcConditionalReadonlyBiConsumer<T, U> {
+    /// Clones the conditional consumer
+    ///
+    /// Creates a new instance that shares the underlying consumer and
+    /// predicate with the original instance.
+    fn clone(&self) -> Self {
+        RcConditionalReadonlyBiConsumer {
+            consumer: self.consumer.clone(),
+            predicate: self.predicate.clone(),
+        }
+    }
+}
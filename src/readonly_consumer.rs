@@ -36,6 +36,8 @@ use std::fmt;
 use std::rc::Rc;
 use std::sync::Arc;
 
+use crate::predicate::Predicate;
+
 // ============================================================================
 // 1. ReadonlyConsumer Trait - Unified ReadonlyConsumer Interface
 // ============================================================================
@@ -633,6 +635,54 @@ where
             name: None,
         }
     }
+
+    /// Creates a consumer that only runs when a predicate is satisfied
+    ///
+    /// Like `and_then`, the result is lock-free: both the consumer and the
+    /// predicate are `Fn + Send + Sync`, so concurrent calls never wait on
+    /// each other. Borrows &self; the name is kept.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - The condition to check. Can be a closure, an
+    ///   `ArcPredicate<T>` or any type implementing
+    ///   `Predicate<T> + Send + Sync`
+    ///
+    /// # Returns
+    ///
+    /// Returns a new conditional `ArcReadonlyConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ReadonlyConsumer, ArcReadonlyConsumer};
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// let hits = Arc::new(AtomicUsize::new(0));
+    /// let h = hits.clone();
+    /// let count = ArcReadonlyConsumer::new(move |_: &i32| {
+    ///     h.fetch_add(1, Ordering::Relaxed);
+    /// });
+    /// let positive = count.when(|x: &i32| *x > 0);
+    /// positive.accept(&5);
+    /// positive.accept(&-5);
+    /// assert_eq!(hits.load(Ordering::Relaxed), 1);
+    /// ```
+    pub fn when<P>(&self, predicate: P) -> ArcReadonlyConsumer<T>
+    where
+        P: Predicate<T> + Send + Sync + 'static,
+    {
+        let function = Arc::clone(&self.function);
+        ArcReadonlyConsumer {
+            function: Arc::new(move |t: &T| {
+                if predicate.test(t) {
+                    function(t);
+                }
+            }),
+            name: self.name.clone(),
+        }
+    }
 }
 
 impl<T> ReadonlyConsumer<T> for ArcReadonlyConsumer<T> {
@@ -118,9 +118,11 @@
 //! Haixing Hu
 
 use std::cell::RefCell;
+use std::collections::VecDeque;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 
+use crate::consumer::Consumer;
 use crate::mapper::Mapper;
 use crate::supplier_once::{BoxSupplierOnce, SupplierOnce};
 
@@ -499,6 +501,290 @@ where
         BoxSupplier::new(move || value.clone())
     }
 
+    /// Creates an infinite sequence generator.
+    ///
+    /// Returns a supplier that produces `initial` first, then each further
+    /// value by applying `update` to the previously produced value.
+    ///
+    /// The next value is computed when the current one is handed out, so
+    /// `update` is invoked once per `get()` call, one step ahead of the
+    /// returned value. No `Clone` bound is required on `T`.
+    ///
+    /// # Parameters
+    ///
+    /// * `initial` - The first value of the sequence
+    /// * `update` - Function computing the next value from the previous one
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxSupplier<T>` producing the generated sequence
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    ///
+    /// let mut powers = BoxSupplier::generate(1, |x: &i32| x * 2);
+    /// assert_eq!(powers.get(), 1);
+    /// assert_eq!(powers.get(), 2);
+    /// assert_eq!(powers.get(), 4);
+    /// assert_eq!(powers.get(), 8);
+    /// ```
+    pub fn generate<F>(initial: T, mut update: F) -> Self
+    where
+        F: FnMut(&T) -> T + 'static,
+    {
+        let mut next = initial;
+        BoxSupplier::new(move || {
+            let following = update(&next);
+            std::mem::replace(&mut next, following)
+        })
+    }
+
+    /// Creates a supplier cycling over several suppliers.
+    ///
+    /// Each call takes one value from the next supplier in turn, starting
+    /// over with the first one after the last.
+    ///
+    /// # Parameters
+    ///
+    /// * `suppliers` - The suppliers to cycle over
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxSupplier<T>` alternating between the given suppliers
+    ///
+    /// # Panics
+    ///
+    /// Panics if `suppliers` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    ///
+    /// let mut balanced = BoxSupplier::round_robin(vec![
+    ///     BoxSupplier::constant("a"),
+    ///     BoxSupplier::constant("b"),
+    /// ]);
+    /// assert_eq!(balanced.get(), "a");
+    /// assert_eq!(balanced.get(), "b");
+    /// assert_eq!(balanced.get(), "a");
+    /// ```
+    pub fn round_robin(suppliers: Vec<BoxSupplier<T>>) -> Self {
+        Self::weighted_round_robin(suppliers.into_iter().map(|s| (1, s)).collect())
+    }
+
+    /// Creates a supplier alternating between two suppliers.
+    ///
+    /// Odd-numbered calls (the first, third, ...) go to `a`, even-numbered
+    /// calls go to `b`. This is [`round_robin`](BoxSupplier::round_robin)
+    /// over exactly two suppliers; use `round_robin` to interleave more.
+    ///
+    /// # Parameters
+    ///
+    /// * `a` - The supplier serving the first, third, ... calls
+    /// * `b` - The supplier serving the second, fourth, ... calls
+    ///
+    /// # Returns
+    ///
+    /// A new interleaved `BoxSupplier<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    ///
+    /// let evens = BoxSupplier::generate(0, |x: &i32| x + 2);
+    /// let odds = BoxSupplier::generate(1, |x: &i32| x + 2);
+    /// let mut naturals = BoxSupplier::interleave(evens, odds);
+    /// let values: Vec<_> = (0..5).map(|_| naturals.get()).collect();
+    /// assert_eq!(values, vec![0, 1, 2, 3, 4]);
+    /// ```
+    pub fn interleave(a: BoxSupplier<T>, b: BoxSupplier<T>) -> Self {
+        Self::round_robin(vec![a, b])
+    }
+
+    /// Creates a supplier cycling over several suppliers with weights.
+    ///
+    /// Takes `weight` consecutive values from each supplier before moving
+    /// on to the next one, starting over with the first one after the last.
+    /// Suppliers with a weight of zero are never called.
+    ///
+    /// # Parameters
+    ///
+    /// * `suppliers` - The suppliers to cycle over, each with its weight
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxSupplier<T>` alternating between the given suppliers
+    ///
+    /// # Panics
+    ///
+    /// Panics if no supplier has a non-zero weight.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    ///
+    /// let mut balanced = BoxSupplier::weighted_round_robin(vec![
+    ///     (2, BoxSupplier::constant("a")),
+    ///     (1, BoxSupplier::constant("b")),
+    /// ]);
+    /// let values: Vec<_> = (0..6).map(|_| balanced.get()).collect();
+    /// assert_eq!(values, vec!["a", "a", "b", "a", "a", "b"]);
+    /// ```
+    pub fn weighted_round_robin(suppliers: Vec<(usize, BoxSupplier<T>)>) -> Self {
+        let mut suppliers: Vec<(usize, BoxSupplier<T>)> = suppliers
+            .into_iter()
+            .filter(|(weight, _)| *weight > 0)
+            .collect();
+        assert!(
+            !suppliers.is_empty(),
+            "round robin requires at least one supplier with a non-zero weight"
+        );
+        let mut index = 0;
+        let mut taken = 0;
+        BoxSupplier::new(move || {
+            let (weight, supplier) = &mut suppliers[index];
+            let value = supplier.get();
+            taken += 1;
+            if taken == *weight {
+                taken = 0;
+                index = (index + 1) % suppliers.len();
+            }
+            value
+        })
+    }
+
+    /// Creates a supplier whose underlying supplier is built on first use.
+    ///
+    /// `factory` is called on the first `get()` only; the supplier it
+    /// returns is kept and serves that call and every later one. Unlike
+    /// [`memoize`](BoxSupplier::memoize), which caches a single value, this
+    /// defers the construction of a whole supplier, so an expensive supplier
+    /// chain costs nothing until it is actually needed.
+    ///
+    /// # Parameters
+    ///
+    /// * `factory` - Builds the underlying supplier
+    ///
+    /// # Returns
+    ///
+    /// A new lazily initialized `BoxSupplier<T>`
+    ///
+    /// # Panics
+    ///
+    /// If `factory` panics, it has been consumed without producing a
+    /// supplier, so every later call panics as well, saying so
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    ///
+    /// let mut ids = BoxSupplier::lazy(|| {
+    ///     // e.g. read the last persisted id here
+    ///     let start = 1000;
+    ///     BoxSupplier::generate(start, |x: &i32| x + 1)
+    /// });
+    ///
+    /// assert_eq!(ids.get(), 1000);
+    /// assert_eq!(ids.get(), 1001);
+    /// ```
+    pub fn lazy<F>(factory: F) -> Self
+    where
+        F: FnOnce() -> BoxSupplier<T> + 'static,
+    {
+        let mut factory = Some(factory);
+        let mut inner: Option<BoxSupplier<T>> = None;
+        BoxSupplier::new(move || {
+            inner
+                .get_or_insert_with(|| {
+                    let factory = factory
+                        .take()
+                        .expect("lazy supplier factory panicked on an earlier call");
+                    factory()
+                })
+                .get()
+        })
+    }
+
+    /// Creates a supplier that produces a single value.
+    ///
+    /// `factory` is called on the first `get()`, and its value is moved
+    /// out rather than cached, so `T` need not be `Clone`. Use
+    /// [`BoxSupplierOnce`](crate::BoxSupplierOnce) if the one-shot
+    /// contract should be enforced at compile time instead.
+    ///
+    /// # Parameters
+    ///
+    /// * `factory` - Produces the value on the first call
+    ///
+    /// # Returns
+    ///
+    /// A new single-use `BoxSupplier<T>`
+    ///
+    /// # Panics
+    ///
+    /// The returned supplier panics on every call after the first
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    ///
+    /// let mut connection = BoxSupplier::once(|| String::from("db://primary"));
+    /// assert_eq!(connection.get(), "db://primary");
+    /// ```
+    pub fn once<F>(factory: F) -> Self
+    where
+        F: FnOnce() -> T + 'static,
+    {
+        let mut factory = Some(factory);
+        BoxSupplier::new(move || match factory.take() {
+            Some(factory) => factory(),
+            None => panic!("once supplier called more than once"),
+        })
+    }
+
+    /// Creates a supplier that produces a value once, then a default.
+    ///
+    /// `factory` is called on the first `get()`; every later call returns
+    /// a clone of `default`.
+    ///
+    /// # Parameters
+    ///
+    /// * `factory` - Produces the value on the first call
+    /// * `default` - The value returned by every later call
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxSupplier<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    ///
+    /// let mut greeting = BoxSupplier::once_or_default(|| "welcome", "welcome back");
+    /// assert_eq!(greeting.get(), "welcome");
+    /// assert_eq!(greeting.get(), "welcome back");
+    /// assert_eq!(greeting.get(), "welcome back");
+    /// ```
+    pub fn once_or_default<F>(factory: F, default: T) -> Self
+    where
+        T: Clone,
+        F: FnOnce() -> T + 'static,
+    {
+        let mut factory = Some(factory);
+        BoxSupplier::new(move || match factory.take() {
+            Some(factory) => factory(),
+            None => default.clone(),
+        })
+    }
+
     /// Maps the output using a transformation function.
     ///
     /// Consumes self and returns a new supplier that applies the
@@ -659,6 +945,144 @@ where
             }
         })
     }
+
+    /// Flattens the output of a vector-producing mapper.
+    ///
+    /// Returns a new supplier that passes each value of this supplier
+    /// to `mapper`, buffers the resulting `Vec<U>` and yields its
+    /// elements one by one. The next value is only pulled from this
+    /// supplier once the buffer is exhausted.
+    ///
+    /// # Parameters
+    ///
+    /// * `mapper` - The mapper expanding each value into zero or more
+    ///   elements. Can be a closure, a `BoxMapper<T, Vec<U>>` or any
+    ///   type implementing `Mapper<T, Vec<U>>`
+    ///
+    /// # Returns
+    ///
+    /// A new flattened `BoxSupplier<U>`
+    ///
+    /// # Note
+    ///
+    /// Empty vectors are skipped, so `get` keeps pulling values from
+    /// this supplier until the mapper produces a non-empty vector. A
+    /// mapper that never does so makes `get` loop forever.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    ///
+    /// let mut counter = 0;
+    /// let mut flat = BoxSupplier::new(move || {
+    ///     counter += 1;
+    ///     counter
+    /// }).flat_map_transformer(|n: i32| vec![n; n as usize]);
+    ///
+    /// assert_eq!(flat.get(), 1);
+    /// assert_eq!(flat.get(), 2);
+    /// assert_eq!(flat.get(), 2);
+    /// assert_eq!(flat.get(), 3);
+    /// ```
+    pub fn flat_map_transformer<U, F>(mut self, mut mapper: F) -> BoxSupplier<U>
+    where
+        F: Mapper<T, Vec<U>> + 'static,
+        U: 'static,
+    {
+        let mut buffer: VecDeque<U> = VecDeque::new();
+        BoxSupplier::new(move || loop {
+            if let Some(value) = buffer.pop_front() {
+                return value;
+            }
+            buffer.extend(mapper.apply(Supplier::get(&mut self)));
+        })
+    }
+
+    /// Runs a hook before the first value is produced.
+    ///
+    /// Returns a new supplier that calls `hook` exactly once, right
+    /// before the first call to `get` pulls a value from this
+    /// supplier. Later calls delegate directly to this supplier.
+    ///
+    /// # Parameters
+    ///
+    /// * `hook` - The one-time hook to run on the first `get`
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxSupplier<T>` running the hook on its first call
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    ///
+    /// let calls = Rc::new(Cell::new(0));
+    /// let c = calls.clone();
+    /// let mut supplier = BoxSupplier::new(|| 42)
+    ///     .on_first_get(move || c.set(c.get() + 1));
+    ///
+    /// assert_eq!(supplier.get(), 42);
+    /// assert_eq!(supplier.get(), 42);
+    /// assert_eq!(calls.get(), 1);
+    /// ```
+    pub fn on_first_get<F>(mut self, hook: F) -> BoxSupplier<T>
+    where
+        F: FnOnce() + 'static,
+    {
+        let mut hook = Some(hook);
+        BoxSupplier::new(move || {
+            if let Some(hook) = hook.take() {
+                hook();
+            }
+            Supplier::get(&mut self)
+        })
+    }
+
+    /// Passes every produced value to a consumer.
+    ///
+    /// Returns a new supplier that calls `hook` with a reference to
+    /// each value produced by this supplier before returning it. The
+    /// values themselves are not modified.
+    ///
+    /// # Parameters
+    ///
+    /// * `hook` - The consumer observing each value. Can be a closure,
+    ///   a `BoxConsumer<T>` or any type implementing `Consumer<T>`
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxSupplier<T>` that reports each value to `hook`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxConsumer, BoxSupplier, Supplier};
+    ///
+    /// let (log, values) = BoxConsumer::collect();
+    /// let mut counter = 0;
+    /// let mut supplier = BoxSupplier::new(move || {
+    ///     counter += 1;
+    ///     counter
+    /// }).on_each_get(log);
+    ///
+    /// assert_eq!(supplier.get(), 1);
+    /// assert_eq!(supplier.get(), 2);
+    /// assert_eq!(*values.lock().unwrap(), vec![1, 2]);
+    /// ```
+    pub fn on_each_get<C>(mut self, mut hook: C) -> BoxSupplier<T>
+    where
+        C: Consumer<T> + 'static,
+    {
+        BoxSupplier::new(move || {
+            let value = Supplier::get(&mut self);
+            hook.accept(&value);
+            value
+        })
+    }
 }
 
 impl<T> Supplier<T> for BoxSupplier<T> {
@@ -724,6 +1148,500 @@ where
     // bound is not satisfied.
 }
 
+// ==========================================================================
+// BoxSupplier Iterator Bridge
+// ==========================================================================
+
+/// Infinite iterator over the values produced by a `BoxSupplier`.
+///
+/// Created by the `IntoIterator` implementation of [`BoxSupplier`]. Each
+/// call to `next` calls `get` on the underlying supplier, so the iterator
+/// never ends on its own; combine it with adapters such as `take` or
+/// `take_while`.
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::BoxSupplier;
+///
+/// let mut counter = 0;
+/// let supplier = BoxSupplier::new(move || {
+///     counter += 1;
+///     counter
+/// });
+/// let first: Vec<i32> = supplier.into_iter().take(3).collect();
+/// assert_eq!(first, vec![1, 2, 3]);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct BoxSupplierIter<T> {
+    supplier: BoxSupplier<T>,
+}
+
+impl<T> Iterator for BoxSupplierIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        Some(Supplier::get(&mut self.supplier))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T> IntoIterator for BoxSupplier<T> {
+    type Item = T;
+    type IntoIter = BoxSupplierIter<T>;
+
+    /// Converts the supplier into an infinite iterator
+    ///
+    /// For suppliers of `Option<T>` that should stop at the first `None`,
+    /// use [`BoxSupplier::into_iter_until_none`] instead.
+    fn into_iter(self) -> BoxSupplierIter<T> {
+        BoxSupplierIter { supplier: self }
+    }
+}
+
+impl<T> BoxSupplier<Option<T>>
+where
+    T: 'static,
+{
+    /// Creates a sequence from an initial state and a step function.
+    ///
+    /// Each call to `get()` invokes `step` with mutable access to the state
+    /// and returns its result. Once `step` returns `None` the supplier is
+    /// exhausted: `step` is never called again and every later `get()`
+    /// returns `None`. This is the dual of folding a sequence into a value.
+    ///
+    /// # Parameters
+    ///
+    /// * `initial_state` - The initial state
+    /// * `step` - Function advancing the state and producing the next value,
+    ///   or `None` to end the sequence
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxSupplier<Option<T>>` producing the unfolded sequence
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    ///
+    /// let mut countdown = BoxSupplier::unfold(3, |n: &mut i32| {
+    ///     if *n == 0 {
+    ///         None
+    ///     } else {
+    ///         *n -= 1;
+    ///         Some(*n + 1)
+    ///     }
+    /// });
+    /// assert_eq!(countdown.get(), Some(3));
+    /// assert_eq!(countdown.get(), Some(2));
+    /// assert_eq!(countdown.get(), Some(1));
+    /// assert_eq!(countdown.get(), None);
+    /// assert_eq!(countdown.get(), None);
+    /// ```
+    pub fn unfold<S, F>(initial_state: S, mut step: F) -> Self
+    where
+        S: 'static,
+        F: FnMut(&mut S) -> Option<T> + 'static,
+    {
+        let mut state = Some(initial_state);
+        BoxSupplier::new(move || {
+            let value = step(state.as_mut()?);
+            if value.is_none() {
+                state = None;
+            }
+            value
+        })
+    }
+
+    /// Converts the supplier into a bounded iterator
+    ///
+    /// The returned iterator yields the contents of every `Some` produced by
+    /// the supplier and ends at the first `None`. The supplier is not called
+    /// again after it returned `None`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the supplied values
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::BoxSupplier;
+    ///
+    /// let mut remaining = 3;
+    /// let countdown = BoxSupplier::new(move || {
+    ///     if remaining == 0 {
+    ///         None
+    ///     } else {
+    ///         remaining -= 1;
+    ///         Some(remaining)
+    ///     }
+    /// });
+    /// let values: Vec<i32> = countdown.into_iter_until_none().collect();
+    /// assert_eq!(values, vec![2, 1, 0]);
+    /// ```
+    pub fn into_iter_until_none(self) -> impl Iterator<Item = T> {
+        let mut supplier = self;
+        std::iter::from_fn(move || Supplier::get(&mut supplier)).fuse()
+    }
+
+    /// Runs a hook when the supplier first returns `None`.
+    ///
+    /// Returns a new supplier that delegates to this supplier and calls
+    /// `hook` exactly once, the first time this supplier returns
+    /// `None`. The hook does not fire again if the supplier keeps
+    /// returning `None` or produces values again later.
+    ///
+    /// # Parameters
+    ///
+    /// * `hook` - The one-time hook to run on exhaustion
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxSupplier<Option<T>>` reporting its exhaustion
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    ///
+    /// let exhausted = Rc::new(Cell::new(false));
+    /// let e = exhausted.clone();
+    /// let mut supplier = BoxSupplier::from(vec![1].into_iter())
+    ///     .on_exhausted(move || e.set(true));
+    ///
+    /// assert_eq!(supplier.get(), Some(1));
+    /// assert!(!exhausted.get());
+    /// assert_eq!(supplier.get(), None);
+    /// assert!(exhausted.get());
+    /// ```
+    pub fn on_exhausted<F>(mut self, hook: F) -> BoxSupplier<Option<T>>
+    where
+        F: FnOnce() + 'static,
+    {
+        let mut hook = Some(hook);
+        BoxSupplier::new(move || {
+            let value = Supplier::get(&mut self);
+            if value.is_none() {
+                if let Some(hook) = hook.take() {
+                    hook();
+                }
+            }
+            value
+        })
+    }
+}
+
+impl<T, I> From<I> for BoxSupplier<Option<T>>
+where
+    I: Iterator<Item = T> + 'static,
+    T: 'static,
+{
+    /// Creates a supplier pulling values from an iterator
+    ///
+    /// Each call to `get` returns the next element of the iterator, and
+    /// `None` once the iterator is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    ///
+    /// let mut supplier = BoxSupplier::from(vec![1, 2].into_iter());
+    /// assert_eq!(supplier.get(), Some(1));
+    /// assert_eq!(supplier.get(), Some(2));
+    /// assert_eq!(supplier.get(), None);
+    /// ```
+    fn from(iter: I) -> Self {
+        let mut iter = iter;
+        BoxSupplier::new(move || iter.next())
+    }
+}
+
+impl<T> BoxSupplier<T>
+where
+    T: Ord + 'static,
+{
+    /// Creates a supplier producing the larger of two suppliers' values.
+    ///
+    /// Each call to `get()` calls `a` and then `b` once and returns the
+    /// greater value. If both are equal, the value from `b` is returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `a` - The first supplier
+    /// * `b` - The second supplier
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxSupplier<T>` producing the maximum of each pair
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    ///
+    /// let a = BoxSupplier::generate(1, |x: &i32| x + 2);
+    /// let b = BoxSupplier::constant(2);
+    /// let mut max = BoxSupplier::max_of_two(a, b);
+    /// assert_eq!(max.get(), 2);
+    /// assert_eq!(max.get(), 3);
+    /// ```
+    pub fn max_of_two(mut a: BoxSupplier<T>, mut b: BoxSupplier<T>) -> Self {
+        BoxSupplier::new(move || std::cmp::max(a.get(), b.get()))
+    }
+
+    /// Creates a supplier producing the smaller of two suppliers' values.
+    ///
+    /// Each call to `get()` calls `a` and then `b` once and returns the
+    /// lesser value. If both are equal, the value from `a` is returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `a` - The first supplier
+    /// * `b` - The second supplier
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxSupplier<T>` producing the minimum of each pair
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    ///
+    /// let a = BoxSupplier::generate(1, |x: &i32| x + 2);
+    /// let b = BoxSupplier::constant(2);
+    /// let mut min = BoxSupplier::min_of_two(a, b);
+    /// assert_eq!(min.get(), 1);
+    /// assert_eq!(min.get(), 2);
+    /// ```
+    pub fn min_of_two(mut a: BoxSupplier<T>, mut b: BoxSupplier<T>) -> Self {
+        BoxSupplier::new(move || std::cmp::min(a.get(), b.get()))
+    }
+}
+
+impl BoxSupplier<f64> {
+    /// Creates a supplier producing the mean of two suppliers' values.
+    ///
+    /// Each call to `get()` calls `a` and then `b` once and returns their
+    /// arithmetic mean.
+    ///
+    /// # Parameters
+    ///
+    /// * `a` - The first supplier
+    /// * `b` - The second supplier
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxSupplier<f64>` producing the average of each pair
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    ///
+    /// let a = BoxSupplier::constant(1.0);
+    /// let b = BoxSupplier::generate(2.0, |x: &f64| x * 2.0);
+    /// let mut average = BoxSupplier::average_of_two(a, b);
+    /// assert_eq!(average.get(), 1.5);
+    /// assert_eq!(average.get(), 2.5);
+    /// ```
+    pub fn average_of_two(mut a: BoxSupplier<f64>, mut b: BoxSupplier<f64>) -> Self {
+        BoxSupplier::new(move || (a.get() + b.get()) / 2.0)
+    }
+}
+
+// ==========================================================================
+// BoxSupplier Random Generation (`rand` feature)
+// ==========================================================================
+
+#[cfg(feature = "rand")]
+impl<T> BoxSupplier<T>
+where
+    T: 'static,
+{
+    /// Creates a supplier drawing values from a distribution.
+    ///
+    /// Each call to `get()` locks `rng` and samples one value from
+    /// `distribution`. Sharing a seeded generator between several suppliers
+    /// keeps a whole simulation reproducible. Available with the `rand`
+    /// feature.
+    ///
+    /// # Parameters
+    ///
+    /// * `rng` - The shared random number generator
+    /// * `distribution` - The distribution to sample from
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxSupplier<T>` producing random values
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    /// use rand::distributions::Bernoulli;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let rng = Arc::new(Mutex::new(StdRng::seed_from_u64(7)));
+    /// let mut always = BoxSupplier::random_from(rng, Bernoulli::new(1.0).unwrap());
+    /// assert!(always.get());
+    /// ```
+    pub fn random_from<R, D>(rng: Arc<Mutex<R>>, distribution: D) -> Self
+    where
+        R: rand::Rng + 'static,
+        D: rand::distributions::Distribution<T> + 'static,
+    {
+        BoxSupplier::new(move || {
+            distribution.sample(
+                &mut *rng
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner),
+            )
+        })
+    }
+
+    /// Creates a supplier producing values of the standard distribution.
+    ///
+    /// Uses `rand::distributions::Standard`, which yields uniformly
+    /// distributed integers, floats in `[0, 1)` and fair booleans. Available
+    /// with the `rand` feature.
+    ///
+    /// # Parameters
+    ///
+    /// * `rng` - The shared random number generator
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxSupplier<T>` producing random values
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let rng = Arc::new(Mutex::new(StdRng::seed_from_u64(7)));
+    /// let mut unit = BoxSupplier::<f64>::random(rng);
+    /// let x = unit.get();
+    /// assert!((0.0..1.0).contains(&x));
+    /// ```
+    pub fn random<R>(rng: Arc<Mutex<R>>) -> Self
+    where
+        R: rand::Rng + 'static,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        BoxSupplier::random_from(rng, rand::distributions::Standard)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T> BoxSupplier<T>
+where
+    T: rand::distributions::uniform::SampleUniform + 'static,
+{
+    /// Creates a supplier producing uniform values in `[low, high)`.
+    ///
+    /// Available with the `rand` feature.
+    ///
+    /// # Parameters
+    ///
+    /// * `rng` - The shared random number generator
+    /// * `low` - The inclusive lower bound
+    /// * `high` - The exclusive upper bound
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxSupplier<T>` producing values in the range
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low >= high`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let rng = Arc::new(Mutex::new(StdRng::seed_from_u64(7)));
+    /// let mut dice = BoxSupplier::random_range(rng, 1, 7);
+    /// let roll = dice.get();
+    /// assert!((1..7).contains(&roll));
+    /// ```
+    pub fn random_range<R>(rng: Arc<Mutex<R>>, low: T, high: T) -> Self
+    where
+        R: rand::Rng + 'static,
+        T::Sampler: 'static,
+    {
+        BoxSupplier::random_from(rng, rand::distributions::Uniform::new(low, high))
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T> BoxSupplier<T>
+where
+    T: Clone + 'static,
+{
+    /// Creates a supplier picking uniformly among the given options.
+    ///
+    /// Each call to `get()` returns a clone of a randomly chosen element.
+    /// Available with the `rand` feature.
+    ///
+    /// # Parameters
+    ///
+    /// * `rng` - The shared random number generator
+    /// * `options` - The values to choose from
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxSupplier<T>` producing one of the options on each call
+    ///
+    /// # Panics
+    ///
+    /// Panics if `options` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxSupplier, Supplier};
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let rng = Arc::new(Mutex::new(StdRng::seed_from_u64(7)));
+    /// let mut color = BoxSupplier::random_choice(rng, vec!["red", "green", "blue"]);
+    /// assert!(["red", "green", "blue"].contains(&color.get()));
+    /// ```
+    pub fn random_choice<R>(rng: Arc<Mutex<R>>, options: Vec<T>) -> Self
+    where
+        R: rand::Rng + 'static,
+    {
+        assert!(
+            !options.is_empty(),
+            "random_choice requires at least one option"
+        );
+        let index = BoxSupplier::random_range(rng, 0, options.len());
+        index.map(move |i: usize| options[i].clone())
+    }
+}
+
 // ==========================================================================
 // ArcSupplier - Thread-safe Shared Ownership Implementation
 // ==========================================================================
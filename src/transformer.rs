@@ -23,10 +23,21 @@
 //!
 //! Hu Haixing
 
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::num::NonZeroUsize;
 use std::rc::Rc;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
 
+use crate::consumer::Consumer;
+use crate::mapper::{ArcMapper, BoxMapper};
+use crate::memoize::LruMemoizedMapper;
+use crate::mutator::BoxMutator;
 use crate::predicate::{ArcPredicate, BoxPredicate, Predicate, RcPredicate};
+use crate::readonly_consumer::ReadonlyConsumer;
+use crate::supplier::Supplier;
 use crate::transformer_once::BoxTransformerOnce;
 
 // ============================================================================
@@ -499,88 +510,910 @@ where
         BoxTransformer::new(move |x: S| self_fn(before.apply(x)))
     }
 
+    /// Fan-out composition - applies self and other to the same input
+    ///
+    /// Creates a new transformer that feeds the input to both this
+    /// transformer and `other`, returning both results as a tuple. Because
+    /// transformers consume their input, the input is cloned once so that
+    /// each transformer receives its own copy. Consumes self.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `S` - The output type of the other transformer
+    /// * `F` - The type of the other transformer (must implement
+    ///   Transformer<T, S>)
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The transformer applied alongside self. Can be:
+    ///   - A closure: `|x: T| -> S`
+    ///   - A `BoxTransformer<T, S>`, `RcTransformer<T, S>` or
+    ///     `ArcTransformer<T, S>`
+    ///   - Any type implementing `Transformer<T, S>`
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxTransformer<T, (R, S)>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    ///
+    /// let len = BoxTransformer::new(|s: String| s.len());
+    /// let upper = BoxTransformer::new(|s: String| s.to_uppercase());
+    /// let both = len.fanout(upper);
+    /// assert_eq!(both.apply("abc".to_string()), (3, "ABC".to_string()));
+    /// ```
+    pub fn fanout<S, F>(self, other: F) -> BoxTransformer<T, (R, S)>
+    where
+        T: Clone,
+        S: 'static,
+        F: Transformer<T, S> + 'static,
+    {
+        let self_fn = self.function;
+        BoxTransformer::new(move |x: T| (self_fn(x.clone()), other.apply(x)))
+    }
+
+    /// Product composition - transforms both components of a pair
+    ///
+    /// Creates a new transformer over pairs that applies this transformer to
+    /// the first component and `other` to the second component. This is the
+    /// complement of [`BoxTransformer::fanout`]: fan-out shares one input,
+    /// product keeps two independent inputs side by side. Consumes self.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `U` - The type of the second component
+    /// * `S` - The output type of the other transformer
+    /// * `F` - The type of the other transformer (must implement
+    ///   Transformer<U, S>)
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The transformer applied to the second component
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxTransformer<(T, U), (R, S)>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    ///
+    /// let double = BoxTransformer::new(|x: i32| x * 2);
+    /// let pair = double.product(|s: String| s.to_uppercase());
+    /// assert_eq!(pair.apply((21, "a".to_string())), (42, "A".to_string()));
+    /// ```
+    pub fn product<U, S, F>(self, other: F) -> BoxTransformer<(T, U), (R, S)>
+    where
+        U: 'static,
+        S: 'static,
+        F: Transformer<U, S> + 'static,
+    {
+        let self_fn = self.function;
+        BoxTransformer::new(move |(t, u): (T, U)| (self_fn(t), other.apply(u)))
+    }
+
+    /// Inspection - observes the output without changing it
+    ///
+    /// Creates a new transformer that applies this transformer, passes a
+    /// reference to the result to `consumer`, then returns the result
+    /// unchanged. Useful for logging or collecting intermediate values of a
+    /// chain. The consumer may be stateful; it is kept in a `RefCell` since
+    /// transformers are called through `&self`. Consumes self.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `C` - The type of the consumer (must implement `Consumer<R>`)
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The consumer observing each output. Can be:
+    ///   - A closure: `|x: &R|`
+    ///   - A `BoxConsumer<R>`, `RcConsumer<R>` or `ArcConsumer<R>`
+    ///   - Any type implementing `Consumer<R>`
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxTransformer<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// let log = Rc::new(RefCell::new(Vec::new()));
+    /// let l = log.clone();
+    /// let pipeline = BoxTransformer::new(|x: i32| x + 1)
+    ///     .inspect(move |x: &i32| l.borrow_mut().push(*x))
+    ///     .and_then(|x: i32| x * 2);
+    /// assert_eq!(pipeline.apply(20), 42);
+    /// assert_eq!(*log.borrow(), vec![21]);
+    /// ```
+    pub fn inspect<C>(self, consumer: C) -> BoxTransformer<T, R>
+    where
+        C: Consumer<R> + 'static,
+    {
+        let self_fn = self.function;
+        let consumer = RefCell::new(consumer);
+        BoxTransformer::new(move |x: T| {
+            let result = self_fn(x);
+            consumer.borrow_mut().accept(&result);
+            result
+        })
+    }
+
+    /// Timing - measures how long each call takes
+    ///
+    /// Creates a new mapper that applies this transformer and passes the
+    /// elapsed wall-clock time of each call to `on_call`. The consumer may
+    /// log the durations, bucket them into a histogram or raise alerts above
+    /// a threshold; the result of the transformer is returned unchanged. The
+    /// time spent in `on_call` itself is not included. Consumes self.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `C` - The type of the consumer (must implement `Consumer<Duration>`)
+    ///
+    /// # Parameters
+    ///
+    /// * `on_call` - The consumer receiving the duration of each call
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxMapper<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Mapper};
+    /// use std::time::Duration;
+    ///
+    /// let mut total = Duration::ZERO;
+    /// let mut calls = 0;
+    /// let mut timed = BoxTransformer::new(|x: u64| x * 2).with_timing(move |d: &Duration| {
+    ///     total += *d;
+    ///     calls += 1;
+    /// });
+    /// assert_eq!(timed.apply(21), 42);
+    /// ```
+    pub fn with_timing<C>(self, on_call: C) -> BoxMapper<T, R>
+    where
+        C: Consumer<Duration> + 'static,
+    {
+        let self_fn = self.function;
+        let mut on_call = on_call;
+        BoxMapper::new(move |x: T| {
+            let start = Instant::now();
+            let result = self_fn(x);
+            on_call.accept(&start.elapsed());
+            result
+        })
+    }
+
+    /// Validation - checks every output against a predicate
+    ///
+    /// Creates a new transformer that applies this transformer and tests the
+    /// result with `predicate`. Valid results are returned as `Ok`; invalid
+    /// ones are first passed by reference to `on_invalid` and then returned
+    /// as `Err`, so the caller still owns the rejected value. The consumer
+    /// may be stateful; it is kept in a `RefCell` since transformers are
+    /// called through `&self`. Consumes self.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `P` - The type of the predicate (must implement `Predicate<R>`)
+    /// * `C` - The type of the consumer (must implement `Consumer<R>`)
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - The condition a valid output must satisfy
+    /// * `on_invalid` - The consumer receiving each invalid output
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxTransformer<T, Result<R, R>>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    ///
+    /// let rejected = Rc::new(Cell::new(0));
+    /// let r = rejected.clone();
+    /// let checked = BoxTransformer::new(|x: i32| x - 10)
+    ///     .validate(|x: &i32| *x >= 0, move |_: &i32| r.set(r.get() + 1));
+    /// assert_eq!(checked.apply(15), Ok(5));
+    /// assert_eq!(checked.apply(3), Err(-7));
+    /// assert_eq!(rejected.get(), 1);
+    /// ```
+    pub fn validate<P, C>(self, predicate: P, on_invalid: C) -> BoxTransformer<T, Result<R, R>>
+    where
+        P: Predicate<R> + 'static,
+        C: Consumer<R> + 'static,
+    {
+        let self_fn = self.function;
+        let on_invalid = RefCell::new(on_invalid);
+        BoxTransformer::new(move |x: T| {
+            let result = self_fn(x);
+            if predicate.test(&result) {
+                Ok(result)
+            } else {
+                on_invalid.borrow_mut().accept(&result);
+                Err(result)
+            }
+        })
+    }
+
+    /// Post-condition filter - keeps only outputs satisfying a predicate
+    ///
+    /// Creates a new transformer that applies this transformer and tests
+    /// the result by reference with `predicate`, returning `Some(result)`
+    /// if it passes and `None` otherwise. Combine with `lift_option` to
+    /// chain further steps that skip rejected values. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - The condition an output must satisfy to be kept
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxTransformer<T, Option<R>>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    ///
+    /// let half = BoxTransformer::new(|x: i32| x / 2).filter_result(|r: &i32| *r > 0);
+    /// assert_eq!(half.apply(10), Some(5));
+    /// assert_eq!(half.apply(1), None);
+    /// ```
+    pub fn filter_result<P>(self, predicate: P) -> BoxTransformer<T, Option<R>>
+    where
+        P: Predicate<R> + 'static,
+    {
+        let self_fn = self.function;
+        BoxTransformer::new(move |x: T| Some(self_fn(x)).filter(|r| predicate.test(r)))
+    }
+
+    /// Pre-condition - checks every input before the call
+    ///
+    /// Creates a new transformer that tests each input with `predicate`
+    /// before applying this transformer. A violated pre-condition is a bug
+    /// in the caller, so it panics with the message built by `violation`
+    /// instead of returning an error. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - The condition every input must satisfy
+    /// * `violation` - Builds the panic message from the rejected input
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxTransformer<T, R>`
+    ///
+    /// # Panics
+    ///
+    /// The returned transformer panics when an input fails `predicate`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    ///
+    /// let sqrt = BoxTransformer::new(|x: f64| x.sqrt())
+    ///     .require(|x: &f64| *x >= 0.0, |x: &f64| format!("negative input: {}", x));
+    /// assert_eq!(sqrt.apply(9.0), 3.0);
+    /// ```
+    pub fn require<P, F>(self, predicate: P, violation: F) -> BoxTransformer<T, R>
+    where
+        P: Predicate<T> + 'static,
+        F: Fn(&T) -> String + 'static,
+    {
+        let self_fn = self.function;
+        BoxTransformer::new(move |x: T| {
+            if !predicate.test(&x) {
+                panic!("pre-condition violated: {}", violation(&x));
+            }
+            self_fn(x)
+        })
+    }
+
+    /// Post-condition - checks every output after the call
+    ///
+    /// Creates a new transformer that tests each result of this transformer
+    /// with `predicate`. A violated post-condition is a bug in the
+    /// transformer, so it panics with the message built by `violation`.
+    /// Use [`validate`](BoxTransformer::validate) to handle invalid outputs
+    /// without panicking. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - The condition every output must satisfy
+    /// * `violation` - Builds the panic message from the rejected output
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxTransformer<T, R>`
+    ///
+    /// # Panics
+    ///
+    /// The returned transformer panics when an output fails `predicate`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    ///
+    /// let abs = BoxTransformer::new(|x: i32| x.abs())
+    ///     .ensure(|r: &i32| *r >= 0, |r: &i32| format!("negative result: {}", r));
+    /// assert_eq!(abs.apply(-4), 4);
+    /// ```
+    pub fn ensure<P, F>(self, predicate: P, violation: F) -> BoxTransformer<T, R>
+    where
+        P: Predicate<R> + 'static,
+        F: Fn(&R) -> String + 'static,
+    {
+        let self_fn = self.function;
+        BoxTransformer::new(move |x: T| {
+            let result = self_fn(x);
+            if !predicate.test(&result) {
+                panic!("post-condition violated: {}", violation(&result));
+            }
+            result
+        })
+    }
+
     /// Creates a conditional transformer
     ///
-    /// Returns a transformer that only executes when a predicate is satisfied.
-    /// You must call `or_else()` to provide an alternative transformer for when
-    /// the condition is not satisfied.
+    /// Returns a transformer that only executes when a predicate is satisfied.
+    /// You must call `or_else()` to provide an alternative transformer for when
+    /// the condition is not satisfied.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - The condition to check. **Note: This parameter is passed
+    ///   by value and will transfer ownership.** If you need to preserve the
+    ///   original predicate, clone it first (if it implements `Clone`). Can be:
+    ///   - A closure: `|x: &T| -> bool`
+    ///   - A function pointer: `fn(&T) -> bool`
+    ///   - A `BoxPredicate<T>`
+    ///   - An `RcPredicate<T>`
+    ///   - An `ArcPredicate<T>`
+    ///   - Any type implementing `Predicate<T>`
+    ///
+    /// # Returns
+    ///
+    /// Returns `BoxConditionalTransformer<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ## Basic usage with or_else
+    ///
+    /// ```rust
+    /// use prism3_function::{Transformer, BoxTransformer};
+    ///
+    /// let double = BoxTransformer::new(|x: i32| x * 2);
+    /// let identity = BoxTransformer::<i32, i32>::identity();
+    /// let conditional = double.when(|x: &i32| *x > 0).or_else(identity);
+    ///
+    /// assert_eq!(conditional.apply(5), 10);
+    /// assert_eq!(conditional.apply(-5), -5); // identity
+    /// ```
+    ///
+    /// ## Preserving predicate with clone
+    ///
+    /// ```rust
+    /// use prism3_function::{Transformer, BoxTransformer, BoxPredicate};
+    ///
+    /// let double = BoxTransformer::new(|x: i32| x * 2);
+    /// let is_positive = BoxPredicate::new(|x: &i32| *x > 0);
+    ///
+    /// // Clone to preserve original predicate
+    /// let conditional = double.when(is_positive.clone())
+    ///     .or_else(BoxTransformer::identity());
+    ///
+    /// assert_eq!(conditional.apply(5), 10);
+    ///
+    /// // Original predicate still usable
+    /// assert!(is_positive.test(&3));
+    /// ```
+    pub fn when<P>(self, predicate: P) -> BoxConditionalTransformer<T, R>
+    where
+        P: Predicate<T> + 'static,
+    {
+        BoxConditionalTransformer {
+            transformer: self,
+            predicate: predicate.into_box(),
+        }
+    }
+
+    /// Lifts this transformer over `Option`
+    ///
+    /// Returns a transformer mapping `Some(x)` to `Some(f(x))` and passing
+    /// `None` through without calling this transformer. Consumes self.
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxTransformer<Option<T>, Option<R>>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    ///
+    /// let len = BoxTransformer::new(|s: String| s.len()).lift_option();
+    /// assert_eq!(len.apply(Some("abc".to_string())), Some(3));
+    /// assert_eq!(len.apply(None), None);
+    /// ```
+    pub fn lift_option(self) -> BoxTransformer<Option<T>, Option<R>> {
+        let function = self.function;
+        BoxTransformer::new(move |x: Option<T>| x.map(&function))
+    }
+
+    /// Lifts this transformer over the `Ok` side of `Result`
+    ///
+    /// Returns a transformer mapping `Ok(x)` to `Ok(f(x))` and passing
+    /// `Err(e)` through without calling this transformer. Consumes self.
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxTransformer<Result<T, E>, Result<R, E>>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    ///
+    /// let double = BoxTransformer::new(|x: i32| x * 2).lift_ok::<String>();
+    /// assert_eq!(double.apply(Ok(21)), Ok(42));
+    /// assert_eq!(double.apply(Err("bad".to_string())), Err("bad".to_string()));
+    /// ```
+    pub fn lift_ok<E>(self) -> BoxTransformer<Result<T, E>, Result<R, E>>
+    where
+        E: 'static,
+    {
+        let function = self.function;
+        BoxTransformer::new(move |x: Result<T, E>| x.map(&function))
+    }
+}
+
+impl<T, R> BoxTransformer<T, R>
+where
+    T: 'static,
+    R: Clone + 'static,
+{
+    /// Creates a constant transformer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    ///
+    /// let constant = BoxTransformer::constant("hello");
+    /// assert_eq!(constant.apply(123), "hello");
+    /// ```
+    pub fn constant(value: R) -> BoxTransformer<T, R> {
+        BoxTransformer::new(move |_| value.clone())
+    }
+
+    /// Validation with fallback - replaces invalid outputs by a default
+    ///
+    /// Creates a new transformer that applies this transformer and returns
+    /// the result if it satisfies `predicate`, or a clone of `default`
+    /// otherwise. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - The condition a valid output must satisfy
+    /// * `default` - The value returned for invalid outputs
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxTransformer<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    ///
+    /// let parse = BoxTransformer::new(|s: &str| s.parse::<i32>().unwrap_or(-1))
+    ///     .validate_or_default(|x: &i32| *x >= 0, 0);
+    /// assert_eq!(parse.apply("42"), 42);
+    /// assert_eq!(parse.apply("oops"), 0);
+    /// ```
+    pub fn validate_or_default<P>(self, predicate: P, default: R) -> BoxTransformer<T, R>
+    where
+        P: Predicate<R> + 'static,
+    {
+        let self_fn = self.function;
+        BoxTransformer::new(move |x: T| {
+            let result = self_fn(x);
+            if predicate.test(&result) {
+                result
+            } else {
+                default.clone()
+            }
+        })
+    }
+
+    /// Creates a caching mapper whose entries expire after a time-to-live
+    ///
+    /// Returns a `BoxMapper` that remembers the result computed for each
+    /// input together with the `Instant` at which it was computed. A cached
+    /// result is returned while it is younger than `ttl`; once it has
+    /// expired, the transformer is called again and the entry is refreshed.
+    /// Expired entries are swept out whenever the cache has doubled in size
+    /// since the previous sweep, so keys that are never requested again do
+    /// not accumulate and the sweep costs amortized O(1) per call. Consumes
+    /// self.
+    ///
+    /// # Parameters
+    ///
+    /// * `ttl` - How long a computed result stays valid
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxMapper<T, R>` caching the results of this transformer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Mapper};
+    /// use std::time::Duration;
+    ///
+    /// let square = BoxTransformer::new(|x: i32| x * x);
+    /// let mut cached = square.cache_with_ttl(Duration::from_secs(60));
+    /// assert_eq!(cached.apply(4), 16); // computed
+    /// assert_eq!(cached.apply(4), 16); // served from the cache
+    /// ```
+    pub fn cache_with_ttl(self, ttl: Duration) -> BoxMapper<T, R>
+    where
+        T: Hash + Eq + Clone,
+    {
+        self.cache_with_ttl_and_clock(ttl, Instant::now)
+    }
+
+    /// Creates a caching mapper whose entries expire after a time-to-live,
+    /// reading the current time from `clock`
+    ///
+    /// Same as [`cache_with_ttl`](Self::cache_with_ttl), which passes
+    /// `Instant::now`. Supplying a manually advanced clock makes expiry
+    /// testable without sleeping. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `ttl` - How long a computed result stays valid
+    /// * `clock` - Returns the current time; must never go backwards
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxMapper<T, R>` caching the results of this transformer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Mapper};
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let now = Rc::new(Cell::new(Instant::now()));
+    /// let clock = Rc::clone(&now);
+    /// let square = BoxTransformer::new(|x: i32| x * x);
+    /// let mut cached =
+    ///     square.cache_with_ttl_and_clock(Duration::from_secs(60), move || clock.get());
+    /// assert_eq!(cached.apply(4), 16); // computed
+    /// now.set(now.get() + Duration::from_secs(61));
+    /// assert_eq!(cached.apply(4), 16); // expired, computed again
+    /// ```
+    pub fn cache_with_ttl_and_clock<C>(self, ttl: Duration, clock: C) -> BoxMapper<T, R>
+    where
+        T: Hash + Eq + Clone,
+        C: Fn() -> Instant + 'static,
+    {
+        let self_fn = self.function;
+        let mut cache: HashMap<T, (R, Instant)> = HashMap::new();
+        let mut sweep_at = 1;
+        BoxMapper::new(move |x: T| {
+            let now = clock();
+            if let Some((value, at)) = cache.get(&x) {
+                if now.duration_since(*at) < ttl {
+                    return value.clone();
+                }
+            }
+            let value = self_fn(x.clone());
+            if cache.len() >= sweep_at {
+                cache.retain(|_, (_, at)| now.duration_since(*at) < ttl);
+                sweep_at = (cache.len() * 2).max(1);
+            }
+            cache.insert(x, (value.clone(), now));
+            value
+        })
+    }
+
+    /// Memoizes results in a bounded LRU cache
+    ///
+    /// Returns a mapper that caches the results of this transformer keyed by
+    /// input. At most `capacity` results are kept; when the cache is full,
+    /// the least recently used result is evicted. The returned
+    /// [`LruMemoizedMapper`] exposes hit and miss counters. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `capacity` - The maximum number of cached results
+    ///
+    /// # Returns
+    ///
+    /// A new `LruMemoizedMapper<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Mapper};
+    /// use std::num::NonZeroUsize;
+    ///
+    /// let len = BoxTransformer::new(|s: String| s.len());
+    /// let mut cached = len.memoize_lru(NonZeroUsize::new(16).unwrap());
+    /// assert_eq!(cached.apply("abc".to_string()), 3);
+    /// assert_eq!(cached.apply("abc".to_string()), 3);
+    /// assert_eq!(cached.cache_hits(), 1);
+    /// assert_eq!(cached.cache_misses(), 1);
+    /// ```
+    pub fn memoize_lru(self, capacity: NonZeroUsize) -> LruMemoizedMapper<T, R>
+    where
+        T: Hash + Eq + Clone,
+    {
+        LruMemoizedMapper::new(self, capacity)
+    }
+}
+
+impl<K, V> BoxTransformer<(K, V), K>
+where
+    K: 'static,
+    V: 'static,
+{
+    /// Creates a projection onto the first component of a pair
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    ///
+    /// let key = BoxTransformer::<(&str, i32), &str>::first();
+    /// assert_eq!(key.apply(("a", 1)), "a");
+    /// ```
+    pub fn first() -> Self {
+        BoxTransformer::new(|(k, _)| k)
+    }
+}
+
+impl<K, V> BoxTransformer<(K, V), V>
+where
+    K: 'static,
+    V: 'static,
+{
+    /// Creates a projection onto the second component of a pair
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    ///
+    /// let value = BoxTransformer::<(&str, i32), i32>::second();
+    /// assert_eq!(value.apply(("a", 1)), 1);
+    /// ```
+    pub fn second() -> Self {
+        BoxTransformer::new(|(_, v)| v)
+    }
+}
+
+impl<K, K2, V> BoxTransformer<(K, V), (K2, V)>
+where
+    K: 'static,
+    K2: 'static,
+    V: 'static,
+{
+    /// Lifts a transformer to act on the first component of a pair
+    ///
+    /// The second component is passed through unchanged.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - The type of the transformer (must implement
+    ///   Transformer<K, K2>)
+    ///
+    /// # Parameters
+    ///
+    /// * `transformer` - The transformer applied to the first component
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxTransformer<(K, V), (K2, V)>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    ///
+    /// let upper_key = BoxTransformer::map_first(|k: &str| k.to_uppercase());
+    /// assert_eq!(upper_key.apply(("id", 7)), ("ID".to_string(), 7));
+    /// ```
+    pub fn map_first<F>(transformer: F) -> Self
+    where
+        F: Transformer<K, K2> + 'static,
+    {
+        BoxTransformer::new(move |(k, v)| (transformer.apply(k), v))
+    }
+}
+
+impl<K, V, V2> BoxTransformer<(K, V), (K, V2)>
+where
+    K: 'static,
+    V: 'static,
+    V2: 'static,
+{
+    /// Lifts a transformer to act on the second component of a pair
+    ///
+    /// The first component is passed through unchanged.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `F` - The type of the transformer (must implement
+    ///   Transformer<V, V2>)
+    ///
+    /// # Parameters
+    ///
+    /// * `transformer` - The transformer applied to the second component
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxTransformer<(K, V), (K, V2)>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    ///
+    /// let parse = BoxTransformer::map_second(|v: &str| v.parse::<i32>().unwrap());
+    /// let total = parse.and_then(|(k, v): (&str, i32)| format!("{}={}", k, v * 2));
+    /// assert_eq!(total.apply(("x", "21")), "x=42");
+    /// ```
+    pub fn map_second<F>(transformer: F) -> Self
+    where
+        F: Transformer<V, V2> + 'static,
+    {
+        BoxTransformer::new(move |(k, v)| (k, transformer.apply(v)))
+    }
+}
+
+impl<T, R, E> BoxTransformer<T, Result<R, E>>
+where
+    T: Clone + 'static,
+    R: 'static,
+    E: 'static,
+{
+    /// Retries a fallible transformation
+    ///
+    /// Returns a mapper that calls this transformer with a clone of the
+    /// input until it returns `Ok` or `max_attempts` calls have been made,
+    /// in which case the last `Err` is returned. A `max_attempts` of 0 is
+    /// treated as 1. Consumes self.
     ///
     /// # Parameters
     ///
-    /// * `predicate` - The condition to check. **Note: This parameter is passed
-    ///   by value and will transfer ownership.** If you need to preserve the
-    ///   original predicate, clone it first (if it implements `Clone`). Can be:
-    ///   - A closure: `|x: &T| -> bool`
-    ///   - A function pointer: `fn(&T) -> bool`
-    ///   - A `BoxPredicate<T>`
-    ///   - An `RcPredicate<T>`
-    ///   - An `ArcPredicate<T>`
-    ///   - Any type implementing `Predicate<T>`
+    /// * `max_attempts` - The maximum number of calls per input
     ///
     /// # Returns
     ///
-    /// Returns `BoxConditionalTransformer<T, R>`
+    /// A new `BoxMapper<T, Result<R, E>>`
     ///
     /// # Examples
     ///
-    /// ## Basic usage with or_else
-    ///
     /// ```rust
-    /// use prism3_function::{Transformer, BoxTransformer};
+    /// use prism3_function::{BoxTransformer, Mapper};
+    /// use std::cell::Cell;
+    ///
+    /// let calls = Cell::new(0);
+    /// let flaky = BoxTransformer::new(move |x: i32| {
+    ///     calls.set(calls.get() + 1);
+    ///     if calls.get() < 3 { Err("busy") } else { Ok(x * 2) }
+    /// });
+    /// let mut reliable = flaky.retry(5);
+    /// assert_eq!(reliable.apply(21), Ok(42));
+    /// ```
+    pub fn retry(self, max_attempts: usize) -> BoxMapper<T, Result<R, E>> {
+        self.retry_with_backoff(max_attempts, 0)
+    }
+
+    /// Retries a fallible transformation with exponential backoff
     ///
-    /// let double = BoxTransformer::new(|x: i32| x * 2);
-    /// let identity = BoxTransformer::<i32, i32>::identity();
-    /// let conditional = double.when(|x: &i32| *x > 0).or_else(identity);
+    /// Like [`retry`](BoxTransformer::retry), but blocks the current thread
+    /// with `std::thread::sleep` between attempts. The first pause lasts
+    /// `delay_ms` milliseconds and each following pause is twice as long.
+    /// No pause follows the last attempt. Consumes self.
     ///
-    /// assert_eq!(conditional.apply(5), 10);
-    /// assert_eq!(conditional.apply(-5), -5); // identity
-    /// ```
+    /// # Parameters
     ///
-    /// ## Preserving predicate with clone
+    /// * `attempts` - The maximum number of calls per input
+    /// * `delay_ms` - The pause before the second attempt, in milliseconds
     ///
-    /// ```rust
-    /// use prism3_function::{Transformer, BoxTransformer, BoxPredicate};
+    /// # Returns
     ///
-    /// let double = BoxTransformer::new(|x: i32| x * 2);
-    /// let is_positive = BoxPredicate::new(|x: &i32| *x > 0);
+    /// A new `BoxMapper<T, Result<R, E>>`
     ///
-    /// // Clone to preserve original predicate
-    /// let conditional = double.when(is_positive.clone())
-    ///     .or_else(BoxTransformer::identity());
+    /// # Examples
     ///
-    /// assert_eq!(conditional.apply(5), 10);
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Mapper};
     ///
-    /// // Original predicate still usable
-    /// assert!(is_positive.test(&3));
+    /// let parse = BoxTransformer::new(|s: String| s.parse::<i32>());
+    /// let mut retried = parse.retry_with_backoff(3, 10);
+    /// assert!(retried.apply("x".to_string()).is_err()); // sleeps 10 + 20 ms
     /// ```
-    pub fn when<P>(self, predicate: P) -> BoxConditionalTransformer<T, R>
-    where
-        P: Predicate<T> + 'static,
-    {
-        BoxConditionalTransformer {
-            transformer: self,
-            predicate: predicate.into_box(),
-        }
+    pub fn retry_with_backoff(self, attempts: usize, delay_ms: u64) -> BoxMapper<T, Result<R, E>> {
+        let self_fn = self.function;
+        let attempts = attempts.max(1);
+        BoxMapper::new(move |x: T| {
+            let mut delay = delay_ms;
+            for _ in 1..attempts {
+                match self_fn(x.clone()) {
+                    Ok(value) => return Ok(value),
+                    Err(_) if delay > 0 => {
+                        std::thread::sleep(Duration::from_millis(delay));
+                        delay = delay.saturating_mul(2);
+                    }
+                    Err(_) => {}
+                }
+            }
+            self_fn(x)
+        })
     }
 }
 
-impl<T, R> BoxTransformer<T, R>
+impl<T> BoxTransformer<T, T>
 where
-    T: 'static,
-    R: Clone + 'static,
+    T: Default + 'static,
 {
-    /// Creates a constant transformer
+    /// Converts this unary operator into a mutator
+    ///
+    /// Each `mutate` takes the value out of the reference with
+    /// `std::mem::take`, applies this operator and writes the result back,
+    /// so `T` need not be `Clone`.
+    ///
+    /// # Panic Safety
+    ///
+    /// While the operator runs, the referenced value is `T::default()`. If
+    /// the operator panics, the original value is lost and the reference is
+    /// left holding the default.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `BoxMutator<T>` replacing each value with its image
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use prism3_function::{BoxTransformer, Transformer};
+    /// use prism3_function::{BoxUnaryOperator, Mutator};
     ///
-    /// let constant = BoxTransformer::constant("hello");
-    /// assert_eq!(constant.apply(123), "hello");
+    /// let trim = BoxUnaryOperator::new(|s: String| s.trim().to_string());
+    /// let mut mutator = trim.into_mutator();
+    /// let mut value = "  padded  ".to_string();
+    /// mutator.mutate(&mut value);
+    /// assert_eq!(value, "padded");
     /// ```
-    pub fn constant(value: R) -> BoxTransformer<T, R> {
-        BoxTransformer::new(move |_| value.clone())
+    pub fn into_mutator(self) -> BoxMutator<T> {
+        let function = self.function;
+        BoxMutator::new(move |value: &mut T| {
+            *value = function(std::mem::take(value));
+        })
     }
 }
 
@@ -771,6 +1604,103 @@ where
             }
         })
     }
+
+    /// Adds an else branch returning `R::default()`
+    ///
+    /// Shortcut for `or_else(|_| R::default())`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `BoxTransformer<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    ///
+    /// let positive_only = BoxTransformer::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_default();
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), 0);
+    /// ```
+    pub fn or_else_default(self) -> BoxTransformer<T, R>
+    where
+        R: Default,
+    {
+        self.or_else(|_: T| R::default())
+    }
+
+    /// Adds an else branch returning a clone of `value`
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The fallback value, cloned on every else-branch call
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `BoxTransformer<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    ///
+    /// let positive_only = BoxTransformer::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_value(-1);
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), -1);
+    /// ```
+    pub fn or_else_value(self, value: R) -> BoxTransformer<T, R>
+    where
+        R: Clone,
+    {
+        self.or_else(move |_: T| value.clone())
+    }
+
+    /// Adds an else branch producing its result from a supplier
+    ///
+    /// The supplier is only called when the predicate rejects the input,
+    /// so it can lazily build expensive fallbacks.
+    ///
+    /// # Parameters
+    ///
+    /// * `supplier` - The supplier of fallback values, can be:
+    ///   - Closure: `|| -> R`
+    ///   - `BoxSupplier<R>`, `RcSupplier<R>`, `ArcSupplier<R>`
+    ///   - Any type implementing `Supplier<R>`
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `BoxTransformer<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, Transformer};
+    ///
+    /// let mut next_id = 100;
+    /// let positive_only = BoxTransformer::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_supply(move || {
+    ///         next_id += 1;
+    ///         next_id
+    ///     });
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), 101);
+    /// assert_eq!(positive_only.apply(-5), 102);
+    /// ```
+    pub fn or_else_supply<S>(self, supplier: S) -> BoxTransformer<T, R>
+    where
+        S: Supplier<R> + 'static,
+    {
+        let supplier = RefCell::new(supplier);
+        self.or_else(move |_: T| supplier.borrow_mut().get())
+    }
 }
 
 // ============================================================================
@@ -790,6 +1720,12 @@ where
 ///   input)
 /// - **Thread Safety**: Thread-safe (`Send + Sync` required)
 /// - **Clonable**: Cheap cloning via `Arc::clone`
+/// - **Lock-Free**: No interior mutability, concurrent calls never contend
+///   on a lock; compositions such as `and_then` and `compose` stay
+///   lock-free
+///
+/// Use [`ArcMapper`] instead when the wrapped function needs to mutate
+/// captured state; it serializes calls through a `Mutex`.
 ///
 /// # Author
 ///
@@ -987,6 +1923,133 @@ where
         }
     }
 
+    /// Fan-out composition - applies self and other to the same input
+    ///
+    /// Creates a new thread-safe transformer that feeds a clone of the input
+    /// to this transformer and the input itself to `other`, returning both
+    /// results as a tuple. Uses &self, so original transformer remains
+    /// usable.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The transformer applied alongside self (must be
+    ///   `Send + Sync`)
+    ///
+    /// # Returns
+    ///
+    /// A new `ArcTransformer<T, (R, S)>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcTransformer, Transformer};
+    ///
+    /// let double = ArcTransformer::new(|x: i32| x * 2);
+    /// let both = double.fanout(|x: i32| x.to_string());
+    /// assert_eq!(both.apply(21), (42, "21".to_string()));
+    /// assert_eq!(double.apply(1), 2);
+    /// ```
+    pub fn fanout<S, F>(&self, other: F) -> ArcTransformer<T, (R, S)>
+    where
+        T: Clone,
+        S: 'static,
+        F: Transformer<T, S> + Send + Sync + 'static,
+    {
+        let self_fn = self.function.clone();
+        ArcTransformer {
+            function: Arc::new(move |x: T| (self_fn(x.clone()), other.apply(x))),
+        }
+    }
+
+    /// Product composition - transforms both components of a pair
+    ///
+    /// Creates a new thread-safe transformer over pairs that applies this
+    /// transformer to the first component and `other` to the second
+    /// component. Uses &self, so original transformer remains usable.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The transformer applied to the second component (must be
+    ///   `Send + Sync`)
+    ///
+    /// # Returns
+    ///
+    /// A new `ArcTransformer<(T, U), (R, S)>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcTransformer, Transformer};
+    ///
+    /// let double = ArcTransformer::new(|x: i32| x * 2);
+    /// let pair = double.product(|b: bool| !b);
+    /// assert_eq!(pair.apply((21, true)), (42, false));
+    /// assert_eq!(double.apply(1), 2);
+    /// ```
+    pub fn product<U, S, F>(&self, other: F) -> ArcTransformer<(T, U), (R, S)>
+    where
+        U: Send + Sync + 'static,
+        S: 'static,
+        F: Transformer<U, S> + Send + Sync + 'static,
+    {
+        let self_fn = self.function.clone();
+        ArcTransformer {
+            function: Arc::new(move |(t, u): (T, U)| (self_fn(t), other.apply(u))),
+        }
+    }
+
+    /// Inspection - observes the output without changing it
+    ///
+    /// Creates a new thread-safe transformer that applies this transformer,
+    /// passes a reference to the result to `consumer`, then returns the
+    /// result unchanged. The consumer is called through `&self` without any
+    /// lock, so the result stays lock-free like every other `ArcTransformer`
+    /// composition; an observer that needs mutable state must synchronize
+    /// it itself, e.g. with an atomic or its own `Mutex`. Uses &self, so
+    /// original transformer remains usable.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `C` - The type of the consumer (must implement
+    ///   `ReadonlyConsumer<R>` and be `Send + Sync`)
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The consumer observing each output. Can be a closure
+    ///   `|x: &R|`, an `ArcReadonlyConsumer<R>` or any type implementing
+    ///   `ReadonlyConsumer<R>`
+    ///
+    /// # Returns
+    ///
+    /// A new `ArcTransformer<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcTransformer, Transformer};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let log = Arc::new(Mutex::new(Vec::new()));
+    /// let l = log.clone();
+    /// let double = ArcTransformer::new(|x: i32| x * 2);
+    /// let traced = double.inspect(move |x: &i32| l.lock().unwrap().push(*x));
+    /// assert_eq!(traced.apply(21), 42);
+    /// assert_eq!(*log.lock().unwrap(), vec![42]);
+    /// ```
+    pub fn inspect<C>(&self, consumer: C) -> ArcTransformer<T, R>
+    where
+        C: ReadonlyConsumer<R> + Send + Sync + 'static,
+    {
+        let self_fn = self.function.clone();
+        ArcTransformer {
+            function: Arc::new(move |x: T| {
+                let result = self_fn(x);
+                consumer.accept(&result);
+                result
+            }),
+        }
+    }
+
     /// Creates a conditional transformer (thread-safe version)
     ///
     /// Returns a transformer that only executes when a predicate is satisfied.
@@ -1050,6 +2113,59 @@ where
             predicate: predicate.into_arc(),
         }
     }
+
+    /// Lifts this transformer over `Option`
+    ///
+    /// Returns a transformer mapping `Some(x)` to `Some(f(x))` and passing
+    /// `None` through without calling this transformer. Borrows &self; the
+    /// function is shared with the original.
+    ///
+    /// # Returns
+    ///
+    /// A new `ArcTransformer<Option<T>, Option<R>>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcTransformer, Transformer};
+    ///
+    /// let len = ArcTransformer::new(|s: String| s.len());
+    /// let lifted = len.lift_option();
+    /// assert_eq!(lifted.apply(Some("abc".to_string())), Some(3));
+    /// assert_eq!(lifted.apply(None), None);
+    /// assert_eq!(len.apply("de".to_string()), 2);
+    /// ```
+    pub fn lift_option(&self) -> ArcTransformer<Option<T>, Option<R>> {
+        let function = Arc::clone(&self.function);
+        ArcTransformer::new(move |x: Option<T>| x.map(&*function))
+    }
+
+    /// Lifts this transformer over the `Ok` side of `Result`
+    ///
+    /// Returns a transformer mapping `Ok(x)` to `Ok(f(x))` and passing
+    /// `Err(e)` through without calling this transformer. Borrows &self;
+    /// the function is shared with the original.
+    ///
+    /// # Returns
+    ///
+    /// A new `ArcTransformer<Result<T, E>, Result<R, E>>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcTransformer, Transformer};
+    ///
+    /// let double = ArcTransformer::new(|x: i32| x * 2).lift_ok::<String>();
+    /// assert_eq!(double.apply(Ok(21)), Ok(42));
+    /// assert_eq!(double.apply(Err("bad".to_string())), Err("bad".to_string()));
+    /// ```
+    pub fn lift_ok<E>(&self) -> ArcTransformer<Result<T, E>, Result<R, E>>
+    where
+        E: Send + Sync + 'static,
+    {
+        let function = Arc::clone(&self.function);
+        ArcTransformer::new(move |x: Result<T, E>| x.map(&*function))
+    }
 }
 
 impl<T, R> ArcTransformer<T, R>
@@ -1059,19 +2175,95 @@ where
 {
     /// Creates a constant transformer
     ///
-    /// # Examples
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcTransformer, Transformer};
+    ///
+    /// let constant = ArcTransformer::constant("hello");
+    /// assert_eq!(constant.apply(123), "hello");
+    /// ```
+    pub fn constant(value: R) -> ArcTransformer<T, R>
+    where
+        R: Send + Sync,
+    {
+        ArcTransformer::new(move |_| value.clone())
+    }
+
+    /// Creates a thread-safe caching mapper whose entries expire after a
+    /// time-to-live
+    ///
+    /// Thread-safe version of [`BoxTransformer::cache_with_ttl`]. The cache
+    /// lives inside the returned `ArcMapper`, so all of its clones share the
+    /// same entries, guarded by the mapper's `Mutex`. Uses &self, so original
+    /// transformer remains usable.
+    ///
+    /// # Parameters
+    ///
+    /// * `ttl` - How long a computed result stays valid
+    ///
+    /// # Returns
+    ///
+    /// A new `ArcMapper<T, R>` caching the results of this transformer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcTransformer, Mapper};
+    /// use std::time::Duration;
+    ///
+    /// let square = ArcTransformer::new(|x: i32| x * x);
+    /// let mut cached = square.cache_with_ttl(Duration::from_secs(60));
+    /// let mut shared = cached.clone();
+    /// assert_eq!(cached.apply(4), 16); // computed
+    /// assert_eq!(shared.apply(4), 16); // served from the shared cache
+    /// ```
+    pub fn cache_with_ttl(&self, ttl: Duration) -> ArcMapper<T, R>
+    where
+        T: Hash + Eq + Clone,
+        R: Send,
+    {
+        self.cache_with_ttl_and_clock(ttl, Instant::now)
+    }
+
+    /// Creates a thread-safe caching mapper whose entries expire after a
+    /// time-to-live, reading the current time from `clock`
+    ///
+    /// Thread-safe version of [`BoxTransformer::cache_with_ttl_and_clock`].
+    /// Uses &self, so original transformer remains usable.
     ///
-    /// ```rust
-    /// use prism3_function::{ArcTransformer, Transformer};
+    /// # Parameters
     ///
-    /// let constant = ArcTransformer::constant("hello");
-    /// assert_eq!(constant.apply(123), "hello");
-    /// ```
-    pub fn constant(value: R) -> ArcTransformer<T, R>
+    /// * `ttl` - How long a computed result stays valid
+    /// * `clock` - Returns the current time; must never go backwards
+    ///
+    /// # Returns
+    ///
+    /// A new `ArcMapper<T, R>` caching the results of this transformer
+    pub fn cache_with_ttl_and_clock<C>(&self, ttl: Duration, clock: C) -> ArcMapper<T, R>
     where
-        R: Send + Sync,
+        T: Hash + Eq + Clone,
+        R: Send,
+        C: Fn() -> Instant + Send + 'static,
     {
-        ArcTransformer::new(move |_| value.clone())
+        let self_fn = self.function.clone();
+        let mut cache: HashMap<T, (R, Instant)> = HashMap::new();
+        let mut sweep_at = 1;
+        ArcMapper::new(move |x: T| {
+            let now = clock();
+            if let Some((value, at)) = cache.get(&x) {
+                if now.duration_since(*at) < ttl {
+                    return value.clone();
+                }
+            }
+            let value = self_fn(x.clone());
+            if cache.len() >= sweep_at {
+                cache.retain(|_, (_, at)| now.duration_since(*at) < ttl);
+                sweep_at = (cache.len() * 2).max(1);
+            }
+            cache.insert(x, (value.clone(), now));
+            value
+        })
     }
 }
 
@@ -1156,6 +2348,52 @@ impl<T, R> Clone for ArcTransformer<T, R> {
     }
 }
 
+// ============================================================================
+// ArcTransformer Parallel Processing (`rayon` feature)
+// ============================================================================
+
+#[cfg(feature = "rayon")]
+impl<T, R> ArcTransformer<T, R>
+where
+    T: Send,
+    R: Send,
+{
+    /// Applies this transformer to every element of a vector in parallel
+    ///
+    /// The elements are distributed over the Rayon global thread pool, and
+    /// the results keep the order of `values`. This needs no lock because
+    /// the wrapped function is `Fn + Send + Sync` and is only called
+    /// through a shared reference. Available with the `rayon` feature.
+    ///
+    /// `BoxTransformer` and `RcTransformer` cannot offer this, since their
+    /// functions are not `Sync`. A stateful `FnMut` transformation needs an
+    /// `ArcMapper`, whose mutex lets only one call run at a time, so it
+    /// would gain nothing from running in parallel.
+    ///
+    /// # Parameters
+    ///
+    /// * `values` - The inputs to transform
+    ///
+    /// # Returns
+    ///
+    /// The outputs, in the order of their inputs
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::ArcTransformer;
+    ///
+    /// let square = ArcTransformer::new(|x: u64| x * x);
+    /// assert_eq!(square.apply_par_vec(vec![1, 2, 3]), vec![1, 4, 9]);
+    /// ```
+    pub fn apply_par_vec(&self, values: Vec<T>) -> Vec<R> {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+        let function = &self.function;
+        values.into_par_iter().map(|x| function(x)).collect()
+    }
+}
+
 // ============================================================================
 // ArcTransformer TransformerOnce implementation
 // ============================================================================
@@ -1397,6 +2635,111 @@ where
             }
         })
     }
+
+    /// Adds an else branch returning `R::default()`
+    ///
+    /// Shortcut for `or_else(|_| R::default())`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `ArcTransformer<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcTransformer, Transformer};
+    ///
+    /// let positive_only = ArcTransformer::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_default();
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), 0);
+    /// ```
+    pub fn or_else_default(self) -> ArcTransformer<T, R>
+    where
+        R: Default + Send + Sync,
+    {
+        self.or_else(|_: T| R::default())
+    }
+
+    /// Adds an else branch returning a clone of `value`
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The fallback value, cloned on every else-branch call
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `ArcTransformer<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcTransformer, Transformer};
+    ///
+    /// let positive_only = ArcTransformer::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_value(-1);
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), -1);
+    /// ```
+    pub fn or_else_value(self, value: R) -> ArcTransformer<T, R>
+    where
+        R: Clone + Send + Sync,
+    {
+        self.or_else(move |_: T| value.clone())
+    }
+
+    /// Adds an else branch producing its result from a supplier
+    ///
+    /// The supplier is only called when the predicate rejects the input,
+    /// so it can lazily build expensive fallbacks. It may be stateful, so
+    /// calls to it are serialized by a `Mutex`; a panicking supplier does
+    /// not poison later calls.
+    ///
+    /// # Parameters
+    ///
+    /// * `supplier` - The supplier of fallback values, can be:
+    ///   - Closure: `|| -> R`
+    ///   - `BoxSupplier<R>`, `RcSupplier<R>`, `ArcSupplier<R>`
+    ///   - Any type implementing `Supplier<R>`
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `ArcTransformer<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcTransformer, Transformer};
+    ///
+    /// let mut next_id = 100;
+    /// let positive_only = ArcTransformer::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_supply(move || {
+    ///         next_id += 1;
+    ///         next_id
+    ///     });
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), 101);
+    /// assert_eq!(positive_only.apply(-5), 102);
+    /// ```
+    pub fn or_else_supply<S>(self, supplier: S) -> ArcTransformer<T, R>
+    where
+        S: Supplier<R> + Send + 'static,
+        R: Send + Sync,
+    {
+        let supplier = Mutex::new(supplier);
+        self.or_else(move |_: T| {
+            supplier
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get()
+        })
+    }
 }
 
 impl<T, R> Clone for ArcConditionalTransformer<T, R> {
@@ -1626,6 +2969,126 @@ where
         }
     }
 
+    /// Fan-out composition - applies self and other to the same input
+    ///
+    /// Creates a new transformer that feeds a clone of the input to this
+    /// transformer and the input itself to `other`, returning both results as
+    /// a tuple. Uses &self, so original transformer remains usable.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The transformer applied alongside self
+    ///
+    /// # Returns
+    ///
+    /// A new `RcTransformer<T, (R, S)>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{RcTransformer, Transformer};
+    ///
+    /// let double = RcTransformer::new(|x: i32| x * 2);
+    /// let both = double.fanout(|x: i32| x.to_string());
+    /// assert_eq!(both.apply(21), (42, "21".to_string()));
+    /// assert_eq!(double.apply(1), 2);
+    /// ```
+    pub fn fanout<S, F>(&self, other: F) -> RcTransformer<T, (R, S)>
+    where
+        T: Clone,
+        S: 'static,
+        F: Transformer<T, S> + 'static,
+    {
+        let self_fn = self.function.clone();
+        RcTransformer {
+            function: Rc::new(move |x: T| (self_fn(x.clone()), other.apply(x))),
+        }
+    }
+
+    /// Product composition - transforms both components of a pair
+    ///
+    /// Creates a new transformer over pairs that applies this transformer to
+    /// the first component and `other` to the second component. Uses &self,
+    /// so original transformer remains usable.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - The transformer applied to the second component
+    ///
+    /// # Returns
+    ///
+    /// A new `RcTransformer<(T, U), (R, S)>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{RcTransformer, Transformer};
+    ///
+    /// let double = RcTransformer::new(|x: i32| x * 2);
+    /// let pair = double.product(|b: bool| !b);
+    /// assert_eq!(pair.apply((21, true)), (42, false));
+    /// assert_eq!(double.apply(1), 2);
+    /// ```
+    pub fn product<U, S, F>(&self, other: F) -> RcTransformer<(T, U), (R, S)>
+    where
+        U: 'static,
+        S: 'static,
+        F: Transformer<U, S> + 'static,
+    {
+        let self_fn = self.function.clone();
+        RcTransformer {
+            function: Rc::new(move |(t, u): (T, U)| (self_fn(t), other.apply(u))),
+        }
+    }
+
+    /// Inspection - observes the output without changing it
+    ///
+    /// Creates a new transformer that applies this transformer, passes a
+    /// reference to the result to `consumer`, then returns the result
+    /// unchanged. The consumer may be stateful; it is kept in a `RefCell`.
+    /// Uses &self, so original transformer remains usable.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `C` - The type of the consumer (must implement `Consumer<R>`)
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The consumer observing each output
+    ///
+    /// # Returns
+    ///
+    /// A new `RcTransformer<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{RcTransformer, Transformer};
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// let log = Rc::new(RefCell::new(Vec::new()));
+    /// let l = log.clone();
+    /// let double = RcTransformer::new(|x: i32| x * 2);
+    /// let traced = double.inspect(move |x: &i32| l.borrow_mut().push(*x));
+    /// assert_eq!(traced.apply(21), 42);
+    /// assert_eq!(*log.borrow(), vec![42]);
+    /// ```
+    pub fn inspect<C>(&self, consumer: C) -> RcTransformer<T, R>
+    where
+        C: Consumer<R> + 'static,
+    {
+        let self_fn = self.function.clone();
+        let consumer = RefCell::new(consumer);
+        RcTransformer {
+            function: Rc::new(move |x: T| {
+                let result = self_fn(x);
+                consumer.borrow_mut().accept(&result);
+                result
+            }),
+        }
+    }
+
     /// Creates a conditional transformer (single-threaded shared version)
     ///
     /// Returns a transformer that only executes when a predicate is satisfied.
@@ -1690,6 +3153,57 @@ where
             predicate: predicate.into_rc(),
         }
     }
+
+    /// Lifts this transformer over `Option`
+    ///
+    /// Returns a transformer mapping `Some(x)` to `Some(f(x))` and passing
+    /// `None` through without calling this transformer. Borrows &self; the
+    /// function is shared with the original.
+    ///
+    /// # Returns
+    ///
+    /// A new `RcTransformer<Option<T>, Option<R>>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{RcTransformer, Transformer};
+    ///
+    /// let len = RcTransformer::new(|s: String| s.len()).lift_option();
+    /// assert_eq!(len.apply(Some("abc".to_string())), Some(3));
+    /// assert_eq!(len.apply(None), None);
+    /// ```
+    pub fn lift_option(&self) -> RcTransformer<Option<T>, Option<R>> {
+        let function = Rc::clone(&self.function);
+        RcTransformer::new(move |x: Option<T>| x.map(&*function))
+    }
+
+    /// Lifts this transformer over the `Ok` side of `Result`
+    ///
+    /// Returns a transformer mapping `Ok(x)` to `Ok(f(x))` and passing
+    /// `Err(e)` through without calling this transformer. Borrows &self;
+    /// the function is shared with the original.
+    ///
+    /// # Returns
+    ///
+    /// A new `RcTransformer<Result<T, E>, Result<R, E>>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{RcTransformer, Transformer};
+    ///
+    /// let double = RcTransformer::new(|x: i32| x * 2).lift_ok::<String>();
+    /// assert_eq!(double.apply(Ok(21)), Ok(42));
+    /// assert_eq!(double.apply(Err("bad".to_string())), Err("bad".to_string()));
+    /// ```
+    pub fn lift_ok<E>(&self) -> RcTransformer<Result<T, E>, Result<R, E>>
+    where
+        E: 'static,
+    {
+        let function = Rc::clone(&self.function);
+        RcTransformer::new(move |x: Result<T, E>| x.map(&*function))
+    }
 }
 
 impl<T, R> RcTransformer<T, R>
@@ -2031,6 +3545,103 @@ where
             }
         })
     }
+
+    /// Adds an else branch returning `R::default()`
+    ///
+    /// Shortcut for `or_else(|_| R::default())`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `RcTransformer<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{RcTransformer, Transformer};
+    ///
+    /// let positive_only = RcTransformer::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_default();
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), 0);
+    /// ```
+    pub fn or_else_default(self) -> RcTransformer<T, R>
+    where
+        R: Default,
+    {
+        self.or_else(|_: T| R::default())
+    }
+
+    /// Adds an else branch returning a clone of `value`
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The fallback value, cloned on every else-branch call
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `RcTransformer<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{RcTransformer, Transformer};
+    ///
+    /// let positive_only = RcTransformer::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_value(-1);
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), -1);
+    /// ```
+    pub fn or_else_value(self, value: R) -> RcTransformer<T, R>
+    where
+        R: Clone,
+    {
+        self.or_else(move |_: T| value.clone())
+    }
+
+    /// Adds an else branch producing its result from a supplier
+    ///
+    /// The supplier is only called when the predicate rejects the input,
+    /// so it can lazily build expensive fallbacks.
+    ///
+    /// # Parameters
+    ///
+    /// * `supplier` - The supplier of fallback values, can be:
+    ///   - Closure: `|| -> R`
+    ///   - `BoxSupplier<R>`, `RcSupplier<R>`, `ArcSupplier<R>`
+    ///   - Any type implementing `Supplier<R>`
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `RcTransformer<T, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{RcTransformer, Transformer};
+    ///
+    /// let mut next_id = 100;
+    /// let positive_only = RcTransformer::new(|x: i32| x * 2)
+    ///     .when(|x: &i32| *x > 0)
+    ///     .or_else_supply(move || {
+    ///         next_id += 1;
+    ///         next_id
+    ///     });
+    ///
+    /// assert_eq!(positive_only.apply(5), 10);
+    /// assert_eq!(positive_only.apply(-5), 101);
+    /// assert_eq!(positive_only.apply(-5), 102);
+    /// ```
+    pub fn or_else_supply<S>(self, supplier: S) -> RcTransformer<T, R>
+    where
+        S: Supplier<R> + 'static,
+    {
+        let supplier = RefCell::new(supplier);
+        self.or_else(move |_: T| supplier.borrow_mut().get())
+    }
 }
 
 impl<T, R> Clone for RcConditionalTransformer<T, R> {
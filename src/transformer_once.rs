@@ -16,11 +16,19 @@
 //! implementations:
 //!
 //! - [`BoxTransformerOnce`]: Single ownership, one-time use
+//! - [`RcTransformerOnce`]: Single-threaded shared ownership, the wrapped
+//!   function runs at most once across all clones
+//! - [`ArcTransformerOnce`]: Thread-safe shared ownership, the wrapped
+//!   function runs at most once across all clones
 //!
 //! # Author
 //!
 //! Hu Haixing
 
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, PoisonError};
+
 use crate::predicate::{BoxPredicate, Predicate};
 
 // ============================================================================
@@ -553,6 +561,352 @@ where
     }
 }
 
+// ============================================================================
+// RcTransformerOnce - Rc<RefCell<Option<Box<dyn FnOnce(T) -> R>>>>
+// ============================================================================
+
+/// Type alias for the shared slot of an `RcTransformerOnce`
+type RcTransformerOnceSlot<T, R> = Rc<RefCell<Option<Box<dyn FnOnce(T) -> R>>>>;
+
+/// Type alias for the shared slot of an `ArcTransformerOnce`
+type ArcTransformerOnceSlot<T, R> = Arc<Mutex<Option<Box<dyn FnOnce(T) -> R + Send>>>>;
+
+/// RcTransformerOnce - single-threaded shared one-shot transformer
+///
+/// A one-shot transformer that can be cloned and registered in several
+/// places at once, e.g. as a callback fired by whichever of several
+/// triggers happens first. All clones share the same underlying function
+/// stored in an `Rc<RefCell<Option<..>>>`; the first invocation through any
+/// clone takes the function out and runs it, every later invocation
+/// observes that it has already been consumed.
+///
+/// # Features
+///
+/// - **Based on**: `Rc<RefCell<Option<Box<dyn FnOnce(T) -> R>>>>`
+/// - **Ownership**: Shared ownership, cloneable
+/// - **Reusability**: The wrapped function runs at most once across all
+///   clones
+/// - **Thread Safety**: Not thread-safe
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::RcTransformerOnce;
+///
+/// let callback = RcTransformerOnce::new(|reason: &str| format!("fired: {}", reason));
+/// let on_timeout = callback.clone();
+/// let on_success = callback.clone();
+///
+/// assert_eq!(on_success.try_apply_once("success"), Some("fired: success".to_string()));
+/// assert_eq!(on_timeout.try_apply_once("timeout"), None);
+/// assert!(callback.is_consumed());
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct RcTransformerOnce<T, R> {
+    function: RcTransformerOnceSlot<T, R>,
+}
+
+impl<T, R> RcTransformerOnce<T, R>
+where
+    T: 'static,
+    R: 'static,
+{
+    /// Creates a new RcTransformerOnce
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure or function to wrap
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{RcTransformerOnce, TransformerOnce};
+    ///
+    /// let double = RcTransformerOnce::new(|x: i32| x * 2);
+    /// assert_eq!(double.apply_once(21), 42);
+    /// ```
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnOnce(T) -> R + 'static,
+    {
+        RcTransformerOnce {
+            function: Rc::new(RefCell::new(Some(Box::new(f)))),
+        }
+    }
+}
+
+impl<T, R> RcTransformerOnce<T, R> {
+    /// Applies the transformer if it has not been consumed yet
+    ///
+    /// Takes the wrapped function out of the shared slot and invokes it. The
+    /// slot is released before the function runs, so the function itself may
+    /// safely touch other clones of this transformer.
+    ///
+    /// # Parameters
+    ///
+    /// * `input` - The input value (consumed)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(result)` on the first invocation across all clones, and
+    /// `None` once the function has already been consumed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::RcTransformerOnce;
+    ///
+    /// let len = RcTransformerOnce::new(|s: String| s.len());
+    /// let other = len.clone();
+    /// assert_eq!(len.try_apply_once("abc".to_string()), Some(3));
+    /// assert_eq!(other.try_apply_once("de".to_string()), None);
+    /// ```
+    pub fn try_apply_once(&self, input: T) -> Option<R> {
+        let function = self.function.borrow_mut().take();
+        function.map(|f| f(input))
+    }
+
+    /// Returns whether the wrapped function has already been consumed
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if any clone has already invoked the function
+    pub fn is_consumed(&self) -> bool {
+        self.function.borrow().is_none()
+    }
+}
+
+impl<T, R> TransformerOnce<T, R> for RcTransformerOnce<T, R> {
+    /// Applies the transformer
+    ///
+    /// # Panics
+    ///
+    /// Panics if the wrapped function has already been consumed through
+    /// another clone. Use [`RcTransformerOnce::try_apply_once`] to handle
+    /// that case without panicking.
+    fn apply_once(self, input: T) -> R {
+        self.try_apply_once(input)
+            .expect("RcTransformerOnce has already been consumed")
+    }
+
+    fn to_box_once(&self) -> BoxTransformerOnce<T, R>
+    where
+        T: 'static,
+        R: 'static,
+    {
+        self.clone().into_box_once()
+    }
+
+    fn to_fn_once(&self) -> impl FnOnce(T) -> R
+    where
+        T: 'static,
+        R: 'static,
+    {
+        self.clone().into_fn_once()
+    }
+}
+
+impl<T, R> Clone for RcTransformerOnce<T, R> {
+    /// Clones the RcTransformerOnce
+    ///
+    /// The clone shares the same one-shot slot with the original.
+    fn clone(&self) -> Self {
+        RcTransformerOnce {
+            function: Rc::clone(&self.function),
+        }
+    }
+}
+
+impl<T, R> From<BoxTransformerOnce<T, R>> for RcTransformerOnce<T, R> {
+    /// Converts a BoxTransformerOnce into a shareable RcTransformerOnce
+    fn from(transformer: BoxTransformerOnce<T, R>) -> Self {
+        RcTransformerOnce {
+            function: Rc::new(RefCell::new(Some(transformer.function))),
+        }
+    }
+}
+
+// ============================================================================
+// ArcTransformerOnce - Arc<Mutex<Option<Box<dyn FnOnce(T) -> R + Send>>>>
+// ============================================================================
+
+/// ArcTransformerOnce - thread-safe shared one-shot transformer
+///
+/// The thread-safe counterpart of [`RcTransformerOnce`]. All clones share
+/// the same underlying function stored in an `Arc<Mutex<Option<..>>>`;
+/// whichever clone is invoked first, from whichever thread, runs the
+/// function, and every later invocation observes that it has already been
+/// consumed.
+///
+/// # Features
+///
+/// - **Based on**: `Arc<Mutex<Option<Box<dyn FnOnce(T) -> R + Send>>>>`
+/// - **Ownership**: Shared ownership, cloneable
+/// - **Reusability**: The wrapped function runs at most once across all
+///   clones and threads
+/// - **Thread Safety**: Thread-safe (`Send + Sync`)
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::ArcTransformerOnce;
+/// use std::thread;
+///
+/// let double = ArcTransformerOnce::new(|x: i32| x * 2);
+/// let handles: Vec<_> = (0..4)
+///     .map(|i| {
+///         let double = double.clone();
+///         thread::spawn(move || double.try_apply_once(i))
+///     })
+///     .collect();
+/// let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+/// assert_eq!(results.iter().filter(|r| r.is_some()).count(), 1);
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct ArcTransformerOnce<T, R> {
+    function: ArcTransformerOnceSlot<T, R>,
+}
+
+impl<T, R> ArcTransformerOnce<T, R>
+where
+    T: 'static,
+    R: 'static,
+{
+    /// Creates a new ArcTransformerOnce
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure or function to wrap; must be `Send`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcTransformerOnce, TransformerOnce};
+    ///
+    /// let double = ArcTransformerOnce::new(|x: i32| x * 2);
+    /// assert_eq!(double.apply_once(21), 42);
+    /// ```
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnOnce(T) -> R + Send + 'static,
+    {
+        ArcTransformerOnce {
+            function: Arc::new(Mutex::new(Some(Box::new(f)))),
+        }
+    }
+}
+
+impl<T, R> ArcTransformerOnce<T, R> {
+    /// Applies the transformer if it has not been consumed yet
+    ///
+    /// Takes the wrapped function out of the shared slot and invokes it. The
+    /// lock is released before the function runs.
+    ///
+    /// # Parameters
+    ///
+    /// * `input` - The input value (consumed)
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(result)` on the first invocation across all clones, and
+    /// `None` once the function has already been consumed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::ArcTransformerOnce;
+    ///
+    /// let double = ArcTransformerOnce::new(|x: i32| x * 2);
+    /// assert_eq!(double.try_apply_once(21), Some(42));
+    /// assert_eq!(double.try_apply_once(21), None);
+    /// ```
+    pub fn try_apply_once(&self, input: T) -> Option<R> {
+        let function = self
+            .function
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+        function.map(|f| f(input))
+    }
+
+    /// Returns whether the wrapped function has already been consumed
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if any clone has already invoked the function
+    pub fn is_consumed(&self) -> bool {
+        self.function
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_none()
+    }
+}
+
+impl<T, R> TransformerOnce<T, R> for ArcTransformerOnce<T, R> {
+    /// Applies the transformer
+    ///
+    /// # Panics
+    ///
+    /// Panics if the wrapped function has already been consumed through
+    /// another clone. Use [`ArcTransformerOnce::try_apply_once`] to handle
+    /// that case without panicking.
+    fn apply_once(self, input: T) -> R {
+        self.try_apply_once(input)
+            .expect("ArcTransformerOnce has already been consumed")
+    }
+
+    fn to_box_once(&self) -> BoxTransformerOnce<T, R>
+    where
+        T: 'static,
+        R: 'static,
+    {
+        self.clone().into_box_once()
+    }
+
+    fn to_fn_once(&self) -> impl FnOnce(T) -> R
+    where
+        T: 'static,
+        R: 'static,
+    {
+        self.clone().into_fn_once()
+    }
+}
+
+impl<T, R> Clone for ArcTransformerOnce<T, R> {
+    /// Clones the ArcTransformerOnce
+    ///
+    /// The clone shares the same one-shot slot with the original.
+    fn clone(&self) -> Self {
+        ArcTransformerOnce {
+            function: Arc::clone(&self.function),
+        }
+    }
+}
+
+impl<T, R> From<ArcTransformerOnce<T, R>> for RcTransformerOnce<T, R>
+where
+    T: 'static,
+    R: 'static,
+{
+    /// Wraps an ArcTransformerOnce into an RcTransformerOnce
+    ///
+    /// The resulting RcTransformerOnce delegates to the same shared slot, so
+    /// consuming either one consumes both.
+    fn from(transformer: ArcTransformerOnce<T, R>) -> Self {
+        RcTransformerOnce::new(move |t| {
+            transformer
+                .try_apply_once(t)
+                .expect("ArcTransformerOnce has already been consumed")
+        })
+    }
+}
+
 // ============================================================================
 // Blanket implementation for standard FnOnce trait
 // ============================================================================
@@ -947,3 +1301,141 @@ where
 ///
 /// Hu Haixing
 pub type BoxUnaryOperatorOnce<T> = BoxTransformerOnce<T, T>;
+
+impl<T> BoxTransformerOnce<T, T>
+where
+    T: 'static,
+{
+    /// Chain composition of one-time unary operators
+    ///
+    /// Same as [`and_then`](BoxTransformerOnce::and_then) restricted to
+    /// operators, so the result is again a `BoxUnaryOperatorOnce<T>` and
+    /// closure arguments need no output type annotation. Consumes both
+    /// operators.
+    ///
+    /// # Parameters
+    ///
+    /// * `after` - The operator to apply after self. Can be:
+    ///   - A closure: `|x: T| -> T`
+    ///   - A `BoxUnaryOperatorOnce<T>`
+    ///   - Any type implementing `UnaryOperatorOnce<T>`
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxUnaryOperatorOnce<T>` applying self, then `after`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxUnaryOperatorOnce, TransformerOnce};
+    ///
+    /// let trim = BoxUnaryOperatorOnce::new(|s: String| s.trim().to_string());
+    /// let shout = trim.and_then_once(|s| s.to_uppercase());
+    /// assert_eq!(shout.apply_once("  hi ".to_string()), "HI");
+    /// ```
+    pub fn and_then_once<G>(self, after: G) -> BoxUnaryOperatorOnce<T>
+    where
+        G: UnaryOperatorOnce<T> + 'static,
+    {
+        self.and_then(after)
+    }
+
+    /// Reverse composition of one-time unary operators
+    ///
+    /// Same as [`compose`](BoxTransformerOnce::compose) restricted to
+    /// operators. Consumes both operators.
+    ///
+    /// # Parameters
+    ///
+    /// * `before` - The operator to apply before self. Can be:
+    ///   - A closure: `|x: T| -> T`
+    ///   - A `BoxUnaryOperatorOnce<T>`
+    ///   - Any type implementing `UnaryOperatorOnce<T>`
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxUnaryOperatorOnce<T>` applying `before`, then self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxUnaryOperatorOnce, TransformerOnce};
+    ///
+    /// let double = BoxUnaryOperatorOnce::new(|x: i32| x * 2);
+    /// let composed = double.compose_once(|x| x + 1);
+    /// assert_eq!(composed.apply_once(5), 12); // (5 + 1) * 2
+    /// ```
+    pub fn compose_once<G>(self, before: G) -> BoxUnaryOperatorOnce<T>
+    where
+        G: UnaryOperatorOnce<T> + 'static,
+    {
+        self.compose(before)
+    }
+}
+
+// ============================================================================
+// FnUnaryOperatorOnceOps - Extension trait for FnOnce(T) -> T closures
+// ============================================================================
+
+/// Extension trait for closures implementing `FnOnce(T) -> T`
+///
+/// Provides `and_then_once` and `compose_once` directly on one-time
+/// same-type closures, returning `BoxUnaryOperatorOnce<T>`.
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{FnUnaryOperatorOnceOps, TransformerOnce};
+///
+/// let tag = String::from("!");
+/// let chain = (|s: String| s + "a")
+///     .and_then_once(move |s| s + &tag)
+///     .compose_once(|s| s.to_uppercase());
+/// assert_eq!(chain.apply_once("x".to_string()), "Xa!");
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub trait FnUnaryOperatorOnceOps<T>: FnOnce(T) -> T + Sized + 'static {
+    /// Chain composition - applies self first, then after
+    ///
+    /// # Parameters
+    ///
+    /// * `after` - The operator to apply after self
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxUnaryOperatorOnce<T>` representing the composition
+    fn and_then_once<G>(self, after: G) -> BoxUnaryOperatorOnce<T>
+    where
+        G: UnaryOperatorOnce<T> + 'static,
+        T: 'static,
+    {
+        BoxUnaryOperatorOnce::new(self).and_then_once(after)
+    }
+
+    /// Reverse composition - applies before first, then self
+    ///
+    /// # Parameters
+    ///
+    /// * `before` - The operator to apply before self
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxUnaryOperatorOnce<T>` representing the composition
+    fn compose_once<G>(self, before: G) -> BoxUnaryOperatorOnce<T>
+    where
+        G: UnaryOperatorOnce<T> + 'static,
+        T: 'static,
+    {
+        BoxUnaryOperatorOnce::new(self).compose_once(before)
+    }
+}
+
+/// Blanket implementation of FnUnaryOperatorOnceOps for all FnOnce closures
+///
+/// # Author
+///
+/// Hu Haixing
+impl<T, F> FnUnaryOperatorOnceOps<T> for F where F: FnOnce(T) -> T + 'static {}
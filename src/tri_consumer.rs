@@ -0,0 +1,1020 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # TriConsumer Types
+//!
+//! Provides tri-consumer interface implementations for operations accepting
+//! three input parameters and returning no result.
+//!
+//! This module provides a unified `TriConsumer` trait and three concrete
+//! implementations based on different ownership models:
+//!
+//! - **`BoxTriConsumer<T, U, V>`**: Box-based single ownership for one-time
+//!   use
+//! - **`ArcTriConsumer<T, U, V>`**: Arc<Mutex<>>-based thread-safe shared
+//!   ownership
+//! - **`RcTriConsumer<T, U, V>`**: Rc<RefCell<>>-based single-threaded
+//!   shared ownership
+//!
+//! A tri-consumer is the three-argument counterpart of
+//! [`BiConsumer`](crate::BiConsumer): it observes three values through
+//! `FnMut(&T, &U, &V)` and may modify its own captured state.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::bi_consumer::{BiConsumer, BoxBiConsumer};
+
+/// Type alias for tri-consumer function signature
+type TriConsumerFn<T, U, V> = dyn FnMut(&T, &U, &V);
+
+/// Type alias for thread-safe tri-consumer function signature
+type SendTriConsumerFn<T, U, V> = dyn FnMut(&T, &U, &V) + Send;
+
+// =======================================================================
+// 1. TriConsumer Trait - Unified TriConsumer Interface
+// =======================================================================
+
+/// TriConsumer trait - Unified tri-consumer interface
+///
+/// Defines the core behavior of all tri-consumer types. Performs operations
+/// that accept three references without returning a result.
+///
+/// This trait is automatically implemented by:
+/// - All closures implementing `FnMut(&T, &U, &V)`
+/// - `BoxTriConsumer<T, U, V>`, `ArcTriConsumer<T, U, V>` and
+///   `RcTriConsumer<T, U, V>`
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BoxTriConsumer, TriConsumer};
+///
+/// fn feed<C: TriConsumer<i32, i32, i32>>(consumer: &mut C) {
+///     consumer.accept(&1, &2, &3);
+/// }
+///
+/// let mut total = 0;
+/// let mut sum = BoxTriConsumer::new(move |a: &i32, b: &i32, c: &i32| {
+///     total += a + b + c;
+///     println!("total = {}", total);
+/// });
+/// feed(&mut sum);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub trait TriConsumer<T, U, V> {
+    /// Performs the consumption operation
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - Reference to the first value to consume
+    /// * `second` - Reference to the second value to consume
+    /// * `third` - Reference to the third value to consume
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTriConsumer, TriConsumer};
+    /// use std::rc::Rc;
+    /// use std::cell::RefCell;
+    ///
+    /// let log = Rc::new(RefCell::new(Vec::new()));
+    /// let l = log.clone();
+    /// let mut consumer = BoxTriConsumer::new(move |x: &i32, y: &i32, z: &i32| {
+    ///     l.borrow_mut().push(x * y * z);
+    /// });
+    /// consumer.accept(&2, &3, &4);
+    /// assert_eq!(*log.borrow(), vec![24]);
+    /// ```
+    fn accept(&mut self, first: &T, second: &U, third: &V);
+
+    /// Converts to BoxTriConsumer
+    ///
+    /// **⚠️ Consumes `self`**: Original tri-consumer becomes unavailable
+    /// after calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `BoxTriConsumer<T, U, V>`
+    fn into_box(self) -> BoxTriConsumer<T, U, V>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        let mut consumer = self;
+        BoxTriConsumer::new(move |t, u, v| consumer.accept(t, u, v))
+    }
+
+    /// Converts to RcTriConsumer
+    ///
+    /// **⚠️ Consumes `self`**: Original tri-consumer becomes unavailable
+    /// after calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `RcTriConsumer<T, U, V>`
+    fn into_rc(self) -> RcTriConsumer<T, U, V>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        let mut consumer = self;
+        RcTriConsumer::new(move |t, u, v| consumer.accept(t, u, v))
+    }
+
+    /// Converts to ArcTriConsumer
+    ///
+    /// **⚠️ Consumes `self`**: Original tri-consumer becomes unavailable
+    /// after calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `ArcTriConsumer<T, U, V>`
+    fn into_arc(self) -> ArcTriConsumer<T, U, V>
+    where
+        Self: Sized + Send + 'static,
+        T: Send + 'static,
+        U: Send + 'static,
+        V: Send + 'static,
+    {
+        let mut consumer = self;
+        ArcTriConsumer::new(move |t, u, v| consumer.accept(t, u, v))
+    }
+
+    /// Converts tri-consumer to a closure
+    ///
+    /// **⚠️ Consumes `self`**: Original tri-consumer becomes unavailable
+    /// after calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns a closure implementing `FnMut(&T, &U, &V)`
+    fn into_fn(self) -> impl FnMut(&T, &U, &V)
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        let mut consumer = self;
+        move |t, u, v| consumer.accept(t, u, v)
+    }
+
+    /// Converts to BoxTriConsumer (non-consuming)
+    ///
+    /// **⚠️ Requires Clone**: Original tri-consumer must implement Clone.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `BoxTriConsumer<T, U, V>` from the clone
+    fn to_box(&self) -> BoxTriConsumer<T, U, V>
+    where
+        Self: Sized + Clone + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        self.clone().into_box()
+    }
+
+    /// Converts to RcTriConsumer (non-consuming)
+    ///
+    /// **⚠️ Requires Clone**: Original tri-consumer must implement Clone.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `RcTriConsumer<T, U, V>` from the clone
+    fn to_rc(&self) -> RcTriConsumer<T, U, V>
+    where
+        Self: Sized + Clone + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        self.clone().into_rc()
+    }
+
+    /// Converts to ArcTriConsumer (non-consuming)
+    ///
+    /// **⚠️ Requires Clone + Send**: Original tri-consumer must implement
+    /// Clone + Send.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `ArcTriConsumer<T, U, V>` from the clone
+    fn to_arc(&self) -> ArcTriConsumer<T, U, V>
+    where
+        Self: Sized + Clone + Send + 'static,
+        T: Send + 'static,
+        U: Send + 'static,
+        V: Send + 'static,
+    {
+        self.clone().into_arc()
+    }
+
+    /// Converts to a closure (non-consuming)
+    ///
+    /// **⚠️ Requires Clone**: Original tri-consumer must implement Clone.
+    ///
+    /// # Returns
+    ///
+    /// Returns a closure implementing `FnMut(&T, &U, &V)` from the clone
+    fn to_fn(&self) -> impl FnMut(&T, &U, &V)
+    where
+        Self: Sized + Clone + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        self.clone().into_fn()
+    }
+}
+
+// =======================================================================
+// 2. BoxTriConsumer - Single Ownership Implementation
+// =======================================================================
+
+/// BoxTriConsumer struct
+///
+/// A tri-consumer implementation based on `Box<dyn FnMut(&T, &U, &V)>` for
+/// single ownership scenarios.
+///
+/// # Features
+///
+/// - **Single Ownership**: Not cloneable, ownership moves on use
+/// - **Zero Overhead**: No reference counting or locking
+/// - **Mutable State**: Can modify captured environment via `FnMut`
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BoxTriConsumer, TriConsumer};
+///
+/// let mut lines = Vec::new();
+/// let mut consumer = BoxTriConsumer::new(move |level: &&str, code: &u32, msg: &String| {
+///     lines.push(format!("[{}] {} {}", level, code, msg));
+/// });
+/// consumer.accept(&"WARN", &42, &"disk almost full".to_string());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct BoxTriConsumer<T, U, V> {
+    function: Box<TriConsumerFn<T, U, V>>,
+}
+
+impl<T, U, V> BoxTriConsumer<T, U, V>
+where
+    T: 'static,
+    U: 'static,
+    V: 'static,
+{
+    /// Creates a new BoxTriConsumer
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxTriConsumer<T, U, V>` instance
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnMut(&T, &U, &V) + 'static,
+    {
+        BoxTriConsumer {
+            function: Box::new(f),
+        }
+    }
+
+    /// Creates a no-op tri-consumer
+    ///
+    /// # Returns
+    ///
+    /// Returns a tri-consumer that ignores its arguments
+    pub fn noop() -> Self {
+        BoxTriConsumer::new(|_, _, _| {})
+    }
+
+    /// Creates a tri-consumer from a bi-consumer, ignoring the third value
+    ///
+    /// Useful to plug an existing bi-consumer in where a tri-consumer is
+    /// expected.
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The bi-consumer receiving the first two values
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxTriConsumer<T, U, V>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTriConsumer, TriConsumer};
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// let log = Rc::new(RefCell::new(Vec::new()));
+    /// let l = log.clone();
+    /// let mut consumer = BoxTriConsumer::from_bi_consumer(move |x: &i32, y: &i32| {
+    ///     l.borrow_mut().push(x + y);
+    /// });
+    /// consumer.accept(&1, &2, &"ignored");
+    /// assert_eq!(*log.borrow(), vec![3]);
+    /// ```
+    pub fn from_bi_consumer<C>(consumer: C) -> Self
+    where
+        C: BiConsumer<T, U> + 'static,
+    {
+        let mut consumer = consumer;
+        BoxTriConsumer::new(move |t, u, _| consumer.accept(t, u))
+    }
+
+    /// Chains another tri-consumer in sequence
+    ///
+    /// Returns a new tri-consumer executing the current operation first,
+    /// then the next operation. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - The tri-consumer to execute after the current operation.
+    ///   **Note: This parameter is passed by value and will transfer
+    ///   ownership.** Can be:
+    ///   - A closure: `|x: &T, y: &U, z: &V|`
+    ///   - A `BoxTriConsumer<T, U, V>`
+    ///   - An `ArcTriConsumer<T, U, V>`
+    ///   - An `RcTriConsumer<T, U, V>`
+    ///   - Any type implementing `TriConsumer<T, U, V>`
+    ///
+    /// # Returns
+    ///
+    /// Returns a new composed `BoxTriConsumer<T, U, V>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTriConsumer, TriConsumer};
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// let log = Rc::new(RefCell::new(Vec::new()));
+    /// let (l1, l2) = (log.clone(), log.clone());
+    /// let mut chained = BoxTriConsumer::new(move |x: &i32, y: &i32, z: &i32| {
+    ///     l1.borrow_mut().push(x + y + z);
+    /// })
+    /// .and_then(move |x: &i32, y: &i32, z: &i32| {
+    ///     l2.borrow_mut().push(x * y * z);
+    /// });
+    /// chained.accept(&1, &2, &3);
+    /// assert_eq!(*log.borrow(), vec![6, 6]);
+    /// ```
+    pub fn and_then<C>(self, next: C) -> Self
+    where
+        C: TriConsumer<T, U, V> + 'static,
+    {
+        let mut first = self.function;
+        let mut second = next;
+        BoxTriConsumer::new(move |t, u, v| {
+            first(t, u, v);
+            second.accept(t, u, v);
+        })
+    }
+
+    /// Fixes the third argument, producing a bi-consumer
+    ///
+    /// Returns a bi-consumer that passes its two values together with a
+    /// reference to `third` to this tri-consumer. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `third` - The value passed as third argument on every call
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxBiConsumer<T, U>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BiConsumer, BoxTriConsumer};
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// let log = Rc::new(RefCell::new(Vec::new()));
+    /// let l = log.clone();
+    /// let tagged = BoxTriConsumer::new(move |k: &&str, v: &i32, tag: &String| {
+    ///     l.borrow_mut().push(format!("{}:{}={}", tag, k, v));
+    /// });
+    /// let mut audit = tagged.bind_third("audit".to_string());
+    /// audit.accept(&"x", &1);
+    /// assert_eq!(*log.borrow(), vec!["audit:x=1"]);
+    /// ```
+    pub fn bind_third(self, third: V) -> BoxBiConsumer<T, U> {
+        let mut function = self.function;
+        BoxBiConsumer::new(move |t, u| function(t, u, &third))
+    }
+}
+
+impl<T, U, V> TriConsumer<T, U, V> for BoxTriConsumer<T, U, V> {
+    fn accept(&mut self, first: &T, second: &U, third: &V) {
+        (self.function)(first, second, third)
+    }
+
+    fn into_box(self) -> BoxTriConsumer<T, U, V>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        self
+    }
+
+    fn into_rc(self) -> RcTriConsumer<T, U, V>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        let mut func = self.function;
+        RcTriConsumer::new(move |t, u, v| func(t, u, v))
+    }
+
+    // do NOT override TriConsumer::into_arc() because BoxTriConsumer is not
+    // Send + Sync and calling BoxTriConsumer::into_arc() will cause a compile
+    // error
+
+    fn into_fn(self) -> impl FnMut(&T, &U, &V)
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        self.function
+    }
+
+    // do NOT override TriConsumer::to_xxx() because BoxTriConsumer is not
+    // Clone and calling BoxTriConsumer::to_xxx() will cause a compile error
+}
+
+impl<T, U, V> fmt::Debug for BoxTriConsumer<T, U, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxTriConsumer")
+            .field("function", &"<function>")
+            .finish()
+    }
+}
+
+// =======================================================================
+// 3. ArcTriConsumer - Thread-Safe Shared Ownership Implementation
+// =======================================================================
+
+/// ArcTriConsumer struct
+///
+/// A tri-consumer implementation based on
+/// `Arc<Mutex<dyn FnMut(&T, &U, &V) + Send>>` for thread-safe shared
+/// ownership scenarios. Clones share the same underlying function and its
+/// captured state.
+///
+/// # Features
+///
+/// - **Shared Ownership**: Cloneable via `Arc`
+/// - **Thread Safe**: Implements `Send + Sync`
+/// - **Interior Mutability**: Uses `Mutex` for the `FnMut` state, recovering
+///   from poisoning so one panicking call does not break the clones
+/// - **Non-Consuming API**: `and_then` borrows `&self`
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{ArcTriConsumer, TriConsumer};
+/// use std::sync::{Arc, Mutex};
+/// use std::thread;
+///
+/// let log = Arc::new(Mutex::new(Vec::new()));
+/// let l = log.clone();
+/// let consumer = ArcTriConsumer::new(move |x: &i32, y: &i32, z: &i32| {
+///     l.lock().unwrap().push(x + y + z);
+/// });
+/// let mut clone = consumer.clone();
+/// thread::spawn(move || clone.accept(&1, &2, &3)).join().unwrap();
+/// assert_eq!(*log.lock().unwrap(), vec![6]);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct ArcTriConsumer<T, U, V> {
+    function: Arc<Mutex<SendTriConsumerFn<T, U, V>>>,
+}
+
+impl<T, U, V> ArcTriConsumer<T, U, V>
+where
+    T: Send + 'static,
+    U: Send + 'static,
+    V: Send + 'static,
+{
+    /// Creates a new ArcTriConsumer
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `ArcTriConsumer<T, U, V>` instance
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnMut(&T, &U, &V) + Send + 'static,
+    {
+        ArcTriConsumer {
+            function: Arc::new(Mutex::new(f)),
+        }
+    }
+
+    /// Creates a no-op tri-consumer
+    ///
+    /// # Returns
+    ///
+    /// Returns a tri-consumer that ignores its arguments
+    pub fn noop() -> Self {
+        ArcTriConsumer::new(|_, _, _| {})
+    }
+
+    /// Chains another ArcTriConsumer in sequence
+    ///
+    /// Returns a new tri-consumer executing the current operation first,
+    /// then the next operation. Borrows &self, does not consume the original
+    /// tri-consumer.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - The tri-consumer to execute after the current operation.
+    ///   **Note: This parameter is passed by reference, so the original
+    ///   tri-consumer remains usable.**
+    ///
+    /// # Returns
+    ///
+    /// Returns a new composed `ArcTriConsumer<T, U, V>`
+    pub fn and_then(&self, next: &ArcTriConsumer<T, U, V>) -> ArcTriConsumer<T, U, V> {
+        let first = Arc::clone(&self.function);
+        let second = Arc::clone(&next.function);
+        ArcTriConsumer {
+            function: Arc::new(Mutex::new(move |t: &T, u: &U, v: &V| {
+                first.lock().unwrap_or_else(PoisonError::into_inner)(t, u, v);
+                second.lock().unwrap_or_else(PoisonError::into_inner)(t, u, v);
+            })),
+        }
+    }
+}
+
+impl<T, U, V> TriConsumer<T, U, V> for ArcTriConsumer<T, U, V> {
+    fn accept(&mut self, first: &T, second: &U, third: &V) {
+        (self.function.lock().unwrap_or_else(PoisonError::into_inner))(first, second, third)
+    }
+
+    fn into_box(self) -> BoxTriConsumer<T, U, V>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        let self_fn = self.function;
+        BoxTriConsumer::new(move |t, u, v| {
+            self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t, u, v)
+        })
+    }
+
+    fn into_rc(self) -> RcTriConsumer<T, U, V>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        let self_fn = self.function;
+        RcTriConsumer::new(move |t, u, v| {
+            self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t, u, v)
+        })
+    }
+
+    fn into_arc(self) -> ArcTriConsumer<T, U, V>
+    where
+        T: Send + 'static,
+        U: Send + 'static,
+        V: Send + 'static,
+    {
+        self
+    }
+
+    fn into_fn(self) -> impl FnMut(&T, &U, &V)
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        let self_fn = self.function;
+        move |t, u, v| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t, u, v)
+    }
+
+    fn to_box(&self) -> BoxTriConsumer<T, U, V>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        let self_fn = self.function.clone();
+        BoxTriConsumer::new(move |t, u, v| {
+            self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t, u, v)
+        })
+    }
+
+    fn to_rc(&self) -> RcTriConsumer<T, U, V>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        let self_fn = self.function.clone();
+        RcTriConsumer::new(move |t, u, v| {
+            self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t, u, v)
+        })
+    }
+
+    fn to_arc(&self) -> ArcTriConsumer<T, U, V>
+    where
+        T: Send + 'static,
+        U: Send + 'static,
+        V: Send + 'static,
+    {
+        self.clone()
+    }
+
+    fn to_fn(&self) -> impl FnMut(&T, &U, &V)
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        let self_fn = self.function.clone();
+        move |t, u, v| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t, u, v)
+    }
+}
+
+impl<T, U, V> Clone for ArcTriConsumer<T, U, V> {
+    /// Clones the ArcTriConsumer
+    ///
+    /// Creates a new ArcTriConsumer sharing the underlying function with the
+    /// original instance.
+    fn clone(&self) -> Self {
+        ArcTriConsumer {
+            function: self.function.clone(),
+        }
+    }
+}
+
+impl<T, U, V> fmt::Debug for ArcTriConsumer<T, U, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArcTriConsumer")
+            .field("function", &"<function>")
+            .finish()
+    }
+}
+
+// =======================================================================
+// 4. RcTriConsumer - Single-Threaded Shared Ownership Implementation
+// =======================================================================
+
+/// RcTriConsumer struct
+///
+/// A tri-consumer implementation based on
+/// `Rc<RefCell<dyn FnMut(&T, &U, &V)>>` for single-threaded shared
+/// ownership scenarios. No lock overhead.
+///
+/// # Features
+///
+/// - **Shared Ownership**: Cloneable via `Rc`
+/// - **Single-Threaded**: Not thread-safe
+/// - **Interior Mutability**: Uses `RefCell` for the `FnMut` state
+/// - **Non-Consuming API**: `and_then` borrows `&self`
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{RcTriConsumer, TriConsumer};
+///
+/// let mut count = 0;
+/// let mut consumer = RcTriConsumer::new(move |_: &i32, _: &i32, _: &i32| {
+///     count += 1;
+/// });
+/// let mut clone = consumer.clone();
+/// consumer.accept(&1, &2, &3);
+/// clone.accept(&4, &5, &6);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct RcTriConsumer<T, U, V> {
+    function: Rc<RefCell<TriConsumerFn<T, U, V>>>,
+}
+
+impl<T, U, V> RcTriConsumer<T, U, V>
+where
+    T: 'static,
+    U: 'static,
+    V: 'static,
+{
+    /// Creates a new RcTriConsumer
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `RcTriConsumer<T, U, V>` instance
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnMut(&T, &U, &V) + 'static,
+    {
+        RcTriConsumer {
+            function: Rc::new(RefCell::new(f)),
+        }
+    }
+
+    /// Creates a no-op tri-consumer
+    ///
+    /// # Returns
+    ///
+    /// Returns a tri-consumer that ignores its arguments
+    pub fn noop() -> Self {
+        RcTriConsumer::new(|_, _, _| {})
+    }
+
+    /// Chains another RcTriConsumer in sequence
+    ///
+    /// Returns a new tri-consumer executing the current operation first,
+    /// then the next operation. Borrows &self, does not consume the original
+    /// tri-consumer.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - The tri-consumer to execute after the current operation.
+    ///   **Note: This parameter is passed by reference, so the original
+    ///   tri-consumer remains usable.**
+    ///
+    /// # Returns
+    ///
+    /// Returns a new composed `RcTriConsumer<T, U, V>`
+    pub fn and_then(&self, next: &RcTriConsumer<T, U, V>) -> RcTriConsumer<T, U, V> {
+        let first = Rc::clone(&self.function);
+        let second = Rc::clone(&next.function);
+        RcTriConsumer {
+            function: Rc::new(RefCell::new(move |t: &T, u: &U, v: &V| {
+                first.borrow_mut()(t, u, v);
+                second.borrow_mut()(t, u, v);
+            })),
+        }
+    }
+}
+
+impl<T, U, V> TriConsumer<T, U, V> for RcTriConsumer<T, U, V> {
+    fn accept(&mut self, first: &T, second: &U, third: &V) {
+        (self.function.borrow_mut())(first, second, third)
+    }
+
+    fn into_box(self) -> BoxTriConsumer<T, U, V>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        let self_fn = self.function;
+        BoxTriConsumer::new(move |t, u, v| self_fn.borrow_mut()(t, u, v))
+    }
+
+    fn into_rc(self) -> RcTriConsumer<T, U, V>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        self
+    }
+
+    // do NOT override TriConsumer::into_arc() because RcTriConsumer is not
+    // Send + Sync and calling RcTriConsumer::into_arc() will cause a compile
+    // error
+
+    fn into_fn(self) -> impl FnMut(&T, &U, &V)
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        let self_fn = self.function;
+        move |t, u, v| self_fn.borrow_mut()(t, u, v)
+    }
+
+    fn to_box(&self) -> BoxTriConsumer<T, U, V>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        let self_fn = self.function.clone();
+        BoxTriConsumer::new(move |t, u, v| self_fn.borrow_mut()(t, u, v))
+    }
+
+    fn to_rc(&self) -> RcTriConsumer<T, U, V>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        self.clone()
+    }
+
+    // do NOT override TriConsumer::to_arc() because RcTriConsumer is not
+    // Send + Sync and calling RcTriConsumer::to_arc() will cause a compile
+    // error
+
+    fn to_fn(&self) -> impl FnMut(&T, &U, &V)
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        let self_fn = self.function.clone();
+        move |t, u, v| self_fn.borrow_mut()(t, u, v)
+    }
+}
+
+impl<T, U, V> Clone for RcTriConsumer<T, U, V> {
+    /// Clones the RcTriConsumer
+    ///
+    /// Creates a new RcTriConsumer sharing the underlying function with the
+    /// original instance.
+    fn clone(&self) -> Self {
+        RcTriConsumer {
+            function: self.function.clone(),
+        }
+    }
+}
+
+impl<T, U, V> fmt::Debug for RcTriConsumer<T, U, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RcTriConsumer")
+            .field("function", &"<function>")
+            .finish()
+    }
+}
+
+// =======================================================================
+// 5. Implement TriConsumer trait for closures
+// =======================================================================
+
+/// Implements TriConsumer for all FnMut(&T, &U, &V)
+impl<T, U, V, F> TriConsumer<T, U, V> for F
+where
+    F: FnMut(&T, &U, &V),
+{
+    fn accept(&mut self, first: &T, second: &U, third: &V) {
+        self(first, second, third)
+    }
+
+    fn into_box(self) -> BoxTriConsumer<T, U, V>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        BoxTriConsumer::new(self)
+    }
+
+    fn into_rc(self) -> RcTriConsumer<T, U, V>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        RcTriConsumer::new(self)
+    }
+
+    fn into_arc(self) -> ArcTriConsumer<T, U, V>
+    where
+        Self: Sized + Send + 'static,
+        T: Send + 'static,
+        U: Send + 'static,
+        V: Send + 'static,
+    {
+        ArcTriConsumer::new(self)
+    }
+
+    fn into_fn(self) -> impl FnMut(&T, &U, &V)
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        self
+    }
+
+    fn to_fn(&self) -> impl FnMut(&T, &U, &V)
+    where
+        Self: Sized + Clone + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        self.clone()
+    }
+}
+
+// =======================================================================
+// 6. Provide extension methods for closures
+// =======================================================================
+
+/// Extension trait providing tri-consumer composition methods for closures
+///
+/// Provides `and_then` for all closures implementing `FnMut(&T, &U, &V)`,
+/// enabling direct method chaining on closures without explicit wrapper
+/// types.
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{FnTriConsumerOps, TriConsumer};
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// let log = Rc::new(RefCell::new(Vec::new()));
+/// let (l1, l2) = (log.clone(), log.clone());
+/// let mut chained = (move |x: &i32, _: &i32, _: &i32| l1.borrow_mut().push(*x))
+///     .and_then(move |_: &i32, _: &i32, z: &i32| l2.borrow_mut().push(*z));
+/// chained.accept(&1, &2, &3);
+/// assert_eq!(*log.borrow(), vec![1, 3]);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub trait FnTriConsumerOps<T, U, V>: FnMut(&T, &U, &V) + Sized {
+    /// Chains another tri-consumer in sequence
+    ///
+    /// Returns a new tri-consumer executing the current closure first, then
+    /// the next operation. Consumes the current closure and returns
+    /// `BoxTriConsumer<T, U, V>`.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - The tri-consumer to execute after the current operation.
+    ///   **Note: This parameter is passed by value and will transfer
+    ///   ownership.** Can be a closure or any type implementing
+    ///   `TriConsumer<T, U, V>`
+    ///
+    /// # Returns
+    ///
+    /// Returns the composed `BoxTriConsumer<T, U, V>`
+    fn and_then<C>(self, next: C) -> BoxTriConsumer<T, U, V>
+    where
+        Self: 'static,
+        C: TriConsumer<T, U, V> + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+    {
+        let mut first = self;
+        let mut second = next;
+        BoxTriConsumer::new(move |t, u, v| {
+            first(t, u, v);
+            second.accept(t, u, v);
+        })
+    }
+}
+
+/// Implements FnTriConsumerOps for all closure types
+impl<T, U, V, F> FnTriConsumerOps<T, U, V> for F where F: FnMut(&T, &U, &V) {}
@@ -0,0 +1,900 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # TriTransformer Types
+//!
+//! Provides Rust implementations of tri-transformer traits for value
+//! transformation with three inputs. TriTransformers consume three input
+//! values (taking ownership) and produce an output value.
+//!
+//! This module provides the `TriTransformer<T, U, V, R>` trait and three
+//! implementations:
+//!
+//! - [`BoxTriTransformer`]: Single ownership, not cloneable
+//! - [`ArcTriTransformer`]: Thread-safe shared ownership, cloneable
+//! - [`RcTriTransformer`]: Single-threaded shared ownership, cloneable
+//!
+//! # Author
+//!
+//! Hu Haixing
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::bi_transformer::{BiTransformer, BoxBiTransformer};
+use crate::transformer::Transformer;
+
+// ============================================================================
+// Core Trait
+// ============================================================================
+
+/// TriTransformer trait - transforms three values to produce a result
+///
+/// Defines the behavior of a tri-transformation: converting three values of
+/// types `T`, `U` and `V` to a value of type `R` by consuming the inputs.
+/// This is analogous to `Fn(T, U, V) -> R` in Rust's standard library.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of the first input value (consumed)
+/// * `U` - The type of the second input value (consumed)
+/// * `V` - The type of the third input value (consumed)
+/// * `R` - The type of the output value
+///
+/// # Author
+///
+/// Hu Haixing
+pub trait TriTransformer<T, U, V, R> {
+    /// Transforms three input values to produce an output value
+    ///
+    /// # Parameters
+    ///
+    /// * `first` - The first input value to transform (consumed)
+    /// * `second` - The second input value to transform (consumed)
+    /// * `third` - The third input value to transform (consumed)
+    ///
+    /// # Returns
+    ///
+    /// The transformed output value
+    fn apply(&self, first: T, second: U, third: V) -> R;
+
+    /// Converts to BoxTriTransformer
+    ///
+    /// **⚠️ Consumes `self`**: The original tri-transformer becomes
+    /// unavailable after calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns `BoxTriTransformer<T, U, V, R>`
+    fn into_box(self) -> BoxTriTransformer<T, U, V, R>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        BoxTriTransformer::new(move |x, y, z| self.apply(x, y, z))
+    }
+
+    /// Converts to RcTriTransformer
+    ///
+    /// **⚠️ Consumes `self`**: The original tri-transformer becomes
+    /// unavailable after calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns `RcTriTransformer<T, U, V, R>`
+    fn into_rc(self) -> RcTriTransformer<T, U, V, R>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        RcTriTransformer::new(move |x, y, z| self.apply(x, y, z))
+    }
+
+    /// Converts to ArcTriTransformer
+    ///
+    /// **⚠️ Consumes `self`**: The original tri-transformer becomes
+    /// unavailable after calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns `ArcTriTransformer<T, U, V, R>`
+    fn into_arc(self) -> ArcTriTransformer<T, U, V, R>
+    where
+        Self: Sized + Send + Sync + 'static,
+        T: Send + Sync + 'static,
+        U: Send + Sync + 'static,
+        V: Send + Sync + 'static,
+        R: Send + Sync + 'static,
+    {
+        ArcTriTransformer::new(move |x, y, z| self.apply(x, y, z))
+    }
+
+    /// Converts tri-transformer to a closure
+    ///
+    /// **⚠️ Consumes `self`**: The original tri-transformer becomes
+    /// unavailable after calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns a closure that implements `Fn(T, U, V) -> R`
+    fn into_fn(self) -> impl Fn(T, U, V) -> R
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        move |t, u, v| self.apply(t, u, v)
+    }
+
+    /// Non-consuming conversion to `BoxTriTransformer` using `&self`.
+    ///
+    /// Default implementation clones `self` and delegates to `into_box`.
+    fn to_box(&self) -> BoxTriTransformer<T, U, V, R>
+    where
+        Self: Sized + Clone + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        self.clone().into_box()
+    }
+
+    /// Non-consuming conversion to `RcTriTransformer` using `&self`.
+    ///
+    /// Default implementation clones `self` and delegates to `into_rc`.
+    fn to_rc(&self) -> RcTriTransformer<T, U, V, R>
+    where
+        Self: Sized + Clone + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        self.clone().into_rc()
+    }
+
+    /// Non-consuming conversion to `ArcTriTransformer` using `&self`.
+    ///
+    /// Default implementation clones `self` and delegates to `into_arc`.
+    fn to_arc(&self) -> ArcTriTransformer<T, U, V, R>
+    where
+        Self: Sized + Clone + Send + Sync + 'static,
+        T: Send + Sync + 'static,
+        U: Send + Sync + 'static,
+        V: Send + Sync + 'static,
+        R: Send + Sync + 'static,
+    {
+        self.clone().into_arc()
+    }
+
+    /// Non-consuming conversion to a closure using `&self`.
+    ///
+    /// Default implementation clones `self` and delegates to `into_fn`.
+    fn to_fn(&self) -> impl Fn(T, U, V) -> R
+    where
+        Self: Sized + Clone + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        self.clone().into_fn()
+    }
+}
+
+// ============================================================================
+// BoxTriTransformer - Box<dyn Fn(T, U, V) -> R>
+// ============================================================================
+
+/// BoxTriTransformer - tri-transformer wrapper based on `Box<dyn Fn>`
+///
+/// A tri-transformer wrapper that provides single ownership with reusable
+/// transformation. The tri-transformer consumes all three inputs and can be
+/// called multiple times.
+///
+/// # Features
+///
+/// - **Based on**: `Box<dyn Fn(T, U, V) -> R>`
+/// - **Ownership**: Single ownership, cannot be cloned
+/// - **Reusability**: Can be called multiple times (each call consumes its
+///   inputs)
+/// - **Thread Safety**: Not thread-safe (no `Send + Sync` requirement)
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct BoxTriTransformer<T, U, V, R> {
+    function: Box<dyn Fn(T, U, V) -> R>,
+}
+
+impl<T, U, V, R> BoxTriTransformer<T, U, V, R>
+where
+    T: 'static,
+    U: 'static,
+    V: 'static,
+    R: 'static,
+{
+    /// Creates a new BoxTriTransformer
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure or function to wrap
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTriTransformer, TriTransformer};
+    ///
+    /// let clamp = BoxTriTransformer::new(|x: i32, lo: i32, hi: i32| x.max(lo).min(hi));
+    /// assert_eq!(clamp.apply(42, 0, 10), 10);
+    /// ```
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(T, U, V) -> R + 'static,
+    {
+        BoxTriTransformer {
+            function: Box::new(f),
+        }
+    }
+
+    /// Adapts a bi-transformer into a tri-transformer
+    ///
+    /// Returns a tri-transformer that passes its first two values to
+    /// `transformer` and drops the third one.
+    ///
+    /// # Parameters
+    ///
+    /// * `transformer` - The bi-transformer receiving the first two values
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxTriTransformer<T, U, V, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTriTransformer, TriTransformer};
+    ///
+    /// let add = BoxTriTransformer::from_bi_transformer(|x: i32, y: i32| x + y);
+    /// assert_eq!(add.apply(20, 22, "ignored"), 42);
+    /// ```
+    pub fn from_bi_transformer<F>(transformer: F) -> Self
+    where
+        F: BiTransformer<T, U, R> + 'static,
+    {
+        BoxTriTransformer::new(move |t, u, _| transformer.apply(t, u))
+    }
+
+    /// Chain composition - applies self first, then after
+    ///
+    /// Creates a new tri-transformer that applies this tri-transformer first,
+    /// then applies the after transformer to the result. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `after` - The transformer to apply after self. **Note: This
+    ///   parameter is passed by value and will transfer ownership.** Can be:
+    ///   - A closure: `|x: R| -> S`
+    ///   - A `BoxTransformer<R, S>`
+    ///   - An `RcTransformer<R, S>`
+    ///   - An `ArcTransformer<R, S>`
+    ///   - Any type implementing `Transformer<R, S>`
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxTriTransformer<T, U, V, S>` representing the composition
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTransformer, BoxTriTransformer, TriTransformer};
+    ///
+    /// let sum = BoxTriTransformer::new(|x: i32, y: i32, z: i32| x + y + z);
+    /// let composed = sum.and_then(BoxTransformer::new(|s: i32| s.to_string()));
+    /// assert_eq!(composed.apply(1, 2, 3), "6");
+    /// ```
+    pub fn and_then<S, F>(self, after: F) -> BoxTriTransformer<T, U, V, S>
+    where
+        S: 'static,
+        F: Transformer<R, S> + 'static,
+    {
+        let self_fn = self.function;
+        BoxTriTransformer::new(move |t: T, u: U, v: V| after.apply(self_fn(t, u, v)))
+    }
+
+    /// Fixes the third argument, producing a bi-transformer
+    ///
+    /// Returns a bi-transformer that passes its two values together with a
+    /// clone of `third` to this tri-transformer. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `third` - The value passed as third argument on every call
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxBiTransformer<T, U, R>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BiTransformer, BoxTriTransformer};
+    ///
+    /// let clamp = BoxTriTransformer::new(|x: i32, lo: i32, hi: i32| x.max(lo).min(hi));
+    /// let clamp_to_100 = clamp.bind_third(100);
+    /// assert_eq!(clamp_to_100.apply(250, 0), 100);
+    /// assert_eq!(clamp_to_100.apply(-5, 0), 0);
+    /// ```
+    pub fn bind_third(self, third: V) -> BoxBiTransformer<T, U, R>
+    where
+        V: Clone,
+    {
+        let self_fn = self.function;
+        BoxBiTransformer::new(move |t, u| self_fn(t, u, third.clone()))
+    }
+}
+
+impl<T, U, V, R> TriTransformer<T, U, V, R> for BoxTriTransformer<T, U, V, R> {
+    fn apply(&self, first: T, second: U, third: V) -> R {
+        (self.function)(first, second, third)
+    }
+
+    fn into_box(self) -> BoxTriTransformer<T, U, V, R>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        // Zero-cost: directly return itself
+        self
+    }
+
+    fn into_rc(self) -> RcTriTransformer<T, U, V, R>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        RcTriTransformer::new(move |t, u, v| (self.function)(t, u, v))
+    }
+
+    // do NOT override BoxTriTransformer::into_arc() because BoxTriTransformer is not Send + Sync
+    // and calling BoxTriTransformer::into_arc() will cause a compile error
+
+    fn into_fn(self) -> impl Fn(T, U, V) -> R
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        move |t: T, u: U, v: V| (self.function)(t, u, v)
+    }
+
+    // do NOT override BoxTriTransformer::to_xxx() because BoxTriTransformer is not Clone
+    // and calling BoxTriTransformer::to_xxx() will cause a compile error
+}
+
+// ============================================================================
+// ArcTriTransformer - Arc<dyn Fn(T, U, V) -> R + Send + Sync>
+// ============================================================================
+
+/// ArcTriTransformer - thread-safe tri-transformer wrapper
+///
+/// A thread-safe, clonable tri-transformer wrapper suitable for
+/// multi-threaded scenarios.
+///
+/// # Features
+///
+/// - **Based on**: `Arc<dyn Fn(T, U, V) -> R + Send + Sync>`
+/// - **Ownership**: Shared ownership via reference counting
+/// - **Reusability**: Can be called multiple times (each call consumes its
+///   inputs)
+/// - **Thread Safety**: Thread-safe (`Send + Sync` required)
+/// - **Clonable**: Cheap cloning via `Arc::clone`
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct ArcTriTransformer<T, U, V, R> {
+    function: Arc<dyn Fn(T, U, V) -> R + Send + Sync>,
+}
+
+impl<T, U, V, R> ArcTriTransformer<T, U, V, R>
+where
+    T: Send + Sync + 'static,
+    U: Send + Sync + 'static,
+    V: Send + Sync + 'static,
+    R: 'static,
+{
+    /// Creates a new ArcTriTransformer
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure or function to wrap (must be Send + Sync)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcTriTransformer, TriTransformer};
+    /// use std::thread;
+    ///
+    /// let sum = ArcTriTransformer::new(|x: i32, y: i32, z: i32| x + y + z);
+    /// let clone = sum.clone();
+    /// let handle = thread::spawn(move || clone.apply(1, 2, 3));
+    /// assert_eq!(handle.join().unwrap(), 6);
+    /// assert_eq!(sum.apply(4, 5, 6), 15);
+    /// ```
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(T, U, V) -> R + Send + Sync + 'static,
+    {
+        ArcTriTransformer {
+            function: Arc::new(f),
+        }
+    }
+
+    /// Chain composition - applies self first, then after
+    ///
+    /// Creates a new tri-transformer that applies this tri-transformer first,
+    /// then applies the after transformer to the result. Uses &self, so the
+    /// original tri-transformer remains usable.
+    ///
+    /// # Parameters
+    ///
+    /// * `after` - The transformer to apply after self. Must be
+    ///   `Send + Sync`.
+    ///
+    /// # Returns
+    ///
+    /// A new `ArcTriTransformer<T, U, V, S>` representing the composition
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{ArcTransformer, ArcTriTransformer, TriTransformer};
+    ///
+    /// let sum = ArcTriTransformer::new(|x: i32, y: i32, z: i32| x + y + z);
+    /// let doubled = sum.and_then(ArcTransformer::new(|s: i32| s * 2));
+    /// assert_eq!(doubled.apply(1, 2, 3), 12);
+    /// assert_eq!(sum.apply(1, 2, 3), 6);
+    /// ```
+    pub fn and_then<S, F>(&self, after: F) -> ArcTriTransformer<T, U, V, S>
+    where
+        S: Send + Sync + 'static,
+        F: Transformer<R, S> + Send + Sync + 'static,
+    {
+        let self_clone = Arc::clone(&self.function);
+        ArcTriTransformer {
+            function: Arc::new(move |t: T, u: U, v: V| after.apply(self_clone(t, u, v))),
+        }
+    }
+}
+
+impl<T, U, V, R> TriTransformer<T, U, V, R> for ArcTriTransformer<T, U, V, R> {
+    fn apply(&self, first: T, second: U, third: V) -> R {
+        (self.function)(first, second, third)
+    }
+
+    fn into_box(self) -> BoxTriTransformer<T, U, V, R>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        BoxTriTransformer::new(move |t, u, v| (self.function)(t, u, v))
+    }
+
+    fn into_rc(self) -> RcTriTransformer<T, U, V, R>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        RcTriTransformer::new(move |t, u, v| (self.function)(t, u, v))
+    }
+
+    fn into_arc(self) -> ArcTriTransformer<T, U, V, R>
+    where
+        T: Send + Sync + 'static,
+        U: Send + Sync + 'static,
+        V: Send + Sync + 'static,
+        R: Send + Sync + 'static,
+    {
+        // Zero-cost: directly return itself
+        self
+    }
+
+    fn into_fn(self) -> impl Fn(T, U, V) -> R
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        move |t: T, u: U, v: V| (self.function)(t, u, v)
+    }
+
+    fn to_box(&self) -> BoxTriTransformer<T, U, V, R>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        let self_fn = self.function.clone();
+        BoxTriTransformer::new(move |t, u, v| self_fn(t, u, v))
+    }
+
+    fn to_rc(&self) -> RcTriTransformer<T, U, V, R>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        let self_fn = self.function.clone();
+        RcTriTransformer::new(move |t, u, v| self_fn(t, u, v))
+    }
+
+    fn to_arc(&self) -> ArcTriTransformer<T, U, V, R>
+    where
+        T: Send + Sync + 'static,
+        U: Send + Sync + 'static,
+        V: Send + Sync + 'static,
+        R: Send + Sync + 'static,
+    {
+        self.clone()
+    }
+
+    fn to_fn(&self) -> impl Fn(T, U, V) -> R
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        let self_fn = self.function.clone();
+        move |t: T, u: U, v: V| self_fn(t, u, v)
+    }
+}
+
+impl<T, U, V, R> Clone for ArcTriTransformer<T, U, V, R> {
+    fn clone(&self) -> Self {
+        ArcTriTransformer {
+            function: Arc::clone(&self.function),
+        }
+    }
+}
+
+// ============================================================================
+// RcTriTransformer - Rc<dyn Fn(T, U, V) -> R>
+// ============================================================================
+
+/// RcTriTransformer - single-threaded tri-transformer wrapper
+///
+/// A single-threaded, clonable tri-transformer wrapper optimized for
+/// scenarios that require sharing without thread-safety overhead.
+///
+/// # Features
+///
+/// - **Based on**: `Rc<dyn Fn(T, U, V) -> R>`
+/// - **Ownership**: Shared ownership via reference counting (non-atomic)
+/// - **Reusability**: Can be called multiple times (each call consumes its
+///   inputs)
+/// - **Thread Safety**: Not thread-safe (no `Send + Sync`)
+/// - **Clonable**: Cheap cloning via `Rc::clone`
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct RcTriTransformer<T, U, V, R> {
+    function: Rc<dyn Fn(T, U, V) -> R>,
+}
+
+impl<T, U, V, R> RcTriTransformer<T, U, V, R>
+where
+    T: 'static,
+    U: 'static,
+    V: 'static,
+    R: 'static,
+{
+    /// Creates a new RcTriTransformer
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure or function to wrap
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{RcTriTransformer, TriTransformer};
+    ///
+    /// let volume = RcTriTransformer::new(|w: u32, h: u32, d: u32| w * h * d);
+    /// let clone = volume.clone();
+    /// assert_eq!(volume.apply(2, 3, 4), 24);
+    /// assert_eq!(clone.apply(1, 1, 1), 1);
+    /// ```
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(T, U, V) -> R + 'static,
+    {
+        RcTriTransformer {
+            function: Rc::new(f),
+        }
+    }
+
+    /// Chain composition - applies self first, then after
+    ///
+    /// Creates a new tri-transformer that applies this tri-transformer first,
+    /// then applies the after transformer to the result. Uses &self, so the
+    /// original tri-transformer remains usable.
+    ///
+    /// # Parameters
+    ///
+    /// * `after` - The transformer to apply after self
+    ///
+    /// # Returns
+    ///
+    /// A new `RcTriTransformer<T, U, V, S>` representing the composition
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{RcTriTransformer, TriTransformer};
+    ///
+    /// let volume = RcTriTransformer::new(|w: u32, h: u32, d: u32| w * h * d);
+    /// let label = volume.and_then(|v: u32| format!("{} m3", v));
+    /// assert_eq!(label.apply(2, 3, 4), "24 m3");
+    /// assert_eq!(volume.apply(2, 3, 4), 24);
+    /// ```
+    pub fn and_then<S, F>(&self, after: F) -> RcTriTransformer<T, U, V, S>
+    where
+        S: 'static,
+        F: Transformer<R, S> + 'static,
+    {
+        let self_clone = Rc::clone(&self.function);
+        RcTriTransformer {
+            function: Rc::new(move |t: T, u: U, v: V| after.apply(self_clone(t, u, v))),
+        }
+    }
+}
+
+impl<T, U, V, R> TriTransformer<T, U, V, R> for RcTriTransformer<T, U, V, R> {
+    fn apply(&self, first: T, second: U, third: V) -> R {
+        (self.function)(first, second, third)
+    }
+
+    fn into_box(self) -> BoxTriTransformer<T, U, V, R>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        BoxTriTransformer::new(move |t, u, v| (self.function)(t, u, v))
+    }
+
+    fn into_rc(self) -> RcTriTransformer<T, U, V, R>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        // Zero-cost: directly return itself
+        self
+    }
+
+    // do NOT override RcTriTransformer::into_arc() because RcTriTransformer is not Send + Sync
+    // and calling RcTriTransformer::into_arc() will cause a compile error
+
+    fn into_fn(self) -> impl Fn(T, U, V) -> R
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        move |t: T, u: U, v: V| (self.function)(t, u, v)
+    }
+
+    fn to_box(&self) -> BoxTriTransformer<T, U, V, R>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        let self_fn = self.function.clone();
+        BoxTriTransformer::new(move |t, u, v| self_fn(t, u, v))
+    }
+
+    fn to_rc(&self) -> RcTriTransformer<T, U, V, R>
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        self.clone()
+    }
+
+    // do NOT override RcTriTransformer::to_arc() because RcTriTransformer is not Send + Sync
+    // and calling RcTriTransformer::to_arc() will cause a compile error
+
+    fn to_fn(&self) -> impl Fn(T, U, V) -> R
+    where
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        let self_fn = self.function.clone();
+        move |t: T, u: U, v: V| self_fn(t, u, v)
+    }
+}
+
+impl<T, U, V, R> Clone for RcTriTransformer<T, U, V, R> {
+    fn clone(&self) -> Self {
+        RcTriTransformer {
+            function: Rc::clone(&self.function),
+        }
+    }
+}
+
+// ============================================================================
+// Blanket implementation for standard Fn trait
+// ============================================================================
+
+/// Implement TriTransformer<T, U, V, R> for any type that implements
+/// Fn(T, U, V) -> R
+///
+/// This allows closures and function pointers to be used directly with our
+/// TriTransformer trait without wrapping.
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::TriTransformer;
+///
+/// fn mul_add(a: i32, b: i32, c: i32) -> i32 { a * b + c }
+///
+/// assert_eq!(mul_add.apply(6, 7, 0), 42);
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+impl<F, T, U, V, R> TriTransformer<T, U, V, R> for F
+where
+    F: Fn(T, U, V) -> R,
+    T: 'static,
+    U: 'static,
+    V: 'static,
+    R: 'static,
+{
+    fn apply(&self, first: T, second: U, third: V) -> R {
+        self(first, second, third)
+    }
+
+    fn into_box(self) -> BoxTriTransformer<T, U, V, R>
+    where
+        Self: Sized + 'static,
+    {
+        BoxTriTransformer::new(self)
+    }
+
+    fn into_rc(self) -> RcTriTransformer<T, U, V, R>
+    where
+        Self: Sized + 'static,
+    {
+        RcTriTransformer::new(self)
+    }
+
+    fn into_arc(self) -> ArcTriTransformer<T, U, V, R>
+    where
+        Self: Sized + Send + Sync + 'static,
+        T: Send + Sync + 'static,
+        U: Send + Sync + 'static,
+        V: Send + Sync + 'static,
+        R: Send + Sync + 'static,
+    {
+        ArcTriTransformer::new(self)
+    }
+
+    fn into_fn(self) -> impl Fn(T, U, V) -> R
+    where
+        Self: Sized + 'static,
+    {
+        move |t: T, u: U, v: V| self(t, u, v)
+    }
+
+    fn to_fn(&self) -> impl Fn(T, U, V) -> R
+    where
+        Self: Sized + Clone + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        self.clone()
+    }
+}
+
+// ============================================================================
+// FnTriTransformerOps - Extension trait for Fn(T, U, V) -> R tri-transformers
+// ============================================================================
+
+/// Extension trait for closures implementing `Fn(T, U, V) -> R`
+///
+/// Provides `and_then` for tri-transformer closures and function pointers
+/// without requiring explicit wrapping in `BoxTriTransformer`.
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{FnTriTransformerOps, TriTransformer};
+///
+/// let sum = |x: i32, y: i32, z: i32| x + y + z;
+/// let composed = sum.and_then(|s: i32| s * 2);
+/// assert_eq!(composed.apply(1, 2, 3), 12);
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub trait FnTriTransformerOps<T, U, V, R>: Fn(T, U, V) -> R + Sized + 'static {
+    /// Chain composition - applies self first, then after
+    ///
+    /// Creates a new tri-transformer that applies this closure first, then
+    /// applies the after transformer to the result. Consumes self and
+    /// returns a `BoxTriTransformer`.
+    ///
+    /// # Parameters
+    ///
+    /// * `after` - The transformer to apply after self
+    ///
+    /// # Returns
+    ///
+    /// A new `BoxTriTransformer<T, U, V, S>` representing the composition
+    fn and_then<S, F>(self, after: F) -> BoxTriTransformer<T, U, V, S>
+    where
+        S: 'static,
+        F: Transformer<R, S> + 'static,
+        T: 'static,
+        U: 'static,
+        V: 'static,
+        R: 'static,
+    {
+        BoxTriTransformer::new(move |t: T, u: U, v: V| after.apply(self(t, u, v)))
+    }
+}
+
+/// Blanket implementation of FnTriTransformerOps for all closures
+///
+/// Automatically implements `FnTriTransformerOps<T, U, V, R>` for any type
+/// that implements `Fn(T, U, V) -> R`.
+///
+/// # Author
+///
+/// Hu Haixing
+impl<T, U, V, R, F> FnTriTransformerOps<T, U, V, R> for F where F: Fn(T, U, V) -> R + 'static {}
@@ -0,0 +1,843 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # TryConsumer Types
+//!
+//! Provides fallible consumer interface implementations for operations that
+//! accept a single input parameter and may fail, e.g. writing to a socket or
+//! inserting into a database.
+//!
+//! This module provides a unified `TryConsumer` trait and three concrete
+//! implementations based on different ownership models:
+//!
+//! - **`BoxTryConsumer<T, E>`**: Box-based single ownership for one-time use
+//! - **`ArcTryConsumer<T, E>`**: Arc<Mutex<>>-based thread-safe shared
+//!   ownership
+//! - **`RcTryConsumer<T, E>`**: Rc<RefCell<>>-based single-threaded shared
+//!   ownership
+//!
+//! A try-consumer is the fallible counterpart of
+//! [`Consumer`](crate::Consumer): it observes a value through
+//! `FnMut(&T) -> Result<(), E>`. Chains built with `and_then` stop at the
+//! first error, and `or_log` turns a try-consumer back into an infallible
+//! consumer by routing errors to a handler.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::consumer::{ArcConsumer, BoxConsumer, Consumer, RcConsumer};
+
+/// Type alias for try-consumer function signature
+type TryConsumerFn<T, E> = dyn FnMut(&T) -> Result<(), E>;
+
+/// Type alias for thread-safe try-consumer function signature
+type SendTryConsumerFn<T, E> = dyn FnMut(&T) -> Result<(), E> + Send;
+
+// =======================================================================
+// 1. TryConsumer Trait - Unified TryConsumer Interface
+// =======================================================================
+
+/// TryConsumer trait - Unified fallible consumer interface
+///
+/// Defines the core behavior of all try-consumer types. Performs an
+/// operation that accepts a reference and reports failure through a
+/// `Result`.
+///
+/// This trait is automatically implemented by:
+/// - All closures implementing `FnMut(&T) -> Result<(), E>`
+/// - `BoxTryConsumer<T, E>`, `ArcTryConsumer<T, E>` and
+///   `RcTryConsumer<T, E>`
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BoxTryConsumer, TryConsumer};
+///
+/// fn feed<C: TryConsumer<i32, String>>(consumer: &mut C) -> Result<(), String> {
+///     consumer.try_accept(&1)?;
+///     consumer.try_accept(&-1)
+/// }
+///
+/// let mut positive = BoxTryConsumer::new(|x: &i32| {
+///     if *x > 0 { Ok(()) } else { Err(format!("{} is not positive", x)) }
+/// });
+/// assert_eq!(feed(&mut positive), Err("-1 is not positive".to_string()));
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub trait TryConsumer<T, E> {
+    /// Performs the fallible consumption operation
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - Reference to the value to consume
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` on success, or the error describing the failure
+    fn try_accept(&mut self, value: &T) -> Result<(), E>;
+
+    /// Converts to BoxTryConsumer
+    ///
+    /// **⚠️ Consumes `self`**: Original try-consumer becomes unavailable
+    /// after calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `BoxTryConsumer<T, E>`
+    fn into_box(self) -> BoxTryConsumer<T, E>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        E: 'static,
+    {
+        let mut consumer = self;
+        BoxTryConsumer::new(move |t| consumer.try_accept(t))
+    }
+
+    /// Converts to RcTryConsumer
+    ///
+    /// **⚠️ Consumes `self`**: Original try-consumer becomes unavailable
+    /// after calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `RcTryConsumer<T, E>`
+    fn into_rc(self) -> RcTryConsumer<T, E>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        E: 'static,
+    {
+        let mut consumer = self;
+        RcTryConsumer::new(move |t| consumer.try_accept(t))
+    }
+
+    /// Converts to ArcTryConsumer
+    ///
+    /// **⚠️ Consumes `self`**: Original try-consumer becomes unavailable
+    /// after calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns the wrapped `ArcTryConsumer<T, E>`
+    fn into_arc(self) -> ArcTryConsumer<T, E>
+    where
+        Self: Sized + Send + 'static,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        let mut consumer = self;
+        ArcTryConsumer::new(move |t| consumer.try_accept(t))
+    }
+
+    /// Converts try-consumer to a closure
+    ///
+    /// **⚠️ Consumes `self`**: Original try-consumer becomes unavailable
+    /// after calling this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns a closure implementing `FnMut(&T) -> Result<(), E>`
+    fn into_fn(self) -> impl FnMut(&T) -> Result<(), E>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        E: 'static,
+    {
+        let mut consumer = self;
+        move |t| consumer.try_accept(t)
+    }
+}
+
+// =======================================================================
+// 2. BoxTryConsumer - Single Ownership Implementation
+// =======================================================================
+
+/// BoxTryConsumer struct
+///
+/// A try-consumer implementation based on
+/// `Box<dyn FnMut(&T) -> Result<(), E>>` for single ownership scenarios.
+///
+/// # Features
+///
+/// - **Single Ownership**: Not cloneable, ownership moves on use
+/// - **Zero Overhead**: No reference counting or locking
+/// - **Short-Circuiting**: `and_then` chains stop at the first error
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BoxTryConsumer, TryConsumer};
+///
+/// let mut written = Vec::new();
+/// let mut sink = BoxTryConsumer::new(move |line: &String| {
+///     if written.len() >= 2 {
+///         return Err("buffer full");
+///     }
+///     written.push(line.clone());
+///     Ok(())
+/// });
+/// assert_eq!(sink.try_accept(&"a".to_string()), Ok(()));
+/// assert_eq!(sink.try_accept(&"b".to_string()), Ok(()));
+/// assert_eq!(sink.try_accept(&"c".to_string()), Err("buffer full"));
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct BoxTryConsumer<T, E> {
+    function: Box<TryConsumerFn<T, E>>,
+}
+
+impl<T, E> BoxTryConsumer<T, E>
+where
+    T: 'static,
+    E: 'static,
+{
+    /// Creates a new BoxTryConsumer
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxTryConsumer<T, E>` instance
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnMut(&T) -> Result<(), E> + 'static,
+    {
+        BoxTryConsumer {
+            function: Box::new(f),
+        }
+    }
+
+    /// Creates a try-consumer from an infallible consumer
+    ///
+    /// The returned try-consumer passes every value to `consumer` and always
+    /// succeeds.
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The consumer to wrap. Can be a closure, a
+    ///   `BoxConsumer<T>`, `RcConsumer<T>`, `ArcConsumer<T>` or any type
+    ///   implementing `Consumer<T>`
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxTryConsumer<T, E>`
+    pub fn from_consumer<C>(consumer: C) -> Self
+    where
+        C: Consumer<T> + 'static,
+    {
+        let mut consumer = consumer;
+        BoxTryConsumer::new(move |t| {
+            consumer.accept(t);
+            Ok(())
+        })
+    }
+
+    /// Chains another try-consumer in sequence
+    ///
+    /// Returns a new try-consumer executing the current operation first,
+    /// then the next operation. If the current operation fails, `next` is
+    /// not called and the error is returned. Consumes self.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - The try-consumer to execute after the current operation.
+    ///   **Note: This parameter is passed by value and will transfer
+    ///   ownership.** Can be:
+    ///   - A closure: `|x: &T| -> Result<(), E>`
+    ///   - A `BoxTryConsumer<T, E>`
+    ///   - An `ArcTryConsumer<T, E>`
+    ///   - An `RcTryConsumer<T, E>`
+    ///   - Any type implementing `TryConsumer<T, E>`
+    ///
+    /// # Returns
+    ///
+    /// Returns a new composed `BoxTryConsumer<T, E>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTryConsumer, TryConsumer};
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// let log = Rc::new(RefCell::new(Vec::new()));
+    /// let l = log.clone();
+    /// let mut chained = BoxTryConsumer::new(|x: &i32| {
+    ///     if *x >= 0 { Ok(()) } else { Err("negative") }
+    /// })
+    /// .and_then(move |x: &i32| {
+    ///     l.borrow_mut().push(*x);
+    ///     Ok(())
+    /// });
+    /// assert_eq!(chained.try_accept(&1), Ok(()));
+    /// assert_eq!(chained.try_accept(&-1), Err("negative"));
+    /// assert_eq!(*log.borrow(), vec![1]);
+    /// ```
+    pub fn and_then<C>(self, next: C) -> Self
+    where
+        C: TryConsumer<T, E> + 'static,
+    {
+        let mut first = self.function;
+        let mut second = next;
+        BoxTryConsumer::new(move |t| {
+            first(t)?;
+            second.try_accept(t)
+        })
+    }
+
+    /// Converts into an infallible consumer reporting errors to a handler
+    ///
+    /// Returns a consumer passing every value to this try-consumer; each
+    /// error is passed by reference to `handler` and then dropped. Consumes
+    /// self.
+    ///
+    /// # Parameters
+    ///
+    /// * `handler` - Consumer receiving the errors. Can be a closure
+    ///   `|e: &E|` or any type implementing `Consumer<E>`
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `BoxConsumer<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use prism3_function::{BoxTryConsumer, Consumer};
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// let errors = Rc::new(RefCell::new(Vec::new()));
+    /// let e = errors.clone();
+    /// let mut consumer = BoxTryConsumer::new(|x: &i32| {
+    ///     if *x % 2 == 0 { Ok(()) } else { Err(format!("{} is odd", x)) }
+    /// })
+    /// .or_log(move |err: &String| e.borrow_mut().push(err.clone()));
+    /// consumer.accept(&2);
+    /// consumer.accept(&3);
+    /// assert_eq!(*errors.borrow(), vec!["3 is odd"]);
+    /// ```
+    pub fn or_log<C>(self, handler: C) -> BoxConsumer<T>
+    where
+        C: Consumer<E> + 'static,
+    {
+        let mut function = self.function;
+        let mut handler = handler;
+        BoxConsumer::new(move |t| {
+            if let Err(e) = function(t) {
+                handler.accept(&e);
+            }
+        })
+    }
+}
+
+impl<T, E> TryConsumer<T, E> for BoxTryConsumer<T, E> {
+    fn try_accept(&mut self, value: &T) -> Result<(), E> {
+        (self.function)(value)
+    }
+
+    fn into_box(self) -> BoxTryConsumer<T, E>
+    where
+        T: 'static,
+        E: 'static,
+    {
+        self
+    }
+
+    fn into_rc(self) -> RcTryConsumer<T, E>
+    where
+        T: 'static,
+        E: 'static,
+    {
+        let mut func = self.function;
+        RcTryConsumer::new(move |t| func(t))
+    }
+
+    // do NOT override TryConsumer::into_arc() because BoxTryConsumer is not
+    // Send + Sync and calling BoxTryConsumer::into_arc() will cause a compile
+    // error
+
+    fn into_fn(self) -> impl FnMut(&T) -> Result<(), E>
+    where
+        T: 'static,
+        E: 'static,
+    {
+        self.function
+    }
+}
+
+impl<T, E> fmt::Debug for BoxTryConsumer<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxTryConsumer")
+            .field("function", &"<function>")
+            .finish()
+    }
+}
+
+// =======================================================================
+// 3. ArcTryConsumer - Thread-Safe Shared Ownership Implementation
+// =======================================================================
+
+/// ArcTryConsumer struct
+///
+/// A try-consumer implementation based on
+/// `Arc<Mutex<dyn FnMut(&T) -> Result<(), E> + Send>>` for thread-safe
+/// shared ownership scenarios. Clones share the same underlying function and
+/// its captured state.
+///
+/// # Features
+///
+/// - **Shared Ownership**: Cloneable via `Arc`
+/// - **Thread Safe**: Implements `Send + Sync`
+/// - **Interior Mutability**: Uses `Mutex` for the `FnMut` state, recovering
+///   from poisoning so one panicking call does not break the clones
+/// - **Non-Consuming API**: `and_then` and `or_log` borrow `&self`
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{ArcTryConsumer, TryConsumer};
+/// use std::thread;
+///
+/// let consumer = ArcTryConsumer::new(|x: &i32| {
+///     if *x < 100 { Ok(()) } else { Err("too large") }
+/// });
+/// let mut clone = consumer.clone();
+/// let result = thread::spawn(move || clone.try_accept(&500)).join().unwrap();
+/// assert_eq!(result, Err("too large"));
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct ArcTryConsumer<T, E> {
+    function: Arc<Mutex<SendTryConsumerFn<T, E>>>,
+}
+
+impl<T, E> ArcTryConsumer<T, E>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    /// Creates a new ArcTryConsumer
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `ArcTryConsumer<T, E>` instance
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnMut(&T) -> Result<(), E> + Send + 'static,
+    {
+        ArcTryConsumer {
+            function: Arc::new(Mutex::new(f)),
+        }
+    }
+
+    /// Creates a try-consumer from an infallible consumer
+    ///
+    /// The returned try-consumer passes every value to `consumer` and always
+    /// succeeds.
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The thread-safe consumer to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `ArcTryConsumer<T, E>`
+    pub fn from_consumer<C>(consumer: C) -> Self
+    where
+        C: Consumer<T> + Send + 'static,
+    {
+        let mut consumer = consumer;
+        ArcTryConsumer::new(move |t| {
+            consumer.accept(t);
+            Ok(())
+        })
+    }
+
+    /// Chains another ArcTryConsumer in sequence
+    ///
+    /// Returns a new try-consumer executing the current operation first,
+    /// then the next operation. If the current operation fails, `next` is
+    /// not called and the error is returned. Borrows &self, does not consume
+    /// the original try-consumer.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - The try-consumer to execute after the current operation.
+    ///   **Note: This parameter is passed by reference, so the original
+    ///   try-consumer remains usable.**
+    ///
+    /// # Returns
+    ///
+    /// Returns a new composed `ArcTryConsumer<T, E>`
+    pub fn and_then(&self, next: &ArcTryConsumer<T, E>) -> ArcTryConsumer<T, E> {
+        let first = Arc::clone(&self.function);
+        let second = Arc::clone(&next.function);
+        ArcTryConsumer {
+            function: Arc::new(Mutex::new(move |t: &T| {
+                first.lock().unwrap_or_else(PoisonError::into_inner)(t)?;
+                second.lock().unwrap_or_else(PoisonError::into_inner)(t)
+            })),
+        }
+    }
+
+    /// Converts into an infallible consumer reporting errors to a handler
+    ///
+    /// Returns a consumer sharing this try-consumer's function; each error
+    /// is passed by reference to `handler` and then dropped. Borrows &self.
+    ///
+    /// # Parameters
+    ///
+    /// * `handler` - Thread-safe consumer receiving the errors
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `ArcConsumer<T>`
+    pub fn or_log<C>(&self, handler: C) -> ArcConsumer<T>
+    where
+        C: Consumer<E> + Send + 'static,
+    {
+        let function = Arc::clone(&self.function);
+        let mut handler = handler;
+        ArcConsumer::new(move |t: &T| {
+            let result = function.lock().unwrap_or_else(PoisonError::into_inner)(t);
+            if let Err(e) = result {
+                handler.accept(&e);
+            }
+        })
+    }
+}
+
+impl<T, E> TryConsumer<T, E> for ArcTryConsumer<T, E> {
+    fn try_accept(&mut self, value: &T) -> Result<(), E> {
+        (self.function.lock().unwrap_or_else(PoisonError::into_inner))(value)
+    }
+
+    fn into_box(self) -> BoxTryConsumer<T, E>
+    where
+        T: 'static,
+        E: 'static,
+    {
+        let self_fn = self.function;
+        BoxTryConsumer::new(move |t| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t))
+    }
+
+    fn into_rc(self) -> RcTryConsumer<T, E>
+    where
+        T: 'static,
+        E: 'static,
+    {
+        let self_fn = self.function;
+        RcTryConsumer::new(move |t| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t))
+    }
+
+    fn into_arc(self) -> ArcTryConsumer<T, E>
+    where
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        self
+    }
+
+    fn into_fn(self) -> impl FnMut(&T) -> Result<(), E>
+    where
+        T: 'static,
+        E: 'static,
+    {
+        let self_fn = self.function;
+        move |t| self_fn.lock().unwrap_or_else(PoisonError::into_inner)(t)
+    }
+}
+
+impl<T, E> Clone for ArcTryConsumer<T, E> {
+    /// Clones the ArcTryConsumer
+    ///
+    /// Creates a new ArcTryConsumer sharing the underlying function with the
+    /// original instance.
+    fn clone(&self) -> Self {
+        ArcTryConsumer {
+            function: self.function.clone(),
+        }
+    }
+}
+
+impl<T, E> fmt::Debug for ArcTryConsumer<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArcTryConsumer")
+            .field("function", &"<function>")
+            .finish()
+    }
+}
+
+// =======================================================================
+// 4. RcTryConsumer - Single-Threaded Shared Ownership Implementation
+// =======================================================================
+
+/// RcTryConsumer struct
+///
+/// A try-consumer implementation based on
+/// `Rc<RefCell<dyn FnMut(&T) -> Result<(), E>>>` for single-threaded shared
+/// ownership scenarios. No lock overhead.
+///
+/// # Features
+///
+/// - **Shared Ownership**: Cloneable via `Rc`
+/// - **Single-Threaded**: Not thread-safe
+/// - **Interior Mutability**: Uses `RefCell` for the `FnMut` state
+/// - **Non-Consuming API**: `and_then` and `or_log` borrow `&self`
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{RcTryConsumer, TryConsumer};
+///
+/// let mut remaining = 1;
+/// let mut consumer = RcTryConsumer::new(move |_: &i32| {
+///     if remaining == 0 {
+///         return Err("quota exceeded");
+///     }
+///     remaining -= 1;
+///     Ok(())
+/// });
+/// let mut clone = consumer.clone();
+/// assert_eq!(consumer.try_accept(&1), Ok(()));
+/// assert_eq!(clone.try_accept(&2), Err("quota exceeded"));
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct RcTryConsumer<T, E> {
+    function: Rc<RefCell<TryConsumerFn<T, E>>>,
+}
+
+impl<T, E> RcTryConsumer<T, E>
+where
+    T: 'static,
+    E: 'static,
+{
+    /// Creates a new RcTryConsumer
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - The closure to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `RcTryConsumer<T, E>` instance
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnMut(&T) -> Result<(), E> + 'static,
+    {
+        RcTryConsumer {
+            function: Rc::new(RefCell::new(f)),
+        }
+    }
+
+    /// Creates a try-consumer from an infallible consumer
+    ///
+    /// The returned try-consumer passes every value to `consumer` and always
+    /// succeeds.
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The consumer to wrap
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `RcTryConsumer<T, E>`
+    pub fn from_consumer<C>(consumer: C) -> Self
+    where
+        C: Consumer<T> + 'static,
+    {
+        let mut consumer = consumer;
+        RcTryConsumer::new(move |t| {
+            consumer.accept(t);
+            Ok(())
+        })
+    }
+
+    /// Chains another RcTryConsumer in sequence
+    ///
+    /// Returns a new try-consumer executing the current operation first,
+    /// then the next operation. If the current operation fails, `next` is
+    /// not called and the error is returned. Borrows &self, does not consume
+    /// the original try-consumer.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - The try-consumer to execute after the current operation.
+    ///   **Note: This parameter is passed by reference, so the original
+    ///   try-consumer remains usable.**
+    ///
+    /// # Returns
+    ///
+    /// Returns a new composed `RcTryConsumer<T, E>`
+    pub fn and_then(&self, next: &RcTryConsumer<T, E>) -> RcTryConsumer<T, E> {
+        let first = Rc::clone(&self.function);
+        let second = Rc::clone(&next.function);
+        RcTryConsumer {
+            function: Rc::new(RefCell::new(move |t: &T| {
+                first.borrow_mut()(t)?;
+                second.borrow_mut()(t)
+            })),
+        }
+    }
+
+    /// Converts into an infallible consumer reporting errors to a handler
+    ///
+    /// Returns a consumer sharing this try-consumer's function; each error
+    /// is passed by reference to `handler` and then dropped. Borrows &self.
+    ///
+    /// # Parameters
+    ///
+    /// * `handler` - Consumer receiving the errors
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `RcConsumer<T>`
+    pub fn or_log<C>(&self, handler: C) -> RcConsumer<T>
+    where
+        C: Consumer<E> + 'static,
+    {
+        let function = Rc::clone(&self.function);
+        let mut handler = handler;
+        RcConsumer::new(move |t: &T| {
+            let result = function.borrow_mut()(t);
+            if let Err(e) = result {
+                handler.accept(&e);
+            }
+        })
+    }
+}
+
+impl<T, E> TryConsumer<T, E> for RcTryConsumer<T, E> {
+    fn try_accept(&mut self, value: &T) -> Result<(), E> {
+        (self.function.borrow_mut())(value)
+    }
+
+    fn into_box(self) -> BoxTryConsumer<T, E>
+    where
+        T: 'static,
+        E: 'static,
+    {
+        let self_fn = self.function;
+        BoxTryConsumer::new(move |t| self_fn.borrow_mut()(t))
+    }
+
+    fn into_rc(self) -> RcTryConsumer<T, E>
+    where
+        T: 'static,
+        E: 'static,
+    {
+        self
+    }
+
+    // do NOT override TryConsumer::into_arc() because RcTryConsumer is not
+    // Send + Sync and calling RcTryConsumer::into_arc() will cause a compile
+    // error
+
+    fn into_fn(self) -> impl FnMut(&T) -> Result<(), E>
+    where
+        T: 'static,
+        E: 'static,
+    {
+        let self_fn = self.function;
+        move |t| self_fn.borrow_mut()(t)
+    }
+}
+
+impl<T, E> Clone for RcTryConsumer<T, E> {
+    /// Clones the RcTryConsumer
+    ///
+    /// Creates a new RcTryConsumer sharing the underlying function with the
+    /// original instance.
+    fn clone(&self) -> Self {
+        RcTryConsumer {
+            function: self.function.clone(),
+        }
+    }
+}
+
+impl<T, E> fmt::Debug for RcTryConsumer<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RcTryConsumer")
+            .field("function", &"<function>")
+            .finish()
+    }
+}
+
+// =======================================================================
+// 5. Implement TryConsumer trait for closures
+// =======================================================================
+
+/// Implements TryConsumer for all FnMut(&T) -> Result<(), E>
+impl<T, E, F> TryConsumer<T, E> for F
+where
+    F: FnMut(&T) -> Result<(), E>,
+{
+    fn try_accept(&mut self, value: &T) -> Result<(), E> {
+        self(value)
+    }
+
+    fn into_box(self) -> BoxTryConsumer<T, E>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        E: 'static,
+    {
+        BoxTryConsumer::new(self)
+    }
+
+    fn into_rc(self) -> RcTryConsumer<T, E>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        E: 'static,
+    {
+        RcTryConsumer::new(self)
+    }
+
+    fn into_arc(self) -> ArcTryConsumer<T, E>
+    where
+        Self: Sized + Send + 'static,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        ArcTryConsumer::new(self)
+    }
+
+    fn into_fn(self) -> impl FnMut(&T) -> Result<(), E>
+    where
+        Self: Sized + 'static,
+        T: 'static,
+        E: 'static,
+    {
+        self
+    }
+}
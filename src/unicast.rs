@@ -0,0 +1,122 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+//! # Unicast Types
+//!
+//! Provides [`UnicastConsumer`], a consumer forwarding values to at most one
+//! replaceable recipient.
+//!
+//! # Author
+//!
+//! Hu Haixing
+
+use std::fmt;
+
+use crate::consumer::{BoxConsumer, Consumer};
+
+/// UnicastConsumer struct
+///
+/// A consumer forwarding every accepted value to at most one registered
+/// `BoxConsumer<T>`. Registering a new recipient replaces the previous one.
+/// When no recipient is registered, accepted values are dropped.
+///
+/// This is a lighter alternative to [`EventBus`](crate::EventBus) when a value stream only
+/// ever has one listener, which may change over time.
+///
+/// # Features
+///
+/// - **Single Recipient**: At most one consumer receives each value
+/// - **Hot Swapping**: Recipients can be replaced or removed at any time
+/// - **Implements Consumer**: Can be used anywhere a `Consumer` is expected
+///
+/// # Examples
+///
+/// ```rust
+/// use prism3_function::{BoxConsumer, Consumer, UnicastConsumer};
+/// use std::sync::{Arc, Mutex};
+///
+/// let log = Arc::new(Mutex::new(Vec::new()));
+/// let l = log.clone();
+/// let mut unicast = UnicastConsumer::new();
+///
+/// unicast.accept(&1); // No recipient, ignored
+/// unicast.register(BoxConsumer::new(move |x: &i32| l.lock().unwrap().push(*x)));
+/// unicast.accept(&2);
+/// unicast.deregister();
+/// unicast.accept(&3); // No recipient, ignored
+///
+/// assert_eq!(*log.lock().unwrap(), vec![2]);
+/// ```
+///
+/// # Author
+///
+/// Hu Haixing
+pub struct UnicastConsumer<T> {
+    recipient: Option<BoxConsumer<T>>,
+}
+
+impl<T> UnicastConsumer<T> {
+    /// Creates a UnicastConsumer without recipient
+    ///
+    /// # Return Value
+    ///
+    /// Returns a new `UnicastConsumer<T>` ignoring all values until a
+    /// recipient is registered
+    pub fn new() -> Self {
+        UnicastConsumer { recipient: None }
+    }
+
+    /// Registers the recipient of subsequent values
+    ///
+    /// # Parameters
+    ///
+    /// * `consumer` - The consumer receiving every value accepted from now on
+    ///
+    /// # Return Value
+    ///
+    /// Returns the previously registered consumer, if any
+    pub fn register(&mut self, consumer: BoxConsumer<T>) -> Option<BoxConsumer<T>> {
+        self.recipient.replace(consumer)
+    }
+
+    /// Removes the current recipient
+    ///
+    /// # Return Value
+    ///
+    /// Returns the removed consumer, or `None` if no consumer was registered
+    pub fn deregister(&mut self) -> Option<BoxConsumer<T>> {
+        self.recipient.take()
+    }
+
+    /// Returns whether a recipient is currently registered
+    pub fn is_registered(&self) -> bool {
+        self.recipient.is_some()
+    }
+}
+
+impl<T> Consumer<T> for UnicastConsumer<T> {
+    fn accept(&mut self, value: &T) {
+        if let Some(recipient) = self.recipient.as_mut() {
+            recipient.accept(value);
+        }
+    }
+}
+
+impl<T> Default for UnicastConsumer<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for UnicastConsumer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnicastConsumer")
+            .field("registered", &self.is_registered())
+            .finish()
+    }
+}
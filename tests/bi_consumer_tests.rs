@@ -379,6 +379,45 @@ mod arc_bi_consumer_tests {
         assert_eq!(*log.lock().unwrap(), vec![8, 12]);
     }
 
+    #[test]
+    fn test_conditional_clone_used_from_two_threads() {
+        let recorded = Arc::new(Mutex::new(Vec::new()));
+        let r = recorded.clone();
+        let record = ArcBiConsumer::new(move |key: &String, value: &i64| {
+            r.lock().unwrap().push((key.clone(), *value));
+        });
+        let gated =
+            record.when(|key: &String, value: &i64| key.starts_with("http.") && *value >= 0);
+
+        let handles: Vec<_> = [
+            vec![("http.latency", 12), ("db.latency", 5)],
+            vec![("http.errors", -1), ("http.requests", 3)],
+        ]
+        .into_iter()
+        .map(|pairs| {
+            let mut consumer = gated.clone();
+            std::thread::spawn(move || {
+                for (key, value) in pairs {
+                    consumer.accept(&key.to_string(), &value);
+                }
+            })
+        })
+        .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let mut recorded = recorded.lock().unwrap().clone();
+        recorded.sort();
+        assert_eq!(
+            recorded,
+            vec![
+                ("http.latency".to_string(), 12),
+                ("http.requests".to_string(), 3)
+            ]
+        );
+    }
+
     #[test]
     fn test_conditional_into_arc() {
         let log = Arc::new(Mutex::new(Vec::new()));
@@ -2517,3 +2556,248 @@ mod bi_consumer_once_tests {
         assert_eq!(*log.borrow(), vec![-8]);
     }
 }
+
+// ============================================================================
+// Partial Application Tests
+// ============================================================================
+
+#[cfg(test)]
+mod bind_tests {
+    use super::*;
+    use prism3_function::Consumer;
+    use std::cell::Cell;
+    use std::thread;
+
+    /// Deliberately not `Clone`: binding must move it in and reuse it
+    struct Logger {
+        lines: RefCell<Vec<String>>,
+        calls: Cell<usize>,
+    }
+
+    impl Logger {
+        fn new() -> Self {
+            Logger {
+                lines: RefCell::new(Vec::new()),
+                calls: Cell::new(0),
+            }
+        }
+    }
+
+    #[test]
+    fn test_box_bind_first_shares_bound_value() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let s = seen.clone();
+        let log = BoxBiConsumer::new(move |logger: &Logger, event: &&str| {
+            logger.calls.set(logger.calls.get() + 1);
+            logger.lines.borrow_mut().push(event.to_string());
+            s.borrow_mut()
+                .push((logger.calls.get(), logger.lines.borrow().len()));
+        });
+        let mut on_event = log.bind_first(Logger::new());
+        on_event.accept(&"start");
+        on_event.accept(&"stop");
+        on_event.accept(&"exit");
+        assert_eq!(*seen.borrow(), vec![(1, 1), (2, 2), (3, 3)]);
+    }
+
+    #[test]
+    fn test_box_bind_second() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let tagged = BoxBiConsumer::new(move |x: &i32, tag: &String| {
+            l.borrow_mut().push(format!("{}{}", tag, x));
+        });
+        let mut consumer = tagged.bind_second("#".to_string());
+        consumer.accept(&1);
+        consumer.accept(&2);
+        assert_eq!(*log.borrow(), vec!["#1", "#2"]);
+    }
+
+    #[test]
+    fn test_arc_bind_keeps_original() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l = log.clone();
+        let pair = ArcBiConsumer::new(move |x: &i32, y: &i32| {
+            l.lock().unwrap().push((*x, *y));
+        });
+        let mut first_bound = pair.bind_first(1);
+        let mut second_bound = pair.bind_second(9);
+        first_bound.accept(&2);
+        second_bound.accept(&3);
+        let mut pair = pair;
+        pair.accept(&4, &5);
+        assert_eq!(*log.lock().unwrap(), vec![(1, 2), (3, 9), (4, 5)]);
+    }
+
+    #[test]
+    fn test_arc_bind_first_shares_state_across_threads() {
+        let total = Arc::new(Mutex::new(0));
+        let t = total.clone();
+        let add = ArcBiConsumer::new(move |counter: &Mutex<i32>, x: &i32| {
+            *counter.lock().unwrap() += x;
+            *t.lock().unwrap() = *counter.lock().unwrap();
+        });
+        let bound = add.bind_first(Mutex::new(0));
+        let handles: Vec<_> = (1..=4)
+            .map(|i| {
+                let mut c = bound.clone();
+                thread::spawn(move || c.accept(&i))
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(*total.lock().unwrap(), 10);
+    }
+
+    #[test]
+    fn test_rc_bind_shares_bound_value() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let c = calls.clone();
+        let record = RcBiConsumer::new(move |logger: &Logger, x: &i32| {
+            logger.calls.set(logger.calls.get() + 1);
+            c.borrow_mut().push((logger.calls.get(), *x));
+        });
+        let mut bound = record.bind_first(Logger::new());
+        bound.accept(&10);
+        bound.accept(&20);
+        assert_eq!(*calls.borrow(), vec![(1, 10), (2, 20)]);
+
+        let mut second = record.bind_second(7);
+        second.accept(&Logger::new());
+        assert_eq!(calls.borrow().len(), 3);
+    }
+
+    #[test]
+    fn test_box_swap_with_value_key_source() {
+        let map = Rc::new(RefCell::new(Vec::new()));
+        let m = map.clone();
+        let mut put = BoxBiConsumer::new(move |key: &&str, value: &i32| {
+            m.borrow_mut().push((key.to_string(), *value));
+        });
+        put.set_name("put");
+        let mut swapped = put.swap();
+        assert_eq!(swapped.name(), Some("put"));
+        for (value, key) in [(1, "a"), (2, "b")] {
+            swapped.accept(&value, &key);
+        }
+        assert_eq!(
+            *map.borrow(),
+            vec![("a".to_string(), 1), ("b".to_string(), 2)]
+        );
+    }
+
+    #[test]
+    fn test_box_swap_then_bind_first() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let put = BoxBiConsumer::new(move |key: &&str, value: &i32| {
+            l.borrow_mut().push(format!("{}={}", key, value));
+        });
+        let mut set_zero = put.swap().bind_first(0);
+        set_zero.accept(&"x");
+        set_zero.accept(&"y");
+        assert_eq!(*log.borrow(), vec!["x=0", "y=0"]);
+    }
+
+    #[test]
+    fn test_arc_swap_keeps_original_and_name() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l = log.clone();
+        let mut pair = ArcBiConsumer::new(move |x: &i32, s: &String| {
+            l.lock().unwrap().push(format!("{}{}", s, x));
+        });
+        pair.set_name("pair");
+        let swapped = pair.swap();
+        assert_eq!(swapped.name(), Some("pair"));
+        let mut tagged = swapped.bind_first("#".to_string());
+        let handle = thread::spawn(move || tagged.accept(&1));
+        handle.join().unwrap();
+        pair.accept(&2, &"$".to_string());
+        assert_eq!(*log.lock().unwrap(), vec!["#1", "$2"]);
+    }
+
+    #[test]
+    fn test_rc_swap_shares_state() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let c = calls.clone();
+        let mut counter = 0;
+        let record = RcBiConsumer::new(move |x: &i32, tag: &char| {
+            counter += 1;
+            c.borrow_mut().push((counter, *tag, *x));
+        });
+        let mut swapped = record.swap();
+        swapped.accept(&'a', &1);
+        let mut bound = record.swap().bind_first('b');
+        bound.accept(&2);
+        let mut record = record;
+        record.accept(&3, &'c');
+        assert_eq!(*calls.borrow(), vec![(1, 'a', 1), (2, 'b', 2), (3, 'c', 3)]);
+    }
+}
+
+// ============================================================================
+// Supplier-Driven Tests
+// ============================================================================
+
+#[cfg(test)]
+mod supplier_driven_tests {
+    use super::*;
+    use prism3_function::{BoxSupplier, Consumer};
+
+    #[test]
+    fn test_reduce_parallel_pairs_values_in_order() {
+        let pairs = Rc::new(RefCell::new(Vec::new()));
+        let p = pairs.clone();
+        let mut record = BoxBiConsumer::new(move |x: &i32, s: &String| {
+            p.borrow_mut().push(format!("{}{}", s, x));
+        });
+        let letters = BoxSupplier::generate("a".to_string(), |s: &String| {
+            ((s.as_bytes()[0] + 1) as char).to_string()
+        });
+        record.reduce_parallel(BoxSupplier::generate(1, |x: &i32| x + 1), letters, 3);
+        assert_eq!(*pairs.borrow(), vec!["a1", "b2", "c3"]);
+        record.accept(&9, &"z".to_string());
+        assert_eq!(pairs.borrow().len(), 4);
+    }
+
+    #[test]
+    fn test_reduce_parallel_zero_does_not_pull() {
+        let pulls = Rc::new(RefCell::new(0));
+        let p = pulls.clone();
+        let mut noop = BoxBiConsumer::new(|_: &i32, _: &i32| {});
+        noop.reduce_parallel(
+            move || {
+                *p.borrow_mut() += 1;
+                0
+            },
+            || 0,
+            0,
+        );
+        assert_eq!(*pulls.borrow(), 0);
+    }
+
+    #[test]
+    fn test_zip_with_suppliers_is_lazy() {
+        let pulls = Rc::new(RefCell::new(0));
+        let p = pulls.clone();
+        let sum = Rc::new(RefCell::new(0));
+        let s = sum.clone();
+        let add = BoxBiConsumer::new_with_name("add", move |x: &i32, y: &i32| {
+            *s.borrow_mut() += x + y;
+        });
+        let mut tick = add.zip_with_suppliers(
+            move || {
+                *p.borrow_mut() += 1;
+                *p.borrow()
+            },
+            BoxSupplier::generate(10, |x: &i32| x + 10),
+        );
+        assert_eq!(*pulls.borrow(), 0);
+        assert_eq!(tick.name(), Some("add"));
+        tick.accept(&());
+        tick.accept(&());
+        assert_eq!(*pulls.borrow(), 2);
+        assert_eq!(*sum.borrow(), 1 + 10 + 2 + 20);
+    }
+}
@@ -0,0 +1,268 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! Unit tests for BiMutator types
+
+use prism3_function::{ArcBiMutator, BiMutator, BoxBiMutator, FnBiMutatorOps, RcBiMutator};
+use std::thread;
+
+// ============================================================================
+// BoxBiMutator Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_box_bi_mutator {
+    use super::*;
+
+    #[test]
+    fn test_new_and_mutate() {
+        let mut mutator = BoxBiMutator::new(|x: &mut i32, y: &mut String| {
+            *x += 1;
+            y.push('!');
+        });
+        let mut a = 1;
+        let mut b = String::from("hi");
+        mutator.mutate(&mut a, &mut b);
+        assert_eq!(a, 2);
+        assert_eq!(b, "hi!");
+    }
+
+    #[test]
+    fn test_stateful_mutator() {
+        let mut calls = 0;
+        let mut mutator = BoxBiMutator::new(move |x: &mut i32, y: &mut i32| {
+            calls += 1;
+            *x = calls;
+            *y = calls * 10;
+        });
+        let (mut a, mut b) = (0, 0);
+        mutator.mutate(&mut a, &mut b);
+        mutator.mutate(&mut a, &mut b);
+        assert_eq!((a, b), (2, 20));
+    }
+
+    #[test]
+    fn test_noop() {
+        let mut noop = BoxBiMutator::<i32, i32>::noop();
+        let (mut a, mut b) = (1, 2);
+        noop.mutate(&mut a, &mut b);
+        assert_eq!((a, b), (1, 2));
+    }
+
+    #[test]
+    fn test_and_then_sees_mutated_values() {
+        let mut chained = BoxBiMutator::new(|x: &mut i32, y: &mut i32| *x += *y)
+            .and_then(|x: &mut i32, y: &mut i32| *y = *x * 2)
+            .and_then(RcBiMutator::new(|x: &mut i32, _y: &mut i32| *x -= 1));
+        let (mut a, mut b) = (1, 2);
+        chained.mutate(&mut a, &mut b);
+        assert_eq!((a, b), (2, 6));
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut swap = BoxBiMutator::swap();
+        let mut a = vec![1, 2];
+        let mut b = vec![3];
+        swap.mutate(&mut a, &mut b);
+        assert_eq!(a, vec![3]);
+        assert_eq!(b, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_into_rc_and_into_fn() {
+        let mut rc = BoxBiMutator::new(|x: &mut i32, y: &mut i32| *x += *y).into_rc();
+        let (mut a, mut b) = (1, 2);
+        rc.mutate(&mut a, &mut b);
+        assert_eq!(a, 3);
+
+        let mut f = BoxBiMutator::<i32, i32>::swap().into_fn();
+        f(&mut a, &mut b);
+        assert_eq!((a, b), (2, 3));
+    }
+}
+
+// ============================================================================
+// ArcBiMutator Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_arc_bi_mutator {
+    use super::*;
+
+    #[test]
+    fn test_clone_shares_state() {
+        let mut count = 0;
+        let mut mutator = ArcBiMutator::new(move |x: &mut i32, _y: &mut i32| {
+            count += 1;
+            *x = count;
+        });
+        let mut clone = mutator.clone();
+        let (mut a, mut b) = (0, 0);
+        mutator.mutate(&mut a, &mut b);
+        clone.mutate(&mut a, &mut b);
+        assert_eq!(a, 2);
+    }
+
+    #[test]
+    fn test_and_then_keeps_originals() {
+        let first = ArcBiMutator::new(|x: &mut i32, y: &mut i32| *x += *y);
+        let second = ArcBiMutator::new(|x: &mut i32, y: &mut i32| *y *= *x);
+        let mut chained = first.and_then(&second);
+        let (mut a, mut b) = (1, 2);
+        chained.mutate(&mut a, &mut b);
+        assert_eq!((a, b), (3, 6));
+
+        let mut first = first;
+        first.mutate(&mut a, &mut b);
+        assert_eq!(a, 9);
+    }
+
+    #[test]
+    fn test_across_threads() {
+        let swap = ArcBiMutator::<String, String>::swap();
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let mut swap = swap.clone();
+                thread::spawn(move || {
+                    let mut a = format!("a{}", i);
+                    let mut b = format!("b{}", i);
+                    swap.mutate(&mut a, &mut b);
+                    (a, b)
+                })
+            })
+            .collect();
+        for (i, handle) in handles.into_iter().enumerate() {
+            assert_eq!(
+                handle.join().unwrap(),
+                (format!("b{}", i), format!("a{}", i))
+            );
+        }
+    }
+
+    #[test]
+    fn test_survives_panicking_mutator() {
+        let mutator = ArcBiMutator::new(|x: &mut i32, y: &mut i32| {
+            assert!(*y != 0, "zero divisor");
+            *x /= *y;
+        });
+        let mut m = mutator.clone();
+        assert!(thread::spawn(move || m.mutate(&mut 1, &mut 0))
+            .join()
+            .is_err());
+
+        let mut mutator = mutator;
+        let (mut a, mut b) = (8, 2);
+        mutator.mutate(&mut a, &mut b);
+        assert_eq!(a, 4);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let mutator = ArcBiMutator::new(|x: &mut i32, y: &mut i32| *x += *y);
+        let mut boxed = mutator.to_box();
+        let mut rc = mutator.to_rc();
+        let (mut a, mut b) = (0, 1);
+        boxed.mutate(&mut a, &mut b);
+        rc.mutate(&mut a, &mut b);
+        {
+            let mut f = mutator.to_fn();
+            f(&mut a, &mut b);
+        }
+        assert_eq!(a, 3);
+        let mut arc = mutator.into_arc();
+        arc.mutate(&mut a, &mut b);
+        assert_eq!(a, 4);
+    }
+}
+
+// ============================================================================
+// RcBiMutator Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_rc_bi_mutator {
+    use super::*;
+
+    #[test]
+    fn test_swap_twice_restores() {
+        let mut swap = RcBiMutator::swap();
+        let mut clone = swap.clone();
+        let (mut a, mut b) = (1, 2);
+        swap.mutate(&mut a, &mut b);
+        assert_eq!((a, b), (2, 1));
+        clone.mutate(&mut a, &mut b);
+        assert_eq!((a, b), (1, 2));
+    }
+
+    #[test]
+    fn test_and_then() {
+        let first = RcBiMutator::new(|x: &mut Vec<i32>, y: &mut Vec<i32>| x.append(y));
+        let second = RcBiMutator::new(|x: &mut Vec<i32>, y: &mut Vec<i32>| y.push(x.len() as i32));
+        let mut chained = first.and_then(&second);
+        let mut a = vec![1];
+        let mut b = vec![2, 3];
+        chained.mutate(&mut a, &mut b);
+        assert_eq!(a, vec![1, 2, 3]);
+        assert_eq!(b, vec![3]);
+    }
+
+    #[test]
+    fn test_to_box_shares_state() {
+        let mut total = 0;
+        let mutator = RcBiMutator::new(move |x: &mut i32, _y: &mut ()| {
+            total += 1;
+            *x = total;
+        });
+        let mut boxed = mutator.to_box();
+        let mut original = mutator;
+        let mut a = 0;
+        boxed.mutate(&mut a, &mut ());
+        original.mutate(&mut a, &mut ());
+        assert_eq!(a, 2);
+    }
+}
+
+// ============================================================================
+// Closure and FnBiMutatorOps Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_closure_bi_mutator {
+    use super::*;
+
+    #[test]
+    fn test_closure_implements_bi_mutator() {
+        let mut closure = |x: &mut i32, y: &mut i32| std::mem::swap(x, y);
+        let (mut a, mut b) = (1, 2);
+        BiMutator::mutate(&mut closure, &mut a, &mut b);
+        assert_eq!((a, b), (2, 1));
+    }
+
+    #[test]
+    fn test_fn_ops_and_then() {
+        let mut chained = (|x: &mut i32, _y: &mut i32| *x += 1)
+            .and_then(|x: &mut i32, y: &mut i32| std::mem::swap(x, y));
+        let (mut a, mut b) = (1, 10);
+        chained.mutate(&mut a, &mut b);
+        assert_eq!((a, b), (10, 2));
+    }
+
+    #[test]
+    fn test_closure_conversions() {
+        let mut arc = (|x: &mut i32, y: &mut i32| *x *= *y).into_arc();
+        let (mut a, mut b) = (2, 3);
+        arc.mutate(&mut a, &mut b);
+        assert_eq!(a, 6);
+
+        let mut rc = (|x: &mut i32, y: &mut i32| *y -= *x).into_rc();
+        rc.mutate(&mut a, &mut b);
+        assert_eq!(b, -3);
+    }
+}
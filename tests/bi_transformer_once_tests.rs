@@ -711,3 +711,170 @@ mod ownership_tests {
         // prefix is moved into closure
     }
 }
+
+// ============================================================================
+// Tests for RcBiTransformerOnce
+// ============================================================================
+
+#[cfg(test)]
+mod rc_bi_transformer_once_tests {
+    use super::*;
+    use prism3_function::RcBiTransformerOnce;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_apply_once() {
+        let add = RcBiTransformerOnce::new(|x: i32, y: i32| x + y);
+        assert_eq!(add.apply_once(20, 22), 42);
+    }
+
+    #[test]
+    fn test_double_invocation_returns_none() {
+        let calls = Rc::new(Cell::new(0));
+        let c = calls.clone();
+        let handler = RcBiTransformerOnce::new(move |x: i32, y: i32| {
+            c.set(c.get() + 1);
+            x * y
+        });
+        let first_event = handler.clone();
+        let second_event = handler.clone();
+
+        assert!(!handler.is_consumed());
+        assert_eq!(first_event.try_transform_once(6, 7), Some(42));
+        assert_eq!(second_event.try_transform_once(6, 7), None);
+        assert_eq!(first_event.try_transform_once(1, 1), None);
+        assert!(handler.is_consumed());
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "already been consumed")]
+    fn test_apply_once_after_consumed_panics() {
+        let add = RcBiTransformerOnce::new(|x: i32, y: i32| x + y);
+        let other = add.clone();
+        assert_eq!(add.try_transform_once(1, 2), Some(3));
+        other.apply_once(1, 2);
+    }
+
+    #[test]
+    fn test_from_box() {
+        let concat = BoxBiTransformerOnce::new(|x: String, y: String| x + &y);
+        let shared: RcBiTransformerOnce<String, String, String> = concat.into();
+        let other = shared.clone();
+        assert_eq!(
+            shared.try_transform_once("a".to_string(), "b".to_string()),
+            Some("ab".to_string())
+        );
+        assert_eq!(
+            other.try_transform_once("c".to_string(), "d".to_string()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_to_box_once_shares_slot() {
+        let add = RcBiTransformerOnce::new(|x: i32, y: i32| x + y);
+        let boxed = add.to_box_once();
+        assert_eq!(boxed.apply_once(2, 3), 5);
+        assert!(add.is_consumed());
+        assert_eq!(add.try_transform_once(2, 3), None);
+    }
+
+    #[test]
+    fn test_function_may_touch_own_clone() {
+        let slot: Rc<Cell<Option<bool>>> = Rc::new(Cell::new(None));
+        let s = slot.clone();
+        let holder: Rc<Cell<Option<RcBiTransformerOnce<i32, i32, i32>>>> = Rc::new(Cell::new(None));
+        let h = holder.clone();
+        let add = RcBiTransformerOnce::new(move |x: i32, y: i32| {
+            let me = h.take().unwrap();
+            s.set(Some(me.is_consumed()));
+            x + y
+        });
+        holder.set(Some(add.clone()));
+        assert_eq!(add.try_transform_once(1, 2), Some(3));
+        assert_eq!(slot.get(), Some(true));
+    }
+}
+
+// ============================================================================
+// Tests for ArcBiTransformerOnce
+// ============================================================================
+
+#[cfg(test)]
+mod arc_bi_transformer_once_tests {
+    use super::*;
+    use prism3_function::{ArcBiTransformerOnce, RcBiTransformerOnce};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test_apply_once() {
+        let add = ArcBiTransformerOnce::new(|x: i32, y: i32| x + y);
+        assert_eq!(add.apply_once(20, 22), 42);
+    }
+
+    #[test]
+    fn test_double_invocation_returns_none() {
+        let mul = ArcBiTransformerOnce::new(|x: i32, y: i32| x * y);
+        let other = mul.clone();
+        assert_eq!(other.try_transform_once(6, 7), Some(42));
+        assert_eq!(mul.try_transform_once(6, 7), None);
+        assert!(mul.is_consumed());
+    }
+
+    #[test]
+    fn test_runs_once_across_threads() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let c = calls.clone();
+        let handler = ArcBiTransformerOnce::new(move |x: usize, y: usize| {
+            c.fetch_add(1, Ordering::SeqCst);
+            x + y
+        });
+
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let handler = handler.clone();
+                thread::spawn(move || handler.try_transform_once(i, 1))
+            })
+            .collect();
+        let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+        assert_eq!(results.iter().filter(|r| r.is_some()).count(), 1);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(handler.is_consumed());
+    }
+
+    #[test]
+    #[should_panic(expected = "already been consumed")]
+    fn test_apply_once_after_consumed_panics() {
+        let add = ArcBiTransformerOnce::new(|x: i32, y: i32| x + y);
+        let _ = add.try_transform_once(1, 2);
+        add.apply_once(1, 2);
+    }
+
+    #[test]
+    fn test_into_rc_shares_slot() {
+        let add = ArcBiTransformerOnce::new(|x: i32, y: i32| x + y);
+        let rc: RcBiTransformerOnce<i32, i32, i32> = add.clone().into();
+        assert_eq!(rc.try_transform_once(1, 2), Some(3));
+        assert!(add.is_consumed());
+    }
+
+    #[test]
+    fn test_to_fn_once() {
+        let add = ArcBiTransformerOnce::new(|x: i32, y: i32| x + y);
+        let f = add.to_fn_once();
+        assert_eq!(f(4, 5), 9);
+        assert_eq!(add.try_transform_once(4, 5), None);
+    }
+
+    #[test]
+    fn test_box_conversion() {
+        let add = ArcBiTransformerOnce::new(|x: i32, y: i32| x + y);
+        let boxed: BoxBiTransformerOnce<i32, i32, i32> = add.into_box_once();
+        assert_eq!(boxed.apply_once(1, 1), 2);
+    }
+}
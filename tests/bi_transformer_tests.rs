@@ -1883,3 +1883,80 @@ mod arc_bi_transformer_once_tests {
         assert_eq!(fn_once(3, 7), 10);
     }
 }
+
+// ============================================================================
+// from_pair Tests
+// ============================================================================
+
+#[cfg(test)]
+mod from_pair_tests {
+    use super::*;
+    use prism3_function::BoxTransformer;
+
+    #[test]
+    fn test_from_pair_with_closures() {
+        let pair = BoxBiTransformer::from_pair(|x: i32| x * 2, |s: String| s.to_uppercase());
+        assert_eq!(pair.apply(21, "abc".to_string()), (42, "ABC".to_string()));
+    }
+
+    #[test]
+    fn test_from_pair_with_box_transformers_and_then() {
+        let pair = BoxBiTransformer::from_pair(
+            BoxTransformer::new(|x: i32| x + 1),
+            BoxTransformer::new(|y: i32| y * 10),
+        )
+        .and_then(|(a, b): (i32, i32)| a + b);
+        assert_eq!(pair.apply(1, 2), 22);
+    }
+}
+
+// ============================================================================
+// Partial Application Tests
+// ============================================================================
+
+#[cfg(test)]
+mod partial_apply_tests {
+    use super::*;
+    use prism3_function::Transformer;
+
+    #[test]
+    fn test_partial_apply_left() {
+        let sub = BoxBiTransformer::new(|x: i32, y: i32| x - y);
+        let from_hundred = sub.partial_apply_left(100);
+        assert_eq!(from_hundred.apply(1), 99);
+        assert_eq!(from_hundred.apply(50), 50);
+    }
+
+    #[test]
+    fn test_partial_apply_right() {
+        let sub = BoxBiTransformer::new(|x: i32, y: i32| x - y);
+        let minus_one = sub.partial_apply_right(1);
+        assert_eq!(minus_one.apply(100), 99);
+        assert_eq!(minus_one.apply(1), 0);
+    }
+
+    #[test]
+    fn test_bound_value_is_cloned_per_call() {
+        let join = BoxBiTransformer::new(|mut v: Vec<i32>, x: i32| {
+            v.push(x);
+            v
+        });
+        let append = join.partial_apply_left(vec![1, 2]);
+        assert_eq!(append.apply(3), vec![1, 2, 3]);
+        assert_eq!(append.apply(4), vec![1, 2, 4]);
+    }
+
+    #[test]
+    fn test_partial_apply_with_different_types() {
+        let repeat = BoxBiTransformer::new(|s: String, n: usize| s.repeat(n));
+        let triple = repeat.partial_apply_right(3);
+        assert_eq!(triple.apply("ab".to_string()), "ababab");
+    }
+
+    #[test]
+    fn test_partial_apply_result_composes() {
+        let add = BoxBiTransformer::new(|x: i32, y: i32| x + y);
+        let add_then_double = add.partial_apply_left(5).and_then(|x: i32| x * 2);
+        assert_eq!(add_then_double.apply(1), 12);
+    }
+}
@@ -9,7 +9,7 @@
 
 use prism3_function::{
     ArcBinaryOperator, BiTransformer, BiTransformerOnce, BinaryOperator, BoxBinaryOperator,
-    BoxBinaryOperatorOnce, RcBinaryOperator,
+    BoxBinaryOperatorOnce, Mapper, RcBinaryOperator,
 };
 use std::thread;
 
@@ -71,3 +71,202 @@ fn test_binary_operator_compatibility() {
     let binary_op = BoxBinaryOperator::new(|a: i32, b: i32| a + b);
     assert_eq!(use_bi_transformer(binary_op, 5, 10), 15);
 }
+
+#[test]
+fn test_binary_operator_reduce() {
+    let sum = BoxBinaryOperator::<i32>::sum();
+    assert_eq!(sum.reduce(Vec::new()), None);
+    assert_eq!(sum.reduce(vec![5]), Some(5));
+    assert_eq!(sum.reduce(1..=10), Some(55));
+
+    let max = |a: i32, b: i32| a.max(b);
+    assert_eq!(max.reduce(vec![3, 9, 2]), Some(9));
+}
+
+#[test]
+fn test_binary_operator_fold() {
+    let product = BoxBinaryOperator::<u64>::product();
+    assert_eq!(product.fold(Vec::new(), 1), 1);
+    assert_eq!(product.fold(vec![7], 1), 7);
+    assert_eq!(product.fold(1..=5, 1), 120);
+
+    // Left-to-right order matters for non-commutative operators
+    let minus = RcBinaryOperator::new(|a: i32, b: i32| a - b);
+    assert_eq!(minus.fold(vec![1, 2, 3], 10), 4);
+    assert_eq!(minus.reduce(vec![10, 1, 2]), Some(7));
+}
+
+#[test]
+fn test_binary_operator_reduce_across_threads() {
+    let concat = ArcBinaryOperator::new(|a: String, b: String| a + &b);
+    let handles: Vec<_> = (0..3)
+        .map(|i| {
+            let concat = concat.clone();
+            thread::spawn(move || concat.reduce((0..3).map(|j| format!("{}{}", i, j))))
+        })
+        .collect();
+    let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    assert_eq!(
+        results,
+        vec![
+            Some("000102".to_string()),
+            Some("101112".to_string()),
+            Some("202122".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_binary_operator_accumulate_seeded_with_first_element() {
+    let mut running_product = BoxBinaryOperator::new(|a: i32, b: i32| a * b).accumulate();
+    let outputs: Vec<i32> = [2, 3, 4]
+        .into_iter()
+        .map(|x| running_product.apply(x))
+        .collect();
+    // Seeding with the first element avoids needing the identity element
+    assert_eq!(outputs, vec![2, 6, 24]);
+}
+
+#[test]
+fn test_binary_operator_accumulate_from_explicit_init() {
+    let mut running_product = BoxBinaryOperator::new(|a: i32, b: i32| a * b).accumulate_from(0);
+    let outputs: Vec<i32> = [2, 3, 4]
+        .into_iter()
+        .map(|x| running_product.apply(x))
+        .collect();
+    assert_eq!(outputs, vec![0, 0, 0]);
+
+    let mut running_sum = RcBinaryOperator::new(|a: i32, b: i32| a + b).accumulate_from(10);
+    assert_eq!(running_sum.map_vec(vec![1, 2, 3]), vec![11, 13, 16]);
+}
+
+#[test]
+fn test_binary_operator_accumulate_restarts_when_operator_panics() {
+    let checked_sum = |a: i32, b: i32| {
+        assert!(b >= 0, "negative input");
+        a + b
+    };
+
+    let mut seeded = checked_sum.accumulate();
+    assert_eq!(seeded.apply(1), 1);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| seeded.apply(-1)));
+    assert!(result.is_err());
+    assert_eq!(seeded.apply(2), 2);
+    assert_eq!(seeded.apply(3), 5);
+
+    let mut from_init = checked_sum.accumulate_from(10);
+    assert_eq!(from_init.apply(1), 11);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| from_init.apply(-1)));
+    assert!(result.is_err());
+    assert_eq!(from_init.apply(2), 12);
+    assert_eq!(from_init.apply(3), 15);
+}
+
+/// Counts its clones in a shared cell
+struct Counted {
+    value: i32,
+    clones: std::rc::Rc<std::cell::Cell<usize>>,
+}
+
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        self.clones.set(self.clones.get() + 1);
+        Counted {
+            value: self.value,
+            clones: self.clones.clone(),
+        }
+    }
+}
+
+#[test]
+fn test_binary_operator_accumulate_clones_once_per_call() {
+    let clones = std::rc::Rc::new(std::cell::Cell::new(0));
+    let counted = |value: i32| Counted {
+        value,
+        clones: clones.clone(),
+    };
+    let add = |a: Counted, b: Counted| Counted {
+        value: a.value + b.value,
+        clones: a.clones,
+    };
+
+    let mut seeded = add.accumulate();
+    let mut from_init = add.accumulate_from(counted(100));
+    assert_eq!(clones.get(), 1); // `init` is kept for restarts
+    for x in 1..=4 {
+        let before = clones.get();
+        assert_eq!(seeded.apply(counted(x)).value, x * (x + 1) / 2);
+        assert_eq!(from_init.apply(counted(x)).value, 100 + x * (x + 1) / 2);
+        assert_eq!(clones.get(), before + 2);
+    }
+}
+
+#[test]
+fn test_binary_operator_accumulate_matches_scan() {
+    let values = vec![5, 1, 8, 3, 9, 2];
+    let expected: Vec<i32> = values
+        .iter()
+        .scan(None, |acc: &mut Option<i32>, &x| {
+            let next = acc.map_or(x, |a| a.max(x));
+            *acc = Some(next);
+            Some(next)
+        })
+        .collect();
+    let mut running_max = (|a: i32, b: i32| a.max(b)).accumulate();
+    assert_eq!(running_max.map_vec(values), expected);
+}
+
+#[test]
+fn test_binary_operator_scan() {
+    let sum = BoxBinaryOperator::new(|a: i32, b: i32| a + b);
+    let prefix_sums: Vec<i32> = sum.scan(vec![1, 2, 3, 4], 0).collect();
+    assert_eq!(prefix_sums, vec![1, 3, 6, 10]);
+    assert_eq!(sum.scan(Vec::new(), 0).count(), 0);
+
+    // Left-to-right order, last item equals fold
+    let minus = RcBinaryOperator::new(|a: i32, b: i32| a - b);
+    let steps: Vec<i32> = minus.scan(vec![1, 2, 3], 10).collect();
+    assert_eq!(steps, vec![9, 7, 4]);
+    assert_eq!(*steps.last().unwrap(), minus.fold(vec![1, 2, 3], 10));
+}
+
+#[test]
+fn test_binary_operator_scan_is_lazy() {
+    let calls = std::cell::Cell::new(0);
+    let concat = |a: String, b: String| {
+        calls.set(calls.get() + 1);
+        a + &b
+    };
+    let mut steps = concat.scan(["a", "b", "c"].iter().map(|s| s.to_string()), String::new());
+    assert_eq!(calls.get(), 0);
+    assert_eq!(steps.next(), Some("a".to_string()));
+    assert_eq!(steps.next(), Some("ab".to_string()));
+    assert_eq!(calls.get(), 2);
+}
+
+#[test]
+fn test_binary_operator_scan_stops_after_operator_panics() {
+    let checked_sum = |a: i32, b: i32| {
+        assert!(b >= 0, "negative input");
+        a + b
+    };
+    let mut steps = checked_sum.scan(vec![1, -1, 2], 0);
+    assert_eq!(steps.next(), Some(1));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| steps.next()));
+    assert!(result.is_err());
+    assert_eq!(steps.next(), None);
+}
+
+#[test]
+fn test_binary_operator_fold_iter_and_scan_iter() {
+    let minus = RcBinaryOperator::new(|a: i32, b: i32| a - b);
+    assert_eq!(
+        minus.fold_iter(10, vec![1, 2, 3]),
+        minus.fold(vec![1, 2, 3], 10)
+    );
+    assert_eq!(minus.fold_iter(10, Vec::new()), 10);
+
+    let steps: Vec<i32> = minus.scan_iter(10, vec![1, 2, 3]).collect();
+    assert_eq!(steps, minus.scan(vec![1, 2, 3], 10).collect::<Vec<_>>());
+    assert_eq!(steps, vec![9, 7, 4]);
+}
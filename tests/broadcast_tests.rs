@@ -0,0 +1,85 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! Unit tests for BroadcastConsumer
+
+use prism3_function::{BoxConsumer, BroadcastConsumer, Consumer, EventBus};
+use std::sync::{Arc, Mutex};
+
+// ============================================================================
+// BroadcastConsumer Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_broadcast_consumer {
+    use super::*;
+
+    fn logger(log: &Arc<Mutex<Vec<String>>>, tag: &'static str) -> BoxConsumer<i32> {
+        let log = log.clone();
+        BoxConsumer::new(move |x: &i32| log.lock().unwrap().push(format!("{}{}", tag, x)))
+    }
+
+    #[test]
+    fn test_broadcast_delivers_every_value_in_order() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut broadcast = BoxConsumer::broadcast(vec![
+            logger(&log, "a"),
+            logger(&log, "b"),
+            logger(&log, "c"),
+        ]);
+        assert_eq!(broadcast.len(), 3);
+        broadcast.accept(&1);
+        broadcast.accept(&2);
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["a1", "b1", "c1", "a2", "b2", "c2"]
+        );
+    }
+
+    #[test]
+    fn test_empty_broadcast_accepts_values() {
+        let mut empty = BoxConsumer::<i32>::broadcast(Vec::new());
+        assert!(empty.is_empty());
+        empty.accept(&1);
+        let mut default = BroadcastConsumer::<i32>::default();
+        default.accept(&2);
+        assert!(default.is_empty());
+    }
+
+    #[test]
+    fn test_push_grows_fan_out() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut broadcast = BroadcastConsumer::new(vec![logger(&log, "a")]);
+        broadcast.accept(&1);
+        broadcast.push(logger(&log, "b"));
+        broadcast.push(logger(&log, "c"));
+        broadcast.accept(&2);
+        assert_eq!(*log.lock().unwrap(), vec!["a1", "a2", "b2", "c2"]);
+        assert_eq!(broadcast.len(), 3);
+    }
+
+    #[test]
+    fn test_broadcast_as_recipient() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut bus = EventBus::new();
+        let broadcast = BroadcastConsumer::new(vec![logger(&log, "a"), logger(&log, "b")]);
+        bus.subscribe("k", broadcast.into_box());
+        bus.publish(&"k", &4);
+        assert_eq!(*log.lock().unwrap(), vec!["a4", "b4"]);
+    }
+
+    #[test]
+    fn test_debug_reports_recipients() {
+        let broadcast = BroadcastConsumer::new(vec![BoxConsumer::<i32>::noop()]);
+        assert_eq!(
+            format!("{:?}", broadcast),
+            "BroadcastConsumer { recipients: 1 }"
+        );
+    }
+}
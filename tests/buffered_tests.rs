@@ -0,0 +1,205 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! Unit tests for buffered consumer types
+
+use prism3_function::{ArcBufferedConsumer, ArcConsumer, BoxConsumer, BufferedConsumer, Consumer};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// ============================================================================
+// BufferedConsumer Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_buffered_consumer {
+    use super::*;
+
+    #[test]
+    fn test_exact_multiple_flushes_every_batch() {
+        let (sink, batches) = BoxConsumer::<Vec<i32>>::collect();
+        let mut buffered = BufferedConsumer::new(2, sink);
+        buffered.accept_all([1, 2, 3, 4, 5, 6]);
+        assert_eq!(
+            *batches.lock().unwrap(),
+            vec![vec![1, 2], vec![3, 4], vec![5, 6]]
+        );
+        assert!(buffered.pending().is_empty());
+        assert!(buffered.into_inner().is_empty());
+    }
+
+    #[test]
+    fn test_trailing_partial_batch_flushed_manually() {
+        let (sink, batches) = BoxConsumer::<Vec<i32>>::collect();
+        let mut buffered = BufferedConsumer::new(3, sink);
+        buffered.accept_all([1, 2, 3, 4, 5]);
+        assert_eq!(*batches.lock().unwrap(), vec![vec![1, 2, 3]]);
+        assert_eq!(buffered.pending(), &[4, 5]);
+        buffered.flush();
+        assert_eq!(*batches.lock().unwrap(), vec![vec![1, 2, 3], vec![4, 5]]);
+        assert!(buffered.pending().is_empty());
+    }
+
+    #[test]
+    fn test_empty_flush_is_noop() {
+        let (sink, batches) = BoxConsumer::<Vec<i32>>::collect();
+        let mut buffered = BufferedConsumer::new(2, sink);
+        buffered.flush();
+        buffered.accept_all([1, 2]);
+        buffered.flush();
+        assert_eq!(*batches.lock().unwrap(), vec![vec![1, 2]]);
+    }
+
+    #[test]
+    fn test_into_inner_recovers_leftovers() {
+        let (sink, batches) = BoxConsumer::<Vec<String>>::collect();
+        let mut buffered = sink.buffered(10);
+        buffered.accept(&"a".to_string());
+        buffered.accept(&"b".to_string());
+        assert_eq!(buffered.into_inner(), vec!["a", "b"]);
+        assert!(batches.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_stateful_sink() {
+        let mut total = 0;
+        let totals = Arc::new(Mutex::new(Vec::new()));
+        let s = totals.clone();
+        let mut buffered = BufferedConsumer::new(2, move |batch: &Vec<i32>| {
+            total += batch.iter().sum::<i32>();
+            s.lock().unwrap().push(total);
+        });
+        buffered.accept_all([1, 2, 3, 4, 5]);
+        buffered.flush();
+        assert_eq!(*totals.lock().unwrap(), vec![3, 10, 15]);
+    }
+
+    #[test]
+    #[should_panic(expected = "batch size must be non-zero")]
+    fn test_zero_batch_size_panics() {
+        let _ = BufferedConsumer::new(0, |_: &Vec<i32>| {});
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut buffered = BufferedConsumer::new(4, |_: &Vec<i32>| {});
+        buffered.accept(&1);
+        assert_eq!(buffered.batch_size(), 4);
+        let debug = format!("{:?}", buffered);
+        assert!(debug.contains("BufferedConsumer"));
+        assert!(debug.contains("pending: 1"));
+    }
+}
+
+// ============================================================================
+// ArcBufferedConsumer Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_arc_buffered_consumer {
+    use super::*;
+
+    type Batches = Arc<Mutex<Vec<Vec<i32>>>>;
+
+    fn collecting_sink() -> (ArcConsumer<Vec<i32>>, Batches) {
+        let batches = Arc::new(Mutex::new(Vec::new()));
+        let b = batches.clone();
+        let sink = ArcConsumer::new(move |batch: &Vec<i32>| b.lock().unwrap().push(batch.clone()));
+        (sink, batches)
+    }
+
+    #[test]
+    fn test_exact_multiple_flushes_every_batch() {
+        let (sink, batches) = collecting_sink();
+        let mut buffered = ArcBufferedConsumer::new(3, sink);
+        buffered.accept_all([1, 2, 3, 4, 5, 6]);
+        assert_eq!(*batches.lock().unwrap(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(buffered.pending_len(), 0);
+    }
+
+    #[test]
+    fn test_trailing_partial_batch_and_empty_flush() {
+        let (sink, batches) = collecting_sink();
+        let mut buffered = sink.buffered(2);
+        buffered.flush();
+        buffered.accept_all([1, 2, 3]);
+        buffered.flush();
+        buffered.flush();
+        assert_eq!(*batches.lock().unwrap(), vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn test_concurrent_accepts_lose_no_values() {
+        let (sink, batches) = collecting_sink();
+        let buffered = ArcBufferedConsumer::new(7, sink);
+        let handles: Vec<_> = (0..4)
+            .map(|t| {
+                let mut consumer = buffered.clone();
+                thread::spawn(move || {
+                    for i in 0..25 {
+                        consumer.accept(&(t * 100 + i));
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(buffered.pending_len(), 100 % 7);
+        let leftovers = buffered.into_inner();
+
+        let batches = batches.lock().unwrap();
+        assert!(batches.iter().all(|batch| batch.len() == 7));
+        let mut all: Vec<i32> = batches.iter().flatten().copied().collect();
+        all.extend(leftovers);
+        all.sort();
+        let mut expected: Vec<i32> = (0..4)
+            .flat_map(|t| (0..25).map(move |i| t * 100 + i))
+            .collect();
+        expected.sort();
+        assert_eq!(all, expected);
+    }
+
+    #[test]
+    fn test_clones_share_buffer() {
+        let (sink, batches) = collecting_sink();
+        let mut first = ArcBufferedConsumer::new(2, sink);
+        let mut second = first.clone();
+        first.accept(&1);
+        second.accept(&2);
+        assert_eq!(*batches.lock().unwrap(), vec![vec![1, 2]]);
+        second.accept(&3);
+        assert_eq!(first.into_inner(), vec![3]);
+        assert_eq!(second.pending_len(), 0);
+    }
+
+    #[test]
+    fn test_panicking_sink_does_not_poison_buffer() {
+        let (sink, batches) = collecting_sink();
+        let mut failed = false;
+        let flaky = ArcConsumer::new(move |batch: &Vec<i32>| {
+            if !failed {
+                failed = true;
+                panic!("sink failure");
+            }
+            sink.clone().accept(batch);
+        });
+        let buffered = ArcBufferedConsumer::new(2, flaky);
+
+        let mut clone = buffered.clone();
+        let result = thread::spawn(move || clone.accept_all([1, 2])).join();
+        assert!(result.is_err());
+        assert_eq!(buffered.pending_len(), 0);
+
+        let mut buffered = buffered;
+        buffered.accept_all([3, 4, 5]);
+        buffered.flush();
+        assert_eq!(*batches.lock().unwrap(), vec![vec![3, 4], vec![5]]);
+    }
+}
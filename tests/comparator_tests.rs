@@ -7,7 +7,7 @@
  *
  ******************************************************************************/
 use prism3_function::comparator::{
-    ArcComparator, BoxComparator, Comparator, FnComparatorOps, RcComparator,
+    ArcComparator, BoxComparator, Comparator, ComparatorBuilder, FnComparatorOps, RcComparator,
 };
 use std::cmp::Ordering;
 
@@ -541,3 +541,283 @@ mod edge_cases {
         assert_eq!(chained.compare(&12, &15), Ordering::Less);
     }
 }
+
+#[cfg(test)]
+mod sort_pair_tests {
+    use super::*;
+    use prism3_function::BiMutator;
+
+    #[test]
+    fn test_box_sort_pair_swaps_when_greater() {
+        let cmp = BoxComparator::new(|a: &i32, b: &i32| a.cmp(b));
+        let (mut a, mut b) = (5, 3);
+        cmp.sort_pair(&mut a, &mut b);
+        assert_eq!((a, b), (3, 5));
+        cmp.sort_pair(&mut a, &mut b);
+        assert_eq!((a, b), (3, 5));
+    }
+
+    #[test]
+    fn test_sort_pair_keeps_equal_values_in_place() {
+        let cmp = ArcComparator::new(|a: &(i32, char), b: &(i32, char)| a.0.cmp(&b.0));
+        let (mut a, mut b) = ((1, 'x'), (1, 'y'));
+        cmp.sort_pair(&mut a, &mut b);
+        assert_eq!((a, b), ((1, 'x'), (1, 'y')));
+    }
+
+    #[test]
+    fn test_rc_sort_pair_reversed() {
+        let cmp = RcComparator::new(|a: &i32, b: &i32| a.cmp(b)).reversed();
+        let (mut a, mut b) = (1, 2);
+        cmp.sort_pair(&mut a, &mut b);
+        assert_eq!((a, b), (2, 1));
+    }
+
+    #[test]
+    fn test_as_sort_mutator_sorting_network() {
+        let mut sorter =
+            BoxComparator::new(|a: &String, b: &String| a.len().cmp(&b.len())).as_sort_mutator();
+        let mut values = vec![
+            "ccc".to_string(),
+            "a".to_string(),
+            "dddd".to_string(),
+            "bb".to_string(),
+        ];
+        // Bubble-sort style compare-exchange passes
+        for pass in 0..values.len() {
+            for i in 0..values.len() - 1 - pass {
+                let (left, right) = values.split_at_mut(i + 1);
+                sorter.mutate(&mut left[i], &mut right[0]);
+            }
+        }
+        assert_eq!(values, vec!["a", "bb", "ccc", "dddd"]);
+    }
+}
+
+#[cfg(test)]
+mod builder_tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Employee {
+        department: String,
+        salary: u32,
+        name: String,
+    }
+
+    fn employee(department: &str, salary: u32, name: &str) -> Employee {
+        Employee {
+            department: department.to_string(),
+            salary,
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_keys_applied_in_priority_order() {
+        let cmp = ComparatorBuilder::new()
+            .then_by(|e: &Employee| e.department.clone())
+            .then_by_desc(|e: &Employee| e.salary)
+            .then_by(|e: &Employee| e.name.clone())
+            .build();
+        let mut staff = [
+            employee("ops", 100, "zed"),
+            employee("dev", 100, "bob"),
+            employee("dev", 200, "amy"),
+            employee("dev", 100, "abe"),
+        ];
+        staff.sort_by(|a, b| cmp.compare(a, b));
+        let names: Vec<&str> = staff.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["amy", "abe", "bob", "zed"]);
+    }
+
+    #[test]
+    fn test_later_keys_only_break_ties() {
+        let cmp = ComparatorBuilder::new()
+            .then_by(|e: &Employee| e.salary)
+            .then_by(|e: &Employee| e.name.clone())
+            .build();
+        let low = employee("x", 1, "zzz");
+        let high = employee("x", 2, "aaa");
+        assert_eq!(cmp.compare(&low, &high), Ordering::Less);
+        let tie = employee("y", 1, "aaa");
+        assert_eq!(cmp.compare(&low, &tie), Ordering::Greater);
+        assert_eq!(cmp.compare(&low, &low.clone()), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_then_with_comparator() {
+        let by_name_len =
+            BoxComparator::new(|a: &Employee, b: &Employee| a.name.len().cmp(&b.name.len()));
+        let cmp = ComparatorBuilder::new()
+            .then_with(by_name_len)
+            .then_by_desc(|e: &Employee| e.name.clone())
+            .build();
+        let a = employee("x", 1, "ab");
+        let b = employee("x", 1, "cd");
+        let c = employee("x", 1, "a");
+        assert_eq!(cmp.compare(&a, &b), Ordering::Greater);
+        assert_eq!(cmp.compare(&c, &a), Ordering::Less);
+    }
+
+    #[test]
+    fn test_computed_keys() {
+        let cmp = ComparatorBuilder::new()
+            .then_by(|e: &Employee| e.name.len())
+            .then_by_desc(|e: &Employee| e.salary / 100)
+            .build();
+        let mut staff = [
+            employee("x", 150, "carol"),
+            employee("x", 250, "dave"),
+            employee("x", 100, "amy"),
+            employee("x", 299, "erin"),
+        ];
+        staff.sort_by(|a, b| cmp.compare(a, b));
+        let names: Vec<&str> = staff.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["amy", "dave", "erin", "carol"]);
+    }
+
+    #[test]
+    fn test_empty_builder_considers_all_equal() {
+        let cmp = ComparatorBuilder::<i32>::default().build();
+        assert_eq!(cmp.compare(&1, &2), Ordering::Equal);
+    }
+}
+
+#[cfg(test)]
+mod equality_tests {
+    use super::*;
+    use prism3_function::BiPredicate;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{BTreeSet, HashMap, HashSet};
+    use std::hash::{Hash, Hasher};
+
+    fn case_insensitive() -> BoxComparator<String> {
+        BoxComparator::new(|a: &String, b: &String| a.to_lowercase().cmp(&b.to_lowercase()))
+    }
+
+    #[test]
+    fn test_equals() {
+        let cmp = case_insensitive();
+        assert!(cmp.equals(&"Rust".to_string(), &"rUST".to_string()));
+        assert!(!cmp.equals(&"Rust".to_string(), &"Go".to_string()));
+    }
+
+    #[test]
+    fn test_into_eq_predicate() {
+        let same = case_insensitive().into_eq_predicate();
+        assert!(same.test(&"ABC".to_string(), &"abc".to_string()));
+        assert!(!same.test(&"ABC".to_string(), &"abd".to_string()));
+    }
+
+    #[test]
+    fn test_into_hash_dedups_in_hash_set() {
+        let key = case_insensitive().into_hash(|s: &String| s.to_lowercase());
+        let set: HashSet<_> = ["Apple", "apple", "Banana", "APPLE"]
+            .iter()
+            .map(|s| key(s.to_string()))
+            .collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_into_hash_as_map_key() {
+        let key =
+            BoxComparator::new(|a: &f64, b: &f64| a.total_cmp(b)).into_hash(|x: &f64| x.to_bits());
+        let mut counts = HashMap::new();
+        for x in [0.5, 1.5, 0.5, 2.5, 0.5] {
+            *counts.entry(key(x)).or_insert(0) += 1;
+        }
+        assert_eq!(counts[&key(0.5)], 3);
+        assert_eq!(counts[&key(1.5)], 1);
+        assert!(!counts.contains_key(&key(9.0)));
+    }
+
+    #[test]
+    fn test_into_hash_keys_are_ordered() {
+        let key = BoxComparator::new(|a: &i32, b: &i32| b.cmp(a)).into_hash(|x: &i32| *x);
+        let set: BTreeSet<_> = [3, 1, 2, 3].into_iter().map(key).collect();
+        let values: Vec<i32> = set.into_iter().map(|k| k.into_inner()).collect();
+        assert_eq!(values, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_into_hash_uses_hash_key() {
+        fn hash_of<K: Hash>(key: &K) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            key.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let key = case_insensitive().into_hash(|s: &String| s.to_lowercase());
+        let apple = hash_of(&key("Apple".to_string()));
+        assert_eq!(apple, hash_of(&key("APPLE".to_string())));
+        assert_eq!(apple, hash_of(&"apple".to_string()));
+        assert_ne!(apple, hash_of(&key("Banana".to_string())));
+    }
+
+    #[test]
+    fn test_comparator_key_get() {
+        let key = case_insensitive().into_hash(|s: &String| s.to_lowercase());
+        let k = key("Hello".to_string());
+        assert_eq!(k.get(), "Hello");
+        assert_eq!(format!("{:?}", k), "ComparatorKey(\"Hello\")");
+    }
+}
+
+#[cfg(test)]
+mod natural_order_tests {
+    use super::*;
+
+    #[test]
+    fn test_natural() {
+        let cmp = BoxComparator::<i32>::natural();
+        assert_eq!(cmp.compare(&1, &2), Ordering::Less);
+        assert_eq!(cmp.compare(&2, &2), Ordering::Equal);
+        assert_eq!(cmp.compare(&3, &2), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_reverse_natural() {
+        let cmp = BoxComparator::<String>::reverse_natural();
+        let mut words = vec!["b".to_string(), "c".to_string(), "a".to_string()];
+        words.sort_by(|a, b| cmp.compare(a, b));
+        assert_eq!(words, vec!["c", "b", "a"]);
+    }
+
+    #[test]
+    fn test_natural_then_comparing() {
+        let cmp = BoxComparator::comparing(|p: &(i32, i32)| &p.0).then_comparing(BoxComparator::<(
+            i32,
+            i32,
+        )>::reverse_natural(
+        ));
+        let mut pairs = vec![(1, 1), (0, 5), (1, 3)];
+        pairs.sort_by(cmp.into_fn());
+        assert_eq!(pairs, vec![(0, 5), (1, 3), (1, 1)]);
+    }
+
+    #[test]
+    fn test_from_partial_ord_comparable() {
+        let cmp = BoxComparator::<f64>::from_partial_ord(Ordering::Equal);
+        assert_eq!(cmp.compare(&1.5, &2.5), Ordering::Less);
+        assert_eq!(cmp.compare(&2.5, &1.5), Ordering::Greater);
+        assert_eq!(cmp.compare(&1.5, &1.5), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_from_partial_ord_incomparable() {
+        let cmp = BoxComparator::<f64>::from_partial_ord(Ordering::Greater);
+        assert_eq!(cmp.compare(&f64::NAN, &1.0), Ordering::Greater);
+        assert_eq!(cmp.compare(&1.0, &f64::NAN), Ordering::Greater);
+        assert_eq!(cmp.compare(&f64::NAN, &f64::NAN), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_from_partial_ord_sorts_values() {
+        let cmp = BoxComparator::<f64>::from_partial_ord(Ordering::Greater);
+        let mut values = [2.0, 1.0, 3.0];
+        values.sort_by(cmp.into_fn());
+        assert_eq!(values, [1.0, 2.0, 3.0]);
+    }
+}
@@ -0,0 +1,159 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! Unit tests for consumer group types
+
+use prism3_function::{ArcConsumer, ArcConsumerGroup, BoxConsumer, Consumer, ConsumerGroup};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// ============================================================================
+// ConsumerGroup Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_consumer_group {
+    use super::*;
+
+    fn tagged(log: &Arc<Mutex<Vec<String>>>, tag: &'static str) -> BoxConsumer<i32> {
+        let log = log.clone();
+        BoxConsumer::new(move |x: &i32| log.lock().unwrap().push(format!("{}{}", tag, x)))
+    }
+
+    #[test]
+    fn test_add_fire_remove_fire() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut group = ConsumerGroup::new();
+        assert!(group.insert("a", tagged(&log, "a")).is_none());
+        assert!(group.insert("b", tagged(&log, "b")).is_none());
+        assert!(group.insert("c", tagged(&log, "c")).is_none());
+        group.accept(&1);
+        assert!(group.remove("b").is_some());
+        assert!(group.remove("b").is_none());
+        group.accept(&2);
+        assert_eq!(*log.lock().unwrap(), vec!["a1", "b1", "c1", "a2", "c2"]);
+        assert_eq!(group.names(), vec!["a", "c"]);
+    }
+
+    #[test]
+    fn test_insert_replaces_in_place() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut group = ConsumerGroup::default();
+        group.insert("first", tagged(&log, "x"));
+        group.insert("second", tagged(&log, "y"));
+        let mut old = group.insert("first", tagged(&log, "z")).unwrap();
+        group.accept(&1);
+        old.accept(&9);
+        assert_eq!(*log.lock().unwrap(), vec!["z1", "y1", "x9"]);
+        assert_eq!(group.len(), 2);
+        assert!(group.contains("first"));
+    }
+
+    #[test]
+    fn test_nested_groups() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut inner = ConsumerGroup::new();
+        inner.insert("i", tagged(&log, "i"));
+        let mut outer = ConsumerGroup::new();
+        outer.insert("inner", inner.into_box());
+        outer.insert("o", tagged(&log, "o"));
+        outer.accept(&3);
+        assert_eq!(*log.lock().unwrap(), vec!["i3", "o3"]);
+    }
+
+    #[test]
+    fn test_empty_group_and_debug() {
+        let mut group = ConsumerGroup::<i32>::new();
+        assert!(group.is_empty());
+        group.accept(&1);
+        group.insert("m", BoxConsumer::noop());
+        assert_eq!(format!("{:?}", group), "ConsumerGroup { members: [\"m\"] }");
+    }
+}
+
+// ============================================================================
+// ArcConsumerGroup Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_arc_consumer_group {
+    use super::*;
+
+    fn tagged(log: &Arc<Mutex<Vec<String>>>, tag: &'static str) -> ArcConsumer<i32> {
+        let log = log.clone();
+        ArcConsumer::new(move |x: &i32| log.lock().unwrap().push(format!("{}{}", tag, x)))
+    }
+
+    fn assert_send_sync<S: Send + Sync>() {}
+
+    #[test]
+    fn test_is_send_sync() {
+        assert_send_sync::<ArcConsumerGroup<i32>>();
+    }
+
+    #[test]
+    fn test_add_fire_remove_fire() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut group = ArcConsumerGroup::new();
+        group.insert("a", tagged(&log, "a"));
+        group.insert("b", tagged(&log, "b"));
+        group.accept(&1);
+        assert!(group.remove("a").is_some());
+        group.accept(&2);
+        assert_eq!(*log.lock().unwrap(), vec!["a1", "b1", "b2"]);
+        assert_eq!(group.names(), vec!["b".to_string()]);
+    }
+
+    #[test]
+    fn test_concurrent_registration() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let group = ArcConsumerGroup::new();
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let group = group.clone();
+                let log = log.clone();
+                thread::spawn(move || {
+                    let name = format!("m{}", i);
+                    let l = log.clone();
+                    group.insert(
+                        name.clone(),
+                        ArcConsumer::new(move |x: &i32| l.lock().unwrap().push(format!("{}", x))),
+                    );
+                    assert!(group.contains(&name));
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(group.len(), 4);
+        let mut g = group.clone();
+        g.accept(&7);
+        assert_eq!(log.lock().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_member_may_modify_group() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let group = ArcConsumerGroup::new();
+        let handle = group.clone();
+        group.insert(
+            "once",
+            ArcConsumer::new(move |_: &i32| {
+                handle.remove("once");
+            }),
+        );
+        group.insert("log", tagged(&log, "l"));
+        let mut g = group.clone();
+        g.accept(&1);
+        g.accept(&2);
+        assert_eq!(group.names(), vec!["log".to_string()]);
+        assert_eq!(*log.lock().unwrap(), vec!["l1", "l2"]);
+    }
+}
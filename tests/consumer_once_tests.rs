@@ -1589,3 +1589,68 @@ mod advanced_to_methods_tests {
         assert_eq!(*log.lock().unwrap(), vec![18]);
     }
 }
+
+// ============================================================================
+// Chaining One-Shot Cleanups with Non-Clone Resources
+// ============================================================================
+
+#[cfg(test)]
+mod chained_cleanup_tests {
+    use super::*;
+
+    /// A resource that can only be released by value and is not `Clone`
+    struct Resource {
+        name: &'static str,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Resource {
+        fn release(self, code: &i32) {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{} released with {}", self.name, code));
+        }
+    }
+
+    #[test]
+    fn test_box_and_then_moves_both_resources() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let file = Resource {
+            name: "file",
+            log: log.clone(),
+        };
+        let socket = Resource {
+            name: "socket",
+            log: log.clone(),
+        };
+        let cleanup = BoxConsumerOnce::new(move |code: &i32| file.release(code))
+            .and_then(BoxConsumerOnce::new(move |code: &i32| socket.release(code)));
+
+        cleanup.accept_once(&0);
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["file released with 0", "socket released with 0"]
+        );
+    }
+
+    #[test]
+    fn test_closure_and_then_moves_both_resources() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let first = Resource {
+            name: "lock",
+            log: log.clone(),
+        };
+        let second = Resource {
+            name: "temp dir",
+            log: log.clone(),
+        };
+        let cleanup = (move |code: &i32| first.release(code))
+            .and_then(move |code: &i32| second.release(code));
+
+        cleanup.accept_once(&7);
+        assert_eq!(log.lock().unwrap().len(), 2);
+        assert_eq!(log.lock().unwrap()[0], "lock released with 7");
+        assert_eq!(log.lock().unwrap()[1], "temp dir released with 7");
+    }
+}
@@ -10,11 +10,14 @@
 //! Unit tests for Consumer types (immutable)
 
 use prism3_function::{
-    ArcConsumer, BoxConsumer, Consumer, ConsumerOnce, FnConsumerOps, RcConsumer,
+    AlreadyConsumedError, ArcConsumer, BoxConsumer, Consumer, ConsumerOnce, FnConsumerOps,
+    RcConsumer, Supplier, TryConsumer,
 };
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::rc::Rc;
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex, PoisonError};
 
 // ============================================================================
 // BoxConsumer Tests
@@ -239,6 +242,509 @@ mod test_box_consumer {
         box_consumer.accept(&5);
         assert_eq!(*log.lock().unwrap(), vec![5]);
     }
+
+    #[test]
+    fn test_count() {
+        let (mut consumer, count) = BoxConsumer::<String>::count();
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+        consumer.accept(&"a".to_string());
+        consumer.accept(&"b".to_string());
+        consumer.accept(&"a".to_string());
+        assert_eq!(count.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_count_composes_with_and_then() {
+        let (counter, count) = BoxConsumer::<i32>::count();
+        let (collector, values) = BoxConsumer::collect();
+        let mut chained = counter.and_then(collector);
+        chained.accept(&7);
+        chained.accept(&8);
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+        assert_eq!(*values.lock().unwrap(), vec![7, 8]);
+    }
+
+    #[test]
+    fn test_collect() {
+        let (mut consumer, values) = BoxConsumer::collect();
+        assert!(values.lock().unwrap().is_empty());
+        consumer.accept(&"x".to_string());
+        consumer.accept(&"y".to_string());
+        consumer.accept(&"x".to_string());
+        assert_eq!(
+            *values.lock().unwrap(),
+            vec!["x".to_string(), "y".to_string(), "x".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_collect_unique() {
+        let (mut consumer, values) = BoxConsumer::collect_unique();
+        for value in [3, 1, 3, 2, 1] {
+            consumer.accept(&value);
+        }
+        let expected: HashSet<i32> = [1, 2, 3].into_iter().collect();
+        assert_eq!(*values.lock().unwrap(), expected);
+    }
+
+    /// Panics on `clone` while `fail` is set, i.e. while the sink holds its lock
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    struct Fragile {
+        id: i32,
+        fail: bool,
+    }
+
+    impl Clone for Fragile {
+        fn clone(&self) -> Self {
+            assert!(!self.fail, "clone failed");
+            Fragile {
+                id: self.id,
+                fail: false,
+            }
+        }
+    }
+
+    #[test]
+    fn test_collect_survives_panicking_clone() {
+        let (mut consumer, values) = BoxConsumer::collect();
+        let bad = Fragile { id: 1, fail: true };
+        let result =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| consumer.accept(&bad)));
+        assert!(result.is_err());
+
+        consumer.accept(&Fragile { id: 2, fail: false });
+        let values = values.lock().unwrap_or_else(PoisonError::into_inner);
+        assert_eq!(*values, vec![Fragile { id: 2, fail: false }]);
+    }
+
+    #[test]
+    fn test_collect_unique_survives_panicking_clone() {
+        let (mut consumer, values) = BoxConsumer::collect_unique();
+        let bad = Fragile { id: 1, fail: true };
+        let result =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| consumer.accept(&bad)));
+        assert!(result.is_err());
+
+        consumer.accept(&Fragile { id: 2, fail: false });
+        consumer.accept(&Fragile { id: 2, fail: false });
+        let values = values.lock().unwrap_or_else(PoisonError::into_inner);
+        assert_eq!(values.len(), 1);
+        assert!(values.contains(&Fragile { id: 2, fail: false }));
+    }
+
+    #[test]
+    fn test_before_and_after_order() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (l1, l2, l3) = (log.clone(), log.clone(), log.clone());
+        let mut consumer =
+            BoxConsumer::new(move |x: &i32| l1.borrow_mut().push(format!("body {}", x)))
+                .before(move |x: &i32| l2.borrow_mut().push(format!("before {}", x)))
+                .after(move |x: &i32| l3.borrow_mut().push(format!("after {}", x)));
+        consumer.accept(&7);
+        assert_eq!(*log.borrow(), vec!["before 7", "body 7", "after 7"]);
+    }
+
+    #[test]
+    fn test_observe_runs_first_and_keeps_name() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (l1, l2) = (log.clone(), log.clone());
+        let mut consumer = BoxConsumer::new_with_name("sink", move |x: &i32| {
+            l1.borrow_mut().push(*x);
+        })
+        .observe(move |x: &i32| l2.borrow_mut().push(-*x));
+        consumer.accept(&1);
+        consumer.accept(&2);
+        assert_eq!(*log.borrow(), vec![-1, 1, -2, 2]);
+        assert_eq!(consumer.name(), Some("sink"));
+    }
+
+    #[test]
+    fn test_tee_feeds_both_in_order() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (l1, l2) = (log.clone(), log.clone());
+        let mut consumer = BoxConsumer::new(move |x: &i32| l1.borrow_mut().push(format!("a{}", x)))
+            .tee(move |x: &i32| l2.borrow_mut().push(format!("b{}", x)));
+        consumer.accept(&1);
+        consumer.accept(&2);
+        assert_eq!(*log.borrow(), vec!["a1", "b1", "a2", "b2"]);
+    }
+
+    #[test]
+    fn test_tee_with_shared_consumer() {
+        let (store, stored) = BoxConsumer::collect();
+        let (counter, count) = BoxConsumer::count();
+        let shared = counter.into_rc();
+        let mut consumer = store.tee(shared.clone());
+        consumer.accept(&5);
+        let mut shared = shared;
+        shared.accept(&6);
+        assert_eq!(*stored.lock().unwrap(), vec![5]);
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_fan_out() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let consumers = ["a", "b", "c"]
+            .into_iter()
+            .map(|tag| {
+                let l = log.clone();
+                BoxConsumer::new(move |x: &i32| l.borrow_mut().push(format!("{}{}", tag, x)))
+            })
+            .collect();
+        let mut fan_out = BoxConsumer::fan_out(consumers);
+        fan_out.accept(&1);
+        assert_eq!(*log.borrow(), vec!["a1", "b1", "c1"]);
+
+        let mut empty = BoxConsumer::<i32>::fan_out(Vec::new());
+        empty.accept(&1);
+    }
+
+    #[test]
+    fn test_adapt_feeds_owned_result() {
+        struct Event {
+            user_id: u32,
+        }
+
+        let (ids, values) = BoxConsumer::collect();
+        let mut consumer = ids.adapt(|e: &Event| e.user_id * 10);
+        consumer.accept(&Event { user_id: 1 });
+        consumer.accept(&Event { user_id: 2 });
+        assert_eq!(*values.lock().unwrap(), vec![10, 20]);
+    }
+
+    #[test]
+    fn test_adapt_keeps_name_and_state() {
+        let mut total = 0;
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let sum = BoxConsumer::new_with_name("sum", move |x: &usize| {
+            total += x;
+            l.borrow_mut().push(total);
+        });
+        let mut consumer = sum.adapt(|s: &String| s.len());
+        consumer.accept(&"ab".to_string());
+        consumer.accept(&"cde".to_string());
+        assert_eq!(consumer.name(), Some("sum"));
+        assert_eq!(*log.borrow(), vec![2, 5]);
+    }
+
+    #[test]
+    fn test_adapt_ref_borrows_field() {
+        struct Event {
+            user: String,
+        }
+
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let users = BoxConsumer::new(move |u: &String| l.borrow_mut().push(u.clone()));
+        let mut consumer = users.adapt_ref(|e: &Event| &e.user);
+        consumer.accept(&Event {
+            user: "alice".to_string(),
+        });
+        consumer.accept(&Event {
+            user: "bob".to_string(),
+        });
+        assert_eq!(*log.borrow(), vec!["alice", "bob"]);
+    }
+
+    #[test]
+    fn test_adapt_ref_chains_projections() {
+        let (values, collected) = BoxConsumer::collect();
+        let mut consumer = values
+            .adapt_ref(|pair: &(i32, i32)| &pair.1)
+            .adapt_ref(|nested: &((i32, i32), bool)| &nested.0);
+        consumer.accept(&((1, 2), true));
+        consumer.accept(&((3, 4), false));
+        assert_eq!(*collected.lock().unwrap(), vec![2, 4]);
+    }
+
+    #[test]
+    fn test_adapt_ref_projects_non_clone_field_without_copying() {
+        // `Payload` is neither `Clone` nor `Copy`, so the consumer can only
+        // ever see the field through a borrow of the original value. Its
+        // drop counter shows that exactly one instance ever exists.
+        struct Payload {
+            len: usize,
+            drops: Rc<RefCell<usize>>,
+        }
+        impl Drop for Payload {
+            fn drop(&mut self) {
+                *self.drops.borrow_mut() += 1;
+            }
+        }
+        struct Inner {
+            payload: Payload,
+        }
+        struct Outer {
+            inner: Inner,
+        }
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let s = seen.clone();
+        let payloads = BoxConsumer::new(move |p: &Payload| s.borrow_mut().push(p.len));
+        let mut consumer = payloads
+            .adapt_ref(|inner: &Inner| &inner.payload)
+            .adapt_ref(|outer: &Outer| &outer.inner);
+        let drops = Rc::new(RefCell::new(0));
+        let outer = Outer {
+            inner: Inner {
+                payload: Payload {
+                    len: 3,
+                    drops: drops.clone(),
+                },
+            },
+        };
+        consumer.accept(&outer);
+        consumer.accept(&outer);
+        assert_eq!(*seen.borrow(), vec![3, 3]);
+        assert_eq!(*drops.borrow(), 0);
+        drop(outer);
+        assert_eq!(*drops.borrow(), 1);
+    }
+
+    #[test]
+    fn test_on_field_is_adapt_ref() {
+        struct User {
+            name: String,
+        }
+        struct Account {
+            owner: User,
+        }
+
+        let (names, collected) = BoxConsumer::collect();
+        let mut consumer = names
+            .on_field(|u: &User| &u.name)
+            .on_field(|a: &Account| &a.owner);
+        consumer.accept(&Account {
+            owner: User {
+                name: "alice".to_string(),
+            },
+        });
+        assert_eq!(*collected.lock().unwrap(), vec!["alice".to_string()]);
+    }
+
+    #[test]
+    fn test_with_panic_recovery_routes_panicking_values() {
+        let (mut inner, accepted) = BoxConsumer::collect();
+        let (failed, recovered) = BoxConsumer::collect();
+        let mut consumer = BoxConsumer::new_with_name("checked", move |x: &i32| {
+            if *x < 0 {
+                panic!("negative value: {}", x);
+            }
+            inner.accept(x);
+        })
+        .with_panic_recovery(failed);
+
+        for x in [1, -2, 3, -4, 5] {
+            consumer.accept(&x);
+        }
+        assert_eq!(*accepted.lock().unwrap(), vec![1, 3, 5]);
+        assert_eq!(*recovered.lock().unwrap(), vec![-2, -4]);
+        assert_eq!(consumer.name(), Some("checked"));
+    }
+
+    #[test]
+    fn test_with_panic_recovery_keeps_inner_state() {
+        let mut calls = 0;
+        let total = Rc::new(RefCell::new(0));
+        let t = total.clone();
+        let (failed, recovered) = BoxConsumer::collect();
+        let mut consumer = BoxConsumer::new(move |x: &i32| {
+            calls += 1;
+            if calls == 2 {
+                panic!("second call");
+            }
+            *t.borrow_mut() += x;
+        })
+        .with_panic_recovery(failed);
+
+        consumer.accept(&10);
+        consumer.accept(&20);
+        consumer.accept(&30);
+        assert_eq!(*total.borrow(), 40);
+        assert_eq!(*recovered.lock().unwrap(), vec![20]);
+    }
+
+    #[test]
+    fn test_once_only_forwards_first_call() {
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.once_only();
+        consumer.accept(&1);
+        assert_eq!(*values.lock().unwrap(), vec![1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "once-only consumer 'init' was already consumed")]
+    fn test_once_only_panics_on_second_call() {
+        let mut consumer = BoxConsumer::new_with_name("init", |_: &i32| {}).once_only();
+        assert_eq!(consumer.name(), Some("init"));
+        consumer.accept(&1);
+        consumer.accept(&2);
+    }
+
+    #[test]
+    fn test_try_once_only_reports_second_call() {
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.try_once_only();
+        assert_eq!(consumer.try_accept(&1), Ok(()));
+        let err = consumer.try_accept(&2).unwrap_err();
+        assert_eq!(err.name(), None);
+        assert_eq!(err.to_string(), "once-only consumer was already consumed");
+        assert!(consumer.try_accept(&3).is_err());
+        assert_eq!(*values.lock().unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_try_once_only_keeps_name_in_error() {
+        let mut consumer = BoxConsumer::new_with_name("init", |_: &i32| {}).try_once_only();
+        consumer.try_accept(&1).unwrap();
+        let err: AlreadyConsumedError = consumer.try_accept(&2).unwrap_err();
+        assert_eq!(err.name(), Some("init"));
+    }
+
+    #[test]
+    fn test_from_sender_delivers_in_order_to_receiver_thread() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let receiver = std::thread::spawn(move || rx.iter().collect::<Vec<String>>());
+        let mut consumer = BoxConsumer::from_sender(tx);
+        for i in 0..100 {
+            consumer.accept(&format!("v{}", i));
+        }
+        drop(consumer);
+        let expected: Vec<String> = (0..100).map(|i| format!("v{}", i)).collect();
+        assert_eq!(receiver.join().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_from_sync_sender_delivers_in_order_to_receiver_thread() {
+        let (tx, rx) = std::sync::mpsc::sync_channel(1);
+        let receiver = std::thread::spawn(move || rx.iter().collect::<Vec<i32>>());
+        let mut consumer = BoxConsumer::from_sync_sender(tx);
+        consumer.accept_all(0..50);
+        drop(consumer);
+        assert_eq!(receiver.join().unwrap(), (0..50).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_from_sender_ignores_disconnected_receiver() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let mut consumer = BoxConsumer::from_sender(tx);
+        drop(rx);
+        consumer.accept(&1);
+        consumer.accept(&2);
+    }
+
+    #[test]
+    fn test_from_sender_with_dropped_counts_undelivered() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let (mut consumer, dropped) = BoxConsumer::from_sender_with_dropped(tx);
+        consumer.accept(&1);
+        consumer.accept(&2);
+        assert_eq!(dropped.load(Ordering::SeqCst), 0);
+        assert_eq!(rx.try_iter().collect::<Vec<_>>(), vec![1, 2]);
+        drop(rx);
+        consumer.accept(&3);
+        consumer.accept(&4);
+        assert_eq!(dropped.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_from_sync_sender_with_dropped_counts_undelivered() {
+        let (tx, rx) = std::sync::mpsc::sync_channel(4);
+        let (mut consumer, dropped) = BoxConsumer::from_sync_sender_with_dropped(tx);
+        consumer.accept(&1);
+        assert_eq!(rx.recv(), Ok(1));
+        drop(rx);
+        consumer.accept(&2);
+        assert_eq!(dropped.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_observe_with_stateful_observer() {
+        let (observer, seen) = BoxConsumer::collect();
+        let (sink, count) = BoxConsumer::<i32>::count();
+        let mut consumer = sink.observe(observer);
+        for x in [3, 1, 4] {
+            consumer.accept(&x);
+        }
+        assert_eq!(*seen.lock().unwrap(), vec![3, 1, 4]);
+        assert_eq!(count.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_handles_usable_across_threads() {
+        let (mut consumer, count) = BoxConsumer::<i32>::count();
+        consumer.accept(&1);
+        let handle = std::thread::spawn(move || count.load(Ordering::SeqCst));
+        assert_eq!(handle.join().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_dead_letter_queue_captures_rejected_values() {
+        let (valid, values) = BoxConsumer::collect();
+        let (mut consumer, mut dead_letters) = valid
+            .when(|s: &String| !s.is_empty())
+            .with_dead_letter_queue(8);
+        for s in ["a", "", "b", ""] {
+            consumer.accept(&s.to_string());
+        }
+        assert_eq!(*values.lock().unwrap(), vec!["a", "b"]);
+        assert_eq!(dead_letters.get(), Some(String::new()));
+        assert_eq!(dead_letters.get(), Some(String::new()));
+        assert_eq!(dead_letters.get(), None);
+    }
+
+    #[test]
+    fn test_dead_letter_queue_is_fifo_and_refillable() {
+        let (mut consumer, mut dead_letters) = BoxConsumer::<i32>::noop()
+            .when(|x: &i32| x % 2 == 0)
+            .with_dead_letter_queue(4);
+        consumer.accept(&1);
+        consumer.accept(&3);
+        assert_eq!(dead_letters.get(), Some(1));
+        consumer.accept(&5);
+        assert_eq!(dead_letters.get(), Some(3));
+        assert_eq!(dead_letters.get(), Some(5));
+        assert_eq!(dead_letters.get(), None);
+    }
+
+    #[test]
+    fn test_dead_letter_queue_drops_oldest_when_full() {
+        let (mut consumer, mut dead_letters) = BoxConsumer::<i32>::noop()
+            .when(|_: &i32| false)
+            .with_dead_letter_queue(2);
+        for i in 1..=5 {
+            consumer.accept(&i);
+        }
+        assert_eq!(dead_letters.get(), Some(4));
+        assert_eq!(dead_letters.get(), Some(5));
+        assert_eq!(dead_letters.get(), None);
+    }
+
+    #[test]
+    fn test_dead_letter_queue_zero_capacity() {
+        let (mut consumer, mut dead_letters) = BoxConsumer::<i32>::noop()
+            .when(|_: &i32| false)
+            .with_dead_letter_queue(0);
+        consumer.accept(&1);
+        assert_eq!(dead_letters.get(), None);
+    }
+
+    #[test]
+    fn test_dead_letter_queue_does_not_preallocate_capacity() {
+        // Reserving usize::MAX slots up front would panic with a capacity
+        // overflow
+        let (mut consumer, mut dead_letters) = BoxConsumer::<i32>::noop()
+            .when(|x: &i32| *x > 0)
+            .with_dead_letter_queue(usize::MAX);
+        consumer.accept(&-1);
+        consumer.accept(&2);
+        consumer.accept(&-3);
+        assert_eq!(dead_letters.get(), Some(-1));
+        assert_eq!(dead_letters.get(), Some(-3));
+        assert_eq!(dead_letters.get(), None);
+    }
 }
 
 // ============================================================================
@@ -293,6 +799,55 @@ mod test_arc_consumer {
         assert_eq!(*log.lock().unwrap(), vec![10, 15]);
     }
 
+    #[test]
+    fn test_before_order_matches_reversed_and_then() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let (l1, l2) = (log.clone(), log.clone());
+        let store =
+            ArcConsumer::new(move |x: &i32| l1.lock().unwrap().push(format!("store {}", x)));
+        let audit = ArcConsumer::new(move |x: &i32| l2.lock().unwrap().push(format!("log {}", x)));
+
+        let mut prepended = store.before(audit.clone());
+        prepended.accept(&1);
+        let mut appended = audit.and_then(&store);
+        appended.accept(&2);
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["log 1", "store 1", "log 2", "store 2"]
+        );
+    }
+
+    #[test]
+    fn test_tee_arc_across_threads() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let (l1, l2) = (log.clone(), log.clone());
+        let store = ArcConsumer::new(move |x: &i32| l1.lock().unwrap().push(*x));
+        let audit = ArcConsumer::new(move |x: &i32| l2.lock().unwrap().push(-*x));
+        let mut both = store.tee_arc(&audit);
+        std::thread::spawn(move || both.accept(&4)).join().unwrap();
+        let mut store = store;
+        store.accept(&5);
+        assert_eq!(*log.lock().unwrap(), vec![4, -4, 5]);
+    }
+
+    #[test]
+    fn test_before_shares_state_and_keeps_name() {
+        let count = Arc::new(Mutex::new(0));
+        let c = count.clone();
+        let mut counter = ArcConsumer::new(move |_: &i32| *c.lock().unwrap() += 1);
+        counter.set_name("counter");
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let s = seen.clone();
+        let mut prepended = counter.before(move |x: &i32| s.lock().unwrap().push(*x));
+        assert_eq!(prepended.name(), Some("counter"));
+        let handle = std::thread::spawn(move || prepended.accept(&7));
+        handle.join().unwrap();
+        counter.accept(&8);
+        assert_eq!(*seen.lock().unwrap(), vec![7]);
+        assert_eq!(*count.lock().unwrap(), 2);
+    }
+
     #[test]
     fn test_thread_safety() {
         let log = Arc::new(Mutex::new(Vec::new()));
@@ -536,6 +1091,57 @@ mod test_arc_consumer {
         // This would not compile - consumer is moved
         // consumer.accept(&3); // Would not compile
     }
+
+    #[test]
+    fn test_when_unsync_with_non_sync_value() {
+        // `Cell` is `Send` but not `Sync`, so `when` cannot be used here
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l = log.clone();
+        let consumer = ArcConsumer::new(move |x: &std::cell::Cell<i32>| {
+            l.lock().unwrap().push(x.get());
+        });
+        let mut conditional = consumer.when_unsync(|x: &std::cell::Cell<i32>| x.get() > 0);
+        conditional.accept(&std::cell::Cell::new(3));
+        conditional.accept(&std::cell::Cell::new(-3));
+        assert_eq!(*log.lock().unwrap(), vec![3]);
+    }
+
+    #[test]
+    fn test_when_unsync_or_else_across_threads() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l1 = log.clone();
+        let l2 = log.clone();
+        let threshold = std::cell::Cell::new(10);
+        let conditional = ArcConsumer::new(move |x: &i32| {
+            l1.lock().unwrap().push(*x);
+        })
+        .when_unsync(move |x: &i32| *x > threshold.get());
+        let mut combined = conditional.or_else(move |x: &i32| {
+            l2.lock().unwrap().push(-*x);
+        });
+        let mut clone = combined.clone();
+        let handle = std::thread::spawn(move || clone.accept(&20));
+        handle.join().unwrap();
+        combined.accept(&5);
+        assert_eq!(*log.lock().unwrap(), vec![20, -5]);
+    }
+
+    #[test]
+    fn test_when_unsync_survives_panicking_predicate() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l = log.clone();
+        let conditional =
+            ArcConsumer::new(move |x: &i32| l.lock().unwrap().push(*x)).when_unsync(|x: &i32| {
+                assert!(*x != 0, "zero is not comparable");
+                *x > 0
+            });
+        let mut clone = conditional.clone();
+        assert!(std::thread::spawn(move || clone.accept(&0)).join().is_err());
+        let mut conditional = conditional;
+        conditional.accept(&4);
+        conditional.accept(&-4);
+        assert_eq!(*log.lock().unwrap(), vec![4]);
+    }
 }
 
 // ============================================================================
@@ -590,6 +1196,33 @@ mod test_rc_consumer {
         assert_eq!(*log.borrow(), vec![10, 15]);
     }
 
+    #[test]
+    fn test_before_order_matches_reversed_and_then() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (l1, l2) = (log.clone(), log.clone());
+        let store = RcConsumer::new(move |x: &i32| l1.borrow_mut().push(format!("store {}", x)));
+        let audit = RcConsumer::new(move |x: &i32| l2.borrow_mut().push(format!("log {}", x)));
+
+        let mut prepended = store.before(audit.clone());
+        prepended.accept(&1);
+        let mut appended = audit.and_then(&store);
+        appended.accept(&2);
+
+        assert_eq!(*log.borrow(), vec!["log 1", "store 1", "log 2", "store 2"]);
+    }
+
+    #[test]
+    fn test_before_chains_multiple_steps() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (l1, l2, l3) = (log.clone(), log.clone(), log.clone());
+        let store = RcConsumer::new(move |_: &i32| l1.borrow_mut().push("store"));
+        let mut pipeline = store
+            .before(move |_: &i32| l2.borrow_mut().push("validate"))
+            .before(move |_: &i32| l3.borrow_mut().push("parse"));
+        pipeline.accept(&0);
+        assert_eq!(*log.borrow(), vec!["parse", "validate", "store"]);
+    }
+
     #[test]
     fn test_noop() {
         let mut noop = RcConsumer::<i32>::noop();
@@ -1342,6 +1975,52 @@ mod test_edge_cases {
         assert_eq!(*log.lock().unwrap(), vec![5, 10, -10]);
     }
 
+    #[test]
+    fn test_arc_conditional_and_then() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l1 = log.clone();
+        let l2 = log.clone();
+        let consumer = ArcConsumer::new(move |x: &i32| {
+            l1.lock().unwrap().push(*x);
+        });
+        let conditional = consumer.when(|x: &i32| *x > 0);
+        let mut chained = conditional.and_then(move |x: &i32| {
+            l2.lock().unwrap().push(*x * 2);
+        });
+        chained.accept(&5);
+        assert_eq!(*log.lock().unwrap(), vec![5, 10]);
+        chained.accept(&-5);
+        assert_eq!(*log.lock().unwrap(), vec![5, 10, -10]);
+
+        // The conditional consumer is still usable after chaining
+        let mut conditional = conditional;
+        conditional.accept(&3);
+        assert_eq!(*log.lock().unwrap(), vec![5, 10, -10, 3]);
+    }
+
+    #[test]
+    fn test_rc_conditional_and_then() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l1 = log.clone();
+        let l2 = log.clone();
+        let consumer = RcConsumer::new(move |x: &i32| {
+            l1.borrow_mut().push(*x);
+        });
+        let conditional = consumer.when(|x: &i32| *x > 0);
+        let mut chained = conditional.and_then(move |x: &i32| {
+            l2.borrow_mut().push(*x * 2);
+        });
+        chained.accept(&5);
+        assert_eq!(*log.borrow(), vec![5, 10]);
+        chained.accept(&-5);
+        assert_eq!(*log.borrow(), vec![5, 10, -10]);
+
+        // The conditional consumer is still usable after chaining
+        let mut conditional = conditional;
+        conditional.accept(&3);
+        assert_eq!(*log.borrow(), vec![5, 10, -10, 3]);
+    }
+
     #[test]
     fn test_arc_when() {
         let log = Arc::new(Mutex::new(Vec::new()));
@@ -2206,3 +2885,838 @@ mod test_closure_to_methods {
         // consumer.accept(&3); // Would not compile
     }
 }
+
+// ============================================================================
+// when_all / when_any Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_when_all_any {
+    use super::*;
+    use prism3_function::{ArcPredicate, BoxPredicate, RcPredicate};
+
+    fn recording(
+        log: &Arc<Mutex<Vec<&'static str>>>,
+        name: &'static str,
+        result: bool,
+    ) -> impl Fn(&i32) -> bool + Send + Sync + 'static {
+        let log = log.clone();
+        move |_: &i32| {
+            log.lock().unwrap().push(name);
+            result
+        }
+    }
+
+    #[test]
+    fn test_box_when_all_short_circuits_in_order() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.when_all(vec![
+            BoxPredicate::new(recording(&log, "a", true)),
+            BoxPredicate::new(recording(&log, "b", false)),
+            BoxPredicate::new(recording(&log, "c", true)),
+        ]);
+        consumer.accept(&1);
+        assert_eq!(*log.lock().unwrap(), vec!["a", "b"]);
+        assert!(values.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_box_when_all_runs_when_all_pass() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.when_all(vec![
+            BoxPredicate::new(recording(&log, "a", true)),
+            BoxPredicate::new(recording(&log, "b", true)),
+        ]);
+        consumer.accept(&1);
+        assert_eq!(*log.lock().unwrap(), vec!["a", "b"]);
+        assert_eq!(*values.lock().unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_box_when_any_short_circuits_in_order() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.when_any(vec![
+            BoxPredicate::new(recording(&log, "a", false)),
+            BoxPredicate::new(recording(&log, "b", true)),
+            BoxPredicate::new(recording(&log, "c", true)),
+        ]);
+        consumer.accept(&1);
+        assert_eq!(*log.lock().unwrap(), vec!["a", "b"]);
+        assert_eq!(*values.lock().unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_box_empty_lists() {
+        let (all, all_values) = BoxConsumer::collect();
+        let (any, any_values) = BoxConsumer::collect();
+        all.when_all(Vec::new()).accept(&1);
+        any.when_any(Vec::new()).accept(&1);
+        assert_eq!(*all_values.lock().unwrap(), vec![1]);
+        assert!(any_values.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_box_when_all_with_or_else() {
+        let (inner, values) = BoxConsumer::collect();
+        let (fallback, rejected) = BoxConsumer::collect();
+        let mut consumer = inner
+            .when_all(vec![
+                BoxPredicate::new(|x: &i32| *x > 0),
+                BoxPredicate::new(|x: &i32| *x < 10),
+            ])
+            .or_else(fallback);
+        for x in [5, 15, -1] {
+            consumer.accept(&x);
+        }
+        assert_eq!(*values.lock().unwrap(), vec![5]);
+        assert_eq!(*rejected.lock().unwrap(), vec![15, -1]);
+    }
+
+    #[test]
+    fn test_arc_when_all_and_when_any() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let s = seen.clone();
+        let consumer = ArcConsumer::new(move |x: &i32| s.lock().unwrap().push(*x));
+
+        let mut all = consumer.when_all(vec![
+            ArcPredicate::new(recording(&log, "a", false)),
+            ArcPredicate::new(recording(&log, "b", true)),
+        ]);
+        let mut any = consumer.when_any(vec![
+            ArcPredicate::new(recording(&log, "c", true)),
+            ArcPredicate::new(recording(&log, "d", true)),
+        ]);
+        all.accept(&1);
+        any.accept(&2);
+        assert_eq!(*log.lock().unwrap(), vec!["a", "c"]);
+        assert_eq!(*seen.lock().unwrap(), vec![2]);
+
+        let mut clone = any.clone();
+        std::thread::spawn(move || clone.accept(&3)).join().unwrap();
+        assert_eq!(*seen.lock().unwrap(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_rc_when_all_and_when_any() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let s = seen.clone();
+        let consumer = RcConsumer::new(move |x: &i32| s.borrow_mut().push(*x));
+
+        let mut all = consumer.when_all(vec![
+            RcPredicate::new(recording(&log, "a", true)),
+            RcPredicate::new(recording(&log, "b", true)),
+        ]);
+        let mut any = consumer.when_any(vec![
+            RcPredicate::new(recording(&log, "c", false)),
+            RcPredicate::new(recording(&log, "d", false)),
+        ]);
+        all.accept(&1);
+        any.accept(&2);
+        assert_eq!(*log.lock().unwrap(), vec!["a", "b", "c", "d"]);
+        assert_eq!(*seen.borrow(), vec![1]);
+    }
+}
+
+// ============================================================================
+// Sampling (every / skip / take) Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_sampling {
+    use super::*;
+
+    fn feed(consumer: &mut impl Consumer<i32>, count: i32) {
+        for x in 1..=count {
+            consumer.accept(&x);
+        }
+    }
+
+    #[test]
+    fn test_every_one_is_passthrough() {
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.every(1);
+        feed(&mut consumer, 5);
+        assert_eq!(*values.lock().unwrap(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_every_three() {
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.every(3);
+        feed(&mut consumer, 10);
+        assert_eq!(*values.lock().unwrap(), vec![1, 4, 7, 10]);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero interval")]
+    fn test_every_zero_panics() {
+        let _ = BoxConsumer::<i32>::noop().every(0);
+    }
+
+    #[test]
+    fn test_skip_boundaries() {
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.skip(3);
+        feed(&mut consumer, 3);
+        assert!(values.lock().unwrap().is_empty());
+        consumer.accept(&4);
+        assert_eq!(*values.lock().unwrap(), vec![4]);
+
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.skip(0);
+        feed(&mut consumer, 2);
+        assert_eq!(*values.lock().unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_take_boundaries() {
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.take(3);
+        feed(&mut consumer, 3);
+        assert_eq!(*values.lock().unwrap(), vec![1, 2, 3]);
+        consumer.accept(&4);
+        assert_eq!(*values.lock().unwrap(), vec![1, 2, 3]);
+
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.take(0);
+        feed(&mut consumer, 2);
+        assert!(values.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_skip_then_every_then_take() {
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.take(2).every(2).skip(1);
+        feed(&mut consumer, 10);
+        assert_eq!(*values.lock().unwrap(), vec![2, 4]);
+    }
+
+    #[test]
+    fn test_keeps_name() {
+        let consumer = BoxConsumer::new_with_name("sink", |_: &i32| {});
+        assert_eq!(consumer.every(2).skip(1).take(1).name(), Some("sink"));
+    }
+
+    #[test]
+    fn test_arc_clones_share_counter() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let s = seen.clone();
+        let sampled = ArcConsumer::new(move |x: &i32| s.lock().unwrap().push(*x)).every(3);
+        let mut a = sampled.clone();
+        let mut b = sampled.clone();
+        a.accept(&1);
+        b.accept(&2);
+        a.accept(&3);
+        b.accept(&4);
+        assert_eq!(*seen.lock().unwrap(), vec![1, 4]);
+    }
+
+    #[test]
+    fn test_arc_take_across_threads() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let s = seen.clone();
+        let limited = ArcConsumer::new(move |x: &i32| s.lock().unwrap().push(*x)).take(10);
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let mut c = limited.clone();
+                std::thread::spawn(move || {
+                    for x in 0..25 {
+                        c.accept(&(i * 100 + x));
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(seen.lock().unwrap().len(), 10);
+    }
+
+    #[test]
+    fn test_arc_skip_keeps_original_usable() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let s = seen.clone();
+        let mut original = ArcConsumer::new(move |x: &i32| s.lock().unwrap().push(*x));
+        let mut skipping = original.skip(1);
+        skipping.accept(&1);
+        skipping.accept(&2);
+        original.accept(&3);
+        assert_eq!(*seen.lock().unwrap(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_rc_clones_share_counter() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let s = seen.clone();
+        let consumer = RcConsumer::new(move |x: &i32| s.borrow_mut().push(*x));
+        let skipping = consumer.skip(2);
+        let mut a = skipping.clone();
+        let mut b = skipping;
+        a.accept(&1);
+        b.accept(&2);
+        a.accept(&3);
+        let mut taking = consumer.take(1);
+        taking.accept(&10);
+        taking.accept(&11);
+        let mut every = consumer.every(2);
+        feed(&mut every, 4);
+        assert_eq!(*seen.borrow(), vec![3, 10, 1, 3]);
+    }
+}
+
+// ============================================================================
+// windowed Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_windowed {
+    use super::*;
+
+    #[test]
+    fn test_fires_only_when_full() {
+        let (inner, windows) = BoxConsumer::<Vec<i32>>::collect();
+        let mut consumer = inner.windowed(3);
+        consumer.accept(&1);
+        consumer.accept(&2);
+        assert!(windows.lock().unwrap().is_empty());
+        consumer.accept(&3);
+        consumer.accept(&4);
+        consumer.accept(&5);
+        assert_eq!(
+            *windows.lock().unwrap(),
+            vec![vec![1, 2, 3], vec![2, 3, 4], vec![3, 4, 5]]
+        );
+    }
+
+    #[test]
+    fn test_window_of_one() {
+        let (inner, windows) = BoxConsumer::<Vec<&str>>::collect();
+        let mut consumer = inner.windowed(1);
+        consumer.accept(&"a");
+        consumer.accept(&"b");
+        assert_eq!(*windows.lock().unwrap(), vec![vec!["a"], vec!["b"]]);
+    }
+
+    #[test]
+    fn test_moving_average() {
+        let (averages, values) = BoxConsumer::collect();
+        let mut consumer = averages
+            .adapt(|w: &Vec<f64>| w.iter().sum::<f64>() / w.len() as f64)
+            .windowed(2);
+        consumer.accept_all([1.0, 3.0, 5.0, 5.0]);
+        assert_eq!(*values.lock().unwrap(), vec![2.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn test_pattern_detection_keeps_name() {
+        let hits = Rc::new(RefCell::new(0));
+        let h = hits.clone();
+        let mut detector = BoxConsumer::new_with_name("rising", move |w: &Vec<i32>| {
+            if w.windows(2).all(|pair| pair[0] < pair[1]) {
+                *h.borrow_mut() += 1;
+            }
+        })
+        .windowed(3);
+        assert_eq!(detector.name(), Some("rising"));
+        detector.accept_all([1, 2, 3, 2, 3, 4, 5]);
+        assert_eq!(*hits.borrow(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero window size")]
+    fn test_zero_window_panics() {
+        let _ = BoxConsumer::<Vec<i32>>::noop().windowed(0);
+    }
+}
+
+// ============================================================================
+// catch_unwind Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_catch_unwind {
+    use super::*;
+
+    fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+        payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_box_consumer_recovers_after_panic() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let panics = Rc::new(RefCell::new(Vec::new()));
+        let (l, p) = (log.clone(), panics.clone());
+        let mut consumer = BoxConsumer::new(move |x: &i32| {
+            if *x < 0 {
+                panic!("negative input: {}", x);
+            }
+            l.borrow_mut().push(*x);
+        })
+        .catch_unwind(move |payload| p.borrow_mut().push(panic_message(&*payload)));
+
+        consumer.accept(&-1);
+        consumer.accept(&2);
+        assert_eq!(*log.borrow(), vec![2]);
+        assert_eq!(*panics.borrow(), vec!["negative input: -1"]);
+    }
+
+    #[test]
+    fn test_keeps_name() {
+        let mut consumer = BoxConsumer::new(|_: &i32| {});
+        consumer.set_name("observer");
+        let consumer = consumer.catch_unwind(|_| {});
+        assert_eq!(consumer.name(), Some("observer"));
+    }
+
+    #[test]
+    fn test_arc_consumer_is_not_poisoned() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l = log.clone();
+        let consumer = ArcConsumer::new(move |x: &i32| {
+            assert!(*x >= 0, "negative input");
+            l.lock().unwrap().push(*x);
+        });
+        let panics = Rc::new(RefCell::new(0));
+        let p = panics.clone();
+        let mut isolated = consumer.clone().catch_unwind(move |_| *p.borrow_mut() += 1);
+
+        isolated.accept(&-1);
+        isolated.accept(&3);
+        // The original shared consumer still works after the caught panic
+        let mut shared = consumer;
+        shared.accept(&4);
+        assert_eq!(*log.lock().unwrap(), vec![3, 4]);
+        assert_eq!(*panics.borrow(), 1);
+    }
+
+    #[test]
+    fn test_isolates_one_observer_in_chain() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (l1, l2) = (log.clone(), log.clone());
+        let faulty = BoxConsumer::new(|x: &i32| {
+            assert!(*x >= 0, "negative input");
+        })
+        .catch_unwind(|_| {});
+        let mut chain = BoxConsumer::new(move |x: &i32| l1.borrow_mut().push(*x))
+            .and_then(faulty)
+            .and_then(move |x: &i32| l2.borrow_mut().push(*x * 10));
+
+        chain.accept(&-1);
+        chain.accept(&1);
+        assert_eq!(*log.borrow(), vec![-1, -10, 1, 10]);
+    }
+
+    #[test]
+    fn test_closure_catch_unwind() {
+        let count = Rc::new(RefCell::new(0));
+        let c = count.clone();
+        let mut consumer = (|x: &i32| {
+            if *x == 0 {
+                panic!("zero");
+            }
+        })
+        .catch_unwind(move |_| *c.borrow_mut() += 1);
+        consumer.accept(&0);
+        consumer.accept(&1);
+        consumer.accept(&0);
+        assert_eq!(*count.borrow(), 2);
+    }
+}
+
+// ============================================================================
+// Consumer to Mutator Conversion Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_into_mutator {
+    use super::*;
+    use prism3_function::Mutator;
+
+    #[test]
+    fn test_box_into_mutator_reads_only() {
+        let (consumer, values) = BoxConsumer::<String>::collect();
+        let mut mutator = consumer.into_mutator();
+        let mut value = "a".to_string();
+        mutator.mutate(&mut value);
+        assert_eq!(value, "a");
+        assert_eq!(*values.lock().unwrap(), vec!["a"]);
+    }
+
+    #[test]
+    fn test_box_into_mutator_in_mutator_chain() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let observe = BoxConsumer::new(move |x: &i32| l.borrow_mut().push(*x)).into_mutator();
+        let mut chain = prism3_function::BoxMutator::new(|x: &mut i32| *x += 1)
+            .and_then(observe)
+            .and_then(|x: &mut i32| *x *= 10);
+        let mut value = 1;
+        chain.mutate(&mut value);
+        assert_eq!(value, 20);
+        assert_eq!(*log.borrow(), vec![2]);
+    }
+
+    #[test]
+    fn test_arc_into_mutator_shares_state() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l = log.clone();
+        let consumer = ArcConsumer::new(move |x: &i32| l.lock().unwrap().push(*x));
+        let mut mutator = consumer.clone().into_mutator();
+        let mut value = 3;
+        mutator.mutate(&mut value);
+        let mut consumer = consumer;
+        consumer.accept(&4);
+        assert_eq!(value, 3);
+        assert_eq!(*log.lock().unwrap(), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_rc_into_mutator() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let mut mutator = RcConsumer::new(move |x: &i32| l.borrow_mut().push(*x)).into_mutator();
+        let mut value = 7;
+        mutator.mutate(&mut value);
+        assert_eq!(value, 7);
+        assert_eq!(*log.borrow(), vec![7]);
+    }
+}
+
+// ============================================================================
+// debounce / throttle_leading Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_timed {
+    use super::*;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_timed_reports_at_least_inner_duration() {
+        let reports = Rc::new(RefCell::new(Vec::new()));
+        let r = reports.clone();
+        let mut consumer = BoxConsumer::new(|ms: &u64| thread::sleep(Duration::from_millis(*ms)))
+            .timed(move |ms: &u64, d: &Duration| r.borrow_mut().push((*ms, *d)));
+        consumer.accept(&20);
+        consumer.accept(&5);
+        let reports = reports.borrow();
+        assert_eq!(reports.len(), 2);
+        for (ms, elapsed) in reports.iter() {
+            assert!(*elapsed >= Duration::from_millis(*ms));
+        }
+    }
+
+    #[test]
+    fn test_timed_forwards_values_and_keeps_name() {
+        let values = Arc::new(Mutex::new(Vec::new()));
+        let v = values.clone();
+        let count = Rc::new(RefCell::new(0));
+        let c = count.clone();
+        let mut consumer =
+            BoxConsumer::new_with_name("sink", move |x: &i32| v.lock().unwrap().push(*x))
+                .timed(move |_: &i32, _: &Duration| *c.borrow_mut() += 1);
+        assert_eq!(consumer.name(), Some("sink"));
+        consumer.accept_all([1, 2, 3]);
+        assert_eq!(*values.lock().unwrap(), vec![1, 2, 3]);
+        assert_eq!(*count.borrow(), 3);
+    }
+
+    #[test]
+    fn test_timed_on_shared_consumer() {
+        let values = Arc::new(Mutex::new(Vec::new()));
+        let v = values.clone();
+        let shared = ArcConsumer::new(move |x: &i32| v.lock().unwrap().push(*x));
+        let total = Rc::new(RefCell::new(0));
+        let t = total.clone();
+        let mut consumer = shared
+            .clone()
+            .timed(move |_: &i32, _: &Duration| *t.borrow_mut() += 1);
+        consumer.accept(&7);
+        let mut shared = shared;
+        shared.accept(&8);
+        assert_eq!(*values.lock().unwrap(), vec![7, 8]);
+        assert_eq!(*total.borrow(), 1);
+    }
+}
+
+#[cfg(test)]
+mod test_debounce {
+    use super::*;
+    use std::cell::Cell;
+    use std::time::{Duration, Instant};
+
+    /// A clock that only moves when the returned handle is advanced
+    fn manual_clock() -> (Rc<Cell<Instant>>, impl Fn() -> Instant + 'static) {
+        let now = Rc::new(Cell::new(Instant::now()));
+        let clock = now.clone();
+        (now, move || clock.get())
+    }
+
+    fn advance(now: &Cell<Instant>, millis: u64) {
+        now.set(now.get() + Duration::from_millis(millis));
+    }
+
+    #[test]
+    fn test_debounce_drops_values_within_interval() {
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.debounce(Duration::from_secs(60));
+        consumer.accept_all([1, 2, 3]);
+        assert_eq!(*values.lock().unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_debounce_zero_interval_forwards_everything() {
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.debounce(Duration::ZERO);
+        consumer.accept_all([1, 2, 3]);
+        assert_eq!(*values.lock().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_debounce_forwards_after_interval() {
+        let (now, clock) = manual_clock();
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.debounce_with_clock(Duration::from_millis(50), clock);
+        consumer.accept(&1);
+        advance(&now, 49);
+        consumer.accept(&2);
+        advance(&now, 1);
+        consumer.accept(&3);
+        assert_eq!(*values.lock().unwrap(), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_debounce_measures_from_last_forwarded_value() {
+        let (now, clock) = manual_clock();
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.debounce_with_clock(Duration::from_millis(200), clock);
+        consumer.accept(&1);
+        advance(&now, 120);
+        consumer.accept(&2);
+        advance(&now, 120);
+        consumer.accept(&3);
+        assert_eq!(*values.lock().unwrap(), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_throttle_leading_measures_from_last_forwarded_value() {
+        let (now, clock) = manual_clock();
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.throttle_leading_with_clock(Duration::from_millis(200), clock);
+        consumer.accept(&1);
+        advance(&now, 120);
+        consumer.accept(&2);
+        advance(&now, 120);
+        consumer.accept(&3);
+        assert_eq!(*values.lock().unwrap(), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_throttle_leading_thins_steady_fast_stream() {
+        let (now, clock) = manual_clock();
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.throttle_leading_with_clock(Duration::from_millis(100), clock);
+        for x in 0..60 {
+            consumer.accept(&x);
+            advance(&now, 5);
+        }
+        assert_eq!(*values.lock().unwrap(), vec![0, 20, 40]);
+    }
+
+    #[test]
+    fn test_throttle_leading_forwards_after_quiet_period() {
+        let (now, clock) = manual_clock();
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.throttle_leading_with_clock(Duration::from_millis(50), clock);
+        consumer.accept_all([1, 2]);
+        advance(&now, 60);
+        consumer.accept_all([3, 4]);
+        assert_eq!(*values.lock().unwrap(), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_throttle_leading_drops_values_within_interval() {
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = inner.throttle_leading(Duration::from_secs(60));
+        consumer.accept_all([1, 2, 3]);
+        assert_eq!(*values.lock().unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_keeps_name() {
+        let mut consumer = BoxConsumer::new(|_: &i32| {});
+        consumer.set_name("ticks");
+        let consumer = consumer.debounce(Duration::ZERO);
+        assert_eq!(consumer.name(), Some("ticks"));
+        let consumer = consumer.throttle_leading(Duration::ZERO);
+        assert_eq!(consumer.name(), Some("ticks"));
+        let (_, clock) = manual_clock();
+        let consumer = consumer.debounce_with_clock(Duration::ZERO, clock);
+        assert_eq!(consumer.name(), Some("ticks"));
+    }
+}
+
+// ============================================================================
+// accept_all Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_accept_all {
+    use super::*;
+
+    #[test]
+    fn test_order_matches_repeated_accept() {
+        let values = vec![3, 1, 4, 1, 5, 9, 2, 6];
+
+        let (mut one_by_one, expected) = BoxConsumer::collect();
+        for v in &values {
+            one_by_one.accept(v);
+        }
+
+        let (mut boxed, from_box) = BoxConsumer::<i32>::collect();
+        boxed.accept_all(&values);
+
+        let from_rc = Rc::new(RefCell::new(Vec::new()));
+        let l = from_rc.clone();
+        let mut rc = RcConsumer::new(move |x: &i32| l.borrow_mut().push(*x));
+        rc.accept_all(values.iter());
+
+        let from_arc = Arc::new(Mutex::new(Vec::new()));
+        let l = from_arc.clone();
+        let mut arc = ArcConsumer::new(move |x: &i32| l.lock().unwrap().push(*x));
+        arc.accept_all(values.clone());
+
+        let from_closure = Rc::new(RefCell::new(Vec::new()));
+        let l = from_closure.clone();
+        let mut closure = move |x: &i32| l.borrow_mut().push(*x);
+        closure.accept_all(&values);
+
+        let expected = expected.lock().unwrap().clone();
+        assert_eq!(expected, values);
+        assert_eq!(*from_box.lock().unwrap(), expected);
+        assert_eq!(*from_rc.borrow(), expected);
+        assert_eq!(*from_arc.lock().unwrap(), expected);
+        assert_eq!(*from_closure.borrow(), expected);
+    }
+
+    #[test]
+    fn test_empty_iterator() {
+        let (mut consumer, values) = BoxConsumer::<i32>::collect();
+        consumer.accept_all(Vec::<i32>::new());
+        assert!(values.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_arc_fast_path_large_batch() {
+        let values: Vec<u64> = (0..10_000).collect();
+        let seen = Arc::new(Mutex::new(Vec::with_capacity(values.len())));
+        let s = seen.clone();
+        let consumer = ArcConsumer::new(move |x: &u64| s.lock().unwrap().push(*x));
+
+        let mut worker = consumer.clone();
+        let batch = values.clone();
+        std::thread::spawn(move || worker.accept_all(&batch))
+            .join()
+            .unwrap();
+
+        assert_eq!(*seen.lock().unwrap(), values);
+        // The shared function is usable again once the batch completes
+        let mut consumer = consumer;
+        consumer.accept(&10_000);
+        assert_eq!(seen.lock().unwrap().len(), 10_001);
+    }
+
+    #[test]
+    fn test_rc_shared_state_across_batches() {
+        let mut sum = 0;
+        let total = Rc::new(RefCell::new(0));
+        let t = total.clone();
+        let consumer = RcConsumer::new(move |x: &i32| {
+            sum += x;
+            *t.borrow_mut() = sum;
+        });
+        let mut a = consumer.clone();
+        let mut b = consumer;
+        a.accept_all([1, 2, 3]);
+        b.accept_all([4, 5]);
+        assert_eq!(*total.borrow(), 15);
+    }
+}
+
+// ============================================================================
+// Stream Adapter Tests
+// ============================================================================
+
+#[cfg(feature = "futures")]
+mod test_stream_adapters {
+    use futures::executor::block_on;
+    use futures::{stream, SinkExt, StreamExt};
+    use prism3_function::{ArcConsumer, BoxConsumer};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    #[test]
+    fn test_into_stream_sink_send() {
+        let (consumer, values) = BoxConsumer::collect();
+        let mut sink = consumer.into_stream_sink();
+        block_on(async {
+            sink.send("a".to_string()).await.unwrap();
+            sink.send("b".to_string()).await.unwrap();
+            sink.close().await.unwrap();
+        });
+        assert_eq!(*values.lock().unwrap(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_into_stream_sink_forward() {
+        let (consumer, values) = BoxConsumer::collect();
+        let sink = consumer.into_stream_sink();
+        block_on(stream::iter(1..=5).map(Ok).forward(sink)).unwrap();
+        assert_eq!(*values.lock().unwrap(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_as_stream_consumer_drains_stream() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let s = seen.clone();
+        let consumer = ArcConsumer::new(move |x: &i32| s.lock().unwrap().push(*x));
+        block_on(consumer.as_stream_consumer(stream::iter(vec![3, 1, 2])));
+        assert_eq!(*seen.lock().unwrap(), vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn test_as_stream_consumer_empty_stream() {
+        let calls = Arc::new(Mutex::new(0));
+        let c = calls.clone();
+        let consumer = ArcConsumer::new(move |_: &i32| *c.lock().unwrap() += 1);
+        block_on(consumer.as_stream_consumer(stream::empty()));
+        assert_eq!(*calls.lock().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_as_stream_consumer_shares_state_across_threads() {
+        let total = Arc::new(Mutex::new(0));
+        let t = total.clone();
+        let consumer = ArcConsumer::new(move |x: &i32| *t.lock().unwrap() += *x);
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let future = consumer.as_stream_consumer(stream::iter(1..=10));
+                thread::spawn(move || block_on(future))
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(*total.lock().unwrap(), 110);
+    }
+}
@@ -0,0 +1,152 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! Unit tests for counting consumer types
+
+use prism3_function::{ArcConsumer, ArcCountingConsumer, BoxConsumer, Consumer, CountingConsumer};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// ============================================================================
+// CountingConsumer Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_counting_consumer {
+    use super::*;
+
+    #[test]
+    fn test_counts_and_forwards() {
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = CountingConsumer::new(inner);
+        assert_eq!(consumer.count(), 0);
+        consumer.accept(&1);
+        consumer.accept(&2);
+        consumer.accept(&3);
+        assert_eq!(consumer.count(), 3);
+        assert_eq!(*values.lock().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_new_does_not_record_last() {
+        let mut consumer = CountingConsumer::new(|_: &i32| {});
+        consumer.accept(&7);
+        assert_eq!(consumer.last(), None);
+    }
+
+    #[test]
+    fn test_with_last_records_last_value() {
+        let mut consumer = CountingConsumer::with_last(|_: &String| {});
+        assert_eq!(consumer.last(), None);
+        consumer.accept(&"a".to_string());
+        consumer.accept(&"b".to_string());
+        assert_eq!(consumer.count(), 2);
+        assert_eq!(consumer.last(), Some("b".to_string()));
+    }
+
+    #[test]
+    fn test_into_inner() {
+        let (inner, values) = BoxConsumer::collect();
+        let mut consumer = CountingConsumer::new(inner);
+        consumer.accept(&1);
+        let mut inner = consumer.into_inner();
+        inner.accept(&2);
+        assert_eq!(*values.lock().unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut consumer = CountingConsumer::new(|_: &i32| {});
+        consumer.accept(&1);
+        assert_eq!(format!("{:?}", consumer), "CountingConsumer { count: 1 }");
+    }
+}
+
+// ============================================================================
+// ArcCountingConsumer Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_arc_counting_consumer {
+    use super::*;
+
+    #[test]
+    fn test_exact_count_from_two_threads() {
+        let sum = Arc::new(Mutex::new(0));
+        let s = sum.clone();
+        let counter =
+            ArcCountingConsumer::new(ArcConsumer::new(move |x: &i32| *s.lock().unwrap() += x));
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let mut c = counter.clone();
+                thread::spawn(move || {
+                    for _ in 0..50 {
+                        c.accept(&1);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(counter.count(), 100);
+        assert_eq!(*sum.lock().unwrap(), 100);
+    }
+
+    #[test]
+    fn test_with_last_shared_between_clones() {
+        let counter = ArcCountingConsumer::with_last(ArcConsumer::new(|_: &i32| {}));
+        let mut a = counter.clone();
+        let mut b = counter.clone();
+        a.accept(&1);
+        b.accept(&2);
+        assert_eq!(counter.count(), 2);
+        assert_eq!(counter.last(), Some(2));
+    }
+
+    #[test]
+    fn test_new_does_not_record_last() {
+        let mut counter = ArcCountingConsumer::new(ArcConsumer::new(|_: &i32| {}));
+        counter.accept(&1);
+        assert_eq!(counter.last(), None);
+    }
+
+    #[test]
+    fn test_panicking_clone_does_not_poison_last() {
+        /// Value whose clone panics while `fragile` is set
+        struct Fragile(i32, Arc<AtomicBool>);
+        impl Clone for Fragile {
+            fn clone(&self) -> Self {
+                assert!(!self.1.load(Ordering::SeqCst), "clone refused");
+                Fragile(self.0, self.1.clone())
+            }
+        }
+
+        let fragile = Arc::new(AtomicBool::new(false));
+        let mut counter = ArcCountingConsumer::with_last(ArcConsumer::new(|_: &Fragile| {}));
+        counter.accept(&Fragile(1, fragile.clone()));
+
+        // Clones the last value while holding its lock, then panics
+        fragile.store(true, Ordering::SeqCst);
+        let clone = counter.clone();
+        assert!(thread::spawn(move || clone.last()).join().is_err());
+        fragile.store(false, Ordering::SeqCst);
+
+        counter.accept(&Fragile(2, fragile.clone()));
+        assert_eq!(counter.count(), 2);
+        assert_eq!(counter.last().map(|f| f.0), Some(2));
+    }
+
+    #[test]
+    fn test_debug() {
+        let counter = ArcCountingConsumer::new(ArcConsumer::new(|_: &i32| {}));
+        assert_eq!(format!("{:?}", counter), "ArcCountingConsumer { count: 0 }");
+    }
+}
@@ -0,0 +1,243 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! Unit tests for EventBus types
+
+use prism3_function::{ArcConsumer, ArcEventBus, BoxConsumer, EventBus};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// ============================================================================
+// EventBus Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_event_bus {
+    use super::*;
+
+    #[test]
+    fn test_publish_to_subscribers_in_order() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l1 = log.clone();
+        let l2 = log.clone();
+        let mut bus = EventBus::new();
+        bus.subscribe(
+            "click",
+            BoxConsumer::new(move |x: &i32| {
+                l1.lock().unwrap().push(*x);
+            }),
+        );
+        bus.subscribe(
+            "click",
+            BoxConsumer::new(move |x: &i32| {
+                l2.lock().unwrap().push(*x * 10);
+            }),
+        );
+
+        bus.publish(&"click", &3);
+        assert_eq!(*log.lock().unwrap(), vec![3, 30]);
+        assert_eq!(bus.subscriber_count(&"click"), 2);
+    }
+
+    #[test]
+    fn test_publish_only_reaches_matching_key() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l1 = log.clone();
+        let l2 = log.clone();
+        let mut bus = EventBus::new();
+        bus.subscribe(
+            "a".to_string(),
+            BoxConsumer::new(move |x: &i32| {
+                l1.lock().unwrap().push(format!("a:{}", x));
+            }),
+        );
+        bus.subscribe(
+            "b".to_string(),
+            BoxConsumer::new(move |x: &i32| {
+                l2.lock().unwrap().push(format!("b:{}", x));
+            }),
+        );
+
+        bus.publish(&"b".to_string(), &1);
+        bus.publish(&"c".to_string(), &2);
+        assert_eq!(*log.lock().unwrap(), vec!["b:1".to_string()]);
+    }
+
+    #[test]
+    fn test_stateful_subscriber() {
+        let total = Arc::new(Mutex::new(0));
+        let t = total.clone();
+        let mut sum = 0;
+        let mut bus = EventBus::new();
+        bus.subscribe(
+            1u8,
+            BoxConsumer::new(move |x: &i32| {
+                sum += *x;
+                *t.lock().unwrap() = sum;
+            }),
+        );
+
+        bus.publish(&1, &2);
+        bus.publish(&1, &5);
+        assert_eq!(*total.lock().unwrap(), 7);
+    }
+
+    #[test]
+    fn test_unsubscribe_all() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l = log.clone();
+        let mut bus = EventBus::new();
+        bus.subscribe(
+            "k",
+            BoxConsumer::new(move |x: &i32| {
+                l.lock().unwrap().push(*x);
+            }),
+        );
+
+        bus.publish(&"k", &1);
+        let removed = bus.unsubscribe_all(&"k");
+        assert_eq!(removed.len(), 1);
+        assert_eq!(bus.subscriber_count(&"k"), 0);
+        bus.publish(&"k", &2);
+        assert_eq!(*log.lock().unwrap(), vec![1]);
+        assert!(bus.unsubscribe_all(&"k").is_empty());
+    }
+
+    #[test]
+    fn test_default_is_empty() {
+        let mut bus: EventBus<&str, i32> = EventBus::default();
+        bus.publish(&"none", &1);
+        assert_eq!(bus.subscriber_count(&"none"), 0);
+    }
+}
+
+// ============================================================================
+// ArcEventBus Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_arc_event_bus {
+    use super::*;
+
+    #[test]
+    fn test_publish_and_unsubscribe() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l = log.clone();
+        let bus = ArcEventBus::new();
+        bus.subscribe(
+            "k",
+            ArcConsumer::new(move |x: &i32| {
+                l.lock().unwrap().push(*x);
+            }),
+        );
+
+        bus.publish(&"k", &1);
+        assert_eq!(bus.unsubscribe_all(&"k").len(), 1);
+        bus.publish(&"k", &2);
+        assert_eq!(*log.lock().unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_clones_share_subscriptions() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l = log.clone();
+        let bus = ArcEventBus::new();
+        let clone = bus.clone();
+        clone.subscribe(
+            "k",
+            ArcConsumer::new(move |x: &i32| {
+                l.lock().unwrap().push(*x);
+            }),
+        );
+
+        bus.publish(&"k", &9);
+        assert_eq!(bus.subscriber_count(&"k"), 1);
+        assert_eq!(*log.lock().unwrap(), vec![9]);
+    }
+
+    #[test]
+    fn test_publish_from_multiple_threads() {
+        let count = Arc::new(Mutex::new(0));
+        let c = count.clone();
+        let bus = ArcEventBus::new();
+        bus.subscribe(
+            "inc",
+            ArcConsumer::new(move |x: &i32| {
+                *c.lock().unwrap() += *x;
+            }),
+        );
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let bus = bus.clone();
+                thread::spawn(move || {
+                    for _ in 0..10 {
+                        bus.publish(&"inc", &1);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(*count.lock().unwrap(), 40);
+    }
+
+    #[test]
+    fn test_subscriber_can_publish_on_same_bus() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l = log.clone();
+        let bus: ArcEventBus<&str, i32> = ArcEventBus::new();
+        let inner = bus.clone();
+        bus.subscribe(
+            "outer",
+            ArcConsumer::new(move |x: &i32| {
+                inner.publish(&"inner", &(*x + 1));
+            }),
+        );
+        bus.subscribe(
+            "inner",
+            ArcConsumer::new(move |x: &i32| {
+                l.lock().unwrap().push(*x);
+            }),
+        );
+
+        bus.publish(&"outer", &1);
+        assert_eq!(*log.lock().unwrap(), vec![2]);
+    }
+
+    #[test]
+    fn test_panic_while_locked_does_not_break_bus() {
+        /// Key whose hash panics for "bad", poisoning the table lock
+        #[derive(PartialEq, Eq, Debug)]
+        struct Key(&'static str);
+        impl std::hash::Hash for Key {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                assert!(self.0 != "bad", "unhashable key");
+                self.0.hash(state);
+            }
+        }
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l = log.clone();
+        let bus: ArcEventBus<Key, i32> = ArcEventBus::new();
+        bus.subscribe(
+            Key("ok"),
+            ArcConsumer::new(move |x: &i32| l.lock().unwrap().push(*x)),
+        );
+        let clone = bus.clone();
+        let result = thread::spawn(move || clone.subscribe(Key("bad"), ArcConsumer::new(|_| {})));
+        assert!(result.join().is_err());
+
+        bus.publish(&Key("ok"), &7);
+        assert_eq!(bus.subscriber_count(&Key("ok")), 1);
+        assert_eq!(format!("{:?}", bus), "ArcEventBus { keys: [Key(\"ok\")] }");
+        assert_eq!(*log.lock().unwrap(), vec![7]);
+    }
+}
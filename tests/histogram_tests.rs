@@ -0,0 +1,94 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! Unit tests for histogram types
+
+use prism3_function::{ArcPredicate, BoxPredicate, Consumer, PredicateHistogram};
+
+// ============================================================================
+// PredicateHistogram Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_predicate_histogram {
+    use super::*;
+
+    fn sign_histogram() -> PredicateHistogram<i32> {
+        PredicateHistogram::new()
+            .bucket("negative", |x: &i32| *x < 0)
+            .bucket("zero", |x: &i32| *x == 0)
+            .bucket("positive", |x: &i32| *x > 0)
+    }
+
+    #[test]
+    fn test_counts_each_bucket() {
+        let mut histogram = sign_histogram();
+        histogram.accept_all([-3, -1, 0, 2, 4, 6]);
+        let report = histogram.report();
+        assert_eq!(report.len(), 3);
+        assert_eq!(report["negative"], 2);
+        assert_eq!(report["zero"], 1);
+        assert_eq!(report["positive"], 3);
+        assert_eq!(histogram.unmatched(), 0);
+    }
+
+    #[test]
+    fn test_first_matching_bucket_wins() {
+        let mut histogram = PredicateHistogram::new()
+            .bucket("small", |x: &i32| *x < 10)
+            .bucket("even", |x: &i32| x % 2 == 0);
+        histogram.accept_all([2, 4, 12, 13]);
+        assert_eq!(histogram.count("small"), Some(2));
+        assert_eq!(histogram.count("even"), Some(1));
+        assert_eq!(histogram.count("missing"), None);
+        assert_eq!(histogram.unmatched(), 1);
+    }
+
+    #[test]
+    fn test_empty_buckets_are_reported() {
+        let histogram = sign_histogram();
+        assert!(histogram.report().values().all(|count| *count == 0));
+    }
+
+    #[test]
+    fn test_accepts_predicate_types() {
+        let mut histogram = PredicateHistogram::new()
+            .bucket("short", BoxPredicate::new(|s: &String| s.len() < 4))
+            .bucket("long", ArcPredicate::new(|s: &String| s.len() >= 4));
+        histogram.accept(&"abc".to_string());
+        histogram.accept(&"abcdef".to_string());
+        assert_eq!(histogram.count("short"), Some(1));
+        assert_eq!(histogram.count("long"), Some(1));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut histogram = sign_histogram().bucket("never", |_: &i32| false);
+        histogram.accept_all([-1, 0, 1]);
+        histogram.reset();
+        assert!(histogram.report().values().all(|count| *count == 0));
+        histogram.accept(&5);
+        assert_eq!(histogram.count("positive"), Some(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate histogram bucket name: zero")]
+    fn test_duplicate_bucket_name_panics() {
+        let _ = sign_histogram().bucket("zero", |_: &i32| true);
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut histogram = sign_histogram();
+        histogram.accept(&0);
+        let debug = format!("{:?}", histogram);
+        assert!(debug.contains("PredicateHistogram"));
+        assert!(debug.contains("(\"zero\", 1)"));
+    }
+}
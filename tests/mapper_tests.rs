@@ -9,8 +9,10 @@
 
 use prism3_function::{
     ArcMapper, ArcPredicate, BoxMapper, BoxPredicate, FnMapperOps, Mapper, MapperOnce, Predicate,
-    RcMapper, RcPredicate,
+    RcMapper, RcPredicate, StatefulBiMapper,
 };
+use std::cell::RefCell;
+use std::rc::Rc;
 
 // ============================================================================
 // BoxMapper Tests
@@ -196,6 +198,16 @@ fn test_arc_mapper_new() {
     assert_eq!(mapper.apply(10), 13);
 }
 
+#[test]
+fn test_arc_mapper_from_fn() {
+    let mapper = ArcMapper::from_fn(|x: i32| x * 2);
+    let mut clone = mapper.clone();
+    let handle = std::thread::spawn(move || clone.apply(21));
+    assert_eq!(handle.join().unwrap(), 42);
+    let mut mapper = mapper;
+    assert_eq!(mapper.apply(5), 10);
+}
+
 #[test]
 fn test_arc_mapper_identity() {
     let mut identity = ArcMapper::<i32, i32>::identity();
@@ -1906,3 +1918,498 @@ fn test_arc_mapper_multiple_clones_one_consumed() {
     let mut clone1_mut = clone1.clone();
     assert_eq!(clone1_mut.apply(10), 30); // 10 * 3
 }
+
+// ============================================================================
+// scan / iter_scan Tests
+// ============================================================================
+
+#[test]
+fn test_scan_counter_mutates_state_in_order() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let l = log.clone();
+    let mut count = 0;
+    let mut mapper = BoxMapper::new(move |x: i32| {
+        count += 1;
+        l.borrow_mut().push((count, x));
+        x * count
+    });
+
+    let result = mapper.scan(vec![10, 20, 30, 40, 50]);
+    assert_eq!(result, vec![10, 40, 90, 160, 250]);
+    assert_eq!(
+        *log.borrow(),
+        vec![(1, 10), (2, 20), (3, 30), (4, 40), (5, 50)]
+    );
+    // State survives the scan
+    assert_eq!(mapper.apply(1), 6);
+}
+
+#[test]
+fn test_scan_empty_input() {
+    let mut mapper = BoxMapper::new(|x: i32| x + 1);
+    assert!(mapper.scan(Vec::new()).is_empty());
+}
+
+#[test]
+fn test_iter_scan_is_lazy() {
+    let calls = Rc::new(RefCell::new(0));
+    let c = calls.clone();
+    let mut mapper = BoxMapper::new(move |x: i32| {
+        *c.borrow_mut() += 1;
+        x * 2
+    });
+
+    {
+        let mut iter = mapper.iter_scan(vec![1, 2, 3, 4, 5]);
+        assert_eq!(*calls.borrow(), 0);
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(4));
+    }
+    assert_eq!(*calls.borrow(), 2);
+}
+
+#[test]
+fn test_iter_scan_moving_average() {
+    let mut window: Vec<f64> = Vec::new();
+    let mut average = move |x: f64| {
+        window.push(x);
+        if window.len() > 2 {
+            window.remove(0);
+        }
+        window.iter().sum::<f64>() / window.len() as f64
+    };
+    let averages: Vec<f64> = average.iter_scan(vec![2.0, 4.0, 6.0]).collect();
+    assert_eq!(averages, vec![2.0, 3.0, 5.0]);
+}
+
+#[test]
+fn test_scan_shared_mappers() {
+    let mut sum = 0;
+    let mut arc = ArcMapper::new(move |x: i32| {
+        sum += x;
+        sum
+    });
+    let mut arc_clone = arc.clone();
+    assert_eq!(arc.scan(vec![1, 2]), vec![1, 3]);
+    assert_eq!(arc_clone.scan(vec![3]), vec![6]);
+
+    let mut product = 1;
+    let mut rc = RcMapper::new(move |x: i32| {
+        product *= x;
+        product
+    });
+    assert_eq!(rc.scan(1..=4), vec![1, 2, 6, 24]);
+}
+
+// ============================================================================
+// Fan-out Tests
+// ============================================================================
+
+#[test]
+fn test_box_mapper_fanout_states_update_independently() {
+    let mut count = 0;
+    let counter = BoxMapper::new(move |_: String| {
+        count += 1;
+        count
+    });
+    let mut total_len = 0;
+    let mut fanned = counter.fanout(move |s: String| {
+        total_len += s.len();
+        total_len
+    });
+
+    assert_eq!(fanned.apply("ab".to_string()), (1, 2));
+    assert_eq!(fanned.apply("cde".to_string()), (2, 5));
+    assert_eq!(fanned.apply(String::new()), (3, 5));
+}
+
+#[test]
+fn test_box_mapper_fanout_with_shared_mapper() {
+    let mut sum = 0;
+    let shared = RcMapper::new(move |x: i32| {
+        sum += x;
+        sum
+    });
+    let mut observer = shared.clone();
+    let mut fanned = BoxMapper::new(|x: i32| x * 10).fanout(shared);
+
+    assert_eq!(fanned.apply(1), (10, 1));
+    assert_eq!(fanned.apply(2), (20, 3));
+    // The shared state is visible through the other handle
+    assert_eq!(observer.apply(0), 3);
+}
+
+// ============================================================================
+// Inspect Tests
+// ============================================================================
+
+#[test]
+fn test_box_mapper_inspect() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let l = log.clone();
+    let mut total = 0;
+    let mut mapper = BoxMapper::new(move |x: i32| {
+        total += x;
+        total
+    })
+    .inspect(move |x: &i32| l.borrow_mut().push(*x))
+    .and_then(|x: i32| x * 10);
+
+    assert_eq!(mapper.apply(1), 10);
+    assert_eq!(mapper.apply(2), 30);
+    assert_eq!(*log.borrow(), vec![1, 3]);
+}
+
+#[test]
+fn test_rc_mapper_inspect() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let l = log.clone();
+    let mut counter = 0;
+    let mapper = RcMapper::new(move |x: i32| {
+        counter += 1;
+        x * counter
+    });
+    let mut traced = mapper.inspect(move |x: &i32| l.borrow_mut().push(*x));
+    assert_eq!(traced.apply(10), 10);
+    let mut original = mapper;
+    assert_eq!(original.apply(10), 20);
+    assert_eq!(*log.borrow(), vec![10]);
+}
+
+#[test]
+fn test_arc_mapper_inspect() {
+    let log = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let l = log.clone();
+    let mut traced = ArcMapper::new(|x: i32| x + 1).inspect(move |x: &i32| {
+        l.lock().unwrap().push(*x);
+    });
+    let mut clone = traced.clone();
+    let handle = std::thread::spawn(move || clone.apply(1));
+    assert_eq!(handle.join().unwrap(), 2);
+    assert_eq!(traced.apply(2), 3);
+    assert_eq!(*log.lock().unwrap(), vec![2, 3]);
+}
+
+// ============================================================================
+// Batch Processing Tests
+// ============================================================================
+
+#[test]
+fn test_box_mapper_map_vec_preserves_order() {
+    let mut index = 0;
+    let mut mapper = BoxMapper::new(move |s: &str| {
+        index += 1;
+        format!("{}:{}", index, s)
+    });
+    assert_eq!(
+        mapper.map_vec(vec!["a", "b", "c"]),
+        vec!["1:a", "2:b", "3:c"]
+    );
+    // State carries over into the next batch
+    assert_eq!(mapper.map_vec(vec!["d"]), vec!["4:d"]);
+    assert!(mapper.map_vec(Vec::new()).is_empty());
+}
+
+#[test]
+fn test_box_mapper_flat_map_vec() {
+    let mut seen = 0;
+    let mut mapper = BoxMapper::new(move |n: usize| {
+        seen += 1;
+        (0..n).map(|i| seen * 10 + i).collect::<Vec<_>>()
+    });
+    assert_eq!(mapper.flat_map_vec(vec![2, 0, 3]), vec![10, 11, 30, 31, 32]);
+}
+
+#[test]
+fn test_box_mapper_process_slice_in_place() {
+    let mut total = 0;
+    let mut running_sum = BoxMapper::new(move |x: i32| {
+        total += x;
+        total
+    });
+    let mut values = vec![1, 2, 3, 4];
+    running_sum.process_slice_in_place(&mut values);
+    assert_eq!(values, vec![1, 3, 6, 10]);
+
+    let mut upper = BoxMapper::new(|s: String| s.to_uppercase());
+    let mut words = ["ab".to_string(), "cd".to_string()];
+    upper.process_slice_in_place(&mut words[1..]);
+    assert_eq!(words, ["ab".to_string(), "CD".to_string()]);
+}
+
+// ============================================================================
+// Explicit State Tests
+// ============================================================================
+
+#[test]
+fn test_box_mapper_with_state_running_total() {
+    let mut running_total = BoxMapper::with_state(0i64, |total: &mut i64, x: i64| {
+        *total += x;
+        *total
+    });
+    let outputs: Vec<i64> = [5, -2, 10, 7]
+        .into_iter()
+        .map(|x| running_total.apply(x))
+        .collect();
+    assert_eq!(outputs, vec![5, 3, 13, 20]);
+    assert_eq!(*running_total.state(), 20);
+
+    *running_total.state_mut() = 0;
+    assert_eq!(running_total.apply(1), 1);
+    assert_eq!(running_total.into_state(), 1);
+}
+
+#[test]
+fn test_box_mapper_with_state_composes() {
+    let seen = BoxMapper::with_state(Vec::new(), |seen: &mut Vec<String>, s: String| {
+        seen.push(s.clone());
+        seen.len()
+    });
+    let mut mapper = BoxMapper::new(|s: &str| s.to_string()).and_then(seen);
+    assert_eq!(mapper.apply("a"), 1);
+    assert_eq!(mapper.apply("b"), 2);
+}
+
+#[test]
+fn test_rc_mapper_with_state_rc() {
+    let (mapper, total) = RcMapper::with_state_rc(0, |total: &mut i32, x: i32| {
+        *total += x;
+        *total
+    });
+    let mut clone = mapper.clone();
+    let mut mapper = mapper;
+    assert_eq!(mapper.apply(2), 2);
+    assert_eq!(clone.apply(3), 5);
+    assert_eq!(*total.borrow(), 5);
+    *total.borrow_mut() = 100;
+    assert_eq!(mapper.apply(1), 101);
+}
+
+#[test]
+fn test_arc_mapper_with_state_arc() {
+    let (mapper, total) = ArcMapper::with_state_arc(0u64, |total: &mut u64, x: u64| {
+        *total += x;
+        *total
+    });
+    let handles: Vec<_> = (1..=4)
+        .map(|i| {
+            let mut m = mapper.clone();
+            std::thread::spawn(move || {
+                for _ in 0..100 {
+                    m.apply(i);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(*total.lock().unwrap(), 1000);
+}
+
+#[test]
+fn test_stateful_bi_mapper_with_state() {
+    let mut weighted = StatefulBiMapper::with_state(
+        (0i64, 0i64),
+        |(sum, weights): &mut (i64, i64), x: i64, w: i64| {
+            *sum += x * w;
+            *weights += w;
+            *sum / *weights
+        },
+    );
+    assert_eq!(weighted.apply(10, 1), 10);
+    assert_eq!(weighted.apply(40, 2), 30);
+    assert_eq!(*weighted.state(), (90, 3));
+
+    *weighted.state_mut() = (0, 0);
+    assert_eq!(weighted.apply(7, 7), 7);
+    assert_eq!(weighted.into_state(), (49, 7));
+}
+
+#[test]
+fn test_stateful_bi_mapper_when_or_else_runs_both_branches() {
+    let mut clamp =
+        StatefulBiMapper::with_state((0, 0), |(hits, _): &mut (i32, i32), x: i32, max: i32| {
+            *hits += 1;
+            x.min(max)
+        })
+        .when(|x: &i32, max: &i32| x > max)
+        .or_else(|(_, misses): &mut (i32, i32), x: i32, _max: i32| {
+            *misses += 1;
+            x
+        });
+
+    assert_eq!(clamp.apply(15, 10), 10);
+    assert_eq!(clamp.apply(5, 10), 5);
+    assert_eq!(clamp.apply(7, 10), 7);
+    assert_eq!(clamp.into_state(), (1, 2));
+}
+
+#[test]
+fn test_stateful_bi_mapper_when_predicate_sees_references() {
+    /// Neither `Clone` nor `Copy`, so the predicate can only borrow it
+    struct Token(u32);
+
+    let mut pick =
+        StatefulBiMapper::with_state(Vec::new(), |seen: &mut Vec<u32>, a: Token, _: Token| {
+            seen.push(a.0);
+            a
+        })
+        .when(|a: &Token, b: &Token| a.0 >= b.0)
+        .or_else(|seen: &mut Vec<u32>, _: Token, b: Token| {
+            seen.push(b.0);
+            b
+        });
+
+    assert_eq!(pick.apply(Token(3), Token(1)).0, 3);
+    assert_eq!(pick.apply(Token(2), Token(4)).0, 4);
+    assert_eq!(*pick.state(), vec![3, 4]);
+}
+
+// ============================================================================
+// Else-Branch Shortcut Tests
+// ============================================================================
+
+#[test]
+fn test_box_conditional_mapper_or_else_default() {
+    let mut count = 0;
+    let mut numbered = BoxMapper::new(move |s: String| {
+        count += 1;
+        format!("{}-{}", count, s)
+    })
+    .when(|s: &String| !s.is_empty())
+    .or_else_default();
+    assert_eq!(numbered.apply("a".to_string()), "1-a");
+    assert_eq!(numbered.apply(String::new()), "");
+    assert_eq!(numbered.apply("b".to_string()), "2-b");
+}
+
+#[test]
+fn test_box_conditional_mapper_or_else_value() {
+    let mut mapper = BoxMapper::new(|x: i32| x * 2)
+        .when(|x: &i32| *x > 0)
+        .or_else_value(-1);
+    assert_eq!(mapper.apply(5), 10);
+    assert_eq!(mapper.apply(-5), -1);
+}
+
+#[test]
+fn test_box_conditional_mapper_or_else_supply_only_on_else_branch() {
+    let calls = Rc::new(RefCell::new(0));
+    let c = calls.clone();
+    let mut mapper = BoxMapper::new(|x: i32| x * 2)
+        .when(|x: &i32| *x > 0)
+        .or_else_supply(move || {
+            *c.borrow_mut() += 1;
+            *c.borrow() * 100
+        });
+    assert_eq!(mapper.apply(1), 2);
+    assert_eq!(mapper.apply(2), 4);
+    assert_eq!(*calls.borrow(), 0);
+    assert_eq!(mapper.apply(-1), 100);
+    assert_eq!(mapper.apply(-2), 200);
+    assert_eq!(*calls.borrow(), 2);
+}
+
+#[test]
+fn test_arc_conditional_mapper_or_else_shortcuts() {
+    let double = ArcMapper::new(|x: i32| x * 2);
+    let mut with_default = double.when(|x: &i32| *x > 0).or_else_default();
+    let mut with_value = double.when(|x: &i32| *x > 0).or_else_value(-1);
+    let mut next = 0;
+    let mut with_supply = double.when(|x: &i32| *x > 0).or_else_supply(move || {
+        next += 1;
+        next
+    });
+    assert_eq!(with_default.apply(-3), 0);
+    assert_eq!(with_value.apply(-3), -1);
+    assert_eq!(with_supply.apply(3), 6);
+    assert_eq!(with_supply.apply(-3), 1);
+    let mut shared = with_supply.clone();
+    assert_eq!(
+        std::thread::spawn(move || shared.apply(-3)).join().unwrap(),
+        2
+    );
+}
+
+#[test]
+fn test_rc_conditional_mapper_or_else_shortcuts() {
+    let double = RcMapper::new(|x: i32| x * 2);
+    let mut with_default = double.clone().when(|x: &i32| *x > 0).or_else_default();
+    let mut with_value = double.clone().when(|x: &i32| *x > 0).or_else_value(9);
+    let mut next = 0;
+    let mut with_supply = double.when(|x: &i32| *x > 0).or_else_supply(move || {
+        next += 1;
+        next
+    });
+    assert_eq!(with_default.apply(-3), 0);
+    assert_eq!(with_value.apply(-3), 9);
+    assert_eq!(with_supply.apply(3), 6);
+    assert_eq!(with_supply.apply(-3), 1);
+    assert_eq!(with_supply.clone().apply(-3), 2);
+}
+
+// ============================================================================
+// Lifting Tests
+// ============================================================================
+
+#[test]
+fn test_box_mapper_lift_option_skips_none() {
+    let mut count = 0;
+    let mut numbered = BoxMapper::new(move |x: i32| {
+        count += 1;
+        (x, count)
+    })
+    .lift_option();
+    assert_eq!(numbered.apply(Some(7)), Some((7, 1)));
+    assert_eq!(numbered.apply(None), None);
+    assert_eq!(numbered.apply(Some(8)), Some((8, 2)));
+}
+
+#[test]
+fn test_box_mapper_lift_ok_skips_err() {
+    let mut total = 0;
+    let mut running = BoxMapper::new(move |x: i32| {
+        total += x;
+        total
+    })
+    .lift_ok::<String>();
+    assert_eq!(running.apply(Ok(2)), Ok(2));
+    assert_eq!(
+        running.apply(Err("bad".to_string())),
+        Err("bad".to_string())
+    );
+    assert_eq!(running.apply(Ok(3)), Ok(5));
+}
+
+#[test]
+fn test_arc_mapper_lift_shares_state() {
+    let mut count = 0;
+    let counter = ArcMapper::new(move |_: ()| {
+        count += 1;
+        count
+    });
+    let mut options = counter.lift_option();
+    let mut results = counter.lift_ok::<i32>();
+    assert_eq!(options.apply(Some(())), Some(1));
+    assert_eq!(options.apply(None), None);
+    assert_eq!(results.apply(Err(-1)), Err(-1));
+    assert_eq!(results.apply(Ok(())), Ok(2));
+    let mut counter = counter;
+    assert_eq!(counter.apply(()), 3);
+}
+
+#[test]
+fn test_rc_mapper_lift_shares_state() {
+    let mut count = 0;
+    let counter = RcMapper::new(move |x: i32| {
+        count += 1;
+        x + count
+    });
+    let mut options = counter.lift_option();
+    let mut results = counter.lift_ok::<&str>();
+    assert_eq!(options.apply(None), None);
+    assert_eq!(options.apply(Some(10)), Some(11));
+    assert_eq!(results.apply(Err("e")), Err("e"));
+    assert_eq!(results.apply(Ok(10)), Ok(12));
+}
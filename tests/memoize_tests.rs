@@ -0,0 +1,106 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! Unit tests for memoization types
+
+use prism3_function::{BoxTransformer, Mapper};
+use std::cell::Cell;
+use std::num::NonZeroUsize;
+use std::rc::Rc;
+
+fn capacity(n: usize) -> NonZeroUsize {
+    NonZeroUsize::new(n).unwrap()
+}
+
+// ============================================================================
+// LruMemoizedMapper Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_lru_memoized_mapper {
+    use super::*;
+
+    fn counting_square(calls: Rc<Cell<usize>>) -> BoxTransformer<u32, u32> {
+        BoxTransformer::new(move |x: u32| {
+            calls.set(calls.get() + 1);
+            x * x
+        })
+    }
+
+    #[test]
+    fn test_hits_do_not_call_transformer() {
+        let calls = Rc::new(Cell::new(0));
+        let mut cached = counting_square(calls.clone()).memoize_lru(capacity(4));
+        for _ in 0..5 {
+            assert_eq!(cached.apply(7), 49);
+        }
+        assert_eq!(calls.get(), 1);
+        assert_eq!(cached.cache_hits(), 4);
+        assert_eq!(cached.cache_misses(), 1);
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let calls = Rc::new(Cell::new(0));
+        let mut cached = counting_square(calls.clone()).memoize_lru(capacity(2));
+        cached.apply(1);
+        cached.apply(2);
+        // Touch 1 so that 2 becomes the least recently used entry
+        cached.apply(1);
+        cached.apply(3);
+        assert_eq!(cached.cache_len(), 2);
+        assert_eq!(calls.get(), 3);
+
+        cached.apply(1); // still cached
+        assert_eq!(calls.get(), 3);
+        cached.apply(2); // evicted, recomputed
+        assert_eq!(calls.get(), 4);
+        assert_eq!(cached.cache_hits(), 2);
+        assert_eq!(cached.cache_misses(), 4);
+    }
+
+    #[test]
+    fn test_capacity_one() {
+        let calls = Rc::new(Cell::new(0));
+        let mut cached = counting_square(calls.clone()).memoize_lru(capacity(1));
+        assert_eq!(cached.apply(2), 4);
+        assert_eq!(cached.apply(3), 9);
+        assert_eq!(cached.apply(2), 4);
+        assert_eq!(cached.apply(2), 4);
+        assert_eq!(calls.get(), 3);
+        assert_eq!(cached.cache_len(), 1);
+        assert_eq!(cached.capacity().get(), 1);
+    }
+
+    #[test]
+    fn test_never_exceeds_capacity() {
+        let mut cached = BoxTransformer::new(|s: String| s.len()).memoize_lru(capacity(3));
+        for i in 0..100 {
+            assert_eq!(cached.apply(format!("{}", i)), format!("{}", i).len());
+            assert!(cached.cache_len() <= 3);
+        }
+        assert_eq!(cached.cache_misses(), 100);
+        // The three most recent keys are retained
+        for i in 97..100 {
+            cached.apply(format!("{}", i));
+        }
+        assert_eq!(cached.cache_hits(), 3);
+    }
+
+    #[test]
+    fn test_usable_as_mapper() {
+        fn run<M: Mapper<u32, u32>>(mut mapper: M) -> Vec<u32> {
+            [1, 2, 1, 3].into_iter().map(|x| mapper.apply(x)).collect()
+        }
+        let calls = Rc::new(Cell::new(0));
+        let cached = counting_square(calls.clone()).memoize_lru(capacity(8));
+        assert_eq!(run(cached), vec![1, 4, 1, 9]);
+        assert_eq!(calls.get(), 3);
+    }
+}
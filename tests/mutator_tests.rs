@@ -166,6 +166,85 @@ mod test_box_mutator {
 
     // Note: BoxMutator cannot be safely converted to ArcMutator because the
     // inner function may not be Send. This test has been removed.
+
+    #[test]
+    fn test_repeat_until_stops_when_satisfied() {
+        let mut mutator =
+            BoxMutator::new(|x: &mut i32| *x *= 2).repeat_until(|x: &i32| *x > 50, 10);
+        let mut value = 1;
+        mutator.mutate(&mut value);
+        assert_eq!(value, 64);
+    }
+
+    #[test]
+    fn test_repeat_until_already_satisfied() {
+        let calls = std::rc::Rc::new(std::cell::Cell::new(0));
+        let c = calls.clone();
+        let mut mutator = BoxMutator::new(move |x: &mut i32| {
+            c.set(c.get() + 1);
+            *x += 1;
+        })
+        .repeat_until(|x: &i32| *x % 5 == 0, 10);
+        let mut value = 10;
+        mutator.mutate(&mut value);
+        assert_eq!(value, 10);
+        assert_eq!(calls.get(), 0);
+    }
+
+    #[test]
+    fn test_repeat_until_respects_max_iters() {
+        let mut mutator = BoxMutator::new(|x: &mut i32| *x += 1).repeat_until(|_: &i32| false, 3);
+        let mut value = 0;
+        mutator.mutate(&mut value);
+        assert_eq!(value, 3);
+        mutator.mutate(&mut value);
+        assert_eq!(value, 6);
+    }
+
+    #[test]
+    fn test_try_repeat_until_ok() {
+        let mut mutator = BoxMutator::new(|s: &mut String| s.push('a'));
+        let mut value = String::new();
+        let result = mutator.try_repeat_until(&mut value, &|s: &String| s.len() == 4, 10);
+        assert_eq!(result, Ok(4));
+        assert_eq!(value, "aaaa");
+    }
+
+    #[test]
+    fn test_try_repeat_until_satisfied_by_last_iteration() {
+        let mut mutator = BoxMutator::new(|x: &mut i32| *x += 1);
+        let mut value = 0;
+        assert_eq!(
+            mutator.try_repeat_until(&mut value, &|x: &i32| *x == 3, 3),
+            Ok(3)
+        );
+    }
+
+    #[test]
+    fn test_try_repeat_until_exhausted() {
+        let mut mutator = BoxMutator::new(|x: &mut i32| *x += 1);
+        let mut value = 0;
+        assert_eq!(
+            mutator.try_repeat_until(&mut value, &|x: &i32| *x < 0, 4),
+            Err(4)
+        );
+        assert_eq!(value, 4);
+    }
+
+    #[test]
+    fn test_try_repeat_until_zero_iters() {
+        let mut mutator = BoxMutator::new(|x: &mut i32| *x += 1);
+        let mut value = 0;
+        assert_eq!(
+            mutator.try_repeat_until(&mut value, &|x: &i32| *x == 0, 0),
+            Ok(0)
+        );
+        assert_eq!(
+            mutator.try_repeat_until(&mut value, &|x: &i32| *x == 1, 0),
+            Err(0)
+        );
+        assert_eq!(value, 0);
+    }
 }
 
 // ============================================================================
@@ -2934,3 +3013,243 @@ mod test_mutator_once_implementations {
         assert_eq!(result, 10);
     }
 }
+
+// ============================================================================
+// Mutator to Consumer Conversion Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_into_consumer {
+    use super::*;
+    use prism3_function::Consumer;
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    /// Counts how many times it has been cloned
+    struct Tracked {
+        value: i32,
+        clones: Rc<Cell<usize>>,
+    }
+
+    impl Clone for Tracked {
+        fn clone(&self) -> Self {
+            self.clones.set(self.clones.get() + 1);
+            Tracked {
+                value: self.value,
+                clones: self.clones.clone(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_box_into_consumer_leaves_original_untouched() {
+        let seen = Rc::new(Cell::new(0));
+        let s = seen.clone();
+        let mut consumer = BoxMutator::new(move |x: &mut i32| {
+            *x *= 2;
+            s.set(*x);
+        })
+        .into_consumer();
+        let value = 21;
+        consumer.accept(&value);
+        assert_eq!(value, 21);
+        assert_eq!(seen.get(), 42);
+    }
+
+    #[test]
+    fn test_box_into_consumer_clones_once_per_accept() {
+        let clones = Rc::new(Cell::new(0));
+        let value = Tracked {
+            value: 1,
+            clones: clones.clone(),
+        };
+        let mut consumer = BoxMutator::new(|t: &mut Tracked| t.value += 1).into_consumer();
+        consumer.accept(&value);
+        consumer.accept(&value);
+        consumer.accept(&value);
+        assert_eq!(clones.get(), 3);
+        assert_eq!(value.value, 1);
+    }
+
+    #[test]
+    fn test_rc_into_consumer_shares_state() {
+        let mut calls = 0;
+        let log = Rc::new(Cell::new(0));
+        let l = log.clone();
+        let mutator = RcMutator::new(move |x: &mut i32| {
+            calls += 1;
+            *x += calls;
+            l.set(*x);
+        });
+        let mut consumer = mutator.clone().into_consumer();
+        consumer.accept(&10);
+        assert_eq!(log.get(), 11);
+        let mut mutator = mutator;
+        let mut value = 10;
+        mutator.mutate(&mut value);
+        assert_eq!(value, 12);
+    }
+
+    #[test]
+    fn test_arc_into_consumer_across_threads() {
+        let total = Arc::new(AtomicUsize::new(0));
+        let t = total.clone();
+        let consumer = ArcMutator::new(move |v: &mut Vec<usize>| {
+            v.push(1);
+            t.fetch_add(v.len(), Ordering::SeqCst);
+        })
+        .into_consumer();
+        let original = Arc::new(Mutex::new(vec![0, 0]));
+        let handles: Vec<_> = (0..3)
+            .map(|_| {
+                let mut c = consumer.clone();
+                let o = original.clone();
+                std::thread::spawn(move || c.accept(&o.lock().unwrap()))
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(total.load(Ordering::SeqCst), 9);
+        assert_eq!(*original.lock().unwrap(), vec![0, 0]);
+    }
+}
+
+// ============================================================================
+// Bulk Mutation Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_apply_to_all {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// A stateful mutation whose result depends on the call order
+    fn running_offset() -> impl FnMut(&mut i64) + Send {
+        let mut step = 0;
+        move |x: &mut i64| {
+            step += 1;
+            *x = *x * 3 + step;
+        }
+    }
+
+    fn per_element(values: &mut [i64]) {
+        let mut mutate = running_offset();
+        for value in values.iter_mut() {
+            mutate(value);
+        }
+    }
+
+    fn assert_matches_loop<M: Mutator<i64>>(mut mutator: M) {
+        let mut expected: Vec<i64> = (0..1000).collect();
+        per_element(&mut expected);
+
+        let mut actual: Vec<i64> = (0..1000).collect();
+        mutator.apply_to_all(&mut actual);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_apply_to_all_matches_per_element_loop() {
+        assert_matches_loop(BoxMutator::new(running_offset()));
+        assert_matches_loop(ArcMutator::new(running_offset()));
+        assert_matches_loop(RcMutator::new(running_offset()));
+        assert_matches_loop(running_offset());
+    }
+
+    #[test]
+    fn test_apply_to_iter_matches_per_element_loop() {
+        let mut expected: Vec<i64> = (0..1000).collect();
+        per_element(&mut expected);
+
+        let mut boxed: Vec<i64> = (0..1000).collect();
+        BoxMutator::new(running_offset()).apply_to_iter(&mut boxed);
+        let mut arc: Vec<i64> = (0..1000).collect();
+        ArcMutator::new(running_offset()).apply_to_iter(arc.iter_mut());
+        let mut rc: Vec<i64> = (0..1000).collect();
+        RcMutator::new(running_offset()).apply_to_iter(&mut rc);
+        assert_eq!(boxed, expected);
+        assert_eq!(arc, expected);
+        assert_eq!(rc, expected);
+    }
+
+    #[test]
+    fn test_apply_to_iter_over_map_values() {
+        let mut scores: HashMap<&str, i32> = [("a", 1), ("b", 2)].into_iter().collect();
+        let mut bonus = ArcMutator::new(|x: &mut i32| *x += 10);
+        bonus.apply_to_iter(scores.values_mut());
+        assert_eq!(scores["a"], 11);
+        assert_eq!(scores["b"], 12);
+    }
+
+    #[test]
+    fn test_clones_share_state_across_bulk_calls() {
+        let mutator = RcMutator::new(running_offset());
+        let mut first = vec![0, 0];
+        let mut second = vec![0];
+        mutator.clone().apply_to_all(&mut first);
+        mutator.clone().apply_to_all(&mut second);
+        assert_eq!(first, vec![1, 2]);
+        assert_eq!(second, vec![3]);
+    }
+
+    #[test]
+    fn test_empty_slice() {
+        let mut mutator = ArcMutator::new(|_: &mut i64| panic!("must not be called"));
+        mutator.apply_to_all(&mut []);
+    }
+}
+
+// ============================================================================
+// Mutator to UnaryOperator Conversion Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_into_unary_operator {
+    use super::*;
+    use prism3_function::Transformer;
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Settings {
+        retries: u32,
+        verbose: bool,
+    }
+
+    #[test]
+    fn test_string() {
+        let op = BoxMutator::new(|s: &mut String| s.push_str("-suffix")).into_unary_operator();
+        assert_eq!(op.apply("name".to_string()), "name-suffix");
+    }
+
+    #[test]
+    fn test_custom_struct_keeps_mutator_state() {
+        let mut calls = 0;
+        let op = BoxMutator::new(move |s: &mut Settings| {
+            calls += 1;
+            s.retries += calls;
+            s.verbose = true;
+        })
+        .into_unary_operator();
+        let once = op.apply(Settings::default());
+        assert_eq!(
+            once,
+            Settings {
+                retries: 1,
+                verbose: true
+            }
+        );
+        assert_eq!(op.apply(once).retries, 3);
+    }
+
+    #[test]
+    fn test_round_trip_through_mutator() {
+        let mut mutator = BoxMutator::new(|s: &mut String| s.insert(0, '>'))
+            .into_unary_operator()
+            .into_mutator();
+        let mut value = "x".to_string();
+        mutator.mutate(&mut value);
+        assert_eq!(value, ">x");
+    }
+}
@@ -0,0 +1,297 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! Unit tests for OwnedConsumer types
+
+use prism3_function::{
+    ArcConsumer, ArcOwnedConsumer, BoxConsumer, BoxConsumerOnce, BoxOwnedConsumer,
+    BoxOwnedConsumerOnce, Consumer, OwnedConsumer, OwnedConsumerOnce, RcOwnedConsumer,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A value that deliberately does not implement `Clone`
+#[derive(Debug, PartialEq)]
+struct Token(u32);
+
+// ============================================================================
+// BoxOwnedConsumer Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_box_owned_consumer {
+    use super::*;
+
+    #[test]
+    fn test_moves_non_clone_values_into_vec() {
+        let sink = Rc::new(RefCell::new(Vec::new()));
+        let s = sink.clone();
+        let mut consumer = BoxOwnedConsumer::new(move |t: Token| s.borrow_mut().push(t));
+        consumer.accept_owned(Token(1));
+        consumer.accept_owned(Token(2));
+        assert_eq!(*sink.borrow(), vec![Token(1), Token(2)]);
+    }
+
+    #[test]
+    fn test_collect() {
+        let (mut consumer, values) = BoxOwnedConsumer::collect();
+        consumer.accept_owned(Token(7));
+        assert_eq!(*values.lock().unwrap(), vec![Token(7)]);
+    }
+
+    #[test]
+    fn test_and_then_clones_for_first_and_moves_into_next() {
+        let (first, a) = BoxOwnedConsumer::collect();
+        let (second, b) = BoxOwnedConsumer::collect();
+        let mut chained = first.and_then(second);
+        chained.accept_owned(vec![1, 2]);
+        assert_eq!(*a.lock().unwrap(), vec![vec![1, 2]]);
+        assert_eq!(*b.lock().unwrap(), vec![vec![1, 2]]);
+    }
+
+    #[test]
+    fn test_and_then_order() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (l1, l2) = (log.clone(), log.clone());
+        let mut chained = BoxOwnedConsumer::new(move |x: i32| l1.borrow_mut().push(x))
+            .and_then(move |x: i32| l2.borrow_mut().push(x * 10));
+        chained.accept_owned(3);
+        assert_eq!(*log.borrow(), vec![3, 30]);
+    }
+
+    #[test]
+    fn test_from_consumer() {
+        let (consumer, values) = BoxConsumer::collect();
+        let mut owned = BoxOwnedConsumer::from_consumer(consumer);
+        owned.accept_owned(5);
+        assert_eq!(*values.lock().unwrap(), vec![5]);
+    }
+
+    #[test]
+    fn test_from_consumer_with_non_clone_value() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let s = seen.clone();
+        let mut owned = BoxOwnedConsumer::from_consumer(move |t: &Token| s.borrow_mut().push(t.0));
+        owned.accept_owned(Token(9));
+        assert_eq!(*seen.borrow(), vec![9]);
+    }
+
+    #[test]
+    fn test_into_consumer() {
+        let (owned, values) = BoxOwnedConsumer::collect();
+        let mut consumer = owned.into_consumer();
+        consumer.accept(&"a".to_string());
+        consumer.accept(&"b".to_string());
+        assert_eq!(*values.lock().unwrap(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_into_rc_and_into_fn() {
+        let (owned, values) = BoxOwnedConsumer::collect();
+        let mut rc = owned.into_rc();
+        rc.accept_owned(Token(1));
+        let mut f = rc.into_fn();
+        f(Token(2));
+        assert_eq!(*values.lock().unwrap(), vec![Token(1), Token(2)]);
+    }
+
+    #[test]
+    fn test_debug() {
+        let consumer = BoxOwnedConsumer::new(|_: i32| {});
+        assert!(format!("{:?}", consumer).contains("BoxOwnedConsumer"));
+    }
+}
+
+// ============================================================================
+// ArcOwnedConsumer Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_arc_owned_consumer {
+    use super::*;
+
+    #[test]
+    fn test_moves_non_clone_values_across_threads() {
+        let sink = Arc::new(Mutex::new(Vec::new()));
+        let s = sink.clone();
+        let consumer = ArcOwnedConsumer::new(move |t: Token| s.lock().unwrap().push(t));
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let mut c = consumer.clone();
+                thread::spawn(move || c.accept_owned(Token(i)))
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        let mut ids: Vec<u32> = sink.lock().unwrap().iter().map(|t| t.0).collect();
+        ids.sort();
+        assert_eq!(ids, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_survives_panicking_consumer() {
+        let sink = Arc::new(Mutex::new(Vec::new()));
+        let s = sink.clone();
+        let consumer = ArcOwnedConsumer::new(move |t: Token| {
+            assert!(t.0 != 0, "token zero rejected");
+            s.lock().unwrap().push(t);
+        });
+        let mut c = consumer.clone();
+        assert!(thread::spawn(move || c.accept_owned(Token(0)))
+            .join()
+            .is_err());
+
+        let mut consumer = consumer;
+        consumer.accept_owned(Token(1));
+        assert_eq!(*sink.lock().unwrap(), vec![Token(1)]);
+    }
+
+    #[test]
+    fn test_and_then_keeps_originals_usable() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let (l1, l2) = (log.clone(), log.clone());
+        let first = ArcOwnedConsumer::new(move |x: i32| l1.lock().unwrap().push(x));
+        let second = ArcOwnedConsumer::new(move |x: i32| l2.lock().unwrap().push(-x));
+        let mut chained = first.and_then(&second);
+        chained.accept_owned(1);
+        let mut first = first;
+        first.accept_owned(2);
+        assert_eq!(*log.lock().unwrap(), vec![1, -1, 2]);
+    }
+
+    #[test]
+    fn test_from_consumer_and_to_consumer() {
+        let values = Arc::new(Mutex::new(Vec::new()));
+        let v = values.clone();
+        let owned = ArcOwnedConsumer::from_consumer(ArcConsumer::new(move |x: &i32| {
+            v.lock().unwrap().push(*x)
+        }));
+        let mut consumer = owned.to_consumer();
+        consumer.accept(&4);
+        let mut owned = owned;
+        owned.accept_owned(5);
+        assert_eq!(*values.lock().unwrap(), vec![4, 5]);
+    }
+
+    #[test]
+    fn test_into_box() {
+        let sink = Arc::new(Mutex::new(Vec::new()));
+        let s = sink.clone();
+        let mut boxed = ArcOwnedConsumer::new(move |t: Token| s.lock().unwrap().push(t)).into_box();
+        boxed.accept_owned(Token(3));
+        assert_eq!(*sink.lock().unwrap(), vec![Token(3)]);
+    }
+}
+
+// ============================================================================
+// RcOwnedConsumer Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_rc_owned_consumer {
+    use super::*;
+
+    #[test]
+    fn test_clones_share_state() {
+        let sink = Rc::new(RefCell::new(Vec::new()));
+        let s = sink.clone();
+        let consumer = RcOwnedConsumer::new(move |t: Token| s.borrow_mut().push(t));
+        let mut a = consumer.clone();
+        let mut b = consumer;
+        a.accept_owned(Token(1));
+        b.accept_owned(Token(2));
+        assert_eq!(*sink.borrow(), vec![Token(1), Token(2)]);
+    }
+
+    #[test]
+    fn test_and_then() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (l1, l2) = (log.clone(), log.clone());
+        let first = RcOwnedConsumer::new(move |s: String| l1.borrow_mut().push(s));
+        let second = RcOwnedConsumer::new(move |s: String| l2.borrow_mut().push(s.to_uppercase()));
+        let mut chained = first.and_then(&second);
+        chained.accept_owned("a".to_string());
+        assert_eq!(*log.borrow(), vec!["a", "A"]);
+    }
+
+    #[test]
+    fn test_to_consumer() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let owned = RcOwnedConsumer::new(move |x: i32| l.borrow_mut().push(x));
+        let mut consumer = owned.to_consumer();
+        consumer.accept(&8);
+        assert_eq!(*log.borrow(), vec![8]);
+    }
+}
+
+// ============================================================================
+// Closure and OwnedConsumerOnce Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_owned_consumer_once {
+    use super::*;
+
+    #[test]
+    fn test_closure_implements_owned_consumer() {
+        let mut sink = Vec::new();
+        let mut push = |t: Token| sink.push(t);
+        push.accept_owned(Token(1));
+        assert_eq!(sink, vec![Token(1)]);
+    }
+
+    #[test]
+    fn test_box_once_moves_captured_state() {
+        let mut sink = vec![Token(0)];
+        let (tx, rx) = std::sync::mpsc::channel();
+        let consumer = BoxOwnedConsumerOnce::new(move |t: Token| {
+            sink.push(t);
+            tx.send(sink).unwrap();
+        });
+        consumer.accept_owned_once(Token(1));
+        assert_eq!(rx.recv().unwrap(), vec![Token(0), Token(1)]);
+    }
+
+    #[test]
+    fn test_box_once_and_then() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (l1, l2) = (log.clone(), log.clone());
+        let consumer = BoxOwnedConsumerOnce::new(move |x: i32| l1.borrow_mut().push(x))
+            .and_then(move |x: i32| l2.borrow_mut().push(x + 1));
+        consumer.accept_owned_once(1);
+        assert_eq!(*log.borrow(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_from_consumer_once() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let once = BoxConsumerOnce::new(move |t: &Token| l.borrow_mut().push(t.0));
+        BoxOwnedConsumerOnce::from_consumer_once(once).accept_owned_once(Token(4));
+        assert_eq!(*log.borrow(), vec![4]);
+    }
+
+    #[test]
+    fn test_reusable_consumers_are_once_consumers() {
+        let (owned, values) = BoxOwnedConsumer::collect();
+        let once = owned.into_box_once();
+        once.accept_owned_once(Token(2));
+        assert_eq!(*values.lock().unwrap(), vec![Token(2)]);
+
+        let sink = Rc::new(RefCell::new(Vec::new()));
+        let s = sink.clone();
+        let f = RcOwnedConsumer::new(move |t: Token| s.borrow_mut().push(t)).into_fn_once();
+        f(Token(3));
+        assert_eq!(*sink.borrow(), vec![Token(3)]);
+    }
+}
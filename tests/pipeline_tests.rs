@@ -0,0 +1,196 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! Unit tests for Pipeline
+
+use prism3_function::{ArcTransformer, BoxTransformer, Pipeline, RcTransformer, Transformer};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+/// Global allocator counting the allocations made by the current thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations the current thread makes in `f`.
+fn count_allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+// ============================================================================
+// Pipeline Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_pipeline {
+    use super::*;
+
+    #[test]
+    fn test_empty_pipeline_is_identity() {
+        let pipeline = Pipeline::<String, String>::default();
+        assert!(pipeline.is_empty());
+        assert_eq!(pipeline.apply("x".to_string()), "x");
+    }
+
+    #[test]
+    fn test_heterogeneous_stages() {
+        let pipeline = Pipeline::new()
+            .then(|s: String| s.len())
+            .then(BoxTransformer::new(|n: usize| n as i64 * 3))
+            .then(RcTransformer::new(|n: i64| n - 1))
+            .then(ArcTransformer::new(|n: i64| format!("<{}>", n)));
+        assert_eq!(pipeline.len(), 4);
+        assert_eq!(pipeline.apply("abcd".to_string()), "<11>");
+        assert_eq!(pipeline.apply(String::new()), "<-1>");
+    }
+
+    #[test]
+    fn test_from_box_transformer() {
+        let pipeline: Pipeline<i32, i32> = BoxTransformer::new(|x: i32| x * 2).into();
+        let pipeline = pipeline.push_stage(|x: i32| x + 1);
+        assert_eq!(pipeline.len(), 2);
+        assert_eq!(pipeline.apply(20), 41);
+    }
+
+    #[test]
+    fn test_into_box() {
+        let boxed = Pipeline::new().push_stage(|x: i32| x + 1).into_box();
+        assert_eq!(boxed.apply(1), 2);
+    }
+
+    #[test]
+    fn test_fifty_stage_chain() {
+        let mut pipeline = Pipeline::<u64, u64>::new();
+        let mut nested = BoxTransformer::new(|x: u64| x);
+        for i in 0..50u64 {
+            pipeline = pipeline.push_stage(move |x: u64| x * 2 + i);
+            nested = nested.and_then(move |x: u64| x * 2 + i);
+        }
+        assert_eq!(pipeline.len(), 50);
+        assert_eq!(pipeline.apply(1), nested.apply(1));
+    }
+
+    #[test]
+    fn test_push_stage_after_then() {
+        let pipeline = Pipeline::new()
+            .push_stage(|s: String| s.trim().to_string())
+            .then(|s: String| s + "!")
+            .push_stage(|s: String| s.to_uppercase());
+        assert_eq!(pipeline.len(), 3);
+        assert_eq!(pipeline.apply(" abc ".to_string()), "ABC!");
+    }
+
+    #[test]
+    fn test_fifty_stage_chain_applies_without_allocating() {
+        let mut pipeline = Pipeline::<u64, u64>::new();
+        for i in 0..50u64 {
+            pipeline = pipeline.push_stage(move |x: u64| x.wrapping_mul(3) + i);
+        }
+        let pipeline = pipeline.then(|x: u64| x % 1000);
+
+        let (first, allocations) = count_allocations(|| pipeline.apply(1));
+        assert_eq!(allocations, 0);
+        let (second, allocations) = count_allocations(|| pipeline.apply(1));
+        assert_eq!(allocations, 0);
+        assert_eq!(first, second);
+    }
+}
+
+// ============================================================================
+// NamedPipeline Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_named_pipeline {
+    use super::*;
+    use prism3_function::{BoxUnaryOperator, NamedPipeline};
+
+    #[test]
+    fn test_no_steps_applies_finisher() {
+        let pipeline = NamedPipeline::new(|x: i32| x.to_string());
+        assert!(pipeline.is_empty());
+        assert_eq!(pipeline.apply(7), "7");
+    }
+
+    #[test]
+    fn test_steps_run_in_insertion_order() {
+        let mut pipeline = NamedPipeline::new(BoxTransformer::new(|x: i32| x * 100));
+        pipeline.add_step("add", |x: i32| x + 1);
+        pipeline.add_step("double", BoxUnaryOperator::new(|x: i32| x * 2));
+        assert_eq!(pipeline.len(), 2);
+        assert_eq!(pipeline.apply(1), 400);
+    }
+
+    #[test]
+    fn test_add_step_replaces_in_place() {
+        let mut pipeline = NamedPipeline::new(|s: String| s);
+        pipeline.add_step("a", |s: String| s + "a");
+        pipeline.add_step("b", |s: String| s + "b");
+        pipeline.add_step("a", |s: String| s + "A");
+        assert_eq!(pipeline.step_names().collect::<Vec<_>>(), vec!["a", "b"]);
+        assert_eq!(pipeline.apply(String::new()), "Ab");
+    }
+
+    #[test]
+    fn test_remove_step() {
+        let mut pipeline = NamedPipeline::new(|x: i32| x);
+        pipeline.add_step("inc", |x: i32| x + 1);
+        pipeline.add_step("neg", |x: i32| -x);
+        assert!(pipeline.remove_step("inc"));
+        assert!(!pipeline.remove_step("inc"));
+        assert!(!pipeline.contains_step("inc"));
+        assert!(pipeline.contains_step("neg"));
+        assert_eq!(pipeline.apply(5), -5);
+    }
+
+    #[test]
+    fn test_reconfigure_between_applies() {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let c = calls.clone();
+        let mut pipeline = NamedPipeline::new(move |x: i32| {
+            c.borrow_mut().push(x);
+            x
+        });
+        pipeline.apply(1);
+        pipeline.add_step("square", |x: i32| x * x);
+        pipeline.apply(3);
+        pipeline.remove_step("square");
+        pipeline.apply(3);
+        assert_eq!(*calls.borrow(), vec![1, 9, 3]);
+    }
+
+    #[test]
+    fn test_debug_lists_step_names() {
+        let mut pipeline = NamedPipeline::new(|x: i32| x);
+        pipeline.add_step("first", |x: i32| x);
+        assert_eq!(
+            format!("{:?}", pipeline),
+            "NamedPipeline { steps: [\"first\"] }"
+        );
+    }
+}
@@ -12,7 +12,8 @@
 use prism3_function::predicate::{
     ArcPredicate, BoxPredicate, FnPredicateOps, Predicate, RcPredicate,
 };
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 
 #[cfg(test)]
@@ -187,6 +188,137 @@ mod box_predicate_tests {
         assert!(pred.test(&5));
         assert!(!pred.test(&-3));
     }
+
+    #[test]
+    fn test_and_short_circuits_evaluation() {
+        let calls = Rc::new(Cell::new(0));
+        let c = calls.clone();
+        let pred = BoxPredicate::new(|x: &i32| *x > 0).and(move |x: &i32| {
+            c.set(c.get() + 1);
+            x % 2 == 0
+        });
+        assert!(!pred.test(&-2));
+        assert_eq!(calls.get(), 0);
+        assert!(pred.test(&2));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_and_lazy_builds_only_when_needed() {
+        let built = Rc::new(Cell::new(0));
+        let b = built.clone();
+        let pred = BoxPredicate::new(|x: &i32| *x > 0).and_lazy(move || {
+            b.set(b.get() + 1);
+            BoxPredicate::new(|x: &i32| x % 2 == 0)
+        });
+        assert!(!pred.test(&-2));
+        assert!(!pred.test(&-4));
+        assert_eq!(built.get(), 0);
+        assert!(pred.test(&2));
+        assert!(!pred.test(&3));
+        assert!(pred.test(&8));
+        assert_eq!(built.get(), 1);
+    }
+
+    #[test]
+    fn test_or_lazy_builds_only_when_needed() {
+        let built = Rc::new(Cell::new(0));
+        let b = built.clone();
+        let pred = BoxPredicate::new(|x: &i32| *x < 0).or_lazy(move || {
+            b.set(b.get() + 1);
+            BoxPredicate::new(|x: &i32| *x > 100)
+        });
+        assert!(pred.test(&-1));
+        assert_eq!(built.get(), 0);
+        assert!(pred.test(&150));
+        assert!(!pred.test(&50));
+        assert_eq!(built.get(), 1);
+    }
+
+    #[test]
+    fn test_lazy_combinators_chain() {
+        let pred = BoxPredicate::new(|x: &i32| *x > 0)
+            .and_lazy(|| BoxPredicate::new(|x: &i32| x % 2 == 0))
+            .or_lazy(|| BoxPredicate::new(|x: &i32| *x == -1));
+        assert!(pred.test(&4));
+        assert!(pred.test(&-1));
+        assert!(!pred.test(&3));
+        assert!(!pred.test(&-2));
+    }
+
+    fn counting(calls: &Rc<Cell<usize>>, result: bool) -> BoxPredicate<i32> {
+        let calls = calls.clone();
+        BoxPredicate::new(move |_: &i32| {
+            calls.set(calls.get() + 1);
+            result
+        })
+    }
+
+    #[test]
+    fn test_all_of() {
+        let pred = BoxPredicate::all_of(vec![
+            BoxPredicate::new(|x: &i32| *x > 0),
+            BoxPredicate::new(|x: &i32| x % 2 == 0),
+            BoxPredicate::new(|x: &i32| *x < 10),
+        ]);
+        assert!(pred.test(&4));
+        assert!(!pred.test(&12));
+        assert!(!pred.test(&3));
+        assert!(BoxPredicate::<i32>::all_of(Vec::new()).test(&0));
+    }
+
+    #[test]
+    fn test_all_of_short_circuits_on_false() {
+        let calls = Rc::new(Cell::new(0));
+        let pred = BoxPredicate::all_of(vec![
+            counting(&calls, true),
+            counting(&calls, false),
+            counting(&calls, true),
+        ]);
+        assert!(!pred.test(&0));
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_any_of() {
+        let pred = BoxPredicate::any_of(vec![
+            BoxPredicate::new(|x: &i32| *x < 0),
+            BoxPredicate::new(|x: &i32| *x > 100),
+        ]);
+        assert!(pred.test(&-5));
+        assert!(pred.test(&200));
+        assert!(!pred.test(&50));
+        assert!(!BoxPredicate::<i32>::any_of(Vec::new()).test(&0));
+    }
+
+    #[test]
+    fn test_any_of_short_circuits_on_true() {
+        let calls = Rc::new(Cell::new(0));
+        let pred = BoxPredicate::any_of(vec![
+            counting(&calls, false),
+            counting(&calls, true),
+            counting(&calls, false),
+        ]);
+        assert!(pred.test(&0));
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_none_of() {
+        let pred = BoxPredicate::none_of(vec![
+            BoxPredicate::new(|x: &i32| *x < 0),
+            BoxPredicate::new(|x: &i32| *x > 100),
+        ]);
+        assert!(pred.test(&50));
+        assert!(!pred.test(&-5));
+        assert!(!pred.test(&200));
+        assert!(BoxPredicate::<i32>::none_of(Vec::new()).test(&0));
+
+        let calls = Rc::new(Cell::new(0));
+        let pred = BoxPredicate::none_of(vec![counting(&calls, true), counting(&calls, false)]);
+        assert!(!pred.test(&0));
+        assert_eq!(calls.get(), 1);
+    }
 }
 
 #[cfg(test)]
@@ -695,6 +827,26 @@ mod logical_operations_tests {
         assert!(!xor.test(&4)); // positive and even: true ^ true = false
     }
 
+    #[test]
+    fn test_box_xor_evaluates_both_predicates() {
+        let calls = Rc::new(Cell::new((0, 0)));
+        let (c1, c2) = (calls.clone(), calls.clone());
+        let first = BoxPredicate::new(move |x: &i32| {
+            c1.set((c1.get().0 + 1, c1.get().1));
+            *x > 0
+        });
+        let second = BoxPredicate::new(move |x: &i32| {
+            c2.set((c2.get().0, c2.get().1 + 1));
+            *x > 10
+        });
+        let xor = first.xor(second);
+
+        assert!(!xor.test(&20)); // both true
+        assert!(!xor.test(&-1)); // both false
+        assert!(xor.test(&5)); // only the first is true
+        assert_eq!(calls.get(), (3, 3));
+    }
+
     // BoxPredicate NOR tests
     #[test]
     fn test_box_nor_basic() {
@@ -2879,3 +3031,44 @@ mod to_methods_comprehensive_tests {
         assert!(arc_pred.test(&Some(10)));
     }
 }
+
+#[cfg(test)]
+mod comparator_conversion_tests {
+    use super::*;
+    use prism3_function::Comparator;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_to_comparator_orders_matching_first() {
+        let cmp = BoxPredicate::new(|x: &i32| *x < 0).to_comparator();
+        assert_eq!(cmp.compare(&-1, &1), Ordering::Less);
+        assert_eq!(cmp.compare(&1, &-1), Ordering::Greater);
+        assert_eq!(cmp.compare(&-1, &-5), Ordering::Equal);
+        assert_eq!(cmp.compare(&1, &5), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_to_comparator_stable_sort_keeps_group_order() {
+        let cmp = BoxPredicate::new(|s: &&str| s.starts_with('a')).to_comparator();
+        let mut words = vec!["bee", "ant", "cat", "ape", "dog", "asp"];
+        words.sort_by(|a, b| cmp.compare(a, b));
+        assert_eq!(words, vec!["ant", "ape", "asp", "bee", "cat", "dog"]);
+    }
+
+    #[test]
+    fn test_partition_by_comparator() {
+        let below_ten = BoxPredicate::partition_by_comparator(10, |a: &i32, b: &i32| a.cmp(b));
+        assert!(below_ten.test(&9));
+        assert!(!below_ten.test(&10));
+        assert!(!below_ten.test(&11));
+    }
+
+    #[test]
+    fn test_partition_then_sort() {
+        let below_ten = BoxPredicate::partition_by_comparator(10, |a: &i32, b: &i32| a.cmp(b));
+        let cmp = below_ten.to_comparator();
+        let mut values = vec![15, 3, 12, 7, 10, 1];
+        values.sort_by(|a, b| cmp.compare(a, b));
+        assert_eq!(values, vec![3, 7, 1, 15, 12, 10]);
+    }
+}
@@ -0,0 +1,155 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! Unit tests for PriorityConsumer
+
+use prism3_function::{ArcComparator, BoxComparator, BoxConsumer, Consumer, PriorityConsumer};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// ============================================================================
+// PriorityConsumer Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_priority_consumer {
+    use super::*;
+
+    fn ascending() -> PriorityConsumer<i32> {
+        PriorityConsumer::new(BoxComparator::new(|a: &i32, b: &i32| a.cmp(b)))
+    }
+
+    #[test]
+    fn test_new_is_empty() {
+        let mut queue = ascending();
+        assert!(queue.is_empty());
+        assert_eq!(queue.len(), 0);
+        assert_eq!(queue.drain_all(|_: &i32| panic!("nothing buffered")), 0);
+    }
+
+    #[test]
+    fn test_drain_all_releases_in_priority_order() {
+        let mut queue = ascending();
+        for x in [3, 9, 1, 7, 5] {
+            queue.accept(&x);
+        }
+        assert_eq!(queue.len(), 5);
+
+        let mut out = Vec::new();
+        assert_eq!(queue.drain_all(|x: &i32| out.push(*x)), 5);
+        assert_eq!(out, vec![9, 7, 5, 3, 1]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_comparator_defines_priority() {
+        let mut queue = PriorityConsumer::new(BoxComparator::new(|a: &String, b: &String| {
+            b.len().cmp(&a.len())
+        }));
+        for s in ["ccc", "a", "bb"] {
+            queue.accept(&s.to_string());
+        }
+
+        let mut out = Vec::new();
+        queue.drain_all(|s: &String| out.push(s.clone()));
+        assert_eq!(out, vec!["a", "bb", "ccc"]);
+    }
+
+    #[test]
+    fn test_flush_top_n_keeps_remaining_values() {
+        let mut queue = ascending();
+        for x in [4, 2, 8, 6] {
+            queue.accept(&x);
+        }
+
+        let mut out = Vec::new();
+        assert_eq!(queue.flush_top_n(2, |x: &i32| out.push(*x)), 2);
+        assert_eq!(out, vec![8, 6]);
+        assert_eq!(queue.len(), 2);
+
+        queue.accept(&5);
+        assert_eq!(queue.drain_all(|x: &i32| out.push(*x)), 3);
+        assert_eq!(out, vec![8, 6, 5, 4, 2]);
+    }
+
+    #[test]
+    fn test_flush_top_n_beyond_len() {
+        let mut queue = ascending();
+        queue.accept(&1);
+        queue.accept(&2);
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l = log.clone();
+        let sink = BoxConsumer::new(move |x: &i32| l.lock().unwrap().push(*x));
+        assert_eq!(queue.flush_top_n(10, sink), 2);
+        assert_eq!(*log.lock().unwrap(), vec![2, 1]);
+        assert_eq!(queue.flush_top_n(0, |_: &i32| panic!("n is zero")), 0);
+    }
+
+    #[test]
+    fn test_many_values_match_sorted_order() {
+        let mut queue = ascending();
+        let values: Vec<i32> = (0..200).map(|i| (i * 7919) % 211 - 100).collect();
+        for x in &values {
+            queue.accept(x);
+        }
+
+        let mut out = Vec::new();
+        queue.flush_top_n(50, |x: &i32| out.push(*x));
+        queue.accept(&1000);
+        queue.drain_all(|x: &i32| out.push(*x));
+
+        let mut expected = values;
+        expected.sort_by(|a, b| b.cmp(a));
+        expected.insert(50, 1000);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_arc_comparator_makes_consumer_send() {
+        let mut queue = PriorityConsumer::new(ArcComparator::new(|a: &i32, b: &i32| a.cmp(b)));
+        queue.accept(&1);
+        let mut queue = thread::spawn(move || {
+            queue.accept(&3);
+            queue.accept(&2);
+            queue
+        })
+        .join()
+        .unwrap();
+
+        let mut out = Vec::new();
+        queue.drain_all(|x: &i32| out.push(*x));
+        assert_eq!(out, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_equal_priorities_are_all_released() {
+        let mut queue =
+            PriorityConsumer::new(BoxComparator::new(|a: &(i32, char), b: &(i32, char)| {
+                a.0.cmp(&b.0)
+            }));
+        for value in [(1, 'a'), (2, 'b'), (1, 'c'), (2, 'd'), (0, 'e')] {
+            queue.accept(&value);
+        }
+
+        let mut out = Vec::new();
+        queue.drain_all(|v: &(i32, char)| out.push(*v));
+        let priorities: Vec<i32> = out.iter().map(|v| v.0).collect();
+        assert_eq!(priorities, vec![2, 2, 1, 1, 0]);
+        out.sort();
+        assert_eq!(out, vec![(0, 'e'), (1, 'a'), (1, 'c'), (2, 'b'), (2, 'd')]);
+    }
+
+    #[test]
+    fn test_debug_reports_len() {
+        let mut queue = ascending();
+        queue.accept(&1);
+        assert_eq!(format!("{:?}", queue), "PriorityConsumer { len: 1 }");
+    }
+}
@@ -1663,3 +1663,203 @@ mod to_methods_tests {
         assert_eq!(*counter.lock().unwrap(), "a:1,b:2,c:3,d:4");
     }
 }
+
+#[cfg(test)]
+mod bind_tests {
+    use super::*;
+    use prism3_function::ReadonlyConsumer;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_box_bind_first_shares_bound_value() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let s = seen.clone();
+        let count = BoxReadonlyBiConsumer::new(move |calls: &Cell<usize>, x: &i32| {
+            calls.set(calls.get() + 1);
+            s.borrow_mut().push((calls.get(), *x));
+        });
+        let bound = count.bind_first(Cell::new(0));
+        bound.accept(&5);
+        bound.accept(&6);
+        assert_eq!(*seen.borrow(), vec![(1, 5), (2, 6)]);
+    }
+
+    #[test]
+    fn test_box_bind_second() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let tagged = BoxReadonlyBiConsumer::new(move |x: &i32, tag: &&str| {
+            l.borrow_mut().push(format!("{}{}", tag, x));
+        });
+        let bound = tagged.bind_second("v");
+        bound.accept(&1);
+        assert_eq!(*log.borrow(), vec!["v1"]);
+    }
+
+    #[test]
+    fn test_arc_bind_keeps_original() {
+        let log = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let l = log.clone();
+        let pair = ArcReadonlyBiConsumer::new(move |x: &i32, y: &i32| {
+            l.lock().unwrap().push((*x, *y));
+        });
+        pair.bind_first(1).accept(&2);
+        pair.bind_second(9).accept(&3);
+        pair.accept(&4, &5);
+        assert_eq!(*log.lock().unwrap(), vec![(1, 2), (3, 9), (4, 5)]);
+    }
+
+    #[test]
+    fn test_rc_bind_first_shares_bound_value() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let s = seen.clone();
+        let count = RcReadonlyBiConsumer::new(move |calls: &Cell<usize>, x: &i32| {
+            calls.set(calls.get() + 1);
+            s.borrow_mut().push((calls.get(), *x));
+        });
+        let bound = count.bind_first(Cell::new(0));
+        let alias = bound.clone();
+        bound.accept(&1);
+        alias.accept(&2);
+        count.bind_second(3).accept(&Cell::new(10));
+        assert_eq!(*seen.borrow(), vec![(1, 1), (2, 2), (11, 3)]);
+    }
+
+    #[test]
+    fn test_box_swap_then_bind_first() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let mut put = BoxReadonlyBiConsumer::new(move |key: &&str, value: &i32| {
+            l.borrow_mut().push(format!("{}={}", key, value));
+        });
+        put.set_name("put");
+        let swapped = put.swap();
+        assert_eq!(swapped.name(), Some("put"));
+        swapped.accept(&1, &"a");
+        swapped.bind_first(2).accept(&"b");
+        assert_eq!(*log.borrow(), vec!["a=1", "b=2"]);
+    }
+
+    #[test]
+    fn test_arc_swap_keeps_original() {
+        let log = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let l = log.clone();
+        let pair = ArcReadonlyBiConsumer::new(move |x: &i32, y: &&str| {
+            l.lock().unwrap().push(format!("{}{}", y, x));
+        });
+        let swapped = pair.swap();
+        std::thread::spawn(move || swapped.bind_first("#").accept(&1))
+            .join()
+            .unwrap();
+        pair.accept(&2, &"$");
+        assert_eq!(*log.lock().unwrap(), vec!["#1", "$2"]);
+    }
+
+    #[test]
+    fn test_rc_swap_then_bind_first() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let mut pair = RcReadonlyBiConsumer::new(move |x: &i32, y: &i32| {
+            l.borrow_mut().push(*x - *y);
+        });
+        pair.set_name("sub");
+        let swapped = pair.swap();
+        assert_eq!(swapped.name(), Some("sub"));
+        swapped.accept(&1, &10);
+        swapped.bind_first(100).accept(&1);
+        pair.accept(&1, &10);
+        assert_eq!(*log.borrow(), vec![9, -99, -9]);
+    }
+}
+
+#[cfg(test)]
+mod conditional_tests {
+    use super::*;
+    use prism3_function::BoxBiPredicate;
+    use std::sync::Mutex;
+    use std::thread;
+
+    #[test]
+    fn test_box_when_both_branches() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let conditional = BoxReadonlyBiConsumer::new(move |x: &i32, y: &i32| {
+            l.borrow_mut().push(x + y);
+        })
+        .when(|x: &i32, y: &i32| x > y);
+        conditional.accept(&5, &3);
+        conditional.accept(&1, &3);
+        assert_eq!(*log.borrow(), vec![8]);
+    }
+
+    #[test]
+    fn test_box_or_else() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (l1, l2) = (log.clone(), log.clone());
+        let consumer = BoxReadonlyBiConsumer::new(move |x: &i32, y: &i32| {
+            l1.borrow_mut().push(format!("then {}", x + y));
+        })
+        .when(BoxBiPredicate::new(|x: &i32, y: &i32| x > y))
+        .or_else(move |x: &i32, y: &i32| l2.borrow_mut().push(format!("else {}", x * y)));
+        consumer.accept(&5, &3);
+        consumer.accept(&1, &3);
+        assert_eq!(*log.borrow(), vec!["then 8", "else 3"]);
+    }
+
+    #[test]
+    fn test_box_conditional_into_fn() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let f = BoxReadonlyBiConsumer::new(move |x: &i32, _: &i32| l.borrow_mut().push(*x))
+            .when(|x: &i32, _: &i32| *x % 2 == 0)
+            .into_fn();
+        f(&1, &0);
+        f(&2, &0);
+        assert_eq!(*log.borrow(), vec![2]);
+    }
+
+    #[test]
+    fn test_arc_conditional_clone_and_threads() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let (l1, l2) = (log.clone(), log.clone());
+        let conditional = ArcReadonlyBiConsumer::new(move |x: &i32, y: &i32| {
+            l1.lock().unwrap().push(x + y);
+        })
+        .when(|x: &i32, y: &i32| *x > 0 && *y > 0);
+
+        let clone = conditional.clone();
+        thread::spawn(move || {
+            clone.accept(&1, &2);
+            clone.accept(&-1, &2);
+        })
+        .join()
+        .unwrap();
+        assert_eq!(*log.lock().unwrap(), vec![3]);
+
+        let branched = conditional.or_else(move |x: &i32, y: &i32| {
+            l2.lock().unwrap().push(x * y);
+        });
+        branched.accept(&-1, &2);
+        conditional.accept(&2, &2);
+        assert_eq!(*log.lock().unwrap(), vec![3, -2, 4]);
+    }
+
+    #[test]
+    fn test_rc_conditional_or_else_keeps_original() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (l1, l2) = (log.clone(), log.clone());
+        let conditional = RcReadonlyBiConsumer::new(move |x: &&str, y: &&str| {
+            l1.borrow_mut().push(format!("{}{}", x, y));
+        })
+        .when(|x: &&str, _: &&str| !x.is_empty());
+        let branched = conditional.or_else(move |_: &&str, y: &&str| {
+            l2.borrow_mut().push(format!("<empty>{}", y));
+        });
+        branched.accept(&"a", &"b");
+        branched.accept(&"", &"c");
+        let clone = conditional.clone();
+        clone.accept(&"", &"d");
+        conditional.accept(&"e", &"f");
+        assert_eq!(*log.borrow(), vec!["ab", "<empty>c", "ef"]);
+    }
+}
@@ -322,6 +322,52 @@ mod arc_readonly_consumer_tests {
 
         assert_eq!(counter.load(std::sync::atomic::Ordering::SeqCst), 10);
     }
+
+    #[test]
+    fn test_when() {
+        let counter = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let c = counter.clone();
+        let mut consumer = ArcReadonlyConsumer::new(move |x: &i32| {
+            c.fetch_add(*x as usize, std::sync::atomic::Ordering::SeqCst);
+        });
+        consumer.set_name("adder");
+        let even = consumer.when(|x: &i32| x % 2 == 0);
+        assert_eq!(even.name(), Some("adder"));
+        for i in 1..=4 {
+            even.accept(&i);
+        }
+        assert_eq!(counter.load(std::sync::atomic::Ordering::SeqCst), 6);
+        consumer.accept(&1);
+        assert_eq!(counter.load(std::sync::atomic::Ordering::SeqCst), 7);
+    }
+
+    #[test]
+    fn test_composed_consumer_runs_concurrently() {
+        // Every call blocks until all threads are inside `accept` at once,
+        // which would deadlock if any layer serialized calls with a lock.
+        const THREADS: usize = 4;
+        let barrier = Arc::new(std::sync::Barrier::new(THREADS));
+        let counter = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let (b, c) = (barrier.clone(), counter.clone());
+        let wait = ArcReadonlyConsumer::new(move |_: &usize| {
+            b.wait();
+        });
+        let count = ArcReadonlyConsumer::new(move |_: &usize| {
+            c.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        });
+        let consumer = wait.and_then(&count).when(|x: &usize| *x < THREADS);
+
+        let handles: Vec<_> = (0..THREADS)
+            .map(|i| {
+                let consumer = consumer.clone();
+                std::thread::spawn(move || consumer.accept(&i))
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(counter.load(std::sync::atomic::Ordering::SeqCst), THREADS);
+    }
 }
 
 #[cfg(test)]
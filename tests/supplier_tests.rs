@@ -10,9 +10,9 @@
 //! Unit tests for Supplier types
 
 use prism3_function::{
-    ArcMapper, ArcSupplier, BoxMapper, BoxSupplier, RcMapper, RcSupplier, Supplier,
+    ArcMapper, ArcSupplier, BoxConsumer, BoxMapper, BoxSupplier, RcMapper, RcSupplier, Supplier,
 };
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -222,6 +222,286 @@ mod test_box_supplier {
         }
     }
 
+    mod test_generate {
+        use super::*;
+
+        #[test]
+        fn test_powers_of_two() {
+            let mut powers = BoxSupplier::generate(1, |x: &i32| x * 2);
+            let values: Vec<i32> = (0..5).map(|_| powers.get()).collect();
+            assert_eq!(values, vec![1, 2, 4, 8, 16]);
+        }
+
+        #[test]
+        fn test_non_clone_values() {
+            let mut lines =
+                BoxSupplier::generate(String::from("a"), |s: &String| format!("{}a", s));
+            assert_eq!(lines.get(), "a");
+            assert_eq!(lines.get(), "aa");
+            assert_eq!(lines.get(), "aaa");
+        }
+
+        #[test]
+        fn test_stateful_update() {
+            let mut step = 0;
+            let mut triangular = BoxSupplier::generate(0, move |x: &i32| {
+                step += 1;
+                x + step
+            });
+            let values: Vec<i32> = (0..5).map(|_| triangular.get()).collect();
+            assert_eq!(values, vec![0, 1, 3, 6, 10]);
+        }
+
+        #[test]
+        fn test_with_iterator_bridge() {
+            let values: Vec<u64> = BoxSupplier::generate((0u64, 1u64), |&(a, b)| (b, a + b))
+                .map(|(a, _)| a)
+                .into_iter()
+                .take(8)
+                .collect();
+            assert_eq!(values, vec![0, 1, 1, 2, 3, 5, 8, 13]);
+        }
+    }
+
+    mod test_unfold {
+        use super::*;
+
+        #[test]
+        fn test_fibonacci_until_limit() {
+            let mut fib = BoxSupplier::unfold((0u32, 1u32), |(a, b): &mut (u32, u32)| {
+                if *a > 20 {
+                    return None;
+                }
+                let current = *a;
+                *a = *b;
+                *b += current;
+                Some(current)
+            });
+            let mut values = Vec::new();
+            while let Some(value) = fib.get() {
+                values.push(value);
+            }
+            assert_eq!(values, vec![0, 1, 1, 2, 3, 5, 8, 13]);
+        }
+
+        #[test]
+        fn test_exhausted_after_first_none() {
+            let calls = Rc::new(Cell::new(0));
+            let c = calls.clone();
+            let mut supplier = BoxSupplier::unfold(0, move |n: &mut i32| {
+                c.set(c.get() + 1);
+                *n += 1;
+                if *n % 3 == 0 {
+                    None
+                } else {
+                    Some(*n)
+                }
+            });
+            assert_eq!(supplier.get(), Some(1));
+            assert_eq!(supplier.get(), Some(2));
+            assert_eq!(supplier.get(), None);
+            assert_eq!(supplier.get(), None);
+            assert_eq!(calls.get(), 3);
+        }
+
+        #[test]
+        fn test_non_clone_state() {
+            let words = vec!["a".to_string(), "b".to_string()];
+            let supplier = BoxSupplier::unfold(words.into_iter(), |it| it.next());
+            let collected: Vec<String> = supplier.into_iter_until_none().collect();
+            assert_eq!(collected, vec!["a".to_string(), "b".to_string()]);
+        }
+    }
+
+    mod test_lazy {
+        use super::*;
+
+        #[test]
+        fn test_factory_not_called_before_get() {
+            let built = Rc::new(Cell::new(0));
+            let b = built.clone();
+            let supplier = BoxSupplier::lazy(move || {
+                b.set(b.get() + 1);
+                BoxSupplier::constant(7)
+            });
+            assert_eq!(built.get(), 0);
+            drop(supplier);
+            assert_eq!(built.get(), 0);
+        }
+
+        #[test]
+        fn test_factory_called_once_and_inner_reused() {
+            let built = Rc::new(Cell::new(0));
+            let b = built.clone();
+            let mut supplier = BoxSupplier::lazy(move || {
+                b.set(b.get() + 1);
+                let mut n = 0;
+                BoxSupplier::new(move || {
+                    n += 1;
+                    n
+                })
+            });
+            assert_eq!(supplier.get(), 1);
+            assert_eq!(supplier.get(), 2);
+            assert_eq!(supplier.get(), 3);
+            assert_eq!(built.get(), 1);
+        }
+
+        #[test]
+        fn test_factory_consumes_captured_state() {
+            let seed = vec!["x".to_string(), "y".to_string()];
+            let mut supplier = BoxSupplier::lazy(move || {
+                let mut it = seed.into_iter().cycle();
+                BoxSupplier::new(move || it.next().unwrap())
+            });
+            assert_eq!(supplier.get(), "x");
+            assert_eq!(supplier.get(), "y");
+            assert_eq!(supplier.get(), "x");
+        }
+
+        #[test]
+        fn test_composes_with_map() {
+            let mut supplier =
+                BoxSupplier::lazy(|| BoxSupplier::generate(1, |x: &i32| x * 3)).map(|x: i32| x + 1);
+            assert_eq!(supplier.get(), 2);
+            assert_eq!(supplier.get(), 4);
+        }
+
+        #[test]
+        fn test_get_after_panicking_factory_names_the_cause() {
+            let mut supplier = BoxSupplier::<i32>::lazy(|| panic!("config missing"));
+            let first = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| supplier.get()));
+            assert!(first.is_err());
+
+            let second = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| supplier.get()));
+            let payload = second.unwrap_err();
+            assert_eq!(
+                payload.downcast_ref::<String>().map(String::as_str),
+                Some("lazy supplier factory panicked on an earlier call")
+            );
+        }
+    }
+
+    mod test_once {
+        use super::*;
+
+        #[test]
+        fn test_once_calls_factory_on_first_get_only() {
+            let built = Rc::new(Cell::new(0));
+            let b = built.clone();
+            let mut supplier = BoxSupplier::once(move || {
+                b.set(b.get() + 1);
+                vec![1, 2, 3]
+            });
+            assert_eq!(built.get(), 0);
+            assert_eq!(supplier.get(), vec![1, 2, 3]);
+            assert_eq!(built.get(), 1);
+        }
+
+        #[test]
+        fn test_once_moves_non_clone_value() {
+            struct Handle(u32);
+            let mut supplier = BoxSupplier::once(|| Handle(9));
+            assert_eq!(supplier.get().0, 9);
+        }
+
+        #[test]
+        #[should_panic(expected = "once supplier called more than once")]
+        fn test_once_panics_on_second_get() {
+            let mut supplier = BoxSupplier::once(|| 1);
+            supplier.get();
+            supplier.get();
+        }
+
+        #[test]
+        fn test_once_or_default_falls_back_after_first_get() {
+            let built = Rc::new(Cell::new(0));
+            let b = built.clone();
+            let mut supplier = BoxSupplier::once_or_default(
+                move || {
+                    b.set(b.get() + 1);
+                    "first".to_string()
+                },
+                "rest".to_string(),
+            );
+            assert_eq!(supplier.get(), "first");
+            assert_eq!(supplier.get(), "rest");
+            assert_eq!(supplier.get(), "rest");
+            assert_eq!(built.get(), 1);
+        }
+    }
+
+    mod test_round_robin {
+        use super::*;
+
+        fn counter(base: i32) -> BoxSupplier<i32> {
+            let mut n = base;
+            BoxSupplier::new(move || {
+                n += 1;
+                n
+            })
+        }
+
+        #[test]
+        fn test_cycles_one_value_each() {
+            let mut supplier =
+                BoxSupplier::round_robin(vec![counter(0), counter(100), counter(200)]);
+            let values: Vec<i32> = (0..7).map(|_| supplier.get()).collect();
+            assert_eq!(values, vec![1, 101, 201, 2, 102, 202, 3]);
+        }
+
+        #[test]
+        fn test_single_supplier() {
+            let mut supplier = BoxSupplier::round_robin(vec![counter(0)]);
+            assert_eq!(supplier.get(), 1);
+            assert_eq!(supplier.get(), 2);
+        }
+
+        #[test]
+        fn test_interleave_alternates_starting_with_first() {
+            let mut supplier = BoxSupplier::interleave(counter(0), counter(100));
+            let values: Vec<i32> = (0..6).map(|_| supplier.get()).collect();
+            assert_eq!(values, vec![1, 101, 2, 102, 3, 103]);
+        }
+
+        #[test]
+        fn test_interleave_calls_each_supplier_lazily() {
+            let calls = Rc::new(RefCell::new(Vec::new()));
+            let (ca, cb) = (calls.clone(), calls.clone());
+            let a = BoxSupplier::new(move || ca.borrow_mut().push("a"));
+            let b = BoxSupplier::new(move || cb.borrow_mut().push("b"));
+            let mut supplier = BoxSupplier::interleave(a, b);
+            supplier.get();
+            assert_eq!(*calls.borrow(), vec!["a"]);
+            supplier.get();
+            supplier.get();
+            assert_eq!(*calls.borrow(), vec!["a", "b", "a"]);
+        }
+
+        #[test]
+        fn test_weighted() {
+            let mut supplier = BoxSupplier::weighted_round_robin(vec![
+                (3, counter(0)),
+                (0, counter(50)),
+                (1, counter(100)),
+            ]);
+            let values: Vec<i32> = (0..8).map(|_| supplier.get()).collect();
+            assert_eq!(values, vec![1, 2, 3, 101, 4, 5, 6, 102]);
+        }
+
+        #[test]
+        #[should_panic(expected = "non-zero weight")]
+        fn test_empty_panics() {
+            let _ = BoxSupplier::<i32>::round_robin(Vec::new());
+        }
+
+        #[test]
+        #[should_panic(expected = "non-zero weight")]
+        fn test_all_zero_weights_panics() {
+            let _ = BoxSupplier::weighted_round_robin(vec![(0, counter(0))]);
+        }
+    }
+
     mod test_get {
         use super::*;
 
@@ -388,6 +668,215 @@ mod test_box_supplier {
         }
     }
 
+    mod test_flat_map_transformer {
+        use super::*;
+
+        #[test]
+        fn test_yields_elements_in_order() {
+            let mut batches = vec![vec![1, 2, 3], vec![4], vec![5, 6]].into_iter();
+            let mut flat = BoxSupplier::new(move || batches.next().unwrap())
+                .flat_map_transformer(|batch: Vec<i32>| batch);
+            let values: Vec<i32> = (0..6).map(|_| flat.get()).collect();
+            assert_eq!(values, vec![1, 2, 3, 4, 5, 6]);
+        }
+
+        #[test]
+        fn test_pulls_source_only_when_buffer_empty() {
+            let pulls = Rc::new(Cell::new(0));
+            let p = pulls.clone();
+            let mut flat = BoxSupplier::new(move || {
+                p.set(p.get() + 1);
+                p.get()
+            })
+            .flat_map_transformer(|n: i32| vec![n * 10, n * 10 + 1]);
+            assert_eq!(flat.get(), 10);
+            assert_eq!(pulls.get(), 1);
+            assert_eq!(flat.get(), 11);
+            assert_eq!(pulls.get(), 1);
+            assert_eq!(flat.get(), 20);
+            assert_eq!(pulls.get(), 2);
+        }
+
+        #[test]
+        fn test_skips_empty_vectors() {
+            let mut counter = 0;
+            let mut flat = BoxSupplier::new(move || {
+                counter += 1;
+                counter
+            })
+            .flat_map_transformer(|n: i32| if n % 3 == 0 { vec![n] } else { vec![] });
+            assert_eq!(flat.get(), 3);
+            assert_eq!(flat.get(), 6);
+        }
+
+        #[test]
+        fn test_with_stateful_mapper() {
+            let mut seen = 0;
+            let mapper = BoxMapper::new(move |s: &'static str| {
+                seen += 1;
+                s.chars()
+                    .map(|c| format!("{}{}", seen, c))
+                    .collect::<Vec<_>>()
+            });
+            let mut words = vec!["ab", "c"].into_iter();
+            let mut flat =
+                BoxSupplier::new(move || words.next().unwrap()).flat_map_transformer(mapper);
+            assert_eq!(flat.get(), "1a");
+            assert_eq!(flat.get(), "1b");
+            assert_eq!(flat.get(), "2c");
+        }
+    }
+
+    mod test_lifecycle_hooks {
+        use super::*;
+
+        #[test]
+        fn test_on_first_get_runs_once_before_first_value() {
+            let log = Rc::new(RefCell::new(Vec::new()));
+            let (l1, l2) = (log.clone(), log.clone());
+            let mut counter = 0;
+            let mut supplier = BoxSupplier::new(move || {
+                counter += 1;
+                l1.borrow_mut().push(format!("get {}", counter));
+                counter
+            })
+            .on_first_get(move || l2.borrow_mut().push("first".to_string()));
+            assert_eq!(supplier.get(), 1);
+            assert_eq!(supplier.get(), 2);
+            assert_eq!(*log.borrow(), vec!["first", "get 1", "get 2"]);
+        }
+
+        #[test]
+        fn test_on_first_get_not_run_without_get() {
+            let called = Rc::new(Cell::new(false));
+            let c = called.clone();
+            let supplier = BoxSupplier::new(|| 1).on_first_get(move || c.set(true));
+            drop(supplier);
+            assert!(!called.get());
+        }
+
+        #[test]
+        fn test_on_each_get_with_closure() {
+            let seen = Rc::new(RefCell::new(Vec::new()));
+            let s = seen.clone();
+            let mut values = vec![3, 1, 2].into_iter();
+            let mut supplier = BoxSupplier::new(move || values.next().unwrap())
+                .on_each_get(move |x: &i32| s.borrow_mut().push(*x));
+            assert_eq!(supplier.get(), 3);
+            assert_eq!(supplier.get(), 1);
+            assert_eq!(supplier.get(), 2);
+            assert_eq!(*seen.borrow(), vec![3, 1, 2]);
+        }
+
+        #[test]
+        fn test_on_each_get_with_box_consumer() {
+            let (log, values) = BoxConsumer::collect();
+            let mut supplier = BoxSupplier::new(|| "x".to_string()).on_each_get(log);
+            assert_eq!(supplier.get(), "x");
+            assert_eq!(supplier.get(), "x");
+            assert_eq!(*values.lock().unwrap(), vec!["x", "x"]);
+        }
+
+        #[test]
+        fn test_on_exhausted_fires_once() {
+            let fired = Rc::new(Cell::new(0));
+            let f = fired.clone();
+            let mut supplier =
+                BoxSupplier::from(vec![1, 2].into_iter()).on_exhausted(move || f.set(f.get() + 1));
+            assert_eq!(supplier.get(), Some(1));
+            assert_eq!(supplier.get(), Some(2));
+            assert_eq!(fired.get(), 0);
+            assert_eq!(supplier.get(), None);
+            assert_eq!(supplier.get(), None);
+            assert_eq!(fired.get(), 1);
+        }
+
+        #[test]
+        fn test_on_exhausted_with_resuming_supplier() {
+            let fired = Rc::new(Cell::new(0));
+            let f = fired.clone();
+            let mut toggle = false;
+            let mut supplier = BoxSupplier::new(move || {
+                toggle = !toggle;
+                if toggle {
+                    None
+                } else {
+                    Some(1)
+                }
+            })
+            .on_exhausted(move || f.set(f.get() + 1));
+            assert_eq!(supplier.get(), None);
+            assert_eq!(supplier.get(), Some(1));
+            assert_eq!(supplier.get(), None);
+            assert_eq!(fired.get(), 1);
+        }
+    }
+
+    mod test_combine_two {
+        use super::*;
+
+        fn sequence(values: Vec<i32>) -> BoxSupplier<i32> {
+            let mut values = values.into_iter();
+            BoxSupplier::new(move || values.next().unwrap())
+        }
+
+        #[test]
+        fn test_max_of_two() {
+            let mut max = BoxSupplier::max_of_two(sequence(vec![1, 5, 3]), sequence(vec![4, 2, 3]));
+            assert_eq!(max.get(), 4);
+            assert_eq!(max.get(), 5);
+            assert_eq!(max.get(), 3);
+        }
+
+        #[test]
+        fn test_min_of_two() {
+            let mut min = BoxSupplier::min_of_two(sequence(vec![1, 5, 3]), sequence(vec![4, 2, 3]));
+            assert_eq!(min.get(), 1);
+            assert_eq!(min.get(), 2);
+            assert_eq!(min.get(), 3);
+        }
+
+        #[test]
+        fn test_calls_both_suppliers_in_order() {
+            let log = Rc::new(RefCell::new(Vec::new()));
+            let (l1, l2) = (log.clone(), log.clone());
+            let a = BoxSupplier::new(move || {
+                l1.borrow_mut().push("a");
+                10
+            });
+            let b = BoxSupplier::new(move || {
+                l2.borrow_mut().push("b");
+                1
+            });
+            let mut max = BoxSupplier::max_of_two(a, b);
+            assert_eq!(max.get(), 10);
+            assert_eq!(max.get(), 10);
+            assert_eq!(*log.borrow(), vec!["a", "b", "a", "b"]);
+        }
+
+        #[test]
+        fn test_average_of_two() {
+            let a = BoxSupplier::generate(0.0, |x: &f64| x + 1.0);
+            let b = BoxSupplier::constant(3.0);
+            let mut average = BoxSupplier::average_of_two(a, b);
+            assert_eq!(average.get(), 1.5);
+            assert_eq!(average.get(), 2.0);
+            assert_eq!(average.get(), 2.5);
+        }
+
+        #[test]
+        fn test_combinators_compose() {
+            let high = BoxSupplier::max_of_two(sequence(vec![1, 8]), sequence(vec![6, 2]));
+            let low = BoxSupplier::min_of_two(sequence(vec![1, 8]), sequence(vec![6, 2]));
+            let mut spread = BoxSupplier::average_of_two(
+                high.map(|x: i32| x as f64),
+                low.map(|x: i32| x as f64),
+            );
+            assert_eq!(spread.get(), 3.5);
+            assert_eq!(spread.get(), 5.0);
+        }
+    }
+
     mod test_memoize {
         use super::*;
 
@@ -2621,3 +3110,196 @@ mod test_custom_clone_supplier {
         assert_eq!(f(), 13);
     }
 }
+
+// ==========================================================================
+// BoxSupplier Iterator Bridge Tests
+// ==========================================================================
+
+#[cfg(test)]
+mod test_box_supplier_iter {
+    use super::*;
+
+    #[test]
+    fn test_into_iter_is_infinite() {
+        let mut counter = 0;
+        let supplier = BoxSupplier::new(move || {
+            counter += 1;
+            counter
+        });
+        let values: Vec<i32> = supplier.into_iter().skip(2).take(3).collect();
+        assert_eq!(values, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_into_iter_size_hint() {
+        let iter = BoxSupplier::constant(1).into_iter();
+        assert_eq!(iter.size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn test_for_loop() {
+        let mut counter = 0;
+        let supplier = BoxSupplier::new(move || {
+            counter += 1;
+            counter
+        });
+        let mut sum = 0;
+        for value in supplier {
+            if value > 4 {
+                break;
+            }
+            sum += value;
+        }
+        assert_eq!(sum, 10);
+    }
+
+    #[test]
+    fn test_into_iter_until_none() {
+        let mut remaining = 3;
+        let supplier = BoxSupplier::new(move || {
+            if remaining == 0 {
+                None
+            } else {
+                remaining -= 1;
+                Some(remaining)
+            }
+        });
+        let values: Vec<i32> = supplier.into_iter_until_none().collect();
+        assert_eq!(values, vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn test_into_iter_until_none_stops_calling_supplier() {
+        let calls = Rc::new(RefCell::new(0));
+        let c = calls.clone();
+        let supplier = BoxSupplier::new(move || {
+            *c.borrow_mut() += 1;
+            let n = *c.borrow();
+            if n == 2 {
+                None
+            } else {
+                Some(n)
+            }
+        });
+        let mut iter = supplier.into_iter_until_none();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(*calls.borrow(), 2);
+    }
+
+    #[test]
+    fn test_from_iterator() {
+        let mut supplier = BoxSupplier::from(vec!["a", "b"].into_iter());
+        assert_eq!(supplier.get(), Some("a"));
+        assert_eq!(supplier.get(), Some("b"));
+        assert_eq!(supplier.get(), None);
+        assert_eq!(supplier.get(), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let supplier: BoxSupplier<Option<i32>> = (1..=4).map(|x| x * x).into();
+        let values: Vec<i32> = supplier.into_iter_until_none().collect();
+        assert_eq!(values, vec![1, 4, 9, 16]);
+    }
+}
+
+// ============================================================================
+// Random Generation Tests (`rand` feature)
+// ============================================================================
+
+#[cfg(feature = "rand")]
+mod test_random {
+    use super::*;
+    use rand::distributions::Bernoulli;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    fn seeded(seed: u64) -> Arc<Mutex<StdRng>> {
+        Arc::new(Mutex::new(StdRng::seed_from_u64(seed)))
+    }
+
+    #[test]
+    fn test_random_is_reproducible_with_same_seed() {
+        let mut a = BoxSupplier::<u64>::random(seeded(42));
+        let mut b = BoxSupplier::<u64>::random(seeded(42));
+        let xs: Vec<u64> = (0..10).map(|_| a.get()).collect();
+        let ys: Vec<u64> = (0..10).map(|_| b.get()).collect();
+        assert_eq!(xs, ys);
+    }
+
+    #[test]
+    fn test_random_f64_in_unit_interval() {
+        let mut unit = BoxSupplier::<f64>::random(seeded(1));
+        for _ in 0..100 {
+            let x = unit.get();
+            assert!((0.0..1.0).contains(&x));
+        }
+    }
+
+    #[test]
+    fn test_random_from_distribution() {
+        let mut never = BoxSupplier::random_from(seeded(3), Bernoulli::new(0.0).unwrap());
+        assert!((0..20).all(|_| !never.get()));
+    }
+
+    #[test]
+    fn test_suppliers_share_generator() {
+        let rng = seeded(9);
+        let mut first = BoxSupplier::<u32>::random(rng.clone());
+        let mut second = BoxSupplier::<u32>::random(rng);
+        let interleaved = vec![first.get(), second.get(), first.get()];
+
+        let mut single = BoxSupplier::<u32>::random(seeded(9));
+        let sequential: Vec<u32> = (0..3).map(|_| single.get()).collect();
+        assert_eq!(interleaved, sequential);
+    }
+
+    #[test]
+    fn test_random_range_stays_in_bounds() {
+        let mut dice = BoxSupplier::random_range(seeded(5), 1, 7);
+        let rolls: HashSet<i32> = (0..200).map(|_| dice.get()).collect();
+        assert!(rolls.iter().all(|r| (1..7).contains(r)));
+        assert_eq!(rolls.len(), 6);
+    }
+
+    #[test]
+    fn test_random_range_floats() {
+        let mut temperature = BoxSupplier::random_range(seeded(5), -10.0, 10.0);
+        for _ in 0..100 {
+            let t = temperature.get();
+            assert!((-10.0..10.0).contains(&t));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_random_range_empty_panics() {
+        let _ = BoxSupplier::random_range(seeded(0), 5, 5);
+    }
+
+    #[test]
+    fn test_random_choice_picks_every_option() {
+        let mut color = BoxSupplier::random_choice(
+            seeded(11),
+            vec!["red".to_string(), "green".to_string(), "blue".to_string()],
+        );
+        let picked: HashSet<String> = (0..100).map(|_| color.get()).collect();
+        assert_eq!(picked.len(), 3);
+    }
+
+    #[test]
+    fn test_random_choice_single_option() {
+        let mut only = BoxSupplier::random_choice(seeded(0), vec![7]);
+        assert_eq!(only.get(), 7);
+        assert_eq!(only.get(), 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "random_choice requires at least one option")]
+    fn test_random_choice_empty_panics() {
+        let _ = BoxSupplier::<i32>::random_choice(seeded(0), vec![]);
+    }
+}
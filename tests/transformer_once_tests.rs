@@ -832,3 +832,147 @@ mod arc_transformer_transformer_once_tests {
         assert_eq!(result, 42);
     }
 }
+
+// ============================================================================
+// Tests for RcTransformerOnce
+// ============================================================================
+
+#[cfg(test)]
+mod rc_transformer_once_tests {
+    use super::*;
+    use prism3_function::RcTransformerOnce;
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    #[test]
+    fn test_apply_once() {
+        let double = RcTransformerOnce::new(|x: i32| x * 2);
+        assert_eq!(double.apply_once(21), 42);
+    }
+
+    #[test]
+    fn test_shared_registry_fires_once() {
+        let calls = Rc::new(Cell::new(0));
+        let c = calls.clone();
+        let callback = RcTransformerOnce::new(move |event: String| {
+            c.set(c.get() + 1);
+            format!("handled {}", event)
+        });
+        let registry: RefCell<Vec<RcTransformerOnce<String, String>>> =
+            RefCell::new(vec![callback.clone(), callback.clone(), callback.clone()]);
+
+        let results: Vec<Option<String>> = registry
+            .borrow()
+            .iter()
+            .enumerate()
+            .map(|(i, trigger)| trigger.try_apply_once(format!("trigger-{}", i)))
+            .collect();
+        assert_eq!(
+            results,
+            vec![Some("handled trigger-0".to_string()), None, None]
+        );
+        assert!(callback.is_consumed());
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "already been consumed")]
+    fn test_apply_once_after_consumed_panics() {
+        let double = RcTransformerOnce::new(|x: i32| x * 2);
+        let other = double.clone();
+        assert_eq!(double.try_apply_once(1), Some(2));
+        other.apply_once(1);
+    }
+
+    #[test]
+    fn test_from_box() {
+        let upper = BoxTransformerOnce::new(|s: String| s.to_uppercase());
+        let shared: RcTransformerOnce<String, String> = upper.into();
+        let other = shared.clone();
+        assert_eq!(
+            shared.try_apply_once("a".to_string()),
+            Some("A".to_string())
+        );
+        assert_eq!(other.try_apply_once("b".to_string()), None);
+    }
+
+    #[test]
+    fn test_to_box_once_shares_slot() {
+        let double = RcTransformerOnce::new(|x: i32| x * 2);
+        let boxed = double.to_box_once();
+        assert_eq!(boxed.apply_once(3), 6);
+        assert_eq!(double.try_apply_once(3), None);
+    }
+}
+
+// ============================================================================
+// Tests for ArcTransformerOnce
+// ============================================================================
+
+#[cfg(test)]
+mod arc_transformer_once_tests {
+    use super::*;
+    use prism3_function::{ArcTransformerOnce, RcTransformerOnce};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Barrier};
+    use std::thread;
+
+    #[test]
+    fn test_apply_once() {
+        let double = ArcTransformerOnce::new(|x: i32| x * 2);
+        assert_eq!(double.apply_once(21), 42);
+    }
+
+    #[test]
+    fn test_two_clones_race() {
+        for _ in 0..50 {
+            let calls = Arc::new(AtomicUsize::new(0));
+            let c = calls.clone();
+            let callback = ArcTransformerOnce::new(move |who: &'static str| {
+                c.fetch_add(1, Ordering::SeqCst);
+                who
+            });
+            let barrier = Arc::new(Barrier::new(2));
+            let handles: Vec<_> = ["left", "right"]
+                .into_iter()
+                .map(|who| {
+                    let callback = callback.clone();
+                    let barrier = barrier.clone();
+                    thread::spawn(move || {
+                        barrier.wait();
+                        callback.try_apply_once(who)
+                    })
+                })
+                .collect();
+            let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+            assert_eq!(results.iter().filter(|r| r.is_some()).count(), 1);
+            assert_eq!(calls.load(Ordering::SeqCst), 1);
+            assert!(callback.is_consumed());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "already been consumed")]
+    fn test_apply_once_after_consumed_panics() {
+        let double = ArcTransformerOnce::new(|x: i32| x * 2);
+        let _ = double.try_apply_once(1);
+        double.apply_once(1);
+    }
+
+    #[test]
+    fn test_into_rc_shares_slot() {
+        let double = ArcTransformerOnce::new(|x: i32| x * 2);
+        let rc: RcTransformerOnce<i32, i32> = double.clone().into();
+        assert_eq!(rc.try_apply_once(4), Some(8));
+        assert!(double.is_consumed());
+    }
+
+    #[test]
+    fn test_to_fn_once() {
+        let double = ArcTransformerOnce::new(|x: i32| x * 2);
+        let f = double.to_fn_once();
+        assert_eq!(f(5), 10);
+        assert_eq!(double.try_apply_once(5), None);
+    }
+}
@@ -156,6 +156,52 @@ mod arc_transformer_tests {
 
         assert_eq!(composed.apply(5), 12); // (5 + 1) * 2
     }
+
+    #[test]
+    fn test_concurrent_calls_do_not_serialize() {
+        use std::sync::{Arc, Barrier};
+
+        // Every thread blocks inside `apply` until all 8 threads are inside
+        // it at the same time, which would deadlock if calls went through a
+        // lock. The composed stages must not introduce one either.
+        let barrier = Arc::new(Barrier::new(8));
+        let b = barrier.clone();
+        let rendezvous = ArcTransformer::new(move |x: i32| {
+            b.wait();
+            x
+        });
+        let composed = rendezvous.and_then(|x: i32| x * 2).compose(|x: i32| x + 1);
+
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let composed = composed.clone();
+                thread::spawn(move || composed.apply(i))
+            })
+            .collect();
+        let results: Vec<i32> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert_eq!(results, (0..8).map(|i| (i + 1) * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_hammer_from_eight_threads() {
+        let pipeline = ArcTransformer::new(|x: u64| x * 3)
+            .and_then(|x: u64| x + 1)
+            .compose(|x: u64| x ^ 0x5);
+
+        let handles: Vec<_> = (0..8u64)
+            .map(|t| {
+                let pipeline = pipeline.clone();
+                thread::spawn(move || {
+                    (0..10_000u64)
+                        .map(|i| pipeline.apply(t * 10_000 + i))
+                        .sum::<u64>()
+                })
+            })
+            .collect();
+        let total: u64 = handles.into_iter().map(|h| h.join().unwrap()).sum();
+        let expected: u64 = (0..80_000u64).map(|x| (x ^ 0x5) * 3 + 1).sum();
+        assert_eq!(total, expected);
+    }
 }
 
 // ============================================================================
@@ -2528,3 +2574,1060 @@ mod transformer_once_tests {
         }
     }
 }
+
+// ============================================================================
+// Fan-out Tests
+// ============================================================================
+
+#[cfg(test)]
+mod fanout_tests {
+    use prism3_function::{ArcTransformer, BoxTransformer, RcTransformer, Transformer};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::thread;
+
+    #[test]
+    fn test_box_fanout_both_observe_same_input() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let s1 = seen.clone();
+        let s2 = seen.clone();
+        let len = BoxTransformer::new(move |s: String| {
+            s1.borrow_mut().push(format!("len:{}", s));
+            s.len()
+        });
+        let fanned = len.fanout(move |s: String| {
+            s2.borrow_mut().push(format!("upper:{}", s));
+            s.to_uppercase()
+        });
+
+        assert_eq!(fanned.apply("abc".to_string()), (3, "ABC".to_string()));
+        assert_eq!(
+            *seen.borrow(),
+            vec!["len:abc".to_string(), "upper:abc".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_box_fanout_composes_with_and_then() {
+        let double = BoxTransformer::new(|x: i32| x * 2);
+        let pipeline = double
+            .fanout(|x: i32| x + 1)
+            .and_then(|(a, b): (i32, i32)| a + b);
+        assert_eq!(pipeline.apply(10), 31);
+    }
+
+    #[test]
+    fn test_arc_fanout() {
+        let double = ArcTransformer::new(|x: i32| x * 2);
+        let fanned = double.fanout(|x: i32| x.to_string());
+        let clone = fanned.clone();
+        let handle = thread::spawn(move || clone.apply(5));
+        assert_eq!(handle.join().unwrap(), (10, "5".to_string()));
+        assert_eq!(fanned.apply(1), (2, "1".to_string()));
+        assert_eq!(double.apply(3), 6);
+    }
+
+    #[test]
+    fn test_rc_fanout() {
+        let negate = RcTransformer::new(|x: i32| -x);
+        let fanned = negate.fanout(RcTransformer::new(|x: i32| x * x));
+        assert_eq!(fanned.apply(4), (-4, 16));
+        assert_eq!(negate.apply(4), -4);
+    }
+}
+
+// ============================================================================
+// TTL Cache Tests
+// ============================================================================
+
+#[cfg(test)]
+mod cache_with_ttl_tests {
+    use prism3_function::{ArcTransformer, BoxTransformer, Mapper, Transformer};
+    use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    /// A clock that only moves when the returned handle is advanced
+    fn manual_clock() -> (Arc<AtomicU64>, impl Fn() -> Instant + Send + 'static) {
+        let start = Instant::now();
+        let millis = Arc::new(AtomicU64::new(0));
+        let m = millis.clone();
+        (millis, move || {
+            start + Duration::from_millis(m.load(Ordering::SeqCst))
+        })
+    }
+
+    #[test]
+    fn test_box_cache_hits_within_ttl() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let c = calls.clone();
+        let square = BoxTransformer::new(move |x: i32| {
+            c.fetch_add(1, Ordering::SeqCst);
+            x * x
+        });
+        let mut cached = square.cache_with_ttl(Duration::from_secs(60));
+
+        assert_eq!(cached.apply(3), 9);
+        assert_eq!(cached.apply(3), 9);
+        assert_eq!(cached.apply(4), 16);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_box_cache_recomputes_after_expiry() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let c = calls.clone();
+        let counter = BoxTransformer::new(move |s: String| {
+            let n = c.fetch_add(1, Ordering::SeqCst) + 1;
+            format!("{}#{}", s, n)
+        });
+        let (millis, clock) = manual_clock();
+        let mut cached = counter.cache_with_ttl_and_clock(Duration::from_millis(20), clock);
+
+        assert_eq!(cached.apply("a".to_string()), "a#1");
+        millis.store(19, Ordering::SeqCst);
+        assert_eq!(cached.apply("a".to_string()), "a#1");
+        millis.store(20, Ordering::SeqCst);
+        assert_eq!(cached.apply("a".to_string()), "a#2");
+        assert_eq!(cached.apply("a".to_string()), "a#2");
+    }
+
+    #[test]
+    fn test_box_cache_zero_ttl_never_hits() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let c = calls.clone();
+        let identity = BoxTransformer::new(move |x: u8| {
+            c.fetch_add(1, Ordering::SeqCst);
+            x
+        });
+        let mut cached = identity.cache_with_ttl(Duration::ZERO);
+        cached.apply(1);
+        cached.apply(1);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_box_cache_sweeps_expired_entries_once_doubled() {
+        // The cache holds one clone of `token` per entry
+        let token = Arc::new(());
+        let t = token.clone();
+        let tokens = BoxTransformer::new(move |_: i32| t.clone());
+        let (millis, clock) = manual_clock();
+        let mut cached = tokens.cache_with_ttl_and_clock(Duration::from_millis(200), clock);
+
+        // Sweeps run at sizes 1, 2, 4 and 8; the next one is due at 16
+        for x in 0..10 {
+            cached.apply(x);
+        }
+        assert_eq!(Arc::strong_count(&token), 2 + 10);
+        millis.store(250, Ordering::SeqCst);
+
+        // Expired entries stay until the cache reaches 16 entries ...
+        for x in 100..106 {
+            cached.apply(x);
+        }
+        assert_eq!(Arc::strong_count(&token), 2 + 16);
+
+        // ... and are then dropped in a single sweep
+        cached.apply(106);
+        assert_eq!(Arc::strong_count(&token), 2 + 7);
+    }
+
+    #[test]
+    fn test_box_cache_stays_bounded_when_every_entry_expires() {
+        let token = Arc::new(());
+        let t = token.clone();
+        let tokens = BoxTransformer::new(move |_: i32| t.clone());
+        let (millis, clock) = manual_clock();
+        let mut cached = tokens.cache_with_ttl_and_clock(Duration::from_millis(10), clock);
+
+        for x in 0..100 {
+            millis.fetch_add(10, Ordering::SeqCst);
+            cached.apply(x);
+            assert!(Arc::strong_count(&token) <= 2 + 2);
+        }
+    }
+
+    #[test]
+    fn test_arc_cache_sweeps_expired_entries_once_doubled() {
+        let token = Arc::new(());
+        let t = token.clone();
+        let tokens = ArcTransformer::new(move |_: i32| t.clone());
+        let (millis, clock) = manual_clock();
+        let mut cached = tokens.cache_with_ttl_and_clock(Duration::from_millis(200), clock);
+        let mut shared = cached.clone();
+
+        for x in 0..10 {
+            cached.apply(x);
+        }
+        assert_eq!(Arc::strong_count(&token), 2 + 10);
+        millis.store(250, Ordering::SeqCst);
+
+        for x in 100..106 {
+            shared.apply(x);
+        }
+        assert_eq!(Arc::strong_count(&token), 2 + 16);
+        shared.apply(106);
+        assert_eq!(Arc::strong_count(&token), 2 + 7);
+    }
+
+    #[test]
+    fn test_arc_cache_shared_across_threads() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let c = calls.clone();
+        let double = ArcTransformer::new(move |x: i32| {
+            c.fetch_add(1, Ordering::SeqCst);
+            x * 2
+        });
+        let cached = double.cache_with_ttl(Duration::from_secs(60));
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let mut mapper = cached.clone();
+                thread::spawn(move || mapper.apply(21))
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), 42);
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        // Original transformer is still usable and bypasses the cache
+        assert_eq!(double.apply(1), 2);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
+
+// ============================================================================
+// Product Tests
+// ============================================================================
+
+#[cfg(test)]
+mod product_tests {
+    use prism3_function::{ArcTransformer, BoxTransformer, RcTransformer, Transformer};
+    use std::thread;
+
+    #[test]
+    fn test_box_product_maps_pair_components() {
+        let double = BoxTransformer::new(|x: i32| x * 2);
+        let upper = BoxTransformer::new(|s: String| s.to_uppercase());
+        let pair = double.product(upper);
+        assert_eq!(
+            pair.apply((21, "hello".to_string())),
+            (42, "HELLO".to_string())
+        );
+    }
+
+    #[test]
+    fn test_box_product_with_fanout() {
+        let double = BoxTransformer::new(|x: i32| x * 2);
+        let to_string = BoxTransformer::new(|x: i32| x.to_string());
+        let pipeline = BoxTransformer::new(|x: i32| x + 1)
+            .fanout(|x: i32| x - 1)
+            .and_then(double.product(to_string));
+        assert_eq!(pipeline.apply(10), (22, "9".to_string()));
+    }
+
+    #[test]
+    fn test_arc_product() {
+        let double = ArcTransformer::new(|x: i32| x * 2);
+        let pair = double.product(|s: String| s.len());
+        let clone = pair.clone();
+        let handle = thread::spawn(move || clone.apply((1, "ab".to_string())));
+        assert_eq!(handle.join().unwrap(), (2, 2));
+        assert_eq!(double.apply(5), 10);
+    }
+
+    #[test]
+    fn test_rc_product() {
+        let negate = RcTransformer::new(|x: i32| -x);
+        let pair = negate.product(negate.clone());
+        assert_eq!(pair.apply((1, 2)), (-1, -2));
+        assert_eq!(negate.apply(3), -3);
+    }
+}
+
+// ============================================================================
+// Inspect Tests
+// ============================================================================
+
+#[cfg(test)]
+mod inspect_tests {
+    use prism3_function::{
+        ArcReadonlyConsumer, ArcTransformer, BoxConsumer, BoxTransformer, RcTransformer,
+        Transformer,
+    };
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    #[test]
+    fn test_box_inspect_logs_and_keeps_output() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l1 = log.clone();
+        let l2 = log.clone();
+        let pipeline = BoxTransformer::new(|s: &str| s.len())
+            .inspect(move |n: &usize| l1.borrow_mut().push(format!("len={}", n)))
+            .and_then(|n: usize| n * 10)
+            .inspect(move |n: &usize| l2.borrow_mut().push(format!("scaled={}", n)));
+
+        assert_eq!(pipeline.apply("abc"), 30);
+        assert_eq!(pipeline.apply(""), 0);
+        assert_eq!(
+            *log.borrow(),
+            vec!["len=3", "scaled=30", "len=0", "scaled=0"]
+        );
+    }
+
+    #[test]
+    fn test_box_inspect_with_stateful_consumer() {
+        let count = Rc::new(RefCell::new(0));
+        let c = count.clone();
+        let mut calls = 0;
+        let traced =
+            BoxTransformer::new(|x: i32| x + 1).inspect(BoxConsumer::new(move |_: &i32| {
+                calls += 1;
+                *c.borrow_mut() = calls;
+            }));
+        assert_eq!(traced.apply(1), 2);
+        assert_eq!(traced.apply(2), 3);
+        assert_eq!(*count.borrow(), 2);
+    }
+
+    #[test]
+    fn test_rc_inspect_keeps_original() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let double = RcTransformer::new(|x: i32| x * 2);
+        let traced = double.inspect(move |x: &i32| l.borrow_mut().push(*x));
+        assert_eq!(traced.apply(4), 8);
+        assert_eq!(double.apply(5), 10);
+        assert_eq!(*log.borrow(), vec![8]);
+    }
+
+    #[test]
+    fn test_arc_inspect_across_threads() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l = log.clone();
+        let traced =
+            ArcTransformer::new(|x: i32| x * 2).inspect(move |x: &i32| l.lock().unwrap().push(*x));
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let traced = traced.clone();
+                thread::spawn(move || traced.apply(i))
+            })
+            .collect();
+        let mut results: Vec<i32> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        results.sort();
+        let mut logged = log.lock().unwrap().clone();
+        logged.sort();
+        assert_eq!(results, vec![0, 2, 4, 6]);
+        assert_eq!(logged, results);
+    }
+
+    #[test]
+    fn test_arc_inspect_survives_panicking_inspector() {
+        let seen = Arc::new(AtomicUsize::new(0));
+        let s = seen.clone();
+        let traced = ArcTransformer::new(|x: i32| x + 1).inspect(move |x: &i32| {
+            s.fetch_add(1, Ordering::SeqCst);
+            assert!(*x != 2, "inspector rejects 2");
+        });
+        let clone = traced.clone();
+        assert!(thread::spawn(move || clone.apply(1)).join().is_err());
+        assert_eq!(traced.apply(5), 6);
+        assert_eq!(traced.clone().apply(9), 10);
+        assert_eq!(seen.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_arc_inspect_with_readonly_consumer() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let c = count.clone();
+        let observer = ArcReadonlyConsumer::new(move |_: &i32| {
+            c.fetch_add(1, Ordering::SeqCst);
+        });
+        let traced = ArcTransformer::new(|x: i32| x * 3).inspect(observer);
+        assert_eq!(traced.apply(2), 6);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+}
+
+#[cfg(test)]
+mod projection_tests {
+    use prism3_function::{BoxTransformer, Transformer};
+
+    #[test]
+    fn test_first_and_second() {
+        let first = BoxTransformer::<(String, i32), String>::first();
+        let second = BoxTransformer::<(String, i32), i32>::second();
+        assert_eq!(first.apply(("k".to_string(), 1)), "k");
+        assert_eq!(second.apply(("k".to_string(), 1)), 1);
+    }
+
+    #[test]
+    fn test_map_first_keeps_second() {
+        let lift = BoxTransformer::map_first(|k: String| k.len());
+        assert_eq!(lift.apply(("abc".to_string(), 'x')), (3, 'x'));
+    }
+
+    #[test]
+    fn test_map_second_then_first() {
+        let parse = |v: String| v.parse::<i32>().unwrap();
+        let pipeline = BoxTransformer::map_second(parse).and_then(BoxTransformer::first());
+        assert_eq!(pipeline.apply(("key", "42".to_string())), "key");
+    }
+
+    #[test]
+    fn test_map_second_then_second() {
+        let parse = |v: String| v.parse::<i32>().unwrap();
+        let pipeline = BoxTransformer::map_second(parse).and_then(BoxTransformer::second());
+        let values: Vec<i32> = vec![("a", "1".to_string()), ("b", "2".to_string())]
+            .into_iter()
+            .map(|pair| pipeline.apply(pair))
+            .collect();
+        assert_eq!(values, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_map_first_and_map_second_compose() {
+        let both = BoxTransformer::map_first(|k: &str| k.to_uppercase())
+            .and_then(BoxTransformer::map_second(|v: i32| v * 10));
+        assert_eq!(both.apply(("a", 4)), ("A".to_string(), 40));
+    }
+
+    #[test]
+    fn test_with_fanout_and_product() {
+        let split = BoxTransformer::new(|s: String| s.len()).fanout(|s: String| s);
+        let pipeline = split
+            .and_then(BoxTransformer::new(|n: usize| n * 2).product(|s: String| s.to_uppercase()))
+            .and_then(BoxTransformer::second());
+        assert_eq!(pipeline.apply("ab".to_string()), "AB");
+    }
+}
+
+#[cfg(test)]
+mod timing_tests {
+    use prism3_function::{BoxConsumer, BoxTransformer, Mapper};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::atomic::Ordering;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_with_timing_reports_each_call() {
+        let durations = Rc::new(RefCell::new(Vec::new()));
+        let d = durations.clone();
+        let mut timed = BoxTransformer::new(|ms: u64| {
+            thread::sleep(Duration::from_millis(ms));
+            ms
+        })
+        .with_timing(move |elapsed: &Duration| d.borrow_mut().push(*elapsed));
+
+        assert_eq!(timed.apply(0), 0);
+        assert_eq!(timed.apply(20), 20);
+        let durations = durations.borrow();
+        assert_eq!(durations.len(), 2);
+        assert!(durations[1] >= Duration::from_millis(20));
+    }
+
+    #[test]
+    fn test_with_timing_threshold_alert() {
+        let (alerts, count) = BoxConsumer::<Duration>::count();
+        let mut timed = BoxTransformer::new(|ms: u64| {
+            thread::sleep(Duration::from_millis(ms));
+            ms * 2
+        })
+        .with_timing(alerts.when(|d: &Duration| *d >= Duration::from_millis(15)));
+
+        assert_eq!(timed.apply(0), 0);
+        assert_eq!(timed.apply(20), 40);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_with_timing_composes() {
+        let (hook, count) = BoxConsumer::<Duration>::count();
+        let mut timed = BoxTransformer::new(|s: String| s.len())
+            .with_timing(hook)
+            .and_then(|n: usize| n * 10);
+        assert_eq!(timed.apply("abc".to_string()), 30);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+}
+
+#[cfg(test)]
+mod or_else_shortcut_tests {
+    use prism3_function::{
+        ArcTransformer, BoxSupplier, BoxTransformer, RcTransformer, Transformer,
+    };
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_box_or_else_default() {
+        let lengths = BoxTransformer::new(|s: String| s.len())
+            .when(|s: &String| !s.is_empty())
+            .or_else_default();
+        assert_eq!(lengths.apply("abc".to_string()), 3);
+        assert_eq!(lengths.apply(String::new()), 0);
+    }
+
+    #[test]
+    fn test_box_or_else_value() {
+        let grade = BoxTransformer::new(|score: u32| format!("score {}", score))
+            .when(|score: &u32| *score <= 100)
+            .or_else_value("invalid".to_string());
+        assert_eq!(grade.apply(90), "score 90");
+        assert_eq!(grade.apply(101), "invalid");
+        assert_eq!(grade.apply(500), "invalid");
+    }
+
+    #[test]
+    fn test_box_or_else_supply_only_called_on_else_branch() {
+        let calls = Rc::new(Cell::new(0));
+        let c = calls.clone();
+        let double = BoxTransformer::new(|x: i32| x * 2)
+            .when(|x: &i32| *x > 0)
+            .or_else_supply(move || {
+                c.set(c.get() + 1);
+                -1
+            });
+        assert_eq!(double.apply(5), 10);
+        assert_eq!(double.apply(7), 14);
+        assert_eq!(calls.get(), 0);
+        assert_eq!(double.apply(-5), -1);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_box_or_else_supply_with_box_supplier() {
+        let mut next = 0;
+        let ids = BoxSupplier::new(move || {
+            next += 1;
+            next
+        });
+        let keep_or_assign = BoxTransformer::new(|id: u32| id)
+            .when(|id: &u32| *id != 0)
+            .or_else_supply(ids);
+        assert_eq!(keep_or_assign.apply(42), 42);
+        assert_eq!(keep_or_assign.apply(0), 1);
+        assert_eq!(keep_or_assign.apply(0), 2);
+    }
+
+    #[test]
+    fn test_arc_or_else_shortcuts() {
+        let double = ArcTransformer::new(|x: i32| x * 2);
+        let with_default = double.when(|x: &i32| *x > 0).or_else_default();
+        let with_value = double.when(|x: &i32| *x > 0).or_else_value(-1);
+        assert_eq!(with_default.apply(3), 6);
+        assert_eq!(with_default.apply(-3), 0);
+        assert_eq!(with_value.apply(3), 6);
+        assert_eq!(with_value.apply(-3), -1);
+    }
+
+    #[test]
+    fn test_arc_or_else_supply_across_threads() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let c = calls.clone();
+        let double = ArcTransformer::new(|x: i32| x * 2)
+            .when(|x: &i32| *x > 0)
+            .or_else_supply(move || c.fetch_add(1, Ordering::SeqCst) as i32 + 100);
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let d = double.clone();
+                std::thread::spawn(move || d.apply(if i % 2 == 0 { i + 1 } else { -i }))
+            })
+            .collect();
+        let mut results: Vec<i32> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        results.sort();
+        assert_eq!(&results[..2], &[2, 6]);
+        assert!(results[2] >= 100 && results[3] >= 100);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_arc_or_else_supply_survives_panicking_supplier() {
+        let mut next = 0;
+        let double = ArcTransformer::new(|x: i32| x * 2)
+            .when(|x: &i32| *x > 0)
+            .or_else_supply(move || {
+                next += 1;
+                assert!(next != 1, "first fallback fails");
+                next
+            });
+        let clone = double.clone();
+        assert!(std::thread::spawn(move || clone.apply(-1)).join().is_err());
+        assert_eq!(double.apply(-1), 2);
+        assert_eq!(double.clone().apply(-1), 3);
+        assert_eq!(double.apply(4), 8);
+    }
+
+    #[test]
+    fn test_rc_or_else_shortcuts() {
+        let calls = Rc::new(Cell::new(0));
+        let c = calls.clone();
+        let double = RcTransformer::new(|x: i32| x * 2);
+        let with_default = double.when(|x: &i32| *x > 0).or_else_default();
+        let with_value = double.when(|x: &i32| *x > 0).or_else_value(7);
+        let with_supply = double.when(|x: &i32| *x > 0).or_else_supply(move || {
+            c.set(c.get() + 1);
+            c.get()
+        });
+        assert_eq!(with_default.apply(-1), 0);
+        assert_eq!(with_value.apply(-1), 7);
+        assert_eq!(with_supply.apply(4), 8);
+        assert_eq!(calls.get(), 0);
+        assert_eq!(with_supply.apply(-4), 1);
+        assert_eq!(with_supply.clone().apply(-4), 2);
+    }
+}
+
+#[cfg(test)]
+mod retry_tests {
+    use prism3_function::{BoxTransformer, Mapper};
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::time::{Duration, Instant};
+
+    type Flaky = BoxTransformer<i32, Result<i32, String>>;
+
+    fn failing_until(successful_call: usize) -> (Flaky, Rc<Cell<usize>>) {
+        let calls = Rc::new(Cell::new(0));
+        let c = calls.clone();
+        let transformer = BoxTransformer::new(move |x: i32| {
+            c.set(c.get() + 1);
+            if c.get() < successful_call {
+                Err(format!("attempt {}", c.get()))
+            } else {
+                Ok(x + 1)
+            }
+        });
+        (transformer, calls)
+    }
+
+    #[test]
+    fn test_retry_returns_first_success() {
+        let (transformer, calls) = failing_until(3);
+        let mut retried = transformer.retry(5);
+        assert_eq!(retried.apply(1), Ok(2));
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn test_retry_stops_on_immediate_success() {
+        let (transformer, calls) = failing_until(1);
+        let mut retried = transformer.retry(5);
+        assert_eq!(retried.apply(1), Ok(2));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_retry_returns_last_error() {
+        let (transformer, calls) = failing_until(10);
+        let mut retried = transformer.retry(3);
+        assert_eq!(retried.apply(1), Err("attempt 3".to_string()));
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn test_retry_zero_attempts_calls_once() {
+        let (transformer, calls) = failing_until(10);
+        let mut retried = transformer.retry(0);
+        assert_eq!(retried.apply(1), Err("attempt 1".to_string()));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_retry_with_backoff_doubles_delay() {
+        let (transformer, calls) = failing_until(10);
+        let mut retried = transformer.retry_with_backoff(3, 10);
+        let start = Instant::now();
+        assert_eq!(retried.apply(1), Err("attempt 3".to_string()));
+        assert!(start.elapsed() >= Duration::from_millis(30));
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn test_retry_with_backoff_skips_delay_after_success() {
+        let (transformer, calls) = failing_until(2);
+        let mut retried = transformer.retry_with_backoff(4, 5);
+        let start = Instant::now();
+        assert_eq!(retried.apply(1), Ok(2));
+        assert!(start.elapsed() >= Duration::from_millis(5));
+        assert_eq!(calls.get(), 2);
+    }
+}
+
+#[cfg(test)]
+mod validate_tests {
+    use prism3_function::{BoxConsumer, BoxPredicate, BoxTransformer, Transformer};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_validate_routes_invalid_outputs() {
+        let (on_invalid, rejected) = BoxConsumer::collect();
+        let checked =
+            BoxTransformer::new(|x: i32| x * 10).validate(|x: &i32| *x <= 100, on_invalid);
+        assert_eq!(checked.apply(5), Ok(50));
+        assert_eq!(checked.apply(20), Err(200));
+        assert_eq!(checked.apply(10), Ok(100));
+        assert_eq!(checked.apply(11), Err(110));
+        assert_eq!(*rejected.lock().unwrap(), vec![200, 110]);
+    }
+
+    #[test]
+    fn test_validate_with_stateful_consumer() {
+        let mut count = 0;
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let s = seen.clone();
+        let checked = BoxTransformer::new(|s: &str| s.trim().to_string()).validate(
+            BoxPredicate::new(|s: &String| !s.is_empty()),
+            move |_: &String| {
+                count += 1;
+                s.borrow_mut().push(count);
+            },
+        );
+        assert_eq!(checked.apply(" a "), Ok("a".to_string()));
+        assert_eq!(checked.apply("   "), Err(String::new()));
+        assert_eq!(checked.apply(""), Err(String::new()));
+        assert_eq!(*seen.borrow(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_validate_composes_with_and_then() {
+        let (on_invalid, _) = BoxConsumer::collect();
+        let pipeline = BoxTransformer::new(|x: i32| x - 1)
+            .validate(|x: &i32| *x > 0, on_invalid)
+            .and_then(|r: Result<i32, i32>| r.unwrap_or_else(|e| -e));
+        assert_eq!(pipeline.apply(5), 4);
+        assert_eq!(pipeline.apply(-1), 2);
+    }
+
+    #[test]
+    fn test_validate_or_default() {
+        let clamped =
+            BoxTransformer::new(|x: f64| x.sqrt()).validate_or_default(|x: &f64| !x.is_nan(), 0.0);
+        assert_eq!(clamped.apply(16.0), 4.0);
+        assert_eq!(clamped.apply(-1.0), 0.0);
+    }
+
+    #[test]
+    fn test_validate_or_default_clones_default() {
+        let names = BoxTransformer::new(|s: String| s.to_lowercase()).validate_or_default(
+            |s: &String| s.chars().all(char::is_alphabetic),
+            "unknown".to_string(),
+        );
+        assert_eq!(names.apply("Alice".to_string()), "alice");
+        assert_eq!(names.apply("b0b".to_string()), "unknown");
+        assert_eq!(names.apply("c@rl".to_string()), "unknown");
+    }
+}
+
+// ============================================================================
+// Contract Tests
+// ============================================================================
+
+#[cfg(test)]
+mod contract_tests {
+    use prism3_function::{BoxTransformer, Transformer};
+    use std::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    fn checked_div() -> BoxTransformer<(i32, i32), i32> {
+        BoxTransformer::new(|(a, b): (i32, i32)| a / b).require(
+            |p: &(i32, i32)| p.1 != 0,
+            |p: &(i32, i32)| format!("{} / 0", p.0),
+        )
+    }
+
+    #[test]
+    fn test_require_passes_valid_input() {
+        assert_eq!(checked_div().apply((7, 2)), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "pre-condition violated: 7 / 0")]
+    fn test_require_panics_with_message() {
+        checked_div().apply((7, 0));
+    }
+
+    #[test]
+    fn test_require_checks_before_call() {
+        let calls = Rc::new(Cell::new(0));
+        let c = calls.clone();
+        let t = BoxTransformer::new(move |x: i32| {
+            c.set(c.get() + 1);
+            x
+        })
+        .require(|x: &i32| *x > 0, |x: &i32| x.to_string());
+        assert!(catch_unwind(AssertUnwindSafe(|| t.apply(-1))).is_err());
+        assert_eq!(calls.get(), 0);
+        assert_eq!(t.apply(1), 1);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "post-condition violated: result 6 is not odd")]
+    fn test_ensure_panics_with_message() {
+        let t = BoxTransformer::new(|x: i32| x * 2).ensure(
+            |r: &i32| r % 2 == 1,
+            |r: &i32| format!("result {} is not odd", r),
+        );
+        t.apply(3);
+    }
+
+    #[test]
+    fn test_require_and_ensure_compose() {
+        let len = BoxTransformer::new(|s: String| s.len())
+            .require(|s: &String| !s.is_empty(), |_: &String| "empty".to_string())
+            .ensure(|n: &usize| *n <= 5, |n: &usize| format!("{} > 5", n));
+        assert_eq!(len.apply("abc".to_string()), 3);
+    }
+}
+
+// ============================================================================
+// Parallel Processing Tests (`rayon` feature)
+// ============================================================================
+
+#[cfg(feature = "rayon")]
+#[cfg(test)]
+mod apply_par_vec_tests {
+    use prism3_function::{ArcTransformer, Transformer};
+    use std::thread;
+
+    #[test]
+    fn test_preserves_order_and_matches_sequential() {
+        let transformer = ArcTransformer::new(|x: u64| x * x + 1);
+        let inputs: Vec<u64> = (0..10_000).collect();
+        let expected: Vec<u64> = inputs.iter().map(|x| transformer.apply(*x)).collect();
+        assert_eq!(transformer.apply_par_vec(inputs), expected);
+    }
+
+    #[test]
+    fn test_empty_vec() {
+        let transformer = ArcTransformer::new(|s: String| s.len());
+        assert!(transformer.apply_par_vec(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn test_non_clone_inputs_and_outputs() {
+        struct Input(String);
+        struct Output(usize);
+        let transformer = ArcTransformer::new(|input: Input| Output(input.0.len()));
+        let outputs = transformer.apply_par_vec(vec![Input("ab".into()), Input("cde".into())]);
+        let lengths: Vec<usize> = outputs.into_iter().map(|o| o.0).collect();
+        assert_eq!(lengths, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_shared_clone_used_from_another_thread() {
+        let transformer = ArcTransformer::new(|x: i32| x - 1);
+        let clone = transformer.clone();
+        let handle = thread::spawn(move || clone.apply_par_vec(vec![1, 2, 3]));
+        assert_eq!(transformer.apply_par_vec(vec![10]), vec![9]);
+        assert_eq!(handle.join().unwrap(), vec![0, 1, 2]);
+    }
+}
+
+// ============================================================================
+// UnaryOperator to Mutator Conversion Tests
+// ============================================================================
+
+#[cfg(test)]
+mod into_mutator_tests {
+    use prism3_function::{BoxUnaryOperator, Mutator};
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Counter {
+        hits: u32,
+        label: String,
+    }
+
+    #[test]
+    fn test_string() {
+        let mut mutator = BoxUnaryOperator::new(|s: String| s + "!").into_mutator();
+        let mut value = "hi".to_string();
+        mutator.mutate(&mut value);
+        mutator.mutate(&mut value);
+        assert_eq!(value, "hi!!");
+    }
+
+    #[test]
+    fn test_custom_default_struct() {
+        let mut mutator = BoxUnaryOperator::new(|c: Counter| Counter {
+            hits: c.hits + 1,
+            label: format!("{}+", c.label),
+        })
+        .into_mutator();
+        let mut value = Counter {
+            hits: 1,
+            label: "a".to_string(),
+        };
+        mutator.mutate(&mut value);
+        assert_eq!(
+            value,
+            Counter {
+                hits: 2,
+                label: "a+".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_panic_leaves_default() {
+        let mut mutator = BoxUnaryOperator::new(|s: String| -> String {
+            panic!("rejected {}", s);
+        })
+        .into_mutator();
+        let mut value = "keep".to_string();
+        assert!(catch_unwind(AssertUnwindSafe(|| mutator.mutate(&mut value))).is_err());
+        assert_eq!(value, String::default());
+    }
+}
+
+// ============================================================================
+// Lifting Tests
+// ============================================================================
+
+#[cfg(test)]
+mod lift_tests {
+    use prism3_function::{ArcTransformer, BoxTransformer, RcTransformer, Transformer};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_box_lift_option() {
+        let calls = Rc::new(Cell::new(0));
+        let c = calls.clone();
+        let len = BoxTransformer::new(move |s: String| {
+            c.set(c.get() + 1);
+            s.len()
+        })
+        .lift_option();
+        assert_eq!(len.apply(Some("abc".to_string())), Some(3));
+        assert_eq!(len.apply(None), None);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_box_lift_ok() {
+        let calls = Rc::new(Cell::new(0));
+        let c = calls.clone();
+        let parse = BoxTransformer::new(move |s: &str| {
+            c.set(c.get() + 1);
+            s.len()
+        })
+        .lift_ok::<i32>();
+        assert_eq!(parse.apply(Ok("ab")), Ok(2));
+        assert_eq!(parse.apply(Err(-1)), Err(-1));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_arc_lift_keeps_original_and_crosses_threads() {
+        let double = ArcTransformer::new(|x: i32| x * 2);
+        let options = double.lift_option();
+        let results = double.lift_ok::<String>();
+        let handle = std::thread::spawn(move || (options.apply(Some(4)), options.apply(None)));
+        assert_eq!(handle.join().unwrap(), (Some(8), None));
+        assert_eq!(results.apply(Ok(5)), Ok(10));
+        assert_eq!(results.apply(Err("e".to_string())), Err("e".to_string()));
+        assert_eq!(double.apply(1), 2);
+    }
+
+    #[test]
+    fn test_rc_lift() {
+        let negate = RcTransformer::new(|x: i32| -x);
+        assert_eq!(negate.lift_option().apply(Some(3)), Some(-3));
+        assert_eq!(negate.lift_option().apply(None), None);
+        assert_eq!(negate.lift_ok::<()>().apply(Ok(3)), Ok(-3));
+        assert_eq!(negate.lift_ok::<()>().apply(Err(())), Err(()));
+        assert_eq!(negate.apply(1), -1);
+    }
+}
+
+// ============================================================================
+// Result Filtering Tests
+// ============================================================================
+
+#[cfg(test)]
+mod filter_result_tests {
+    use prism3_function::{BoxTransformer, Transformer};
+
+    /// A value that cannot be cloned, so the predicate can only borrow it
+    #[derive(Debug, PartialEq)]
+    struct Reading(u32);
+
+    fn sensor() -> BoxTransformer<u32, Option<Reading>> {
+        BoxTransformer::new(|raw: u32| Reading(raw * 10)).filter_result(|r: &Reading| r.0 <= 100)
+    }
+
+    #[test]
+    fn test_passing_output_is_kept() {
+        assert_eq!(sensor().apply(7), Some(Reading(70)));
+    }
+
+    #[test]
+    fn test_failing_output_is_dropped() {
+        assert_eq!(sensor().apply(11), None);
+    }
+
+    #[test]
+    fn test_predicate_sees_computed_value_by_reference() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        /// Non-Clone output counting how many instances were dropped
+        struct Tracked {
+            value: usize,
+            drops: Rc<Cell<usize>>,
+        }
+        impl Drop for Tracked {
+            fn drop(&mut self) {
+                self.drops.set(self.drops.get() + 1);
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let d = drops.clone();
+        let checked = Rc::new(Cell::new(0));
+        let c = checked.clone();
+        let keep_even = BoxTransformer::new(move |n: usize| Tracked {
+            value: n,
+            drops: d.clone(),
+        })
+        .filter_result(move |t: &Tracked| {
+            c.set(c.get() + 1);
+            t.value % 2 == 0
+        });
+
+        let output = keep_even.apply(4).unwrap();
+        assert_eq!(output.value, 4);
+        assert_eq!(checked.get(), 1);
+        assert_eq!(drops.get(), 0);
+        drop(output);
+        assert_eq!(drops.get(), 1);
+
+        assert!(keep_even.apply(3).is_none());
+        assert_eq!(checked.get(), 2);
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    fn test_composes_with_lift_option() {
+        let pipeline = BoxTransformer::new(|x: i32| x - 3)
+            .filter_result(|r: &i32| *r >= 0)
+            .and_then(BoxTransformer::new(|r: i32| r * 2).lift_option());
+        assert_eq!(pipeline.apply(5), Some(4));
+        assert_eq!(pipeline.apply(1), None);
+    }
+}
@@ -0,0 +1,278 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! Unit tests for TriConsumer types
+
+use prism3_function::{
+    ArcTriConsumer, BiConsumer, BoxTriConsumer, FnTriConsumerOps, RcTriConsumer, TriConsumer,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// ============================================================================
+// BoxTriConsumer Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_box_tri_consumer {
+    use super::*;
+
+    #[test]
+    fn test_new_and_accept() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let mut consumer = BoxTriConsumer::new(move |x: &i32, y: &i32, z: &i32| {
+            l.borrow_mut().push(x + y + z);
+        });
+        consumer.accept(&1, &2, &3);
+        consumer.accept(&10, &20, &30);
+        assert_eq!(*log.borrow(), vec![6, 60]);
+    }
+
+    #[test]
+    fn test_noop() {
+        let mut consumer = BoxTriConsumer::<i32, i32, i32>::noop();
+        consumer.accept(&1, &2, &3);
+    }
+
+    #[test]
+    fn test_from_bi_consumer() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let mut consumer = BoxTriConsumer::from_bi_consumer(move |x: &i32, y: &i32| {
+            l.borrow_mut().push(x * y);
+        });
+        consumer.accept(&6, &7, &"ignored");
+        assert_eq!(*log.borrow(), vec![42]);
+    }
+
+    #[test]
+    fn test_and_then() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (l1, l2) = (log.clone(), log.clone());
+        let mut chained = BoxTriConsumer::new(move |x: &i32, _: &i32, _: &i32| {
+            l1.borrow_mut().push(*x);
+        })
+        .and_then(move |_: &i32, _: &i32, z: &i32| {
+            l2.borrow_mut().push(*z);
+        });
+        chained.accept(&1, &2, &3);
+        assert_eq!(*log.borrow(), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_bind_third() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let tagged = BoxTriConsumer::new(move |k: &&str, v: &i32, tag: &String| {
+            l.borrow_mut().push(format!("{}:{}={}", tag, k, v));
+        });
+        let mut audit = tagged.bind_third("audit".to_string());
+        audit.accept(&"x", &1);
+        audit.accept(&"y", &2);
+        assert_eq!(*log.borrow(), vec!["audit:x=1", "audit:y=2"]);
+    }
+
+    #[test]
+    fn test_into_rc() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let consumer = BoxTriConsumer::new(move |x: &i32, y: &i32, z: &i32| {
+            l.borrow_mut().push(x * y * z);
+        });
+        let mut rc = consumer.into_rc();
+        rc.accept(&2, &3, &4);
+        assert_eq!(*log.borrow(), vec![24]);
+    }
+
+    #[test]
+    fn test_into_fn() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let consumer = BoxTriConsumer::new(move |x: &i32, y: &i32, z: &i32| {
+            l.borrow_mut().push(x - y - z);
+        });
+        let mut f = consumer.into_fn();
+        f(&10, &3, &2);
+        assert_eq!(*log.borrow(), vec![5]);
+    }
+}
+
+// ============================================================================
+// ArcTriConsumer Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_arc_tri_consumer {
+    use super::*;
+
+    #[test]
+    fn test_clone_shares_state() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l = log.clone();
+        let mut consumer = ArcTriConsumer::new(move |x: &i32, y: &i32, z: &i32| {
+            l.lock().unwrap().push(x + y + z);
+        });
+        let mut clone = consumer.clone();
+        consumer.accept(&1, &2, &3);
+        clone.accept(&4, &5, &6);
+        assert_eq!(*log.lock().unwrap(), vec![6, 15]);
+    }
+
+    #[test]
+    fn test_thread_safety() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l = log.clone();
+        let consumer = ArcTriConsumer::new(move |x: &i32, y: &i32, z: &i32| {
+            l.lock().unwrap().push(x * y * z);
+        });
+        let handles: Vec<_> = (1..=3)
+            .map(|i| {
+                let mut c = consumer.clone();
+                thread::spawn(move || c.accept(&i, &i, &i))
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        let mut values = log.lock().unwrap().clone();
+        values.sort();
+        assert_eq!(values, vec![1, 8, 27]);
+    }
+
+    #[test]
+    fn test_survives_panicking_consumer() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l = log.clone();
+        let consumer = ArcTriConsumer::new(move |x: &i32, y: &i32, z: &i32| {
+            assert!(*x >= 0, "negative input");
+            l.lock().unwrap().push(x + y + z);
+        });
+        let mut c = consumer.clone();
+        assert!(thread::spawn(move || c.accept(&-1, &0, &0)).join().is_err());
+
+        let mut consumer = consumer;
+        consumer.accept(&1, &2, &3);
+        assert_eq!(*log.lock().unwrap(), vec![6]);
+    }
+
+    #[test]
+    fn test_and_then_keeps_originals() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let (l1, l2) = (log.clone(), log.clone());
+        let first = ArcTriConsumer::new(move |x: &i32, _: &i32, _: &i32| {
+            l1.lock().unwrap().push(*x);
+        });
+        let second = ArcTriConsumer::new(move |_: &i32, y: &i32, _: &i32| {
+            l2.lock().unwrap().push(*y);
+        });
+        let mut chained = first.and_then(&second);
+        chained.accept(&1, &2, &3);
+        let mut first = first;
+        first.accept(&7, &8, &9);
+        assert_eq!(*log.lock().unwrap(), vec![1, 2, 7]);
+    }
+
+    #[test]
+    fn test_noop() {
+        let mut consumer = ArcTriConsumer::<i32, i32, i32>::noop();
+        consumer.accept(&1, &2, &3);
+    }
+}
+
+// ============================================================================
+// RcTriConsumer Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_rc_tri_consumer {
+    use super::*;
+
+    #[test]
+    fn test_clone_shares_state() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let mut consumer = RcTriConsumer::new(move |x: &i32, y: &i32, z: &i32| {
+            l.borrow_mut().push(x + y + z);
+        });
+        let mut clone = consumer.clone();
+        consumer.accept(&1, &1, &1);
+        clone.accept(&2, &2, &2);
+        assert_eq!(*log.borrow(), vec![3, 6]);
+    }
+
+    #[test]
+    fn test_and_then() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (l1, l2) = (log.clone(), log.clone());
+        let first = RcTriConsumer::new(move |x: &i32, _: &i32, _: &i32| {
+            l1.borrow_mut().push(*x);
+        });
+        let second = RcTriConsumer::new(move |_: &i32, _: &i32, z: &i32| {
+            l2.borrow_mut().push(*z);
+        });
+        let mut chained = first.and_then(&second);
+        chained.accept(&1, &2, &3);
+        assert_eq!(*log.borrow(), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_to_box() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let consumer = RcTriConsumer::new(move |x: &i32, _: &i32, _: &i32| {
+            l.borrow_mut().push(*x);
+        });
+        let mut boxed = consumer.to_box();
+        boxed.accept(&5, &0, &0);
+        let mut consumer = consumer;
+        consumer.accept(&6, &0, &0);
+        assert_eq!(*log.borrow(), vec![5, 6]);
+    }
+}
+
+// ============================================================================
+// Closure Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_closure_tri_consumer {
+    use super::*;
+
+    #[test]
+    fn test_closure_accept() {
+        let mut total = 0;
+        let mut add = |x: &i32, y: &i32, z: &i32| total += x + y + z;
+        add.accept(&1, &2, &3);
+        add.accept(&4, &5, &6);
+        assert_eq!(total, 21);
+    }
+
+    #[test]
+    fn test_fn_tri_consumer_ops_and_then() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (l1, l2) = (log.clone(), log.clone());
+        let mut chained = (move |x: &i32, _: &i32, _: &i32| l1.borrow_mut().push(*x))
+            .and_then(move |_: &i32, y: &i32, _: &i32| l2.borrow_mut().push(*y));
+        chained.accept(&1, &2, &3);
+        assert_eq!(*log.borrow(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_bind_third_round_trip_with_bi_consumer() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let l = log.clone();
+        let closure = move |x: &i32, y: &i32, z: &i32| l.borrow_mut().push(x + y + z);
+        let mut bi = closure.into_box().bind_third(100);
+        bi.accept(&1, &2);
+        assert_eq!(*log.borrow(), vec![103]);
+    }
+}
@@ -0,0 +1,125 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! Unit tests for TriTransformer types
+
+use prism3_function::{
+    ArcTransformer, ArcTriTransformer, BiTransformer, BoxTransformer, BoxTriTransformer,
+    FnTriTransformerOps, RcTriTransformer, TriTransformer,
+};
+use std::thread;
+
+#[test]
+fn test_box_tri_transformer_apply() {
+    let clamp = BoxTriTransformer::new(|x: i32, lo: i32, hi: i32| x.max(lo).min(hi));
+    assert_eq!(clamp.apply(42, 0, 10), 10);
+    assert_eq!(clamp.apply(-3, 0, 10), 0);
+    assert_eq!(clamp.apply(7, 0, 10), 7);
+}
+
+#[test]
+fn test_box_tri_transformer_consumes_owned_inputs() {
+    let join = BoxTriTransformer::new(|a: String, b: String, c: String| a + &b + &c);
+    assert_eq!(
+        join.apply("a".to_string(), "b".to_string(), "c".to_string()),
+        "abc"
+    );
+}
+
+#[test]
+fn test_box_tri_transformer_and_then() {
+    let sum = BoxTriTransformer::new(|x: i32, y: i32, z: i32| x + y + z);
+    let composed = sum.and_then(BoxTransformer::new(|s: i32| s.to_string()));
+    assert_eq!(composed.apply(1, 2, 3), "6");
+}
+
+#[test]
+fn test_box_tri_transformer_from_bi_transformer() {
+    let add = BoxTriTransformer::from_bi_transformer(|x: i32, y: i32| x + y);
+    assert_eq!(add.apply(20, 22, ()), 42);
+}
+
+#[test]
+fn test_box_tri_transformer_bind_third() {
+    let mul_add = BoxTriTransformer::new(|a: i32, b: i32, c: i32| a * b + c);
+    let bi = mul_add.bind_third(1);
+    assert_eq!(bi.apply(6, 7), 43);
+    assert_eq!(bi.apply(0, 7), 1);
+}
+
+#[test]
+fn test_box_tri_transformer_into_rc_and_fn() {
+    let sum = BoxTriTransformer::new(|x: i32, y: i32, z: i32| x + y + z);
+    let rc = sum.into_rc();
+    assert_eq!(rc.apply(1, 1, 1), 3);
+    let f = rc.into_fn();
+    assert_eq!(f(2, 2, 2), 6);
+}
+
+#[test]
+fn test_arc_tri_transformer_thread_safety() {
+    let sum = ArcTriTransformer::new(|x: i32, y: i32, z: i32| x + y + z);
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let s = sum.clone();
+            thread::spawn(move || s.apply(i, i, i))
+        })
+        .collect();
+    let results: Vec<i32> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    assert_eq!(results, vec![0, 3, 6, 9]);
+}
+
+#[test]
+fn test_arc_tri_transformer_and_then_keeps_original() {
+    let sum = ArcTriTransformer::new(|x: i32, y: i32, z: i32| x + y + z);
+    let doubled = sum.and_then(ArcTransformer::new(|s: i32| s * 2));
+    assert_eq!(doubled.apply(1, 2, 3), 12);
+    assert_eq!(sum.apply(1, 2, 3), 6);
+}
+
+#[test]
+fn test_arc_tri_transformer_conversions() {
+    let sum = ArcTriTransformer::new(|x: i32, y: i32, z: i32| x + y + z);
+    assert_eq!(sum.to_box().apply(1, 2, 3), 6);
+    assert_eq!(sum.to_rc().apply(1, 2, 3), 6);
+    assert_eq!(sum.to_arc().apply(1, 2, 3), 6);
+    assert_eq!((sum.to_fn())(1, 2, 3), 6);
+}
+
+#[test]
+fn test_rc_tri_transformer_and_then() {
+    let volume = RcTriTransformer::new(|w: u32, h: u32, d: u32| w * h * d);
+    let label = volume.and_then(|v: u32| format!("{} m3", v));
+    assert_eq!(label.apply(2, 3, 4), "24 m3");
+    assert_eq!(volume.clone().apply(1, 2, 3), 6);
+}
+
+#[test]
+fn test_closure_tri_transformer() {
+    let mul_add = |a: i32, b: i32, c: i32| a * b + c;
+    assert_eq!(mul_add.apply(6, 7, 0), 42);
+    assert_eq!(mul_add.into_box().apply(1, 2, 3), 5);
+}
+
+#[test]
+fn test_closure_tri_transformer_into_arc() {
+    let sum = (|x: i32, y: i32, z: i32| x + y + z).into_arc();
+    let clone = sum.clone();
+    assert_eq!(
+        thread::spawn(move || clone.apply(1, 2, 3)).join().unwrap(),
+        6
+    );
+}
+
+#[test]
+fn test_fn_tri_transformer_ops_and_then() {
+    let sum = |x: i32, y: i32, z: i32| x + y + z;
+    let composed = sum.and_then(|s: i32| s * 2);
+    assert_eq!(composed.apply(1, 2, 3), 12);
+}
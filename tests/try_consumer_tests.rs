@@ -0,0 +1,269 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! Unit tests for TryConsumer types
+
+use prism3_function::{
+    ArcConsumer, ArcTryConsumer, BoxConsumer, BoxTryConsumer, Consumer, RcTryConsumer, TryConsumer,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+fn stage(
+    log: &Rc<RefCell<Vec<String>>>,
+    name: &'static str,
+    fails: bool,
+) -> impl FnMut(&i32) -> Result<(), String> {
+    let log = log.clone();
+    move |x: &i32| {
+        log.borrow_mut().push(format!("{}{}", name, x));
+        if fails {
+            Err(format!("{} failed", name))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// ============================================================================
+// BoxTryConsumer Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_box_try_consumer {
+    use super::*;
+
+    #[test]
+    fn test_new_and_try_accept() {
+        let mut even = BoxTryConsumer::new(|x: &i32| if x % 2 == 0 { Ok(()) } else { Err(*x) });
+        assert_eq!(even.try_accept(&2), Ok(()));
+        assert_eq!(even.try_accept(&3), Err(3));
+    }
+
+    #[test]
+    fn test_and_then_runs_all_stages_on_success() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut chain = BoxTryConsumer::new(stage(&log, "a", false))
+            .and_then(stage(&log, "b", false))
+            .and_then(stage(&log, "c", false));
+        assert_eq!(chain.try_accept(&1), Ok(()));
+        assert_eq!(*log.borrow(), vec!["a1", "b1", "c1"]);
+    }
+
+    #[test]
+    fn test_and_then_stops_at_failing_middle_stage() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut chain = BoxTryConsumer::new(stage(&log, "a", false))
+            .and_then(stage(&log, "b", true))
+            .and_then(stage(&log, "c", false));
+        assert_eq!(chain.try_accept(&1), Err("b failed".to_string()));
+        assert_eq!(*log.borrow(), vec!["a1", "b1"]);
+    }
+
+    #[test]
+    fn test_from_consumer_always_succeeds() {
+        let (consumer, values) = BoxConsumer::collect();
+        let mut fallible = BoxTryConsumer::<i32, String>::from_consumer(consumer);
+        assert_eq!(fallible.try_accept(&1), Ok(()));
+        assert_eq!(fallible.try_accept(&2), Ok(()));
+        assert_eq!(*values.lock().unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_or_log_routes_errors_to_handler() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let errors = Rc::new(RefCell::new(Vec::new()));
+        let e = errors.clone();
+        let mut consumer = BoxTryConsumer::new(stage(&log, "a", false))
+            .and_then(stage(&log, "b", true))
+            .or_log(move |err: &String| e.borrow_mut().push(err.clone()));
+        consumer.accept(&1);
+        consumer.accept(&2);
+        assert_eq!(*log.borrow(), vec!["a1", "b1", "a2", "b2"]);
+        assert_eq!(*errors.borrow(), vec!["b failed", "b failed"]);
+    }
+
+    #[test]
+    fn test_or_log_skips_handler_on_success() {
+        let (handler, count) = BoxConsumer::<String>::count();
+        let mut consumer = BoxTryConsumer::new(|_: &i32| Ok(())).or_log(handler);
+        consumer.accept(&1);
+        assert_eq!(count.load(std::sync::atomic::Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_into_rc_and_into_fn() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut rc = BoxTryConsumer::new(stage(&log, "a", false)).into_rc();
+        assert_eq!(rc.try_accept(&1), Ok(()));
+        let mut f = BoxTryConsumer::new(stage(&log, "b", true)).into_fn();
+        assert_eq!(f(&2), Err("b failed".to_string()));
+        assert_eq!(*log.borrow(), vec!["a1", "b2"]);
+    }
+
+    #[test]
+    fn test_debug() {
+        let consumer = BoxTryConsumer::<i32, ()>::new(|_| Ok(()));
+        assert!(format!("{:?}", consumer).contains("BoxTryConsumer"));
+    }
+}
+
+// ============================================================================
+// ArcTryConsumer Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_arc_try_consumer {
+    use super::*;
+
+    fn arc_stage(
+        log: &Arc<Mutex<Vec<String>>>,
+        name: &'static str,
+        fails: bool,
+    ) -> ArcTryConsumer<i32, String> {
+        let log = log.clone();
+        ArcTryConsumer::new(move |x: &i32| {
+            log.lock().unwrap().push(format!("{}{}", name, x));
+            if fails {
+                Err(format!("{} failed", name))
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    #[test]
+    fn test_and_then_stops_at_failing_middle_stage() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let first = arc_stage(&log, "a", false);
+        let second = arc_stage(&log, "b", true);
+        let third = arc_stage(&log, "c", false);
+        let mut chain = first.and_then(&second).and_then(&third);
+        assert_eq!(chain.try_accept(&1), Err("b failed".to_string()));
+        assert_eq!(*log.lock().unwrap(), vec!["a1", "b1"]);
+
+        // The stages remain usable on their own
+        let mut third = third;
+        assert_eq!(third.try_accept(&2), Ok(()));
+        assert_eq!(*log.lock().unwrap(), vec!["a1", "b1", "c2"]);
+    }
+
+    #[test]
+    fn test_thread_safety() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let consumer = arc_stage(&log, "a", false);
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let mut c = consumer.clone();
+                thread::spawn(move || c.try_accept(&i))
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), Ok(()));
+        }
+        assert_eq!(log.lock().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_survives_panicking_stage() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let l = log.clone();
+        let consumer = ArcTryConsumer::<i32, String>::new(move |x: &i32| {
+            assert!(*x >= 0, "negative input");
+            l.lock().unwrap().push(*x);
+            Ok(())
+        });
+        let mut c = consumer.clone();
+        assert!(thread::spawn(move || c.try_accept(&-1)).join().is_err());
+
+        let mut consumer = consumer;
+        assert_eq!(consumer.try_accept(&2), Ok(()));
+        assert_eq!(*log.lock().unwrap(), vec![2]);
+    }
+
+    #[test]
+    fn test_or_log_and_from_consumer() {
+        let values = Arc::new(Mutex::new(Vec::new()));
+        let v = values.clone();
+        let collect =
+            ArcTryConsumer::<i32, String>::from_consumer(ArcConsumer::new(move |x: &i32| {
+                v.lock().unwrap().push(*x)
+            }));
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let e = errors.clone();
+        let mut consumer = collect
+            .and_then(&arc_stage(&log, "b", true))
+            .or_log(move |err: &String| e.lock().unwrap().push(err.clone()));
+        consumer.accept(&7);
+        assert_eq!(*values.lock().unwrap(), vec![7]);
+        assert_eq!(*errors.lock().unwrap(), vec!["b failed"]);
+    }
+}
+
+// ============================================================================
+// RcTryConsumer Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_rc_try_consumer {
+    use super::*;
+
+    #[test]
+    fn test_and_then_stops_at_failing_middle_stage() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let first = RcTryConsumer::new(stage(&log, "a", false));
+        let second = RcTryConsumer::new(stage(&log, "b", true));
+        let third = RcTryConsumer::new(stage(&log, "c", false));
+        let mut chain = first.and_then(&second).and_then(&third);
+        assert_eq!(chain.try_accept(&1), Err("b failed".to_string()));
+        assert_eq!(*log.borrow(), vec!["a1", "b1"]);
+    }
+
+    #[test]
+    fn test_clones_share_state() {
+        let mut remaining = 2;
+        let consumer = RcTryConsumer::new(move |_: &i32| {
+            if remaining == 0 {
+                return Err("exhausted");
+            }
+            remaining -= 1;
+            Ok(())
+        });
+        let mut a = consumer.clone();
+        let mut b = consumer.clone();
+        assert_eq!(a.try_accept(&1), Ok(()));
+        assert_eq!(b.try_accept(&2), Ok(()));
+        assert_eq!(a.try_accept(&3), Err("exhausted"));
+    }
+
+    #[test]
+    fn test_or_log_keeps_original_usable() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let fallible = RcTryConsumer::new(stage(&log, "a", true));
+        let errors = Rc::new(RefCell::new(Vec::new()));
+        let e = errors.clone();
+        let mut infallible = fallible.or_log(move |err: &String| e.borrow_mut().push(err.clone()));
+        infallible.accept(&1);
+        let mut fallible = fallible;
+        assert_eq!(fallible.try_accept(&2), Err("a failed".to_string()));
+        assert_eq!(*errors.borrow(), vec!["a failed"]);
+        assert_eq!(*log.borrow(), vec!["a1", "a2"]);
+    }
+
+    #[test]
+    fn test_from_consumer() {
+        let (consumer, values) = BoxConsumer::collect();
+        let mut fallible = RcTryConsumer::<i32, ()>::from_consumer(consumer);
+        assert_eq!(fallible.try_accept(&5), Ok(()));
+        assert_eq!(*values.lock().unwrap(), vec![5]);
+    }
+}
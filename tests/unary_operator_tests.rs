@@ -8,8 +8,8 @@
  ******************************************************************************/
 
 use prism3_function::{
-    ArcUnaryOperator, BoxUnaryOperator, BoxUnaryOperatorOnce, RcUnaryOperator, Transformer,
-    TransformerOnce, UnaryOperator,
+    ArcUnaryOperator, BoxUnaryOperator, BoxUnaryOperatorOnce, FnUnaryOperatorOnceOps,
+    RcUnaryOperator, Transformer, TransformerOnce, UnaryOperator,
 };
 use std::thread;
 
@@ -60,3 +60,70 @@ fn test_box_unary_operator_once() {
     let double: BoxUnaryOperatorOnce<i32> = BoxUnaryOperatorOnce::new(|x| x * 2);
     assert_eq!(double.apply_once(21), 42);
 }
+
+/// A value that is deliberately neither `Clone` nor `Copy`
+#[derive(Debug, PartialEq)]
+struct Token(Vec<String>);
+
+#[test]
+fn test_box_unary_operator_once_three_stage_chain() {
+    let suffix = String::from("c");
+    let chain = BoxUnaryOperatorOnce::new(|mut t: Token| {
+        t.0.push("a".to_string());
+        t
+    })
+    .and_then_once(|mut t: Token| {
+        t.0.push("b".to_string());
+        t
+    })
+    .and_then_once(move |mut t: Token| {
+        t.0.push(suffix);
+        t
+    });
+    assert_eq!(
+        chain.apply_once(Token(Vec::new())),
+        Token(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+    );
+}
+
+#[test]
+fn test_box_unary_operator_once_compose_once() {
+    let push = |s: &'static str| {
+        move |mut t: Token| {
+            t.0.push(s.to_string());
+            t
+        }
+    };
+    let chain = BoxUnaryOperatorOnce::new(push("last"))
+        .compose_once(push("middle"))
+        .compose_once(push("first"));
+    assert_eq!(
+        chain.apply_once(Token(Vec::new())),
+        Token(vec![
+            "first".to_string(),
+            "middle".to_string(),
+            "last".to_string()
+        ])
+    );
+}
+
+#[test]
+fn test_fn_unary_operator_once_ops() {
+    let owned = Token(vec!["moved".to_string()]);
+    let chain = (move |mut t: Token| {
+        t.0.extend(owned.0);
+        t
+    })
+    .and_then_once(|mut t: Token| {
+        t.0.reverse();
+        t
+    })
+    .compose_once(|mut t: Token| {
+        t.0.push("start".to_string());
+        t
+    });
+    assert_eq!(
+        chain.apply_once(Token(Vec::new())),
+        Token(vec!["moved".to_string(), "start".to_string()])
+    );
+}
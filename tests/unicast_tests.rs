@@ -0,0 +1,89 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! Unit tests for UnicastConsumer
+
+use prism3_function::{BoxConsumer, Consumer, UnicastConsumer};
+use std::sync::{Arc, Mutex};
+
+// ============================================================================
+// UnicastConsumer Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test_unicast_consumer {
+    use super::*;
+
+    fn recorder(log: &Arc<Mutex<Vec<String>>>, tag: &'static str) -> BoxConsumer<i32> {
+        let log = log.clone();
+        BoxConsumer::new(move |x: &i32| log.lock().unwrap().push(format!("{}{}", tag, x)))
+    }
+
+    #[test]
+    fn test_accept_without_recipient_is_noop() {
+        let mut unicast = UnicastConsumer::<i32>::new();
+        assert!(!unicast.is_registered());
+        unicast.accept(&1);
+        assert!(unicast.deregister().is_none());
+    }
+
+    #[test]
+    fn test_register_delivers_to_recipient() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut unicast = UnicastConsumer::new();
+        assert!(unicast.register(recorder(&log, "a")).is_none());
+        assert!(unicast.is_registered());
+        unicast.accept(&1);
+        unicast.accept(&2);
+        assert_eq!(*log.lock().unwrap(), vec!["a1", "a2"]);
+    }
+
+    #[test]
+    fn test_register_replaces_and_returns_previous() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut unicast = UnicastConsumer::default();
+        unicast.register(recorder(&log, "a"));
+        unicast.accept(&1);
+        let mut previous = unicast.register(recorder(&log, "b")).unwrap();
+        unicast.accept(&2);
+        previous.accept(&3);
+        assert_eq!(*log.lock().unwrap(), vec!["a1", "b2", "a3"]);
+    }
+
+    #[test]
+    fn test_deregister_stops_delivery() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut unicast = UnicastConsumer::new();
+        unicast.register(recorder(&log, "a"));
+        unicast.accept(&1);
+        let mut removed = unicast.deregister().unwrap();
+        unicast.accept(&2);
+        removed.accept(&3);
+        assert!(!unicast.is_registered());
+        assert_eq!(*log.lock().unwrap(), vec!["a1", "a3"]);
+    }
+
+    #[test]
+    fn test_used_as_generic_consumer() {
+        fn feed<C: Consumer<i32>>(consumer: &mut C) {
+            for i in 0..3 {
+                consumer.accept(&i);
+            }
+        }
+        let (counter, values) = BoxConsumer::collect();
+        let mut unicast = UnicastConsumer::new();
+        unicast.register(counter);
+        feed(&mut unicast);
+        assert_eq!(*values.lock().unwrap(), vec![0, 1, 2]);
+        assert_eq!(
+            format!("{:?}", unicast),
+            "UnicastConsumer { registered: true }"
+        );
+    }
+}